@@ -0,0 +1,400 @@
+use std::{
+    cmp::Ordering,
+    fmt::{Display, Formatter},
+    ops::{Add, Mul, Neg, Sub},
+};
+
+// An arbitrary-precision integer, for the bignum integer width. The
+// magnitude is in base 2^32 with the least significant digit first and no
+// zero digits on the end, so every number has exactly one representation
+// and zero is an empty, non-negative magnitude
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct BigInt {
+    negative: bool,
+    magnitude: Vec<u32>,
+}
+
+impl BigInt {
+    fn new(negative: bool, mut magnitude: Vec<u32>) -> Self {
+        while magnitude.last() == Some(&0) {
+            magnitude.pop();
+        }
+        Self {
+            negative: negative && !magnitude.is_empty(),
+            magnitude,
+        }
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.magnitude.is_empty()
+    }
+
+    pub fn abs(&self) -> Self {
+        Self::new(false, self.magnitude.clone())
+    }
+
+    pub fn to_i128(&self) -> Option<i128> {
+        if self.magnitude.len() > 4 {
+            return None;
+        }
+        let magnitude = self
+            .magnitude
+            .iter()
+            .rev()
+            .fold(0u128, |acc, digit| acc << 32 | *digit as u128);
+        if self.negative {
+            0i128.checked_sub_unsigned(magnitude)
+        } else {
+            i128::try_from(magnitude).ok()
+        }
+    }
+
+    // Like parsing an i128: an optional sign and then at least one digit
+    pub fn parse(s: &str) -> Option<Self> {
+        let (negative, digits) = match s.strip_prefix('-') {
+            Some(digits) => (true, digits),
+            None => (false, s.strip_prefix('+').unwrap_or(s)),
+        };
+        if digits.is_empty() {
+            return None;
+        }
+        let mut magnitude = Vec::new();
+        for c in digits.chars() {
+            let digit = c.to_digit(10)?;
+            mul_add_small(&mut magnitude, 10, digit);
+        }
+        Some(Self::new(negative, magnitude))
+    }
+
+    // Truncates towards zero, and None for NaN and the infinities
+    pub fn from_f64(f: f64) -> Option<Self> {
+        if !f.is_finite() {
+            return None;
+        }
+        let bits = f.to_bits();
+        let exponent = ((bits >> 52) & 0x7ff) as i32;
+        if exponent == 0 {
+            // Zero or subnormal, either way less than 1
+            return Some(Self::from(0));
+        }
+        let mantissa = (bits & ((1 << 52) - 1)) | (1 << 52);
+        let shift = exponent - 1075;
+        let magnitude = if shift < 0 {
+            Self::from((mantissa.checked_shr(-shift as u32).unwrap_or(0)) as i128)
+        } else {
+            Self::from(mantissa as i128).shl(shift as usize)
+        };
+        Some(Self::new(f < 0.0, magnitude.magnitude))
+    }
+
+    pub fn to_f64(&self) -> f64 {
+        let magnitude = self
+            .magnitude
+            .iter()
+            .rev()
+            .fold(0.0, |acc, digit| acc * 4294967296.0 + *digit as f64);
+        if self.negative {
+            -magnitude
+        } else {
+            magnitude
+        }
+    }
+
+    fn shl(&self, bits: usize) -> Self {
+        let mut magnitude = vec![0; bits / 32];
+        let shift = bits % 32;
+        let mut carry = 0;
+        for digit in &self.magnitude {
+            let shifted = (*digit as u64) << shift | carry;
+            magnitude.push(shifted as u32);
+            carry = shifted >> 32;
+        }
+        magnitude.push(carry as u32);
+        Self::new(self.negative, magnitude)
+    }
+
+    // Truncates towards zero like i128 division, so the remainder has the
+    // sign of self. None when dividing by zero
+    pub fn div_rem(&self, divisor: &Self) -> Option<(Self, Self)> {
+        if divisor.is_zero() {
+            return None;
+        }
+        let mut quotient = vec![0; self.magnitude.len()];
+        let mut remainder = Vec::new();
+        for i in (0..self.magnitude.len() * 32).rev() {
+            mul_add_small(&mut remainder, 2, self.magnitude[i / 32] >> (i % 32) & 1);
+            if compare_magnitudes(&remainder, &divisor.magnitude) != Ordering::Less {
+                remainder = sub_magnitudes(&remainder, &divisor.magnitude);
+                quotient[i / 32] |= 1 << (i % 32);
+            }
+        }
+        Some((
+            Self::new(self.negative != divisor.negative, quotient),
+            Self::new(self.negative, remainder),
+        ))
+    }
+}
+
+impl From<i128> for BigInt {
+    fn from(n: i128) -> Self {
+        let mut magnitude = n.unsigned_abs();
+        let mut digits = Vec::new();
+        while magnitude != 0 {
+            digits.push(magnitude as u32);
+            magnitude >>= 32;
+        }
+        Self::new(n < 0, digits)
+    }
+}
+
+// magnitude * factor + addend, in place
+fn mul_add_small(magnitude: &mut Vec<u32>, factor: u32, addend: u32) {
+    let mut carry = addend as u64;
+    for digit in magnitude.iter_mut() {
+        let product = *digit as u64 * factor as u64 + carry;
+        *digit = product as u32;
+        carry = product >> 32;
+    }
+    if carry != 0 {
+        magnitude.push(carry as u32);
+    }
+}
+
+fn compare_magnitudes(a: &[u32], b: &[u32]) -> Ordering {
+    // Neither has zero digits on the end, so the longer one is bigger
+    a.len()
+        .cmp(&b.len())
+        .then_with(|| a.iter().rev().cmp(b.iter().rev()))
+}
+
+fn add_magnitudes(a: &[u32], b: &[u32]) -> Vec<u32> {
+    let mut sum = Vec::with_capacity(a.len().max(b.len()) + 1);
+    let mut carry = 0;
+    for i in 0..a.len().max(b.len()) {
+        let digit = *a.get(i).unwrap_or(&0) as u64 + *b.get(i).unwrap_or(&0) as u64 + carry;
+        sum.push(digit as u32);
+        carry = digit >> 32;
+    }
+    sum.push(carry as u32);
+    sum
+}
+
+// a - b, where a is at least as big as b
+fn sub_magnitudes(a: &[u32], b: &[u32]) -> Vec<u32> {
+    let mut difference = Vec::with_capacity(a.len());
+    let mut borrow = 0;
+    for (i, digit) in a.iter().enumerate() {
+        let (digit, under) = digit.overflowing_sub(*b.get(i).unwrap_or(&0));
+        let (digit, borrowed) = digit.overflowing_sub(borrow);
+        difference.push(digit);
+        borrow = (under || borrowed) as u32;
+    }
+    while difference.last() == Some(&0) {
+        difference.pop();
+    }
+    difference
+}
+
+impl Add for &BigInt {
+    type Output = BigInt;
+
+    fn add(self, other: &BigInt) -> BigInt {
+        if self.negative == other.negative {
+            return BigInt::new(
+                self.negative,
+                add_magnitudes(&self.magnitude, &other.magnitude),
+            );
+        }
+        // Opposite signs, so the smaller magnitude comes off the bigger one
+        match compare_magnitudes(&self.magnitude, &other.magnitude) {
+            Ordering::Less => BigInt::new(
+                other.negative,
+                sub_magnitudes(&other.magnitude, &self.magnitude),
+            ),
+            _ => BigInt::new(
+                self.negative,
+                sub_magnitudes(&self.magnitude, &other.magnitude),
+            ),
+        }
+    }
+}
+
+impl Sub for &BigInt {
+    type Output = BigInt;
+
+    fn sub(self, other: &BigInt) -> BigInt {
+        self + &-other
+    }
+}
+
+impl Mul for &BigInt {
+    type Output = BigInt;
+
+    fn mul(self, other: &BigInt) -> BigInt {
+        let mut product = vec![0u32; self.magnitude.len() + other.magnitude.len()];
+        for (i, a) in self.magnitude.iter().enumerate() {
+            let mut carry = 0;
+            for (j, b) in other.magnitude.iter().enumerate() {
+                let digit = product[i + j] as u64 + *a as u64 * *b as u64 + carry;
+                product[i + j] = digit as u32;
+                carry = digit >> 32;
+            }
+            product[i + other.magnitude.len()] = carry as u32;
+        }
+        BigInt::new(self.negative != other.negative, product)
+    }
+}
+
+impl Neg for &BigInt {
+    type Output = BigInt;
+
+    fn neg(self) -> BigInt {
+        BigInt::new(!self.negative, self.magnitude.clone())
+    }
+}
+
+impl PartialOrd for BigInt {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for BigInt {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self.negative, other.negative) {
+            (false, true) => Ordering::Greater,
+            (true, false) => Ordering::Less,
+            (false, false) => compare_magnitudes(&self.magnitude, &other.magnitude),
+            (true, true) => compare_magnitudes(&other.magnitude, &self.magnitude),
+        }
+    }
+}
+
+impl Display for BigInt {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        if self.is_zero() {
+            return write!(f, "0");
+        }
+        // Peel off nine decimal digits at a time, least significant first
+        let mut magnitude = self.magnitude.clone();
+        let mut chunks = Vec::new();
+        while !magnitude.is_empty() {
+            let mut remainder = 0u64;
+            for digit in magnitude.iter_mut().rev() {
+                let value = remainder << 32 | *digit as u64;
+                *digit = (value / 1_000_000_000) as u32;
+                remainder = value % 1_000_000_000;
+            }
+            while magnitude.last() == Some(&0) {
+                magnitude.pop();
+            }
+            chunks.push(remainder);
+        }
+        if self.negative {
+            write!(f, "-")?;
+        }
+        let mut chunks = chunks.iter().rev();
+        write!(f, "{}", chunks.next().unwrap())?;
+        for chunk in chunks {
+            write!(f, "{chunk:09}")?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn big(s: &str) -> BigInt {
+        BigInt::parse(s).unwrap()
+    }
+
+    #[test]
+    fn i128_values_round_trip() {
+        for n in [0, 1, -1, u32::MAX as i128 + 1, i128::MAX, i128::MIN] {
+            assert_eq!(BigInt::from(n).to_i128(), Some(n));
+            assert_eq!(BigInt::from(n).to_string(), n.to_string());
+            assert_eq!(big(&n.to_string()), BigInt::from(n));
+        }
+        assert_eq!(
+            (&BigInt::from(i128::MAX) + &BigInt::from(1)).to_i128(),
+            None
+        );
+        assert_eq!(
+            (&BigInt::from(i128::MIN) - &BigInt::from(1)).to_i128(),
+            None
+        );
+    }
+
+    #[test]
+    fn arithmetic_goes_past_i128() {
+        let max = BigInt::from(i128::MAX);
+        assert_eq!(
+            (&max + &BigInt::from(1)).to_string(),
+            "170141183460469231731687303715884105728"
+        );
+        assert_eq!(
+            (&max * &max).to_string(),
+            "28948022309329048855892746252171976962977213799489202546401021394546514198529"
+        );
+        assert_eq!(&(&max * &max) - &(&max * &max), BigInt::from(0));
+        assert_eq!(
+            (&BigInt::from(-5) * &max).to_string(),
+            "-850705917302346158658436518579420528635"
+        );
+    }
+
+    #[test]
+    fn division_truncates_towards_zero() {
+        for (a, b) in [(7, 2), (-7, 2), (7, -2), (-7, -2), (0, 3), (i128::MIN, 7)] {
+            let (quotient, remainder) = BigInt::from(a).div_rem(&BigInt::from(b)).unwrap();
+            assert_eq!(quotient.to_i128(), Some(a / b));
+            assert_eq!(remainder.to_i128(), Some(a % b));
+        }
+        let square = &BigInt::from(i128::MAX) * &BigInt::from(i128::MAX);
+        assert_eq!(
+            square.div_rem(&BigInt::from(i128::MAX)),
+            Some((BigInt::from(i128::MAX), BigInt::from(0)))
+        );
+        assert_eq!(BigInt::from(1).div_rem(&BigInt::from(0)), None);
+    }
+
+    #[test]
+    fn parsing_takes_a_sign_and_digits() {
+        assert_eq!(big("+12"), BigInt::from(12));
+        assert_eq!(big("-0"), BigInt::from(0));
+        for bad in ["", "-", "1.5", " 1", "1_000"] {
+            assert_eq!(BigInt::parse(bad), None);
+        }
+    }
+
+    #[test]
+    fn ordering_follows_the_sign() {
+        let mut numbers = vec![BigInt::from(1), BigInt::from(i128::MIN)];
+        numbers.push(&BigInt::from(i128::MIN) - &BigInt::from(1));
+        numbers.push(&BigInt::from(i128::MAX) + &BigInt::from(1));
+        numbers.sort();
+        assert_eq!(
+            numbers.iter().map(BigInt::to_string).collect::<Vec<_>>(),
+            [
+                "-170141183460469231731687303715884105729",
+                "-170141183460469231731687303715884105728",
+                "1",
+                "170141183460469231731687303715884105728"
+            ]
+        );
+    }
+
+    #[test]
+    fn floats_convert_both_ways() {
+        assert_eq!(BigInt::from_f64(-2.9), Some(BigInt::from(-2)));
+        assert_eq!(BigInt::from_f64(0.5), Some(BigInt::from(0)));
+        assert_eq!(BigInt::from_f64(f64::NAN), None);
+        assert_eq!(BigInt::from_f64(1e40).unwrap().to_f64(), 1e40);
+        assert_eq!(
+            BigInt::from_f64(2f64.powi(130)),
+            Some(BigInt::from(1).shl(130))
+        );
+    }
+}
@@ -1,104 +1,3621 @@
 use {
-    crate::interpreter::{BuiltinFn, FnType, Value, ValueType},
-    std::collections::HashMap,
+    crate::{
+        bigint::BigInt,
+        interpreter::{
+            call_value, eval_source, get_valuetype_from, int_arithmetic, parse_source, BuiltinFn,
+            ComposedFn, Context, FnType, IntArithmetic, MemoizedFn, PartialFn, RangeIter,
+            RuntimeError, Scope, UserFn, Value, ValueType,
+        },
+        normalize::{normalize, Form},
+        parser::Operator,
+        regex::Regex,
+        symbol::Symbol,
+        width::char_width,
+    },
+    indexmap::IndexMap,
+    serde::{
+        ser::{self, SerializeMap, SerializeSeq},
+        Deserialize, Serialize, Serializer,
+    },
+    std::{
+        cell::RefCell,
+        cmp::Ordering,
+        collections::HashMap,
+        io::{Read, Write},
+        rc::Rc,
+        time::Duration,
+    },
 };
 
-pub fn make_builtins(scope: &mut HashMap<String, Box<Value>>) {
+pub fn make_builtins(scope: &mut Scope) {
     scope.insert(
-        "print".to_string(),
+        Symbol::intern("print"),
         Box::new(Value::Fn(FnType::Builtin(BuiltinFn {
             name: "print".to_string(),
             return_type: ValueType::Nothing,
         }))),
     );
     scope.insert(
-        "println".to_string(),
+        Symbol::intern("println"),
         Box::new(Value::Fn(FnType::Builtin(BuiltinFn {
             name: "println".to_string(),
             return_type: ValueType::Nothing,
         }))),
     );
     scope.insert(
-        "max".to_string(),
+        Symbol::intern("max"),
         Box::new(Value::Fn(FnType::Builtin(BuiltinFn {
             name: "max".to_string(),
             return_type: ValueType::Int,
         }))),
     );
     scope.insert(
-        "min".to_string(),
+        Symbol::intern("min"),
         Box::new(Value::Fn(FnType::Builtin(BuiltinFn {
             name: "min".to_string(),
             return_type: ValueType::Int,
         }))),
     );
-}
-
-pub fn call_builtin(name: &str, args: Vec<Value>) -> Value {
-    match name {
-        "print" => {
-            if args.len() > 1 {
-                for arg in &args {
-                    if arg == &args[args.len() - 1] {
-                        print!("{arg}");
-                    } else {
-                        print!("{arg}, ");
-                    }
-                }
-            } else {
-                print!("{}", args[0]);
-            }
-            Value::Nothing
-        }
-        "println" => {
-            if args.len() > 1 {
-                for arg in &args {
-                    if arg == &args[args.len() - 1] {
-                        print!("{arg}");
-                    } else {
-                        print!("{arg}, ");
-                    }
-                }
-                println!();
-            } else {
-                println!("{}", args[0]);
-            }
-            Value::Nothing
-        }
-        "max" => match args[0] {
-            Value::Int(a) => {
-                let mut max = a;
-                for arg in &args[1..] {
-                    match arg {
-                        Value::Int(b) => {
-                            if b > &max {
-                                max = *b;
-                            }
-                        }
-                        _ => panic!("max takes only ints"),
-                    }
-                }
-                Value::Int(max)
-            }
-            _ => panic!("max takes only ints"),
-        },
-        "min" => match args[0] {
-            Value::Int(a) => {
-                let mut min = a;
-                for arg in &args[1..] {
-                    match arg {
-                        Value::Int(b) => {
-                            if b < &min {
-                                min = *b;
-                            }
-                        }
-                        _ => panic!("min takes only ints"),
-                    }
-                }
-                Value::Int(min)
-            }
-            _ => panic!("min takes only ints"),
-        },
-        _ => panic!("Not a function"),
+    scope.insert(
+        Symbol::intern("index_of"),
+        Box::new(Value::Fn(FnType::Builtin(BuiltinFn {
+            name: "index_of".to_string(),
+            return_type: ValueType::Int,
+        }))),
+    );
+    scope.insert(
+        Symbol::intern("concat"),
+        Box::new(Value::Fn(FnType::Builtin(BuiltinFn {
+            name: "concat".to_string(),
+            return_type: ValueType::Array,
+        }))),
+    );
+    scope.insert(
+        Symbol::intern("get"),
+        Box::new(Value::Fn(FnType::Builtin(BuiltinFn {
+            name: "get".to_string(),
+            return_type: ValueType::Any,
+        }))),
+    );
+    scope.insert(
+        Symbol::intern("time"),
+        Box::new(Value::Fn(FnType::Builtin(BuiltinFn {
+            name: "time".to_string(),
+            return_type: ValueType::Any,
+        }))),
+    );
+    scope.insert(
+        Symbol::intern("bool"),
+        Box::new(Value::Fn(FnType::Builtin(BuiltinFn {
+            name: "bool".to_string(),
+            return_type: ValueType::Bool,
+        }))),
+    );
+    scope.insert(
+        Symbol::intern("max_by"),
+        Box::new(Value::Fn(FnType::Builtin(BuiltinFn {
+            name: "max_by".to_string(),
+            return_type: ValueType::Any,
+        }))),
+    );
+    scope.insert(
+        Symbol::intern("min_by"),
+        Box::new(Value::Fn(FnType::Builtin(BuiltinFn {
+            name: "min_by".to_string(),
+            return_type: ValueType::Any,
+        }))),
+    );
+    scope.insert(
+        Symbol::intern("reduce"),
+        Box::new(Value::Fn(FnType::Builtin(BuiltinFn {
+            name: "reduce".to_string(),
+            return_type: ValueType::Any,
+        }))),
+    );
+    scope.insert(
+        Symbol::intern("enumerate"),
+        Box::new(Value::Fn(FnType::Builtin(BuiltinFn {
+            name: "enumerate".to_string(),
+            return_type: ValueType::Array,
+        }))),
+    );
+    scope.insert(
+        Symbol::intern("zip"),
+        Box::new(Value::Fn(FnType::Builtin(BuiltinFn {
+            name: "zip".to_string(),
+            return_type: ValueType::Array,
+        }))),
+    );
+    scope.insert(
+        Symbol::intern("copy"),
+        Box::new(Value::Fn(FnType::Builtin(BuiltinFn {
+            name: "copy".to_string(),
+            return_type: ValueType::Any,
+        }))),
+    );
+    scope.insert(
+        Symbol::intern("is_empty"),
+        Box::new(Value::Fn(FnType::Builtin(BuiltinFn {
+            name: "is_empty".to_string(),
+            return_type: ValueType::Bool,
+        }))),
+    );
+    scope.insert(
+        Symbol::intern("sum"),
+        Box::new(Value::Fn(FnType::Builtin(BuiltinFn {
+            name: "sum".to_string(),
+            return_type: ValueType::Any,
+        }))),
+    );
+    scope.insert(
+        Symbol::intern("product"),
+        Box::new(Value::Fn(FnType::Builtin(BuiltinFn {
+            name: "product".to_string(),
+            return_type: ValueType::Any,
+        }))),
+    );
+    scope.insert(
+        Symbol::intern("floor"),
+        Box::new(Value::Fn(FnType::Builtin(BuiltinFn {
+            name: "floor".to_string(),
+            return_type: ValueType::Int,
+        }))),
+    );
+    scope.insert(
+        Symbol::intern("ceil"),
+        Box::new(Value::Fn(FnType::Builtin(BuiltinFn {
+            name: "ceil".to_string(),
+            return_type: ValueType::Int,
+        }))),
+    );
+    scope.insert(
+        Symbol::intern("round"),
+        Box::new(Value::Fn(FnType::Builtin(BuiltinFn {
+            name: "round".to_string(),
+            return_type: ValueType::Int,
+        }))),
+    );
+    scope.insert(
+        Symbol::intern("exp"),
+        Box::new(Value::Fn(FnType::Builtin(BuiltinFn {
+            name: "exp".to_string(),
+            return_type: ValueType::Float,
+        }))),
+    );
+    scope.insert(
+        Symbol::intern("ln"),
+        Box::new(Value::Fn(FnType::Builtin(BuiltinFn {
+            name: "ln".to_string(),
+            return_type: ValueType::Float,
+        }))),
+    );
+    scope.insert(
+        Symbol::intern("log"),
+        Box::new(Value::Fn(FnType::Builtin(BuiltinFn {
+            name: "log".to_string(),
+            return_type: ValueType::Float,
+        }))),
+    );
+    scope.insert(
+        Symbol::intern("sin"),
+        Box::new(Value::Fn(FnType::Builtin(BuiltinFn {
+            name: "sin".to_string(),
+            return_type: ValueType::Float,
+        }))),
+    );
+    scope.insert(
+        Symbol::intern("cos"),
+        Box::new(Value::Fn(FnType::Builtin(BuiltinFn {
+            name: "cos".to_string(),
+            return_type: ValueType::Float,
+        }))),
+    );
+    scope.insert(
+        Symbol::intern("tan"),
+        Box::new(Value::Fn(FnType::Builtin(BuiltinFn {
+            name: "tan".to_string(),
+            return_type: ValueType::Float,
+        }))),
+    );
+    scope.insert(
+        Symbol::intern("pi"),
+        Box::new(Value::Fn(FnType::Builtin(BuiltinFn {
+            name: "pi".to_string(),
+            return_type: ValueType::Float,
+        }))),
+    );
+    scope.insert(
+        Symbol::intern("gcd"),
+        Box::new(Value::Fn(FnType::Builtin(BuiltinFn {
+            name: "gcd".to_string(),
+            return_type: ValueType::Int,
+        }))),
+    );
+    scope.insert(
+        Symbol::intern("lcm"),
+        Box::new(Value::Fn(FnType::Builtin(BuiltinFn {
+            name: "lcm".to_string(),
+            return_type: ValueType::Int,
+        }))),
+    );
+    scope.insert(
+        Symbol::intern("to_map"),
+        Box::new(Value::Fn(FnType::Builtin(BuiltinFn {
+            name: "to_map".to_string(),
+            return_type: ValueType::Map,
+        }))),
+    );
+    scope.insert(
+        Symbol::intern("to_pairs"),
+        Box::new(Value::Fn(FnType::Builtin(BuiltinFn {
+            name: "to_pairs".to_string(),
+            return_type: ValueType::Array,
+        }))),
+    );
+    scope.insert(
+        Symbol::intern("read_all"),
+        Box::new(Value::Fn(FnType::Builtin(BuiltinFn {
+            name: "read_all".to_string(),
+            return_type: ValueType::String,
+        }))),
+    );
+    scope.insert(
+        Symbol::intern("pad_left"),
+        Box::new(Value::Fn(FnType::Builtin(BuiltinFn {
+            name: "pad_left".to_string(),
+            return_type: ValueType::String,
+        }))),
+    );
+    scope.insert(
+        Symbol::intern("pad_right"),
+        Box::new(Value::Fn(FnType::Builtin(BuiltinFn {
+            name: "pad_right".to_string(),
+            return_type: ValueType::String,
+        }))),
+    );
+    scope.insert(
+        Symbol::intern("env"),
+        Box::new(Value::Fn(FnType::Builtin(BuiltinFn {
+            name: "env".to_string(),
+            return_type: ValueType::Any,
+        }))),
+    );
+    scope.insert(
+        Symbol::intern("abs"),
+        Box::new(Value::Fn(FnType::Builtin(BuiltinFn {
+            name: "abs".to_string(),
+            return_type: ValueType::Any,
+        }))),
+    );
+    scope.insert(
+        Symbol::intern("lines"),
+        Box::new(Value::Fn(FnType::Builtin(BuiltinFn {
+            name: "lines".to_string(),
+            return_type: ValueType::Array,
+        }))),
+    );
+    scope.insert(
+        Symbol::intern("int"),
+        Box::new(Value::Fn(FnType::Builtin(BuiltinFn {
+            name: "int".to_string(),
+            return_type: ValueType::Int,
+        }))),
+    );
+    scope.insert(
+        Symbol::intern("hash"),
+        Box::new(Value::Fn(FnType::Builtin(BuiltinFn {
+            name: "hash".to_string(),
+            return_type: ValueType::Int,
+        }))),
+    );
+    scope.insert(
+        Symbol::intern("is_number"),
+        Box::new(Value::Fn(FnType::Builtin(BuiltinFn {
+            name: "is_number".to_string(),
+            return_type: ValueType::Bool,
+        }))),
+    );
+    scope.insert(
+        Symbol::intern("is_finite"),
+        Box::new(Value::Fn(FnType::Builtin(BuiltinFn {
+            name: "is_finite".to_string(),
+            return_type: ValueType::Bool,
+        }))),
+    );
+    scope.insert(
+        Symbol::intern("substr"),
+        Box::new(Value::Fn(FnType::Builtin(BuiltinFn {
+            name: "substr".to_string(),
+            return_type: ValueType::String,
+        }))),
+    );
+    scope.insert(
+        Symbol::intern("title"),
+        Box::new(Value::Fn(FnType::Builtin(BuiltinFn {
+            name: "title".to_string(),
+            return_type: ValueType::String,
+        }))),
+    );
+    scope.insert(
+        Symbol::intern("matches_at"),
+        Box::new(Value::Fn(FnType::Builtin(BuiltinFn {
+            name: "matches_at".to_string(),
+            return_type: ValueType::Bool,
+        }))),
+    );
+    scope.insert(
+        Symbol::intern("eval"),
+        Box::new(Value::Fn(FnType::Builtin(BuiltinFn {
+            name: "eval".to_string(),
+            return_type: ValueType::Any,
+        }))),
+    );
+    scope.insert(
+        Symbol::intern("def"),
+        Box::new(Value::Fn(FnType::Builtin(BuiltinFn {
+            name: "def".to_string(),
+            return_type: ValueType::Nothing,
+        }))),
+    );
+    scope.insert(
+        Symbol::intern("format_int"),
+        Box::new(Value::Fn(FnType::Builtin(BuiltinFn {
+            name: "format_int".to_string(),
+            return_type: ValueType::String,
+        }))),
+    );
+    scope.insert(
+        Symbol::intern("arity"),
+        Box::new(Value::Fn(FnType::Builtin(BuiltinFn {
+            name: "arity".to_string(),
+            return_type: ValueType::Int,
+        }))),
+    );
+    scope.insert(
+        Symbol::intern("name_of"),
+        Box::new(Value::Fn(FnType::Builtin(BuiltinFn {
+            name: "name_of".to_string(),
+            return_type: ValueType::String,
+        }))),
+    );
+    scope.insert(
+        Symbol::intern("compare"),
+        Box::new(Value::Fn(FnType::Builtin(BuiltinFn {
+            name: "compare".to_string(),
+            return_type: ValueType::Int,
+        }))),
+    );
+    scope.insert(
+        Symbol::intern("error"),
+        Box::new(Value::Fn(FnType::Builtin(BuiltinFn {
+            name: "error".to_string(),
+            return_type: ValueType::Nothing,
+        }))),
+    );
+    scope.insert(
+        Symbol::intern("default"),
+        Box::new(Value::Fn(FnType::Builtin(BuiltinFn {
+            name: "default".to_string(),
+            return_type: ValueType::Any,
+        }))),
+    );
+    scope.insert(
+        Symbol::intern("flatten"),
+        Box::new(Value::Fn(FnType::Builtin(BuiltinFn {
+            name: "flatten".to_string(),
+            return_type: ValueType::Array,
+        }))),
+    );
+    scope.insert(
+        Symbol::intern("flatten_deep"),
+        Box::new(Value::Fn(FnType::Builtin(BuiltinFn {
+            name: "flatten_deep".to_string(),
+            return_type: ValueType::Array,
+        }))),
+    );
+    scope.insert(
+        Symbol::intern("unique"),
+        Box::new(Value::Fn(FnType::Builtin(BuiltinFn {
+            name: "unique".to_string(),
+            return_type: ValueType::Array,
+        }))),
+    );
+    scope.insert(
+        Symbol::intern("group_by"),
+        Box::new(Value::Fn(FnType::Builtin(BuiltinFn {
+            name: "group_by".to_string(),
+            return_type: ValueType::Map,
+        }))),
+    );
+    scope.insert(
+        Symbol::intern("mean"),
+        Box::new(Value::Fn(FnType::Builtin(BuiltinFn {
+            name: "mean".to_string(),
+            return_type: ValueType::Float,
+        }))),
+    );
+    scope.insert(
+        Symbol::intern("median"),
+        Box::new(Value::Fn(FnType::Builtin(BuiltinFn {
+            name: "median".to_string(),
+            return_type: ValueType::Float,
+        }))),
+    );
+    scope.insert(
+        Symbol::intern("mode"),
+        Box::new(Value::Fn(FnType::Builtin(BuiltinFn {
+            name: "mode".to_string(),
+            return_type: ValueType::Any,
+        }))),
+    );
+    scope.insert(
+        Symbol::intern("chunk"),
+        Box::new(Value::Fn(FnType::Builtin(BuiltinFn {
+            name: "chunk".to_string(),
+            return_type: ValueType::Array,
+        }))),
+    );
+    scope.insert(
+        Symbol::intern("divmod"),
+        Box::new(Value::Fn(FnType::Builtin(BuiltinFn {
+            name: "divmod".to_string(),
+            return_type: ValueType::Array,
+        }))),
+    );
+    scope.insert(
+        Symbol::intern("deep_equal"),
+        Box::new(Value::Fn(FnType::Builtin(BuiltinFn {
+            name: "deep_equal".to_string(),
+            return_type: ValueType::Bool,
+        }))),
+    );
+    scope.insert(
+        Symbol::intern("times"),
+        Box::new(Value::Fn(FnType::Builtin(BuiltinFn {
+            name: "times".to_string(),
+            return_type: ValueType::Array,
+        }))),
+    );
+    scope.insert(
+        Symbol::intern("concat_str"),
+        Box::new(Value::Fn(FnType::Builtin(BuiltinFn {
+            name: "concat_str".to_string(),
+            return_type: ValueType::String,
+        }))),
+    );
+    scope.insert(
+        Symbol::intern("table"),
+        Box::new(Value::Fn(FnType::Builtin(BuiltinFn {
+            name: "table".to_string(),
+            return_type: ValueType::String,
+        }))),
+    );
+    scope.insert(
+        Symbol::intern("memoize"),
+        Box::new(Value::Fn(FnType::Builtin(BuiltinFn {
+            name: "memoize".to_string(),
+            return_type: ValueType::Fn,
+        }))),
+    );
+    scope.insert(
+        Symbol::intern("partial"),
+        Box::new(Value::Fn(FnType::Builtin(BuiltinFn {
+            name: "partial".to_string(),
+            return_type: ValueType::Fn,
+        }))),
+    );
+    scope.insert(
+        Symbol::intern("compose"),
+        Box::new(Value::Fn(FnType::Builtin(BuiltinFn {
+            name: "compose".to_string(),
+            return_type: ValueType::Fn,
+        }))),
+    );
+    scope.insert(
+        Symbol::intern("map"),
+        Box::new(Value::Fn(FnType::Builtin(BuiltinFn {
+            name: "map".to_string(),
+            return_type: ValueType::Array,
+        }))),
+    );
+    scope.insert(
+        Symbol::intern("filter"),
+        Box::new(Value::Fn(FnType::Builtin(BuiltinFn {
+            name: "filter".to_string(),
+            return_type: ValueType::Array,
+        }))),
+    );
+    scope.insert(
+        Symbol::intern("range"),
+        Box::new(Value::Fn(FnType::Builtin(BuiltinFn {
+            name: "range".to_string(),
+            return_type: ValueType::Iterator,
+        }))),
+    );
+    scope.insert(
+        Symbol::intern("sleep"),
+        Box::new(Value::Fn(FnType::Builtin(BuiltinFn {
+            name: "sleep".to_string(),
+            return_type: ValueType::Nothing,
+        }))),
+    );
+    scope.insert(
+        Symbol::intern("parse_json"),
+        Box::new(Value::Fn(FnType::Builtin(BuiltinFn {
+            name: "parse_json".to_string(),
+            return_type: ValueType::Any,
+        }))),
+    );
+    scope.insert(
+        Symbol::intern("to_json"),
+        Box::new(Value::Fn(FnType::Builtin(BuiltinFn {
+            name: "to_json".to_string(),
+            return_type: ValueType::String,
+        }))),
+    );
+    scope.insert(
+        Symbol::intern("parse_kv"),
+        Box::new(Value::Fn(FnType::Builtin(BuiltinFn {
+            name: "parse_kv".to_string(),
+            return_type: ValueType::Map,
+        }))),
+    );
+    scope.insert(
+        Symbol::intern("url_encode"),
+        Box::new(Value::Fn(FnType::Builtin(BuiltinFn {
+            name: "url_encode".to_string(),
+            return_type: ValueType::String,
+        }))),
+    );
+    scope.insert(
+        Symbol::intern("url_decode"),
+        Box::new(Value::Fn(FnType::Builtin(BuiltinFn {
+            name: "url_decode".to_string(),
+            return_type: ValueType::String,
+        }))),
+    );
+    scope.insert(
+        Symbol::intern("base64_encode"),
+        Box::new(Value::Fn(FnType::Builtin(BuiltinFn {
+            name: "base64_encode".to_string(),
+            return_type: ValueType::String,
+        }))),
+    );
+    scope.insert(
+        Symbol::intern("base64_decode"),
+        Box::new(Value::Fn(FnType::Builtin(BuiltinFn {
+            name: "base64_decode".to_string(),
+            return_type: ValueType::String,
+        }))),
+    );
+    scope.insert(
+        Symbol::intern("regex_match"),
+        Box::new(Value::Fn(FnType::Builtin(BuiltinFn {
+            name: "regex_match".to_string(),
+            return_type: ValueType::Bool,
+        }))),
+    );
+    scope.insert(
+        Symbol::intern("regex_find_all"),
+        Box::new(Value::Fn(FnType::Builtin(BuiltinFn {
+            name: "regex_find_all".to_string(),
+            return_type: ValueType::Array,
+        }))),
+    );
+    scope.insert(
+        Symbol::intern("regex_capture"),
+        Box::new(Value::Fn(FnType::Builtin(BuiltinFn {
+            name: "regex_capture".to_string(),
+            return_type: ValueType::Any,
+        }))),
+    );
+    scope.insert(
+        Symbol::intern("profile"),
+        Box::new(Value::Fn(FnType::Builtin(BuiltinFn {
+            name: "profile".to_string(),
+            return_type: ValueType::Map,
+        }))),
+    );
+    scope.insert(
+        Symbol::intern("cumsum"),
+        Box::new(Value::Fn(FnType::Builtin(BuiltinFn {
+            name: "cumsum".to_string(),
+            return_type: ValueType::Array,
+        }))),
+    );
+    scope.insert(
+        Symbol::intern("cumprod"),
+        Box::new(Value::Fn(FnType::Builtin(BuiltinFn {
+            name: "cumprod".to_string(),
+            return_type: ValueType::Array,
+        }))),
+    );
+    scope.insert(
+        Symbol::intern("display_width"),
+        Box::new(Value::Fn(FnType::Builtin(BuiltinFn {
+            name: "display_width".to_string(),
+            return_type: ValueType::Int,
+        }))),
+    );
+    scope.insert(
+        Symbol::intern("normalize"),
+        Box::new(Value::Fn(FnType::Builtin(BuiltinFn {
+            name: "normalize".to_string(),
+            return_type: ValueType::String,
+        }))),
+    );
+    scope.insert(
+        Symbol::intern("wrap"),
+        Box::new(Value::Fn(FnType::Builtin(BuiltinFn {
+            name: "wrap".to_string(),
+            return_type: ValueType::Array,
+        }))),
+    );
+    scope.insert(
+        Symbol::intern("edit_distance"),
+        Box::new(Value::Fn(FnType::Builtin(BuiltinFn {
+            name: "edit_distance".to_string(),
+            return_type: ValueType::Int,
+        }))),
+    );
+}
+
+pub fn call_builtin(
+    name: &str,
+    args: Vec<Value>,
+    scope: &mut Scope,
+    ctx: &mut Context,
+) -> Result<Value, RuntimeError> {
+    Ok(match name {
+        "print" => {
+            if args.len() > 1 {
+                for arg in &args {
+                    if arg == &args[args.len() - 1] {
+                        write_output(ctx, &format!("{arg}"))?;
+                    } else {
+                        write_output(ctx, &format!("{arg}, "))?;
+                    }
+                }
+            } else {
+                write_output(ctx, &format!("{}", args[0]))?;
+            }
+            Value::Nothing
+        }
+        "println" => {
+            if args.len() > 1 {
+                for arg in &args {
+                    if arg == &args[args.len() - 1] {
+                        write_output(ctx, &format!("{arg}"))?;
+                    } else {
+                        write_output(ctx, &format!("{arg}, "))?;
+                    }
+                }
+                write_output(ctx, "\n")?;
+            } else {
+                write_output(ctx, &format!("{}\n", args[0]))?;
+            }
+            Value::Nothing
+        }
+        "max" => match args[0] {
+            Value::Int(a) => {
+                let mut max = a;
+                for arg in &args[1..] {
+                    match arg {
+                        Value::Int(b) => {
+                            if b > &max {
+                                max = *b;
+                            }
+                        }
+                        _ => panic!("max takes only ints"),
+                    }
+                }
+                Value::Int(max)
+            }
+            _ => panic!("max takes only ints"),
+        },
+        "min" => match args[0] {
+            Value::Int(a) => {
+                let mut min = a;
+                for arg in &args[1..] {
+                    match arg {
+                        Value::Int(b) => {
+                            if b < &min {
+                                min = *b;
+                            }
+                        }
+                        _ => panic!("min takes only ints"),
+                    }
+                }
+                Value::Int(min)
+            }
+            _ => panic!("min takes only ints"),
+        },
+        "index_of" => match (&args[0], &args[1]) {
+            (Value::String(haystack), Value::String(needle)) => {
+                match haystack.find(needle.as_str()) {
+                    // Report the position in chars rather than bytes
+                    Some(byte_index) => Value::Int(haystack[..byte_index].chars().count() as i128),
+                    None => Value::Int(-1),
+                }
+            }
+            (Value::Array(haystack), needle) => match haystack.iter().position(|v| v == needle) {
+                Some(index) => Value::Int(index as i128),
+                None => Value::Int(-1),
+            },
+            _ => panic!("index_of takes a string and a substring, or an array and a value"),
+        },
+        "concat" => {
+            let mut result = Vec::new();
+            for arg in args {
+                match arg {
+                    Value::Array(items) => result.extend(items),
+                    _ => panic!("concat takes only arrays"),
+                }
+            }
+            Value::Array(result)
+        }
+        "get" => match (&args[0], &args[1]) {
+            (Value::Map(map), Value::String(key)) => match map.get(key) {
+                Some(value) => value.clone(),
+                None => args[2].clone(),
+            },
+            _ => panic!("get takes a map, a string key and a default value"),
+        },
+        "time" => match &args[0] {
+            Value::Fn(FnType::User(UserFn { args, .. })) if !args.is_empty() => {
+                panic!("time takes a function with no arguments")
+            }
+            Value::Fn(func) => {
+                let start = ctx.clock.now();
+                let result = call_value(func, vec![], scope, ctx)?;
+                let elapsed = ctx.clock.now() - start;
+                write_output(ctx, &format!("{func} took {}ms\n", elapsed.as_millis()))?;
+                result
+            }
+            _ => panic!("time takes a function"),
+        },
+        "bool" => Value::Bool(args[0].is_truthy()),
+        "max_by" | "min_by" => match (&args[0], &args[1]) {
+            (Value::Array(items), Value::Fn(key_fn)) => {
+                if items.is_empty() {
+                    panic!("{name} takes a non-empty array");
+                }
+                let wanted = if name == "max_by" {
+                    Ordering::Greater
+                } else {
+                    Ordering::Less
+                };
+                let mut best = &items[0];
+                let mut best_key = call_value(key_fn, vec![best.clone()], scope, ctx)?;
+                // Checks the key type even when there's only one element
+                compare_keys(name, &best_key, &best_key);
+                for item in &items[1..] {
+                    let key = call_value(key_fn, vec![item.clone()], scope, ctx)?;
+                    // Only a strictly better key replaces the current one, so ties
+                    // keep the first occurrence
+                    if compare_keys(name, &key, &best_key) == wanted {
+                        best = item;
+                        best_key = key;
+                    }
+                }
+                best.clone()
+            }
+            _ => panic!("{name} takes an array and a function"),
+        },
+        "reduce" => match (args[0].items(), &args[1]) {
+            (Some(_), Value::Fn(FnType::User(UserFn { args, .. }))) if args.len() != 2 => {
+                panic!("reduce takes a function with two arguments")
+            }
+            (Some(items), Value::Fn(func)) => {
+                let mut acc = args[2].clone();
+                for item in items {
+                    acc = call_value(func, vec![acc, item], scope, ctx)?;
+                }
+                acc
+            }
+            _ => panic!("reduce takes an array or iterator, a function and an initial value"),
+        },
+        "enumerate" => match &args[0] {
+            Value::Array(items) => Value::Array(
+                items
+                    .iter()
+                    .enumerate()
+                    .map(|(i, item)| Value::Array(vec![Value::Int(i as i128), item.clone()]))
+                    .collect(),
+            ),
+            _ => panic!("enumerate takes an array"),
+        },
+        "zip" => match (&args[0], &args[1]) {
+            (Value::Array(a), Value::Array(b)) => Value::Array(
+                a.iter()
+                    .zip(b)
+                    .map(|(x, y)| Value::Array(vec![x.clone(), y.clone()]))
+                    .collect(),
+            ),
+            _ => panic!("zip takes two arrays"),
+        },
+        // Values own their contents, so a clone is already a deep copy
+        "copy" => args[0].clone(),
+        "is_empty" => match &args[0] {
+            Value::String(s) => Value::Bool(s.is_empty()),
+            Value::Array(items) => Value::Bool(items.is_empty()),
+            Value::Map(entries) => Value::Bool(entries.is_empty()),
+            _ => panic!("is_empty takes a string, an array or a map"),
+        },
+        "sum" | "product" => match &args[0] {
+            Value::Array(items) => {
+                let mut acc = if name == "sum" {
+                    Value::Int(0)
+                } else {
+                    Value::Int(1)
+                };
+                for item in items {
+                    acc = accumulate(name, name == "sum", acc, item, ctx)?;
+                }
+                acc
+            }
+            _ => panic!("{name} takes an array"),
+        },
+        // Running totals, so the last element is what sum or product would give
+        "cumsum" | "cumprod" => match &args[0] {
+            Value::Array(items) => {
+                let (mut acc, sum) = if name == "cumsum" {
+                    (Value::Int(0), true)
+                } else {
+                    (Value::Int(1), false)
+                };
+                let mut totals = Vec::with_capacity(items.len());
+                for item in items {
+                    acc = accumulate(name, sum, acc, item, ctx)?;
+                    totals.push(acc.clone());
+                }
+                Value::Array(totals)
+            }
+            _ => panic!("{name} takes an array"),
+        },
+        // These always return an int, and round takes halves away from zero so
+        // round(2.5) is 3 and round(-2.5) is -3. NaN, infinities and anything
+        // too big for the integer width are errors rather than saturating
+        "floor" | "ceil" | "round" => match &args[0] {
+            int @ (Value::Int(_) | Value::BigInt(_)) => int.clone(),
+            Value::Float(f) if f.is_nan() => {
+                return Err(RuntimeError::InvalidNumber {
+                    text: f.to_string(),
+                })
+            }
+            Value::Float(f) => {
+                let rounded = match name {
+                    "floor" => f.floor(),
+                    "ceil" => f.ceil(),
+                    _ => f.round(),
+                };
+                match BigInt::from_f64(rounded).and_then(|n| bounded(n, ctx)) {
+                    Some(rounded) => rounded,
+                    None => {
+                        return Err(RuntimeError::IntegerOverflow {
+                            name: name.to_string(),
+                        })
+                    }
+                }
+            }
+            _ => panic!("{name} takes a number"),
+        },
+        "exp" => Value::Float(as_float(name, &args[0]).exp()),
+        "ln" => match as_float(name, &args[0]) {
+            x if x <= 0.0 => return Err(math_domain(name, x)),
+            x => Value::Float(x.ln()),
+        },
+        "log" => match (as_float(name, &args[0]), as_float(name, &args[1])) {
+            (x, _) if x <= 0.0 => return Err(math_domain(name, x)),
+            // The base has to be positive too, and can't be 1
+            (_, base) if base <= 0.0 || base == 1.0 => return Err(math_domain(name, base)),
+            (x, base) => Value::Float(x.log(base)),
+        },
+        "sin" => Value::Float(as_float(name, &args[0]).sin()),
+        "cos" => Value::Float(as_float(name, &args[0]).cos()),
+        "tan" => Value::Float(as_float(name, &args[0]).tan()),
+        "pi" => Value::Float(std::f64::consts::PI),
+        "gcd" | "lcm" => match (&args[0], &args[1]) {
+            (Value::Int(a), Value::Int(b)) => {
+                let divisor = gcd(a.unsigned_abs(), b.unsigned_abs());
+                let result = if name == "gcd" {
+                    Some(divisor)
+                } else {
+                    match a.unsigned_abs().checked_div(divisor) {
+                        Some(quotient) => quotient.checked_mul(b.unsigned_abs()),
+                        // Both arguments were zero
+                        None => Some(0),
+                    }
+                };
+                match result
+                    .and_then(|result| i128::try_from(result).ok())
+                    .and_then(|result| ctx.integers.bound(result))
+                {
+                    Some(result) => Value::Int(result),
+                    // Past an i128 the bignum width can still have an answer
+                    None => match bounded(big_gcd_or_lcm(name, &args[0], &args[1]), ctx) {
+                        Some(result) => result,
+                        None => {
+                            return Err(RuntimeError::IntegerOverflow {
+                                name: name.to_string(),
+                            })
+                        }
+                    },
+                }
+            }
+            (a @ (Value::Int(_) | Value::BigInt(_)), b @ (Value::Int(_) | Value::BigInt(_))) => {
+                match bounded(big_gcd_or_lcm(name, a, b), ctx) {
+                    Some(result) => result,
+                    None => {
+                        return Err(RuntimeError::IntegerOverflow {
+                            name: name.to_string(),
+                        })
+                    }
+                }
+            }
+            _ => panic!("{name} takes two ints"),
+        },
+        "to_map" => match &args[0] {
+            Value::Array(pairs) => {
+                let mut map = IndexMap::new();
+                for pair in pairs {
+                    match pair {
+                        Value::Array(pair) if pair.len() == 2 => match &pair[0] {
+                            Value::String(key) => {
+                                map.insert(key.clone(), pair[1].clone());
+                            }
+                            key => panic!("to_map keys must be strings, got {}", key.get_type()),
+                        },
+                        _ => panic!("to_map takes an array of [key, value] pairs"),
+                    }
+                }
+                Value::Map(map)
+            }
+            _ => panic!("to_map takes an array of [key, value] pairs"),
+        },
+        "to_pairs" => match &args[0] {
+            Value::Map(map) => Value::Array(
+                map.iter()
+                    .map(|(key, value)| {
+                        Value::Array(vec![Value::String(key.clone()), value.clone()])
+                    })
+                    .collect(),
+            ),
+            _ => panic!("to_pairs takes a map"),
+        },
+        "read_all" => {
+            if !ctx.capabilities.stdin {
+                panic!("read_all is not allowed to read from stdin");
+            }
+            let mut contents = String::new();
+            ctx.input
+                .read_to_string(&mut contents)
+                .expect("Failed to read from stdin");
+            Value::String(contents)
+        }
+        "pad_left" | "pad_right" => match (&args[0], &args[1], &args[2]) {
+            (Value::String(s), Value::Int(width), Value::String(fill)) => {
+                let mut fill_chars = fill.chars();
+                let fill = match (fill_chars.next(), fill_chars.next()) {
+                    (Some(c), None) => c,
+                    _ => panic!("{name} takes a single character fill string"),
+                };
+                let len = s.chars().count() as i128;
+                let padding = match usize::try_from(width.saturating_sub(len).max(0)) {
+                    Ok(padding) => padding,
+                    Err(_) => panic!("{name} width is too large"),
+                };
+                let padding: String = std::iter::repeat_n(fill, padding).collect();
+                if name == "pad_left" {
+                    Value::String(padding + s)
+                } else {
+                    Value::String(s.clone() + &padding)
+                }
+            }
+            _ => panic!("{name} takes a string, a width and a fill string"),
+        },
+        "env" => {
+            if !ctx.capabilities.env {
+                panic!("env is not allowed to read environment variables");
+            }
+            match &args[0] {
+                Value::String(key) => match std::env::var(key) {
+                    Ok(value) => Value::String(value),
+                    Err(_) => Value::Nothing,
+                },
+                _ => panic!("env takes a variable name string"),
+            }
+        }
+        "abs" => match &args[0] {
+            Value::Int(i) => match i.checked_abs().and_then(|i| ctx.integers.bound(i)) {
+                Some(i) => Value::Int(i),
+                // Only i128::MIN is left, which a bignum width can still take
+                None => match bounded(BigInt::from(*i).abs(), ctx) {
+                    Some(abs) => abs,
+                    None => {
+                        return Err(RuntimeError::IntegerOverflow {
+                            name: name.to_string(),
+                        })
+                    }
+                },
+            },
+            Value::BigInt(n) => Value::from(n.abs()),
+            Value::Float(f) => Value::Float(f.abs()),
+            _ => panic!("abs takes a number"),
+        },
+        "lines" => match &args[0] {
+            // str::lines already strips a trailing \r and ignores a final newline
+            Value::String(s) => {
+                Value::Array(s.lines().map(|l| Value::String(l.to_string())).collect())
+            }
+            _ => panic!("lines takes a string"),
+        },
+        "int" => {
+            let parsed = match &args[0] {
+                Value::Int(i) => BigInt::from(*i),
+                Value::BigInt(n) => n.clone(),
+                Value::Float(f) if f.is_nan() => {
+                    return Err(RuntimeError::InvalidNumber {
+                        text: f.to_string(),
+                    })
+                }
+                // Only the infinities are left with no int to truncate to
+                Value::Float(f) => match BigInt::from_f64(*f) {
+                    Some(n) => n,
+                    None => {
+                        return Err(RuntimeError::IntegerOverflow {
+                            name: name.to_string(),
+                        })
+                    }
+                },
+                Value::String(s) => match BigInt::parse(s.trim()) {
+                    Some(n) => n,
+                    None => return Err(RuntimeError::InvalidNumber { text: s.clone() }),
+                },
+                _ => panic!("int takes an int, a float or a string"),
+            };
+            // The result also has to fit the integer width in use
+            match bounded(parsed, ctx) {
+                Some(parsed) => parsed,
+                None => {
+                    return Err(RuntimeError::IntegerOverflow {
+                        name: name.to_string(),
+                    })
+                }
+            }
+        }
+        "hash" => match &args[0] {
+            // Read as signed so the hash fits the default 64-bit ints
+            Value::String(s) => match ctx.integers.bound(fnv1a(s.as_bytes()) as i64 as i128) {
+                Some(hash) => Value::Int(hash),
+                None => {
+                    return Err(RuntimeError::IntegerOverflow {
+                        name: name.to_string(),
+                    })
+                }
+            },
+            _ => panic!("hash takes a string"),
+        },
+        "is_number" => Value::Bool(matches!(
+            args[0],
+            Value::Int(_) | Value::BigInt(_) | Value::Float(_)
+        )),
+        "is_finite" => match &args[0] {
+            Value::Int(_) => Value::Bool(true),
+            Value::Float(f) => Value::Bool(f.is_finite()),
+            _ => Value::Bool(false),
+        },
+        "substr" => match (&args[0], &args[1], &args[2]) {
+            (Value::String(s), Value::Int(start), Value::Int(len)) => {
+                if *start < 0 || *len < 0 {
+                    panic!("substr takes a non-negative start and length");
+                }
+                // Anything past the end of the string is dropped rather than an error,
+                // including values too big for usize
+                let start = usize::try_from(*start).unwrap_or(usize::MAX);
+                let len = usize::try_from(*len).unwrap_or(usize::MAX);
+                Value::String(s.chars().skip(start).take(len).collect())
+            }
+            _ => panic!("substr takes a string, a start index and a length"),
+        },
+        "title" => match &args[0] {
+            Value::String(s) => Value::String(title_case(s)),
+            _ => panic!("title takes a string"),
+        },
+        "matches_at" => match (&args[0], &args[1], &args[2]) {
+            (Value::String(s), Value::Int(index), Value::String(needle)) => {
+                // Convert the char index to a byte offset, allowing the very end
+                let offset = usize::try_from(*index).ok().and_then(|index| {
+                    s.char_indices()
+                        .map(|(offset, _)| offset)
+                        .chain([s.len()])
+                        .nth(index)
+                });
+                Value::Bool(match offset {
+                    Some(offset) => s[offset..].starts_with(needle.as_str()),
+                    None => false,
+                })
+            }
+            _ => panic!("matches_at takes a string, an index and a substring"),
+        },
+        "eval" => match &args[0] {
+            Value::String(source) => eval_source(source, scope, ctx)?,
+            _ => panic!("eval takes a string of code"),
+        },
+        "def" => match (&args[0], &args[1], &args[2]) {
+            (Value::String(fn_name), Value::Array(params), Value::String(source)) => {
+                let mut fn_args = HashMap::new();
+                for (i, param) in params.iter().enumerate() {
+                    // Parameters are either "type name" or a bare name that
+                    // accepts any type
+                    let (value_type, param_name) = match param {
+                        Value::String(param) => match param.split_once(' ') {
+                            Some((t, n)) => (get_valuetype_from(t.trim()), n.trim()),
+                            None => (ValueType::Any, param.trim()),
+                        },
+                        _ => panic!("def takes parameter names as strings"),
+                    };
+                    fn_args.insert((i, Symbol::intern(param_name)), value_type);
+                }
+                scope.insert(
+                    Symbol::intern(fn_name),
+                    Box::new(Value::Fn(FnType::User(UserFn {
+                        name: fn_name.clone(),
+                        args: fn_args,
+                        body: Rc::new(parse_source(source)?),
+                        return_type: ValueType::Any,
+                        captured: None,
+                    }))),
+                );
+                Value::Nothing
+            }
+            _ => panic!("def takes a name, an array of parameters and a body string"),
+        },
+        "format_int" => match (&args[0], &args[1]) {
+            (n @ (Value::Int(_) | Value::BigInt(_)), Value::String(sep)) => {
+                let n = n.to_string();
+                let digits = n.trim_start_matches('-');
+                let mut result = String::new();
+                for (i, digit) in digits.chars().enumerate() {
+                    if i > 0 && (digits.len() - i) % 3 == 0 {
+                        result.push_str(sep);
+                    }
+                    result.push(digit);
+                }
+                if n.starts_with('-') {
+                    result.insert(0, '-');
+                }
+                Value::String(result)
+            }
+            _ => panic!("format_int takes an int and a separator string"),
+        },
+        "arity" => match &args[0] {
+            Value::Fn(func) => Value::Int(fn_arity(func)),
+            other => panic!("arity takes a function, got {}", other.get_type()),
+        },
+        "name_of" => match &args[0] {
+            Value::Fn(func) => Value::String(func.to_string()),
+            other => panic!("name_of takes a function, got {}", other.get_type()),
+        },
+        "compare" => match compare_values(&args[0], &args[1]) {
+            Some(ordering) => Value::Int(ordering as i128),
+            None => panic!(
+                "compare cannot order {} and {}",
+                args[0].get_type(),
+                args[1].get_type()
+            ),
+        },
+        "error" => match &args[0] {
+            Value::String(message) => {
+                return Err(RuntimeError::UserError {
+                    message: message.clone(),
+                })
+            }
+            _ => panic!("error takes a message string"),
+        },
+        "default" => match &args[0] {
+            Value::String(type_name) => match type_name.to_ascii_lowercase().as_str() {
+                "int" => Value::Int(0),
+                "float" => Value::Float(0.0),
+                "string" => Value::String(String::new()),
+                "bool" => Value::Bool(false),
+                "array" => Value::Array(Vec::new()),
+                "map" => Value::Map(IndexMap::new()),
+                "tuple" | "function" | "any" | "nothing" => Value::Nothing,
+                _ => panic!("default got an unknown type name: {type_name}"),
+            },
+            _ => panic!("default takes a type name string"),
+        },
+        "flatten" => match &args[0] {
+            Value::Array(items) => {
+                let mut result = Vec::new();
+                for item in items {
+                    match item {
+                        Value::Array(inner) => result.extend(inner.iter().cloned()),
+                        item => result.push(item.clone()),
+                    }
+                }
+                Value::Array(result)
+            }
+            _ => panic!("flatten takes an array"),
+        },
+        "flatten_deep" => match &args[0] {
+            Value::Array(items) => {
+                let mut result = Vec::new();
+                flatten_into(items, &mut result);
+                Value::Array(result)
+            }
+            _ => panic!("flatten_deep takes an array"),
+        },
+        "unique" => match &args[0] {
+            // Values can't be hashed because of floats, so this checks each
+            // item against the ones already kept
+            Value::Array(items) => {
+                let mut result: Vec<Value> = Vec::new();
+                for item in items {
+                    if !result.contains(item) {
+                        result.push(item.clone());
+                    }
+                }
+                Value::Array(result)
+            }
+            _ => panic!("unique takes an array"),
+        },
+        "group_by" => match (&args[0], &args[1]) {
+            (Value::Array(items), Value::Fn(key_fn)) => {
+                let mut groups: IndexMap<String, Value> = IndexMap::new();
+                for item in items {
+                    let key = match call_value(key_fn, vec![item.clone()], scope, ctx)? {
+                        key @ (Value::Int(_)
+                        | Value::Float(_)
+                        | Value::String(_)
+                        | Value::Bool(_)) => key.to_string(),
+                        other => panic!(
+                            "group_by keys must be strings, numbers or bools, got {}",
+                            other.get_type()
+                        ),
+                    };
+                    match groups
+                        .entry(key)
+                        .or_insert_with(|| Value::Array(Vec::new()))
+                    {
+                        Value::Array(group) => group.push(item.clone()),
+                        _ => unreachable!("Groups are always arrays"),
+                    }
+                }
+                Value::Map(groups)
+            }
+            _ => panic!("group_by takes an array and a key function"),
+        },
+        "mean" | "median" | "mode" => match &args[0] {
+            Value::Array(items) if items.is_empty() => panic!("{name} takes a non-empty array"),
+            Value::Array(items) => match name {
+                "mean" => Value::Float(
+                    items.iter().map(|item| as_float(name, item)).sum::<f64>() / items.len() as f64,
+                ),
+                "median" => {
+                    let mut numbers: Vec<f64> =
+                        items.iter().map(|item| as_float(name, item)).collect();
+                    numbers.sort_by(|a, b| a.total_cmp(b));
+                    let middle = numbers.len() / 2;
+                    if numbers.len().is_multiple_of(2) {
+                        Value::Float((numbers[middle - 1] + numbers[middle]) / 2.0)
+                    } else {
+                        Value::Float(numbers[middle])
+                    }
+                }
+                // Ties go to whichever value appears first
+                _ => {
+                    let mut counts: Vec<(&Value, usize)> = Vec::new();
+                    for item in items {
+                        // Called just to reject anything that isn't a number
+                        as_float(name, item);
+                        match counts.iter_mut().find(|(value, _)| *value == item) {
+                            Some((_, count)) => *count += 1,
+                            None => counts.push((item, 1)),
+                        }
+                    }
+                    let mut best = counts[0];
+                    for entry in &counts[1..] {
+                        if entry.1 > best.1 {
+                            best = *entry;
+                        }
+                    }
+                    best.0.clone()
+                }
+            },
+            _ => panic!("{name} takes an array of numbers"),
+        },
+        "chunk" => match (&args[0], &args[1]) {
+            // A size too big for usize is bigger than any array, so it clamps
+            (Value::Array(items), Value::Int(size)) if *size > 0 => Value::Array(
+                items
+                    .chunks(usize::try_from(*size).unwrap_or(usize::MAX))
+                    .map(|chunk| Value::Array(chunk.to_vec()))
+                    .collect(),
+            ),
+            (Value::Array(_), Value::Int(size)) => {
+                panic!("chunk takes a positive size, got {size}")
+            }
+            _ => panic!("chunk takes an array and a size"),
+        },
+        // Truncates towards zero like `/`, so the remainder keeps the sign of a
+        "divmod" => match (&args[0], &args[1]) {
+            (Value::Int(_) | Value::BigInt(_), Value::Int(0)) => {
+                return Err(RuntimeError::DivisionByZero)
+            }
+            (a @ (Value::Int(_) | Value::BigInt(_)), b @ (Value::Int(_) | Value::BigInt(_))) => {
+                match (
+                    int_arithmetic(&Operator::Div, a, b, &*ctx.integers),
+                    int_arithmetic(&Operator::Percent, a, b, &*ctx.integers),
+                ) {
+                    (Some(quotient), Some(remainder)) => Value::Array(vec![quotient, remainder]),
+                    _ => {
+                        return Err(RuntimeError::IntegerOverflow {
+                            name: name.to_string(),
+                        })
+                    }
+                }
+            }
+            _ => panic!("divmod takes two ints"),
+        },
+        "deep_equal" => Value::Bool(deep_equal(&args[0], &args[1])),
+        "times" => match (&args[0], &args[1]) {
+            (Value::Int(n), Value::Fn(func)) if *n >= 0 => {
+                let takes_index = fn_arity(func) != 0;
+                let mut results = Vec::new();
+                for i in 0..*n {
+                    let fn_args = if takes_index {
+                        vec![Value::Int(i)]
+                    } else {
+                        vec![]
+                    };
+                    results.push(call_value(func, fn_args, scope, ctx)?);
+                }
+                Value::Array(results)
+            }
+            (Value::Int(n), Value::Fn(_)) => panic!("times takes a non-negative count, got {n}"),
+            _ => panic!("times takes a count and a function"),
+        },
+        "concat_str" => Value::String(args.iter().map(Value::to_string).collect()),
+        "table" => match &args[0] {
+            Value::Array(rows) => {
+                let rows: Vec<&IndexMap<String, Value>> = rows
+                    .iter()
+                    .map(|row| match row {
+                        Value::Map(entries) => entries,
+                        other => panic!("table takes an array of maps, got {}", other.get_type()),
+                    })
+                    .collect();
+                Value::String(render_table(&rows))
+            }
+            _ => panic!("table takes an array of maps"),
+        },
+        "memoize" => match &args[0] {
+            Value::Fn(func) => Value::Fn(FnType::Memoized(MemoizedFn {
+                func: Box::new(func.clone()),
+                cache: Rc::new(RefCell::new(HashMap::new())),
+            })),
+            other => panic!("memoize takes a function, got {}", other.get_type()),
+        },
+        "partial" => match &args[0] {
+            Value::Fn(func) => {
+                let arity = fn_arity(func);
+                if arity >= 0 && args.len() - 1 > arity as usize {
+                    panic!(
+                        "partial was given {} arguments for {func}, which takes {arity}",
+                        args.len() - 1
+                    );
+                }
+                Value::Fn(FnType::Partial(PartialFn {
+                    func: Box::new(func.clone()),
+                    args: args[1..].to_vec(),
+                }))
+            }
+            other => panic!("partial takes a function, got {}", other.get_type()),
+        },
+        "compose" => match (&args[0], &args[1]) {
+            (Value::Fn(outer), Value::Fn(inner)) => {
+                if fn_arity(outer) != 1 || fn_arity(inner) != 1 {
+                    panic!("compose takes two functions with one argument each");
+                }
+                Value::Fn(FnType::Composed(ComposedFn {
+                    outer: Box::new(outer.clone()),
+                    inner: Box::new(inner.clone()),
+                }))
+            }
+            _ => panic!("compose takes two functions"),
+        },
+        "map" => match (args[0].items(), &args[1]) {
+            (Some(items), Value::Fn(func)) => {
+                let mut results = Vec::new();
+                for item in items {
+                    results.push(call_value(func, vec![item], scope, ctx)?);
+                }
+                Value::Array(results)
+            }
+            _ => panic!("map takes an array or iterator and a function"),
+        },
+        "filter" => match (args[0].items(), &args[1]) {
+            (Some(items), Value::Fn(func)) => {
+                let mut results = Vec::new();
+                for item in items {
+                    if call_value(func, vec![item.clone()], scope, ctx)?.is_truthy() {
+                        results.push(item);
+                    }
+                }
+                Value::Array(results)
+            }
+            _ => panic!("filter takes an array or iterator and a function"),
+        },
+        "range" => {
+            let bounds = args
+                .iter()
+                .map(|arg| match arg {
+                    Value::Int(i) => *i,
+                    other => panic!("range takes ints, got {}", other.get_type()),
+                })
+                .collect::<Vec<_>>();
+            let (start, end, step) = match bounds[..] {
+                [end] => (0, end, 1),
+                [start, end] => (start, end, 1),
+                [start, end, step] => (start, end, step),
+                _ => panic!("range takes an end, a start and end, or a start, end and step"),
+            };
+            if step == 0 {
+                panic!("range takes a non-zero step");
+            }
+            Value::Iterator(RangeIter { start, end, step })
+        }
+        "sleep" => match &args[0] {
+            Value::Int(ms) if *ms >= 0 => {
+                ctx.clock.sleep(Duration::from_millis(
+                    u64::try_from(*ms).unwrap_or(u64::MAX),
+                ));
+                Value::Nothing
+            }
+            other => panic!("sleep takes a non-negative number of milliseconds, got {other}"),
+        },
+        "parse_json" => match &args[0] {
+            Value::String(s) => serde_json::from_str::<JsonValue>(s)
+                .map_err(|err| RuntimeError::ParseFailed {
+                    message: format!("Invalid JSON: {err}"),
+                })?
+                .into_value(&*ctx.integers)
+                .ok_or_else(|| RuntimeError::IntegerOverflow {
+                    name: name.to_string(),
+                })?,
+            other => panic!("parse_json takes a string, got {}", other.get_type()),
+        },
+        "to_json" => Value::String(serde_json::to_string(&JsonOut(&args[0])).map_err(|err| {
+            RuntimeError::NotSerializable {
+                message: err.to_string(),
+            }
+        })?),
+        "parse_kv" => match &args[0] {
+            Value::String(s) => {
+                let mut entries = IndexMap::new();
+                for (i, line) in s.lines().enumerate() {
+                    let line = line.trim();
+                    if line.is_empty() || line.starts_with('#') {
+                        continue;
+                    }
+                    match line.split_once('=') {
+                        Some((key, value)) => {
+                            entries.insert(
+                                key.trim().to_string(),
+                                Value::String(value.trim().to_string()),
+                            );
+                        }
+                        None => {
+                            return Err(RuntimeError::ParseFailed {
+                                message: format!("Expected key=value on line {}", i + 1),
+                            })
+                        }
+                    }
+                }
+                Value::Map(entries)
+            }
+            other => panic!("parse_kv takes a string, got {}", other.get_type()),
+        },
+        "url_encode" => match &args[0] {
+            Value::String(s) => {
+                let mut result = String::new();
+                for byte in s.bytes() {
+                    if byte.is_ascii_alphanumeric() || b"-_.~".contains(&byte) {
+                        result.push(byte as char);
+                    } else {
+                        result.push_str(&format!("%{byte:02X}"));
+                    }
+                }
+                Value::String(result)
+            }
+            other => panic!("url_encode takes a string, got {}", other.get_type()),
+        },
+        "url_decode" => match &args[0] {
+            Value::String(s) => Value::String(url_decode(s)?),
+            other => panic!("url_decode takes a string, got {}", other.get_type()),
+        },
+        "base64_encode" => match &args[0] {
+            Value::String(s) => Value::String(base64_encode(s.as_bytes())),
+            other => panic!("base64_encode takes a string, got {}", other.get_type()),
+        },
+        "base64_decode" => match &args[0] {
+            Value::String(s) => {
+                let invalid = |message: String| RuntimeError::ParseFailed { message };
+                let bytes = base64_decode(s)
+                    .ok_or_else(|| invalid(format!("{s:?} is not valid base64")))?;
+                Value::String(
+                    String::from_utf8(bytes)
+                        .map_err(|_| invalid(format!("{s:?} does not decode to UTF-8")))?,
+                )
+            }
+            other => panic!("base64_decode takes a string, got {}", other.get_type()),
+        },
+        "regex_match" | "regex_find_all" => match (&args[0], &args[1]) {
+            (Value::String(s), Value::String(pattern)) => {
+                let regex = Regex::new(pattern)
+                    .map_err(|message| RuntimeError::InvalidRegex { message })?;
+                if name == "regex_match" {
+                    Value::Bool(regex.is_match(s))
+                } else {
+                    Value::Array(
+                        regex
+                            .find_all(s)
+                            .into_iter()
+                            .map(|(start, end)| Value::String(s[start..end].to_string()))
+                            .collect(),
+                    )
+                }
+            }
+            _ => panic!("{name} takes a string and a pattern string"),
+        },
+        // Groups that took no part in the match come back as nothing
+        "regex_capture" => match (&args[0], &args[1]) {
+            (Value::String(s), Value::String(pattern)) => {
+                let regex = Regex::new(pattern)
+                    .map_err(|message| RuntimeError::InvalidRegex { message })?;
+                match regex.captures(s) {
+                    Some(groups) => Value::Array(
+                        groups
+                            .into_iter()
+                            .map(|group| match group {
+                                Some((start, end)) => Value::String(s[start..end].to_string()),
+                                None => Value::Nothing,
+                            })
+                            .collect(),
+                    ),
+                    None => Value::Nothing,
+                }
+            }
+            _ => panic!("regex_capture takes a string and a pattern string"),
+        },
+        "profile" => match &ctx.call_counts {
+            Some(counts) => Value::Map(
+                counts
+                    .iter()
+                    .map(|(name, count)| (name.clone(), Value::Int(*count)))
+                    .collect(),
+            ),
+            None => panic!("profile needs the interpreter to be run with profiling on"),
+        },
+        "display_width" => match &args[0] {
+            Value::String(s) => Value::Int(s.chars().map(char_width).sum::<usize>() as i128),
+            other => panic!("display_width takes a string, got {}", other.get_type()),
+        },
+        "normalize" => match (&args[0], &args[1]) {
+            (Value::String(s), Value::String(form)) => match Form::from_name(form) {
+                Some(form) => Value::String(normalize(s, form)),
+                None => panic!("normalize takes a form of NFC, NFD, NFKC or NFKD, got {form:?}"),
+            },
+            _ => panic!("normalize takes a string and a form string"),
+        },
+        // Greedy, one word at a time. Runs of whitespace, newlines included,
+        // count as a single break
+        "wrap" => match (&args[0], &args[1]) {
+            (Value::String(s), Value::Int(width)) if *width > 0 => Value::Array(
+                wrap_words(s, usize::try_from(*width).unwrap_or(usize::MAX))
+                    .into_iter()
+                    .map(Value::String)
+                    .collect(),
+            ),
+            (Value::String(_), Value::Int(width)) => {
+                panic!("wrap takes a positive width, got {width}")
+            }
+            _ => panic!("wrap takes a string and a width"),
+        },
+        "edit_distance" => match (&args[0], &args[1]) {
+            (Value::String(a), Value::String(b)) => Value::Int(levenshtein(a, b) as i128),
+            _ => panic!("edit_distance takes two strings"),
+        },
+        _ => panic!("Not a function"),
+    })
+}
+
+fn compare_keys(name: &str, a: &Value, b: &Value) -> Ordering {
+    match (a, b) {
+        (Value::Int(a), Value::Int(b)) => a.cmp(b),
+        (Value::Int(_) | Value::BigInt(_), Value::Int(_) | Value::BigInt(_)) => {
+            a.as_big().cmp(&b.as_big())
+        }
+        (Value::String(a), Value::String(b)) => a.cmp(b),
+        _ => panic!("{name} keys must all be ints or all be strings"),
+    }
+}
+
+fn math_domain(name: &str, value: f64) -> RuntimeError {
+    RuntimeError::MathDomain {
+        name: name.to_string(),
+        value,
+    }
+}
+
+fn as_float(name: &str, value: &Value) -> f64 {
+    match value {
+        Value::Int(i) => *i as f64,
+        Value::BigInt(n) => n.to_f64(),
+        Value::Float(f) => *f,
+        _ => panic!("{name} takes only numbers"),
+    }
+}
+
+fn gcd(mut a: u128, mut b: u128) -> u128 {
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    a
+}
+
+// gcd or lcm on BigInts, for when the u128 version overflows
+fn big_gcd_or_lcm(name: &str, a: &Value, b: &Value) -> BigInt {
+    let (a, b) = (a.as_big().unwrap().abs(), b.as_big().unwrap().abs());
+    let (mut divisor, mut rest) = (a.clone(), b.clone());
+    while !rest.is_zero() {
+        let (_, remainder) = divisor.div_rem(&rest).unwrap();
+        (divisor, rest) = (rest, remainder);
+    }
+    match a.div_rem(&divisor) {
+        Some((quotient, _)) if name == "lcm" => &quotient * &b,
+        // The gcd, or the lcm when both arguments were zero
+        _ => divisor,
+    }
+}
+
+// An int that didn't come from arithmetic, like a rounded float, as the
+// integer width in use stores it. None if it doesn't fit rather than wrapping
+fn bounded(n: BigInt, ctx: &Context) -> Option<Value> {
+    match n.to_i128() {
+        Some(i) => (ctx.integers.bound(i) == Some(i)).then_some(Value::Int(i)),
+        None => ctx.integers.bound_big(n).map(Value::BigInt),
+    }
+}
+
+// 64-bit FNV-1a, picked so hashes stay the same across runs and platforms
+fn fnv1a(bytes: &[u8]) -> u64 {
+    let mut hash: u64 = 0xcbf29ce484222325;
+    for byte in bytes {
+        hash ^= *byte as u64;
+        hash = hash.wrapping_mul(0x100000001b3);
+    }
+    hash
+}
+
+fn title_case(s: &str) -> String {
+    let mut result = String::with_capacity(s.len());
+    let mut at_word_start = true;
+    for c in s.chars() {
+        if c.is_whitespace() {
+            at_word_start = true;
+            result.push(c);
+        } else if at_word_start && c.is_alphabetic() {
+            at_word_start = false;
+            result.extend(c.to_uppercase());
+        } else {
+            result.extend(c.to_lowercase());
+        }
+    }
+    result
+}
+
+// Variadic builtins report an arity of -1
+fn builtin_arity(name: &str) -> i128 {
+    match name {
+        "print" | "println" | "max" | "min" | "concat" | "concat_str" | "partial" | "range" => -1,
+        "pi" | "read_all" | "profile" => 0,
+        "time" | "bool" | "enumerate" | "copy" | "is_empty" | "sum" | "product" | "floor"
+        | "ceil" | "round" | "exp" | "ln" | "sin" | "cos" | "tan" | "to_map" | "to_pairs"
+        | "env" | "abs" | "lines" | "int" | "hash" | "is_number" | "is_finite" | "title"
+        | "eval" | "arity" | "name_of" | "error" | "default" | "flatten" | "flatten_deep"
+        | "unique" | "mean" | "median" | "mode" | "table" | "memoize" | "sleep" | "parse_json"
+        | "to_json" | "parse_kv" | "url_encode" | "url_decode" | "base64_encode"
+        | "base64_decode" | "cumsum" | "cumprod" | "display_width" => 1,
+        "index_of" | "max_by" | "min_by" | "zip" | "log" | "gcd" | "lcm" | "format_int"
+        | "compare" | "group_by" | "chunk" | "divmod" | "deep_equal" | "times" | "compose"
+        | "map" | "filter" | "regex_match" | "regex_find_all" | "regex_capture" | "normalize"
+        | "wrap" | "edit_distance" => 2,
+        "get" | "reduce" | "pad_left" | "pad_right" | "substr" | "matches_at" | "def" => 3,
+        _ => panic!("Unknown builtin: {name}"),
+    }
+}
+
+// One step of a sum or product. Ints follow the integer width, and a float on
+// either side makes the result a float
+fn accumulate(
+    name: &str,
+    sum: bool,
+    acc: Value,
+    item: &Value,
+    ctx: &Context,
+) -> Result<Value, RuntimeError> {
+    match (acc, item) {
+        (acc @ (Value::Int(_) | Value::BigInt(_)), Value::Int(_) | Value::BigInt(_)) => {
+            let op = if sum { Operator::Add } else { Operator::Mul };
+            int_arithmetic(&op, &acc, item, &*ctx.integers).ok_or(RuntimeError::IntegerOverflow {
+                name: name.to_string(),
+            })
+        }
+        (acc, item) => {
+            let (a, b) = (as_float(name, &acc), as_float(name, item));
+            Ok(Value::Float(if sum { a + b } else { a * b }))
+        }
+    }
+}
+
+// Arrays compare element by element, with a shorter prefix ordering first
+fn compare_values(a: &Value, b: &Value) -> Option<Ordering> {
+    match (a, b) {
+        (Value::Int(a), Value::Int(b)) => Some(a.cmp(b)),
+        (Value::Int(_) | Value::BigInt(_), Value::Int(_) | Value::BigInt(_)) => {
+            Some(a.as_big().cmp(&b.as_big()))
+        }
+        (Value::Float(a), Value::Float(b)) => a.partial_cmp(b),
+        (Value::String(a), Value::String(b)) => Some(a.cmp(b)),
+        (Value::Bool(a), Value::Bool(b)) => Some(a.cmp(b)),
+        (Value::Array(a), Value::Array(b)) => {
+            for (x, y) in a.iter().zip(b) {
+                match compare_values(x, y)? {
+                    Ordering::Equal => (),
+                    ordering => return Some(ordering),
+                }
+            }
+            Some(a.len().cmp(&b.len()))
+        }
+        _ => None,
+    }
+}
+
+// Writes that would go over the limit are dropped entirely rather than cut off
+fn write_output(ctx: &mut Context, text: &str) -> Result<(), RuntimeError> {
+    let total = ctx.output_bytes + text.len();
+    if let Some(limit) = ctx.max_output_bytes {
+        if total > limit {
+            return Err(RuntimeError::OutputLimitExceeded { limit });
+        }
+    }
+    ctx.output_bytes = total;
+    ctx.output
+        .write_all(text.as_bytes())
+        .expect("Failed to write output");
+    Ok(())
+}
+
+fn flatten_into(items: &[Value], result: &mut Vec<Value>) {
+    for item in items {
+        match item {
+            Value::Array(inner) => flatten_into(inner, result),
+            item => result.push(item.clone()),
+        }
+    }
+}
+
+// Map entries are matched by key, so insertion order doesn't matter
+fn deep_equal(a: &Value, b: &Value) -> bool {
+    match (a, b) {
+        (Value::Array(a), Value::Array(b)) | (Value::Tuple(a), Value::Tuple(b)) => {
+            a.len() == b.len() && a.iter().zip(b).all(|(x, y)| deep_equal(x, y))
+        }
+        (Value::Map(a), Value::Map(b)) => {
+            a.len() == b.len()
+                && a.iter().all(|(key, x)| match b.get(key) {
+                    Some(y) => deep_equal(x, y),
+                    None => false,
+                })
+        }
+        (a, b) => a == b,
+    }
+}
+
+// Columns come from the keys in the order they first appear in any row
+fn render_table(rows: &[&IndexMap<String, Value>]) -> String {
+    let mut keys: Vec<&String> = Vec::new();
+    for row in rows {
+        for key in row.keys() {
+            if !keys.contains(&key) {
+                keys.push(key);
+            }
+        }
+    }
+    let cells: Vec<Vec<String>> = rows
+        .iter()
+        .map(|row| {
+            keys.iter()
+                .map(|key| row.get(*key).map(Value::to_string).unwrap_or_default())
+                .collect()
+        })
+        .collect();
+    let widths: Vec<usize> = keys
+        .iter()
+        .enumerate()
+        .map(|(i, key)| {
+            cells
+                .iter()
+                .map(|row| row[i].chars().count())
+                .chain([key.chars().count()])
+                .max()
+                .unwrap_or(0)
+        })
+        .collect();
+    let render_row = |row: Vec<&str>| {
+        row.iter()
+            .zip(&widths)
+            .map(|(cell, width)| format!("{cell}{}", " ".repeat(width - cell.chars().count())))
+            .collect::<Vec<_>>()
+            .join(" | ")
+            .trim_end()
+            .to_string()
+    };
+    let mut lines = vec![render_row(keys.iter().map(|key| key.as_str()).collect())];
+    lines.push(
+        widths
+            .iter()
+            .map(|width| "-".repeat(*width))
+            .collect::<Vec<_>>()
+            .join("-+-"),
+    );
+    for row in &cells {
+        lines.push(render_row(row.iter().map(String::as_str).collect()));
+    }
+    lines.join("\n")
+}
+
+pub(crate) fn fn_arity(func: &FnType) -> i128 {
+    match func {
+        FnType::Builtin(builtin) => builtin_arity(&builtin.name),
+        FnType::User(user) => user.args.len() as i128,
+        FnType::Memoized(memoized) => fn_arity(&memoized.func),
+        FnType::Partial(partial) => match fn_arity(&partial.func) {
+            -1 => -1,
+            arity => arity - partial.args.len() as i128,
+        },
+        FnType::Composed(_) => 1,
+    }
+}
+
+// Objects go into an IndexMap so parse_json keeps their keys in order.
+// Integers past i64 are tried as u64 before falling back to a float
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum JsonValue {
+    Null,
+    Bool(bool),
+    Int(i64),
+    UInt(u64),
+    Float(f64),
+    String(String),
+    Array(Vec<JsonValue>),
+    Object(IndexMap<String, JsonValue>),
+}
+
+impl JsonValue {
+    // None if an integer doesn't fit the integer width in use
+    fn into_value(self, integers: &dyn IntArithmetic) -> Option<Value> {
+        Some(match self {
+            JsonValue::Null => Value::Nothing,
+            JsonValue::Bool(b) => Value::Bool(b),
+            JsonValue::Int(i) => bounded_int(i.into(), integers)?,
+            JsonValue::UInt(i) => bounded_int(i.into(), integers)?,
+            JsonValue::Float(f) => Value::Float(f),
+            JsonValue::String(s) => Value::String(s),
+            JsonValue::Array(items) => Value::Array(
+                items
+                    .into_iter()
+                    .map(|item| item.into_value(integers))
+                    .collect::<Option<_>>()?,
+            ),
+            JsonValue::Object(entries) => Value::Map(
+                entries
+                    .into_iter()
+                    .map(|(key, value)| Some((key, value.into_value(integers)?)))
+                    .collect::<Option<_>>()?,
+            ),
+        })
+    }
+}
+
+fn bounded_int(i: i128, integers: &dyn IntArithmetic) -> Option<Value> {
+    integers
+        .bound(i)
+        .filter(|bounded| *bounded == i)
+        .map(Value::Int)
+}
+
+struct JsonOut<'a>(&'a Value);
+
+impl Serialize for JsonOut<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self.0 {
+            Value::Int(i) => match (i64::try_from(*i), u64::try_from(*i)) {
+                (Ok(i), _) => serializer.serialize_i64(i),
+                (_, Ok(i)) => serializer.serialize_u64(i),
+                _ => Err(ser::Error::custom(format!(
+                    "{i} is too large for a JSON number"
+                ))),
+            },
+            Value::BigInt(n) => Err(ser::Error::custom(format!(
+                "{n} is too large for a JSON number"
+            ))),
+            Value::Float(f) if f.is_finite() => serializer.serialize_f64(*f),
+            Value::Float(f) => Err(ser::Error::custom(format!("{f} is not a JSON number"))),
+            Value::String(s) => serializer.serialize_str(s),
+            Value::Bool(b) => serializer.serialize_bool(*b),
+            Value::Array(items) | Value::Tuple(items) => {
+                let mut seq = serializer.serialize_seq(Some(items.len()))?;
+                for item in items {
+                    seq.serialize_element(&JsonOut(item))?;
+                }
+                seq.end()
+            }
+            Value::Map(entries) => {
+                let mut map = serializer.serialize_map(Some(entries.len()))?;
+                for (key, value) in entries {
+                    map.serialize_entry(key, &JsonOut(value))?;
+                }
+                map.end()
+            }
+            Value::Nothing => serializer.serialize_unit(),
+            other => Err(ser::Error::custom(format!(
+                "a {} has no JSON form",
+                other.get_type()
+            ))),
+        }
+    }
+}
+
+fn url_decode(s: &str) -> Result<String, RuntimeError> {
+    let invalid = |message: String| RuntimeError::ParseFailed { message };
+    let mut bytes = Vec::new();
+    let mut rest = s.as_bytes();
+    while let Some((&byte, tail)) = rest.split_first() {
+        rest = tail;
+        if byte != b'%' {
+            bytes.push(byte);
+            continue;
+        }
+        let hex = rest
+            .get(..2)
+            .filter(|hex| hex.iter().all(u8::is_ascii_hexdigit))
+            .and_then(|hex| std::str::from_utf8(hex).ok())
+            .and_then(|hex| u8::from_str_radix(hex, 16).ok())
+            .ok_or_else(|| invalid(format!("Invalid percent-encoding in {s:?}")))?;
+        bytes.push(hex);
+        rest = &rest[2..];
+    }
+    String::from_utf8(bytes).map_err(|_| invalid(format!("{s:?} does not decode to UTF-8")))
+}
+
+const BASE64_ALPHABET: &[u8; 64] =
+    b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+fn base64_encode(bytes: &[u8]) -> String {
+    let mut result = String::new();
+    for chunk in bytes.chunks(3) {
+        let n = chunk
+            .iter()
+            .enumerate()
+            .fold(0u32, |n, (i, byte)| n | u32::from(*byte) << (16 - 8 * i));
+        for i in 0..4 {
+            if i <= chunk.len() {
+                result.push(BASE64_ALPHABET[(n >> (18 - 6 * i) & 0x3f) as usize] as char);
+            } else {
+                result.push('=');
+            }
+        }
+    }
+    result
+}
+
+// Only accepts padded input, with the padding at the very end
+fn base64_decode(s: &str) -> Option<Vec<u8>> {
+    let s = s.as_bytes();
+    if !s.len().is_multiple_of(4) {
+        return None;
+    }
+    let padding = s.iter().rev().take_while(|&&c| c == b'=').count();
+    if padding > 2 {
+        return None;
+    }
+    let mut bytes = Vec::new();
+    for (i, chunk) in s.chunks(4).enumerate() {
+        let is_last = i == s.len() / 4 - 1;
+        let mut n = 0u32;
+        for (j, c) in chunk.iter().enumerate() {
+            let value = if *c == b'=' && is_last && j >= 4 - padding {
+                0
+            } else {
+                BASE64_ALPHABET.iter().position(|a| a == c)? as u32
+            };
+            n = n << 6 | value;
+        }
+        let len = if is_last { 3 - padding } else { 3 };
+        bytes.extend(&n.to_be_bytes()[1..=len]);
+    }
+    Some(bytes)
+}
+
+// A word longer than width gets a line to itself rather than being split
+fn wrap_words(s: &str, width: usize) -> Vec<String> {
+    let mut lines = Vec::new();
+    let mut line = String::new();
+    let mut line_len = 0;
+    for word in s.split_whitespace() {
+        let word_len = word.chars().count();
+        if line_len > 0 && line_len + 1 + word_len > width {
+            lines.push(std::mem::take(&mut line));
+            line_len = 0;
+        }
+        if line_len > 0 {
+            line.push(' ');
+            line_len += 1;
+        }
+        line.push_str(word);
+        line_len += word_len;
+    }
+    if line_len > 0 {
+        lines.push(line);
+    }
+    lines
+}
+
+// Insertions, deletions and substitutions of single chars each cost one.
+// Only the previous row of the table is kept around
+pub(crate) fn levenshtein(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+    for (i, a_char) in a.chars().enumerate() {
+        let mut current = vec![i + 1; b.len() + 1];
+        for (j, b_char) in b.iter().enumerate() {
+            let substitution = previous[j] + usize::from(a_char != *b_char);
+            current[j + 1] = substitution.min(previous[j + 1] + 1).min(current[j] + 1);
+        }
+        previous = current;
+    }
+    previous[b.len()]
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::{builtin_arity, make_builtins},
+        crate::{
+            interpreter::{
+                Bignum, BuiltinFn, Checked64, Clock, FnType, Interpreter, RuntimeError, Scope,
+                Value,
+            },
+            symbol::Symbol,
+            testing::{
+                error, error_at_width, global, interpreter, output, output_at_width,
+                panicked_builtin, Output,
+            },
+        },
+        indexmap::IndexMap,
+        std::{cell::RefCell, rc::Rc, time::Duration},
+    };
+
+    #[test]
+    fn index_of_finds_a_substring_by_char_position() {
+        let source = r#"found := index_of("héllo", "llo"); missing := index_of("hello", "z");"#;
+        assert_eq!(global(source, "found"), Value::Int(2));
+        assert_eq!(global(source, "missing"), Value::Int(-1));
+    }
+
+    #[test]
+    fn index_of_finds_an_array_element() {
+        let source = "found := index_of([3, 5, 7], 7); missing := index_of([3, 5, 7], 4);";
+        assert_eq!(global(source, "found"), Value::Int(2));
+        assert_eq!(global(source, "missing"), Value::Int(-1));
+    }
+
+    #[test]
+    fn concat_joins_two_or_more_arrays() {
+        assert_eq!(
+            global("joined := concat([1, 2], [3]);", "joined"),
+            Value::Array(vec![Value::Int(1), Value::Int(2), Value::Int(3)])
+        );
+        assert_eq!(
+            global("joined := concat([1], [], [2, 3], [4]);", "joined"),
+            Value::Array(vec![
+                Value::Int(1),
+                Value::Int(2),
+                Value::Int(3),
+                Value::Int(4)
+            ])
+        );
+    }
+
+    #[test]
+    fn concat_rejects_non_arrays() {
+        assert_eq!(
+            panicked_builtin(error("joined := concat([1], 2);")),
+            "concat"
+        );
+    }
+
+    #[test]
+    fn get_returns_the_value_for_a_present_key() {
+        let source = r#"found := get({"a": 1, "b": 2}, "b", 0);"#;
+        assert_eq!(global(source, "found"), Value::Int(2));
+    }
+
+    #[test]
+    fn get_falls_back_to_the_default_for_a_missing_key() {
+        let source = r#"found := get({"a": 1}, "z", "none");"#;
+        assert_eq!(global(source, "found"), Value::String("none".to_string()));
+    }
+
+    #[test]
+    fn get_rejects_a_non_string_key() {
+        assert_eq!(
+            panicked_builtin(error(r#"found := get({"a": 1}, 1, 0);"#)),
+            "get"
+        );
+    }
+
+    // Moves forward a fixed step every time it's read
+    struct StepClock {
+        now: Duration,
+        step: Duration,
+    }
+
+    impl Clock for StepClock {
+        fn now(&mut self) -> Duration {
+            self.now += self.step;
+            self.now
+        }
+    }
+
+    #[test]
+    fn time_reports_the_elapsed_clock_time_and_returns_the_result() {
+        let (mut interpreter, output) =
+            interpreter("func work() -> int { return 7; } println(time(work));");
+        interpreter.context.clock = Box::new(StepClock {
+            now: Duration::ZERO,
+            step: Duration::from_millis(25),
+        });
+        interpreter.run().unwrap();
+        assert_eq!(output.text(), "work took 25ms\n7\n");
+    }
+
+    #[test]
+    fn time_rejects_functions_that_take_arguments() {
+        assert_eq!(
+            panicked_builtin(error(
+                "func work(int n) -> int { return n; } x := time(work);"
+            )),
+            "time"
+        );
+    }
+
+    #[test]
+    fn bool_converts_by_truthiness() {
+        let cases = [
+            ("0", false),
+            ("7", true),
+            (r#""""#, false),
+            (r#""a""#, true),
+            ("true", true),
+            ("false", false),
+            ("[1]", true),
+        ];
+        for (value, expected) in cases {
+            let source = format!("b := bool({value});");
+            assert_eq!(global(&source, "b"), Value::Bool(expected), "bool({value})");
+        }
+    }
+
+    #[test]
+    fn bool_of_nothing_is_false() {
+        assert!(!Value::Nothing.is_truthy());
+    }
+
+    #[test]
+    fn max_by_and_min_by_compare_keys() {
+        let source = r#"func neg(int x) -> int { return 0 - x; }
+            func same(string s) -> string { return s; }
+            big := max_by([3, 9, 2], neg); small := min_by([3, 9, 2], neg);
+            word := max_by(["pear", "zoo", "apple"], same);"#;
+        assert_eq!(global(source, "big"), Value::Int(2));
+        assert_eq!(global(source, "small"), Value::Int(9));
+        assert_eq!(global(source, "word"), Value::String("zoo".to_string()));
+    }
+
+    #[test]
+    fn max_by_keeps_the_first_of_equal_keys() {
+        let source = "func zero(int x) -> int { return 0; } first := max_by([5, 6], zero);";
+        assert_eq!(global(source, "first"), Value::Int(5));
+    }
+
+    #[test]
+    fn max_by_rejects_an_empty_array() {
+        assert_eq!(
+            panicked_builtin(error(
+                "func id(int x) -> int { return x; } x := max_by([], id);"
+            )),
+            "max_by"
+        );
+    }
+
+    #[test]
+    fn reduce_threads_an_accumulator() {
+        let source = "func add(int acc, int x) -> int { return acc + x; }
+            total := reduce([1, 2, 3, 4], add, 0); empty := reduce([], add, 5);";
+        assert_eq!(global(source, "total"), Value::Int(10));
+        assert_eq!(global(source, "empty"), Value::Int(5));
+    }
+
+    #[test]
+    fn reduce_rejects_a_one_argument_function() {
+        assert_eq!(
+            panicked_builtin(error(
+                "func id(int x) -> int { return x; } x := reduce([1], id, 0);"
+            )),
+            "reduce"
+        );
+    }
+
+    #[test]
+    fn enumerate_pairs_indices_with_elements() {
+        let pairs = global(r#"pairs := enumerate(["a", "b"]);"#, "pairs");
+        assert_eq!(pairs.to_string(), "[[0, a], [1, b]]");
+    }
+
+    #[test]
+    fn enumerate_rejects_non_arrays() {
+        assert_eq!(
+            panicked_builtin(error("pairs := enumerate(1);")),
+            "enumerate"
+        );
+    }
+
+    #[test]
+    fn zip_pairs_equal_length_arrays() {
+        let pairs = global(r#"pairs := zip([1, 2], ["a", "b"]);"#, "pairs");
+        assert_eq!(pairs.to_string(), "[[1, a], [2, b]]");
+    }
+
+    #[test]
+    fn zip_truncates_to_the_shorter_array() {
+        let source = "short := zip([1, 2, 3], [4]); empty := zip([], [4]);";
+        assert_eq!(global(source, "short").to_string(), "[[1, 4]]");
+        assert_eq!(global(source, "empty").to_string(), "[]");
+    }
+
+    #[test]
+    fn zip_rejects_non_arrays() {
+        assert_eq!(panicked_builtin(error("pairs := zip([1], 2);")), "zip");
+    }
+
+    #[test]
+    fn copy_leaves_the_original_unchanged() {
+        // There's no push, so the copy grows through concat instead
+        let source = r#"a := [1, [2, 3]]; b := copy(a); b := concat(b, [4]);
+            m := copy({"k": [1]}); n := copy(5);"#;
+        assert_eq!(global(source, "a").to_string(), "[1, [2, 3]]");
+        assert_eq!(global(source, "b").to_string(), "[1, [2, 3], 4]");
+        assert_eq!(global(source, "m").to_string(), "{k: [1]}");
+        assert_eq!(global(source, "n"), Value::Int(5));
+    }
+
+    #[test]
+    fn is_empty_checks_strings_arrays_and_maps() {
+        let cases = [
+            (r#""""#, true),
+            (r#""a""#, false),
+            ("[]", true),
+            ("[1]", false),
+            ("{}", true),
+            (r#"{"a": 1}"#, false),
+        ];
+        for (value, expected) in cases {
+            let source = format!("e := is_empty({value});");
+            assert_eq!(global(&source, "e"), Value::Bool(expected), "{value}");
+        }
+    }
+
+    #[test]
+    fn is_empty_rejects_scalars() {
+        assert_eq!(panicked_builtin(error("e := is_empty(1);")), "is_empty");
+    }
+
+    #[test]
+    fn sum_and_product_of_empty_arrays_are_the_identities() {
+        let source = "s := sum([]); p := product([]);";
+        assert_eq!(global(source, "s"), Value::Int(0));
+        assert_eq!(global(source, "p"), Value::Int(1));
+    }
+
+    #[test]
+    fn sum_and_product_become_floats_when_any_element_is() {
+        let source = "s := sum([1, 2, 3]); p := product([2, 3, 4]);
+            fs := sum([1, 2.5]); fp := product([2, 1.5]);";
+        assert_eq!(global(source, "s"), Value::Int(6));
+        assert_eq!(global(source, "p"), Value::Int(24));
+        assert_eq!(global(source, "fs"), Value::Float(3.5));
+        assert_eq!(global(source, "fp"), Value::Float(3.0));
+    }
+
+    #[test]
+    fn sum_rejects_non_numbers() {
+        assert_eq!(panicked_builtin(error(r#"s := sum([1, "a"]);"#)), "sum");
+    }
+
+    #[test]
+    fn aggregates_overflow_at_the_integer_width() {
+        let big = "[4611686018427387904, 4611686018427387904]";
+        for name in ["sum", "product"] {
+            assert_eq!(
+                error(&format!("{name}({big});")),
+                RuntimeError::IntegerOverflow {
+                    name: name.to_string()
+                }
+            );
+        }
+        assert_eq!(
+            output_at_width::<Bignum>(&format!("println(sum({big}));")),
+            "9223372036854775808\n"
+        );
+    }
+
+    #[test]
+    fn floor_ceil_and_round_return_ints() {
+        let source =
+            "a := floor(2.6); b := ceil(2.4); c := round(2.4); d := round(2.6); e := floor(7);";
+        for (name, expected) in [("a", 2), ("b", 3), ("c", 2), ("d", 3), ("e", 7)] {
+            assert_eq!(global(source, name), Value::Int(expected), "{name}");
+        }
+    }
+
+    #[test]
+    fn round_takes_halves_away_from_zero() {
+        let source = "up := round(2.5); down := round(-2.5);";
+        assert_eq!(global(source, "up"), Value::Int(3));
+        assert_eq!(global(source, "down"), Value::Int(-3));
+    }
+
+    #[test]
+    fn rounding_rejects_non_finite_and_out_of_range_floats() {
+        assert_eq!(
+            error("floor(0.0 / 0.0);"),
+            RuntimeError::InvalidNumber {
+                text: "NaN".to_string()
+            }
+        );
+        let overflow = RuntimeError::IntegerOverflow {
+            name: "ceil".to_string(),
+        };
+        assert_eq!(error("ceil(exp(1000.0));"), overflow);
+        // Fits in an i128 but not in the default 64 bits
+        assert_eq!(error("ceil(exp(100.0));"), overflow);
+        assert_eq!(error_at_width::<Bignum>("ceil(exp(1000.0));"), overflow);
+    }
+
+    #[test]
+    fn exp_ln_and_log_return_floats() {
+        let source = "a := ln(1); b := exp(0); c := log(8, 2);";
+        assert_eq!(global(source, "a"), Value::Float(0.0));
+        assert_eq!(global(source, "b"), Value::Float(1.0));
+        assert_eq!(global(source, "c"), Value::Float(3.0));
+    }
+
+    #[test]
+    fn ln_of_zero_is_an_error() {
+        assert_eq!(
+            error("x := ln(0);").to_string(),
+            "Math domain error: 0 is out of range for ln"
+        );
+    }
+
+    #[test]
+    fn log_of_a_negative_number_is_an_error() {
+        assert_eq!(
+            error("x := log(-4, 2);"),
+            RuntimeError::MathDomain {
+                name: "log".to_string(),
+                value: -4.0
+            }
+        );
+        assert_eq!(
+            error("x := log(-4, 2);").to_string(),
+            "Math domain error: -4 is out of range for log"
+        );
+    }
+
+    #[test]
+    fn log_base_one_is_an_error() {
+        assert_eq!(
+            error("x := log(4, 1);").to_string(),
+            "Math domain error: 1 is out of range for log"
+        );
+    }
+
+    #[test]
+    fn trigonometry_takes_radians() {
+        let source = "a := sin(0); b := cos(0); c := sin(pi() / 2.0); d := tan(pi() / 4.0);";
+        for (name, expected) in [("a", 0.0), ("b", 1.0), ("c", 1.0), ("d", 1.0)] {
+            match global(source, name) {
+                Value::Float(f) => assert!((f - expected).abs() < 1e-9, "{name} is {f}"),
+                other => panic!("Expected a float, got {other}"),
+            }
+        }
+        assert_eq!(
+            global("p := pi();", "p"),
+            Value::Float(std::f64::consts::PI)
+        );
+    }
+
+    #[test]
+    fn gcd_and_lcm_use_euclids_algorithm() {
+        let source = "a := gcd(12, 18); b := gcd(0, 0); c := lcm(4, 6); d := lcm(0, 0);";
+        for (name, expected) in [("a", 6), ("b", 0), ("c", 12), ("d", 0)] {
+            assert_eq!(global(source, name), Value::Int(expected), "{name}");
+        }
+    }
+
+    #[test]
+    fn lcm_overflow_is_an_error() {
+        assert_eq!(
+            error("lcm(4611686018427387903, 4);"),
+            RuntimeError::IntegerOverflow {
+                name: "lcm".to_string()
+            }
+        );
+        assert_eq!(
+            output_at_width::<Bignum>("println(lcm(85070591730234615865843651857942052863, 4));"),
+            "340282366920938463463374607431768211452\n"
+        );
+    }
+
+    #[test]
+    fn to_map_and_to_pairs_round_trip_in_order() {
+        let source = r#"pairs := [["b", 1], ["a", 2]]; m := to_map(pairs); back := to_pairs(m);"#;
+        assert_eq!(global(source, "m").to_string(), "{b: 1, a: 2}");
+        assert_eq!(global(source, "back"), global(source, "pairs"));
+    }
+
+    #[test]
+    fn to_map_rejects_pairs_of_the_wrong_length() {
+        assert_eq!(
+            panicked_builtin(error(r#"m := to_map([["a"]]);"#)),
+            "to_map"
+        );
+    }
+
+    #[test]
+    fn to_map_rejects_non_string_keys() {
+        assert_eq!(panicked_builtin(error("m := to_map([[1, 2]]);")), "to_map");
+    }
+
+    #[test]
+    fn read_all_returns_the_rest_of_the_input() {
+        let (mut interpreter, _) = interpreter("text := read_all();");
+        interpreter.context.input = Box::new("first\nsecond\n".as_bytes());
+        interpreter.run().unwrap();
+        assert_eq!(
+            *interpreter.state.toplevel_scope[&Symbol::intern("text")],
+            Value::String("first\nsecond\n".to_string())
+        );
+    }
+
+    #[test]
+    fn read_all_needs_the_stdin_capability() {
+        let (mut interpreter, _) = interpreter("text := read_all();");
+        interpreter.context.capabilities.stdin = false;
+        assert_eq!(panicked_builtin(interpreter.run().unwrap_err()), "read_all");
+    }
+
+    #[test]
+    fn pad_left_and_pad_right_fill_up_to_the_width() {
+        let source = r#"a := pad_left("7", 3, "0"); b := pad_right("ab", 4, ".");
+            c := pad_left("long", 2, " "); d := pad_right("x", -5, " ");"#;
+        for (name, expected) in [("a", "007"), ("b", "ab.."), ("c", "long"), ("d", "x")] {
+            assert_eq!(global(source, name), Value::String(expected.to_string()));
+        }
+    }
+
+    #[test]
+    fn padding_needs_a_single_character_fill() {
+        assert_eq!(
+            panicked_builtin(error(r#"pad_left("a", 3, "");"#)),
+            "pad_left"
+        );
+        assert_eq!(
+            panicked_builtin(error(r#"pad_right("a", 3, "ab");"#)),
+            "pad_right"
+        );
+    }
+
+    #[test]
+    fn padding_rejects_a_width_too_large_for_memory() {
+        assert_eq!(
+            panicked_builtin(error_at_width::<Bignum>(
+                r#"pad_left("a", 100000000000000000000000000000, " ");"#
+            )),
+            "pad_left"
+        );
+    }
+
+    fn with_env(source: &str, name: &str) -> Value {
+        let (mut interpreter, _) = interpreter(source);
+        interpreter.context.capabilities.env = true;
+        interpreter.run().unwrap();
+        *interpreter.state.toplevel_scope[&Symbol::intern(name)].clone()
+    }
+
+    #[test]
+    fn env_reads_a_set_variable() {
+        std::env::set_var("PULSAR_TEST_ENV_SET", "hello");
+        assert_eq!(
+            with_env(r#"v := env("PULSAR_TEST_ENV_SET");"#, "v"),
+            Value::String("hello".to_string())
+        );
+    }
+
+    #[test]
+    fn env_gives_nothing_for_an_unset_variable() {
+        assert_eq!(
+            with_env(r#"v := env("PULSAR_TEST_ENV_UNSET");"#, "v"),
+            Value::Nothing
+        );
+    }
+
+    #[test]
+    fn env_is_denied_by_default() {
+        assert_eq!(panicked_builtin(error(r#"env("PATH");"#)), "env");
+    }
+
+    #[test]
+    fn abs_returns_the_magnitude() {
+        let source = "a := abs(-5); b := abs(5); c := abs(-2.5);";
+        assert_eq!(global(source, "a"), Value::Int(5));
+        assert_eq!(global(source, "b"), Value::Int(5));
+        assert_eq!(global(source, "c"), Value::Float(2.5));
+    }
+
+    #[test]
+    fn abs_of_the_smallest_int_overflows() {
+        let overflow = |name: &str| RuntimeError::IntegerOverflow {
+            name: name.to_string(),
+        };
+        assert_eq!(
+            error_at_width::<Checked64>("abs(-9223372036854775807 - 1);"),
+            overflow("abs")
+        );
+        // The literal itself is already out of range before abs sees it
+        assert_eq!(
+            error_at_width::<Checked64>("abs(-9223372036854775808);"),
+            overflow("9223372036854775808")
+        );
+        assert_eq!(
+            output_at_width::<Bignum>(
+                "println(abs(-170141183460469231731687303715884105727 - 1));"
+            ),
+            "170141183460469231731687303715884105728\n"
+        );
+    }
+
+    #[test]
+    fn lines_splits_on_lf_and_crlf() {
+        let source = r#"a := lines("a\nb\nc"); b := lines("a\r\nb\r\n");"#;
+        assert_eq!(global(source, "a").to_string(), "[a, b, c]");
+        assert_eq!(global(source, "b").to_string(), "[a, b]");
+    }
+
+    #[test]
+    fn lines_ignores_a_trailing_newline() {
+        let source = r#"a := lines("a\nb\n"); b := lines("a\n\n");"#;
+        assert_eq!(global(source, "a").to_string(), "[a, b]");
+        assert_eq!(global(source, "b").to_string(), "[a, ]");
+    }
+
+    #[test]
+    fn int_parses_strings_and_truncates_floats() {
+        let source = r#"a := int(" 42 "); b := int("-7"); c := int(2.9); d := int(5);"#;
+        for (name, expected) in [("a", 42), ("b", -7), ("c", 2), ("d", 5)] {
+            assert_eq!(global(source, name), Value::Int(expected), "{name}");
+        }
+    }
+
+    #[test]
+    fn int_tells_overflow_apart_from_invalid_text() {
+        let overflow = RuntimeError::IntegerOverflow {
+            name: "int".to_string(),
+        };
+        assert_eq!(
+            error_at_width::<Checked64>(r#"int("99999999999999999999");"#),
+            overflow
+        );
+        assert_eq!(
+            output_at_width::<Bignum>(
+                r#"println(int("-999999999999999999999999999999999999999"));"#
+            ),
+            "-999999999999999999999999999999999999999\n"
+        );
+        assert_eq!(error_at_width::<Checked64>("int(exp(50.0));"), overflow);
+        assert_eq!(
+            error(r#"int("abc");"#),
+            RuntimeError::InvalidNumber {
+                text: "abc".to_string()
+            }
+        );
+    }
+
+    #[test]
+    fn hash_is_64_bit_fnv_1a() {
+        let source = r#"a := hash(""); b := hash("a"); c := hash("hello");"#;
+        assert_eq!(global(source, "a"), Value::Int(-3750763034362895579));
+        assert_eq!(global(source, "b"), Value::Int(-5808556873153909620));
+        assert_eq!(global(source, "c"), Value::Int(-6615550055289275125));
+    }
+
+    #[test]
+    fn is_number_accepts_ints_and_floats() {
+        let cases = [
+            ("1", true),
+            ("1.5", true),
+            (r#""1""#, false),
+            ("[1]", false),
+        ];
+        for (value, expected) in cases {
+            let source = format!("b := is_number({value});");
+            assert_eq!(global(&source, "b"), Value::Bool(expected), "{value}");
+        }
+    }
+
+    #[test]
+    fn is_finite_rejects_nan_and_infinities() {
+        let cases = [
+            ("1", true),
+            ("1.5", true),
+            ("0.0 / 0.0", false),
+            ("exp(1000.0)", false),
+            (r#""1""#, false),
+        ];
+        for (value, expected) in cases {
+            let source = format!("b := is_finite({value});");
+            assert_eq!(global(&source, "b"), Value::Bool(expected), "{value}");
+        }
+    }
+
+    #[test]
+    fn substr_takes_characters_from_a_start_index() {
+        let source = r#"s := substr("héllo", 1, 3);"#;
+        assert_eq!(global(source, "s"), Value::String("éll".to_string()));
+    }
+
+    #[test]
+    fn substr_clamps_past_the_end() {
+        let cases = [
+            (r#"substr("hello", 3, 10)"#, "lo"),
+            (r#"substr("hello", 9, 1)"#, ""),
+            // 2^64 would truncate to 0 with a plain cast
+            (r#"substr("hello", 18446744073709551616, 2)"#, ""),
+            (r#"substr("hello", 1, 18446744073709551617)"#, "ello"),
+        ];
+        for (call, expected) in cases {
+            assert_eq!(
+                output_at_width::<Bignum>(&format!("println({call});")),
+                format!("{expected}\n"),
+                "{call}"
+            );
+        }
+    }
+
+    #[test]
+    fn substr_rejects_negative_arguments() {
+        assert_eq!(
+            panicked_builtin(error(r#"substr("hello", -1, 2);"#)),
+            "substr"
+        );
+        assert_eq!(
+            panicked_builtin(error(r#"substr("hello", 1, -2);"#)),
+            "substr"
+        );
+    }
+
+    #[test]
+    fn title_capitalizes_each_word() {
+        let source = r#"s := title("hello world");"#;
+        assert_eq!(
+            global(source, "s"),
+            Value::String("Hello World".to_string())
+        );
+    }
+
+    #[test]
+    fn title_handles_mixed_case_punctuation_and_unicode() {
+        assert_eq!(
+            super::title_case("hELLO, wORLD! (again) élan"),
+            "Hello, World! (Again) Élan"
+        );
+    }
+
+    #[test]
+    fn matches_at_checks_a_char_index() {
+        let cases = [
+            (r#"matches_at("héllo", 2, "ll")"#, true),
+            (r#"matches_at("hello", 1, "l")"#, false),
+            (r#"matches_at("hello", 5, "")"#, true),
+            (r#"matches_at("hello", 9, "o")"#, false),
+            (r#"matches_at("hello", -1, "h")"#, false),
+        ];
+        for (call, expected) in cases {
+            let source = format!("b := {call};");
+            assert_eq!(global(&source, "b"), Value::Bool(expected), "{call}");
+        }
+    }
+
+    #[test]
+    fn eval_runs_code_in_the_callers_scope() {
+        assert_eq!(global(r#"x := eval("1 + 2;");"#, "x"), Value::Int(3));
+        let source = r#"x := 20; doubled := eval("x * 2;"); eval("y := x + 1;");"#;
+        assert_eq!(global(source, "doubled"), Value::Int(40));
+        assert_eq!(global(source, "y"), Value::Int(21));
+    }
+
+    #[test]
+    fn eval_reports_parse_errors() {
+        assert!(matches!(
+            error(r#"eval("1 +");"#),
+            RuntimeError::ParseFailed { .. }
+        ));
+    }
+
+    #[test]
+    fn def_registers_a_callable_function() {
+        let source = r#"def("add", ["int a", "b"], "return a + b;"); x := add(2, 3);"#;
+        assert_eq!(global(source, "x"), Value::Int(5));
+    }
+
+    #[test]
+    fn def_reports_parse_errors_in_the_body() {
+        assert!(matches!(
+            error(r#"def("broken", [], "return (;");"#),
+            RuntimeError::ParseFailed { .. }
+        ));
+    }
+
+    #[test]
+    fn format_int_groups_digits_in_threes() {
+        let cases = [
+            (r#"format_int(1234567, ",")"#, "1,234,567"),
+            (r#"format_int(-1234567, ",")"#, "-1,234,567"),
+            (r#"format_int(999, ",")"#, "999"),
+            (r#"format_int(-100, ",")"#, "-100"),
+            (r#"format_int(1000, " - ")"#, "1 - 000"),
+            (r#"format_int(1000000, "")"#, "1000000"),
+        ];
+        for (call, expected) in cases {
+            let source = format!("s := {call};");
+            assert_eq!(
+                global(&source, "s"),
+                Value::String(expected.to_string()),
+                "{call}"
+            );
+        }
+    }
+
+    #[test]
+    fn arity_counts_declared_parameters() {
+        let source = "func add(int a, int b) -> int { return a + b; }
+            user := arity(add); fixed := arity(zip); variadic := arity(println);";
+        assert_eq!(global(source, "user"), Value::Int(2));
+        assert_eq!(global(source, "fixed"), Value::Int(2));
+        assert_eq!(global(source, "variadic"), Value::Int(-1));
+        assert_eq!(panicked_builtin(error("arity(5);")), "arity");
+    }
+
+    #[test]
+    fn every_builtin_has_an_arity() {
+        let mut scope = Scope::new();
+        make_builtins(&mut scope);
+        for value in scope.values() {
+            match value.as_ref() {
+                Value::Fn(FnType::Builtin(BuiltinFn { name, .. })) => {
+                    builtin_arity(name);
+                }
+                other => panic!("Expected a builtin, got {other}"),
+            }
+        }
+    }
+
+    #[test]
+    fn name_of_reports_a_functions_name() {
+        let source = "func on_click() -> int { return 1; } handler := on_click;
+            user := name_of(handler); builtin := name_of(max);";
+        assert_eq!(
+            global(source, "user"),
+            Value::String("on_click".to_string())
+        );
+        assert_eq!(global(source, "builtin"), Value::String("max".to_string()));
+        assert_eq!(panicked_builtin(error(r#"name_of("f");"#)), "name_of");
+    }
+
+    #[test]
+    fn compare_orders_scalars() {
+        let cases = [
+            ("compare(1, 2)", -1),
+            ("compare(2.5, 1.5)", 1),
+            (r#"compare("a", "a")"#, 0),
+            ("compare(false, true)", -1),
+        ];
+        for (call, expected) in cases {
+            let source = format!("c := {call};");
+            assert_eq!(global(&source, "c"), Value::Int(expected), "{call}");
+        }
+    }
+
+    #[test]
+    fn compare_orders_arrays_lexicographically() {
+        let cases = [
+            ("compare([1, 2], [1, 3])", -1),
+            ("compare([1, 2], [1])", 1),
+            ("compare([], [])", 0),
+        ];
+        for (call, expected) in cases {
+            let source = format!("c := {call};");
+            assert_eq!(global(&source, "c"), Value::Int(expected), "{call}");
+        }
+    }
+
+    #[test]
+    fn compare_rejects_incomparable_values() {
+        assert_eq!(panicked_builtin(error(r#"compare(1, "1");"#)), "compare");
+        assert_eq!(panicked_builtin(error("compare(max, max);")), "compare");
+        assert_eq!(
+            panicked_builtin(error(r#"compare([1], ["a"]);"#)),
+            "compare"
+        );
+    }
+
+    #[test]
+    fn error_aborts_with_a_user_error() {
+        let (mut interpreter, _) = interpreter(r#"before := 1; error("boom"); after := 2;"#);
+        assert_eq!(
+            interpreter.run(),
+            Err(RuntimeError::UserError {
+                message: "boom".to_string()
+            })
+        );
+        let scope = &interpreter.state.toplevel_scope;
+        assert!(scope.contains_key(&Symbol::intern("before")));
+        assert!(!scope.contains_key(&Symbol::intern("after")));
+        assert_eq!(panicked_builtin(error("error(1);")), "error");
+    }
+
+    #[test]
+    fn default_gives_each_types_zero_value() {
+        let cases = [
+            ("Int", Value::Int(0)),
+            ("float", Value::Float(0.0)),
+            ("String", Value::String(String::new())),
+            ("Bool", Value::Bool(false)),
+            ("Array", Value::Array(Vec::new())),
+            ("Map", Value::Map(IndexMap::new())),
+            ("nothing", Value::Nothing),
+        ];
+        for (type_name, expected) in cases {
+            let source = format!(r#"x := default("{type_name}");"#);
+            assert_eq!(global(&source, "x"), expected, "{type_name}");
+        }
+    }
+
+    #[test]
+    fn default_rejects_unknown_type_names() {
+        assert_eq!(panicked_builtin(error(r#"default("Widget");"#)), "default");
+    }
+
+    #[test]
+    fn print_and_println_write_to_the_output_sink() {
+        assert_eq!(
+            output(r#"print("a", 1); println("b"); println(2, "c");"#),
+            "a, 1b\n2, c\n"
+        );
+    }
+
+    fn limited(source: &str, limit: usize) -> (Interpreter, Output) {
+        let (mut interpreter, output) = interpreter(source);
+        interpreter.context.max_output_bytes = Some(limit);
+        (interpreter, output)
+    }
+
+    #[test]
+    fn a_print_loop_stops_at_the_output_limit() {
+        let (mut interpreter, output) = limited(r#"while true { print("xyz"); }"#, 10);
+        assert_eq!(
+            interpreter.run(),
+            Err(RuntimeError::OutputLimitExceeded { limit: 10 })
+        );
+        // The write that would go over is dropped whole
+        assert_eq!(output.text(), "xyzxyzxyz");
+        assert_eq!(interpreter.context.output_bytes, 9);
+    }
+
+    #[test]
+    fn a_dropped_write_does_not_count_towards_the_limit() {
+        let (mut interpreter, output) = limited(
+            r#"try { print("0123456789"); } catch e { print("!"); } print("ok");"#,
+            4,
+        );
+        interpreter.run().unwrap();
+        assert_eq!(output.text(), "!ok");
+    }
+
+    #[test]
+    fn time_reports_count_towards_the_limit() {
+        let (mut interpreter, output) = limited("func work() -> int { return 7; } time(work);", 5);
+        assert_eq!(
+            interpreter.run(),
+            Err(RuntimeError::OutputLimitExceeded { limit: 5 })
+        );
+        assert_eq!(output.text(), "");
+    }
+
+    #[test]
+    fn flatten_removes_one_level_of_nesting() {
+        assert_eq!(
+            output("println(flatten([[1, 2], [3]])); println(flatten([1, [2, [3]]]));"),
+            "[1, 2, 3]\n[1, 2, [3]]\n"
+        );
+        assert_eq!(panicked_builtin(error("flatten(1);")), "flatten");
+    }
+
+    #[test]
+    fn flatten_deep_removes_every_level() {
+        assert_eq!(
+            output("println(flatten_deep([1, [2, [3, [4]]], []]));"),
+            "[1, 2, 3, 4]\n"
+        );
+        assert_eq!(panicked_builtin(error("flatten_deep(1);")), "flatten_deep");
+    }
+
+    #[test]
+    fn unique_keeps_the_first_occurrence_in_order() {
+        assert_eq!(output("println(unique([3, 1, 3, 2, 1]));"), "[3, 1, 2]\n");
+        // Values of different types never count as the same
+        assert_eq!(
+            global(r#"xs := unique([1, "1", 1, [1], "1", [1], 1.5]);"#, "xs"),
+            Value::Array(vec![
+                Value::Int(1),
+                Value::String("1".to_string()),
+                Value::Array(vec![Value::Int(1)]),
+                Value::Float(1.5)
+            ])
+        );
+    }
+
+    #[test]
+    fn group_by_collects_elements_under_their_key() {
+        assert_eq!(
+            output(
+                r#"func size(int n) -> string { if n < 3 { return "small"; } return "big"; }
+                println(group_by([3, 1, 4, 2, 5], size));"#
+            ),
+            "{big: [3, 4, 5], small: [1, 2]}\n"
+        );
+        assert_eq!(panicked_builtin(error("group_by([1], 2);")), "group_by");
+    }
+
+    #[test]
+    fn mean_median_and_mode_of_small_datasets() {
+        assert_eq!(
+            output(
+                "println(mean([1, 2, 3, 4])); println(median([3, 1, 2])); println(median([4, 1, 3, 2]));
+                println(mode([1, 2, 2, 3, 3])); println(mode([1.5, 2, 1.5]));"
+            ),
+            "2.5\n2\n2.5\n2\n1.5\n"
+        );
+    }
+
+    #[test]
+    fn statistics_of_an_empty_array_are_errors() {
+        for name in ["mean", "median", "mode"] {
+            assert_eq!(panicked_builtin(error(&format!("{name}([]);"))), name);
+        }
+        assert_eq!(panicked_builtin(error(r#"mean([1, "a"]);"#)), "mean");
+    }
+
+    #[test]
+    fn chunk_splits_into_fixed_size_pieces() {
+        assert_eq!(
+            output("println(chunk([1, 2, 3, 4], 2)); println(chunk([1, 2, 3, 4, 5], 2));"),
+            "[[1, 2], [3, 4]]\n[[1, 2], [3, 4], [5]]\n"
+        );
+        // 2^64 + 1 would truncate to 1 with a plain cast
+        assert_eq!(
+            output_at_width::<Bignum>("println(chunk([1, 2, 3], 18446744073709551617));"),
+            "[[1, 2, 3]]\n"
+        );
+    }
+
+    #[test]
+    fn chunk_rejects_sizes_below_one() {
+        assert_eq!(panicked_builtin(error("chunk([1], 0);")), "chunk");
+        assert_eq!(panicked_builtin(error("chunk([1], -2);")), "chunk");
+    }
+
+    #[test]
+    fn divmod_results_destructure_into_two_names() {
+        assert_eq!(
+            output("(q, r) = divmod(17, 5); println(q); println(r); (q, r) := divmod(-17, 5); println(q); println(r);"),
+            "3\n2\n-3\n-2\n"
+        );
+        assert_eq!(error("divmod(1, 0);"), RuntimeError::DivisionByZero);
+    }
+
+    #[test]
+    fn deep_equal_compares_nested_arrays() {
+        assert_eq!(
+            output("println(deep_equal([1, [2, [3]]], [1, [2, [3]]])); println(deep_equal([1, [2]], [1, [3]])); println(deep_equal([1], [1, 1]));"),
+            "true\nfalse\nfalse\n"
+        );
+    }
+
+    #[test]
+    fn deep_equal_matches_map_entries_by_key() {
+        assert_eq!(
+            output(
+                r#"println(deep_equal({"a": [1], "b": 2}, {"b": 2, "a": [1]}));
+                println(deep_equal({"a": [1]}, {"a": [2]})); println(deep_equal({"a": 1}, {"b": 1}));"#
+            ),
+            "true\nfalse\nfalse\n"
+        );
+    }
+
+    #[test]
+    fn times_passes_the_index_to_one_argument_functions() {
+        assert_eq!(
+            output("func f(int i) -> int { return i; } println(times(3, f));"),
+            "[0, 1, 2]\n"
+        );
+    }
+
+    #[test]
+    fn times_calls_zero_argument_functions_without_an_index() {
+        assert_eq!(
+            output(
+                r#"func f() -> string { return "x"; } println(times(2, f)); println(times(0, f));"#
+            ),
+            "[x, x]\n[]\n"
+        );
+        assert_eq!(
+            panicked_builtin(error("func f() -> int { return 1; } times(-1, f);")),
+            "times"
+        );
+    }
+
+    #[test]
+    fn concat_str_joins_the_display_of_each_value() {
+        assert_eq!(
+            output(r#"println(concat_str(1, " is ", true)); println(concat_str([1], 2.5) + "!");"#),
+            "1 is true\n[1]2.5!\n"
+        );
+    }
+
+    #[test]
+    fn table_aligns_columns_and_leaves_missing_keys_empty() {
+        assert_eq!(
+            output(
+                r#"print(table([{"name": "ada", "age": 36}, {"name": "grace", "lang": "cobol"}]));"#
+            ),
+            "name  | age | lang\n------+-----+------\nada   | 36  |\ngrace |     | cobol"
+        );
+        assert_eq!(panicked_builtin(error("table([1]);")), "table");
+    }
+
+    #[test]
+    fn memoized_functions_run_once_per_distinct_argument() {
+        assert_eq!(
+            output(
+                r#"func sq(int x) -> int { println("run"); return x * x; }
+m := memoize(sq);
+println(m(3)); println(m(3)); println(m(4)); println(m(3)); println(arity(m));"#
+            ),
+            "run\n9\n9\nrun\n16\n9\n1\n"
+        );
+    }
+
+    #[test]
+    fn memoized_recursion_goes_through_the_cache() {
+        assert_eq!(
+            output(
+                "func fib(int n) -> int { println(n); if n < 2 { return n; } return fib(n - 1) + fib(n - 2); }
+fib := memoize(fib);
+println(fib(3));"
+            ),
+            "3\n2\n1\n0\n2\n"
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "takes int, string or bool arguments")]
+    fn memoized_functions_need_hashable_arguments() {
+        output("func f(array x) -> int { return 1; } memoize(f)([1]);");
+    }
+
+    #[test]
+    fn memoize_takes_a_function() {
+        assert_eq!(panicked_builtin(error("memoize(1);")), "memoize");
+    }
+
+    #[test]
+    fn partial_puts_captured_arguments_first() {
+        assert_eq!(
+            output(
+                "func add(int a, int b) -> int { return a + b; }
+func sub(int a, int b) -> int { return a - b; }
+add10 := partial(add, 10);
+println(add10(5)); println(partial(sub, 10)(3)); println(arity(add10)); println(partial(add, 1, 2)());"
+            ),
+            "15\n7\n1\n3\n"
+        );
+    }
+
+    #[test]
+    fn partial_checks_the_arity() {
+        let add = "func add(int a, int b) -> int { return a + b; }";
+        assert_eq!(
+            panicked_builtin(error(&format!("{add} partial(add, 1, 2, 3);"))),
+            "partial"
+        );
+        assert_eq!(panicked_builtin(error("partial(1, 2);")), "partial");
+    }
+
+    #[test]
+    #[should_panic(expected = "takes 2 arguments, got 3")]
+    fn partial_results_check_the_total_arity() {
+        output("func add(int a, int b) -> int { return a + b; } partial(add, 1)(2, 3);");
+    }
+
+    #[test]
+    fn compose_calls_the_second_function_first() {
+        assert_eq!(
+            output(
+                "func inc(int x) -> int { return x + 1; }
+func double(int x) -> int { return x * 2; }
+println(compose(inc, double)(5)); println(compose(double, inc)(5)); println(arity(compose(inc, inc)));"
+            ),
+            "11\n12\n1\n"
+        );
+    }
+
+    #[test]
+    fn compose_needs_one_argument_functions() {
+        let add = "func add(int a, int b) -> int { return a + b; }";
+        assert_eq!(
+            panicked_builtin(error(&format!("{add} compose(add, add);"))),
+            "compose"
+        );
+        assert_eq!(panicked_builtin(error("compose(1, 2);")), "compose");
+    }
+
+    #[test]
+    fn map_and_filter_take_inline_lambdas() {
+        assert_eq!(
+            output(
+                "println(map([1, 2, 3], fn(x) { x * 2 })); println(filter([1, 2, 3, 4], fn(int x) { x > 2 }));"
+            ),
+            "[2, 4, 6]\n[3, 4]\n"
+        );
+        assert_eq!(panicked_builtin(error("map(1, 2);")), "map");
+        assert_eq!(panicked_builtin(error("filter([1], 2);")), "filter");
+    }
+
+    #[test]
+    fn lambdas_see_outer_variables() {
+        assert_eq!(
+            output(
+                "k := 10; f := fn(x) { x + k }; println(map([1, 2], f)); println(fn() { k }());"
+            ),
+            "[11, 12]\n10\n"
+        );
+    }
+
+    #[test]
+    fn huge_ranges_are_never_materialized() {
+        // An array of 10^18 ints couldn't be allocated, so getting here at
+        // all means the loop pulled one element at a time
+        assert_eq!(
+            output(
+                "n := 0; for i in range(1000000000000000000) { if i == 1000 { break; } n = n + 1; } println(n);"
+            ),
+            "1000\n"
+        );
+    }
+
+    #[test]
+    fn ranges_feed_map_filter_and_reduce() {
+        assert_eq!(
+            output(
+                "println(map(range(3), fn(x) { x * 2 })); println(filter(range(10, 0, -3), fn(x) { x > 5 })); println(reduce(range(1, 5), fn(a, b) { a + b }, 0));"
+            ),
+            "[0, 2, 4]\n[10, 7]\n10\n"
+        );
+    }
+
+    #[test]
+    fn ranges_can_be_iterated_more_than_once() {
+        assert_eq!(
+            output(
+                "r := range(2); for i in r { println(i); } for i in r { println(i); } println(r);"
+            ),
+            "0\n1\n0\n1\nrange(0, 2, 1)\n"
+        );
+    }
+
+    #[test]
+    fn range_needs_a_non_zero_step() {
+        assert_eq!(panicked_builtin(error("range(0, 5, 0);")), "range");
+        assert_eq!(panicked_builtin(error(r#"range("a");"#)), "range");
+    }
+
+    // Records what it was asked to sleep for instead of waiting
+    #[derive(Clone, Default)]
+    struct SleepRecorder(Rc<RefCell<Vec<Duration>>>);
+
+    impl Clock for SleepRecorder {
+        fn now(&mut self) -> Duration {
+            Duration::ZERO
+        }
+
+        fn sleep(&mut self, duration: Duration) {
+            self.0.borrow_mut().push(duration);
+        }
+    }
+
+    #[test]
+    fn sleep_goes_through_the_clock() {
+        let (mut interpreter, output) =
+            interpreter("println(sleep(60000)); sleep(0); println(\"done\");");
+        let sleeps = SleepRecorder::default();
+        interpreter.context.clock = Box::new(sleeps.clone());
+        interpreter.run().unwrap();
+        assert_eq!(output.text(), "nothing\ndone\n");
+        assert_eq!(
+            *sleeps.0.borrow(),
+            [Duration::from_millis(60000), Duration::ZERO]
+        );
+    }
+
+    #[test]
+    fn sleep_rejects_negative_durations() {
+        assert_eq!(panicked_builtin(error("sleep(-1);")), "sleep");
+        assert_eq!(panicked_builtin(error("sleep(1.5);")), "sleep");
+    }
+
+    #[test]
+    fn json_round_trips_a_nested_structure() {
+        assert_eq!(
+            output(
+                r#"s := "{\"b\": [1, 2.5, {\"c\": null}], \"a\": true, \"s\": \"x\"}";
+v := parse_json(s);
+println(to_json(v)); println(deep_equal(parse_json(to_json(v)), v)); println(to_json((1, "a")));"#
+            ),
+            "{\"b\":[1,2.5,{\"c\":null}],\"a\":true,\"s\":\"x\"}\ntrue\n[1,\"a\"]\n"
+        );
+    }
+
+    #[test]
+    fn json_integers_past_i64_stay_integers() {
+        assert_eq!(
+            output_at_width::<Bignum>(
+                r#"v := parse_json("[18446744073709551615]"); println(v[0] - 1); println(to_json(v));"#
+            ),
+            "18446744073709551614\n[18446744073709551615]\n"
+        );
+        assert_eq!(
+            error(r#"parse_json("[18446744073709551615]");"#),
+            RuntimeError::IntegerOverflow {
+                name: "parse_json".to_string()
+            }
+        );
+    }
+
+    #[test]
+    fn malformed_json_is_a_parse_error() {
+        assert_eq!(
+            error(r#"parse_json("{");"#),
+            RuntimeError::ParseFailed {
+                message: "Invalid JSON: EOF while parsing an object at line 1 column 1".to_string()
+            }
+        );
+    }
+
+    #[test]
+    fn values_without_a_json_form_are_errors() {
+        assert_eq!(
+            error("func f() -> int { return 1; } to_json([f]);"),
+            RuntimeError::NotSerializable {
+                message: "a function has no JSON form".to_string()
+            }
+        );
+        assert_eq!(
+            error("to_json(exp(1000.0));"),
+            RuntimeError::NotSerializable {
+                message: "inf is not a JSON number".to_string()
+            }
+        );
+    }
+
+    #[test]
+    fn parse_kv_reads_trimmed_pairs() {
+        assert_eq!(
+            output(r#"println(to_json(parse_kv("name = pulsar\nurl=a=b\n  mode=fast  ")));"#),
+            "{\"name\":\"pulsar\",\"url\":\"a=b\",\"mode\":\"fast\"}\n"
+        );
+    }
+
+    #[test]
+    fn parse_kv_skips_comments_and_blank_lines() {
+        assert_eq!(
+            output(r##"println(to_json(parse_kv("# settings\n\n  \n  # indented\nk=v\n")));"##),
+            "{\"k\":\"v\"}\n"
+        );
+    }
+
+    #[test]
+    fn parse_kv_reports_the_malformed_line() {
+        assert_eq!(
+            error(r#"parse_kv("a=1\n\njunk\n");"#),
+            RuntimeError::ParseFailed {
+                message: "Expected key=value on line 3".to_string()
+            }
+        );
+        assert_eq!(panicked_builtin(error("parse_kv(1);")), "parse_kv");
+    }
+
+    #[test]
+    fn url_encoding_round_trips() {
+        assert_eq!(
+            output(
+                r#"e := url_encode("a b/c?d=é~_.-"); println(e); println(url_decode(e)); println(url_decode("a+b%2a"));"#
+            ),
+            "a%20b%2Fc%3Fd%3D%C3%A9~_.-\na b/c?d=é~_.-\na+b*\n"
+        );
+    }
+
+    #[test]
+    fn malformed_percent_sequences_are_errors() {
+        for input in ["%", "%4", "%zz", "%+1"] {
+            assert_eq!(
+                error(&format!("url_decode({input:?});")),
+                RuntimeError::ParseFailed {
+                    message: format!("Invalid percent-encoding in {input:?}")
+                }
+            );
+        }
+        assert_eq!(
+            error(r#"url_decode("%FF");"#),
+            RuntimeError::ParseFailed {
+                message: r#""%FF" does not decode to UTF-8"#.to_string()
+            }
+        );
+    }
+
+    #[test]
+    fn base64_matches_the_standard_vectors() {
+        let vectors = [
+            ("", ""),
+            ("f", "Zg=="),
+            ("fo", "Zm8="),
+            ("foo", "Zm9v"),
+            ("foob", "Zm9vYg=="),
+            ("fooba", "Zm9vYmE="),
+            ("foobar", "Zm9vYmFy"),
+            ("é?>", "w6k/Pg=="),
+        ];
+        for (plain, encoded) in vectors {
+            assert_eq!(
+                output(&format!(
+                    "println(base64_encode({plain:?})); println(base64_decode({encoded:?}));"
+                )),
+                format!("{encoded}\n{plain}\n")
+            );
+        }
+    }
+
+    #[test]
+    fn invalid_base64_is_an_error() {
+        for input in ["Zm9", "Z===", "Zg=v", "Zm9v!A==", "Zg==Zg=="] {
+            assert_eq!(
+                error(&format!("base64_decode({input:?});")),
+                RuntimeError::ParseFailed {
+                    message: format!("{input:?} is not valid base64")
+                }
+            );
+        }
+        assert_eq!(
+            error(r#"base64_decode("/w==");"#),
+            RuntimeError::ParseFailed {
+                message: r#""/w==" does not decode to UTF-8"#.to_string()
+            }
+        );
+    }
+
+    #[test]
+    fn regex_match_and_find_all_look_for_digits() {
+        assert_eq!(
+            output(
+                r#"println(regex_match("abc123", "\\d+")); println(regex_match("abc", "\\d+")); println(to_json(regex_find_all("a1 b22 c333", "\\d+")));"#
+            ),
+            "true\nfalse\n[\"1\",\"22\",\"333\"]\n"
+        );
+    }
+
+    #[test]
+    fn invalid_patterns_report_the_compile_error() {
+        assert_eq!(
+            error(r#"regex_match("a", "(");"#),
+            RuntimeError::InvalidRegex {
+                message: "regex parse error:\n    (\n    ^\nerror: unclosed group".to_string()
+            }
+        );
+        assert_eq!(
+            error(r#"regex_find_all("a", "(a{1000}){1000}");"#),
+            RuntimeError::InvalidRegex {
+                message: "pattern is too large, it needs more than 100000 steps".to_string()
+            }
+        );
+    }
+
+    #[test]
+    fn regex_matching_does_not_blow_up_on_nested_repetition() {
+        assert_eq!(
+            output(&format!(
+                r#"println(regex_match("{}", "(a|aa)*c"));"#,
+                "a".repeat(2000)
+            )),
+            "false\n"
+        );
+    }
+
+    #[test]
+    fn regex_capture_returns_the_groups_of_the_first_match() {
+        assert_eq!(
+            output(
+                r#"println(to_json(regex_capture("on 2024-05 and 2025-06", "(\\d{4})-(\\d{2})")));"#
+            ),
+            "[\"2024-05\",\"2024\",\"05\"]\n"
+        );
+        assert_eq!(
+            output(
+                r#"println(to_json(regex_capture("b", "(a)?b"))); println(to_json(regex_capture("abc", "(\\w)+"))); println(regex_capture("x", "\\d"));"#
+            ),
+            "[\"b\",null]\n[\"abc\",\"c\"]\nnothing\n"
+        );
+    }
+
+    #[test]
+    fn profile_counts_calls_by_name() {
+        let source = r#"func add(int a, int b) -> int { return a + b; }
+add(1, 2); add(3, 4); add(5, 6);
+counts := profile(); println(counts["add"]); println(counts["profile"]);"#;
+        for bytecode in [false, true] {
+            let (mut interpreter, output) = interpreter(source);
+            interpreter.enable_profiling();
+            if bytecode {
+                interpreter.run_bytecode().unwrap();
+            } else {
+                interpreter.run().unwrap();
+            }
+            assert_eq!(output.text(), "3\n1\n");
+        }
+    }
+
+    #[test]
+    fn profile_needs_profiling_on() {
+        assert_eq!(panicked_builtin(error("profile();")), "profile");
+    }
+
+    #[test]
+    fn cumsum_and_cumprod_keep_running_totals() {
+        assert_eq!(
+            output(
+                "println(cumsum([1, 2, 3])); println(cumprod([1, 2, 3])); println(cumsum([])); println(cumsum([1, 0.5, 2]));"
+            ),
+            "[1, 3, 6]\n[1, 2, 6]\n[]\n[1, 1.5, 3.5]\n"
+        );
+        assert_eq!(panicked_builtin(error(r#"cumsum([1, "a"]);"#)), "cumsum");
+        assert_eq!(panicked_builtin(error("cumprod(1);")), "cumprod");
+        assert_eq!(
+            error("cumsum([9223372036854775807, 1]);"),
+            RuntimeError::IntegerOverflow {
+                name: "cumsum".to_string()
+            }
+        );
+    }
+
+    #[test]
+    fn display_width_counts_terminal_columns() {
+        assert_eq!(
+            output(
+                r#"println(display_width("hello")); println(display_width("日本語")); println(display_width("élève")); println(display_width("go 🚀"));"#
+            ),
+            "5\n6\n5\n5\n"
+        );
+        assert_eq!(
+            panicked_builtin(error("display_width(1);")),
+            "display_width"
+        );
+    }
+
+    #[test]
+    fn normalize_composes_decomposed_accents() {
+        assert_eq!(
+            output(
+                r#"println(normalize("Cafe\u{301} cre\u{300}me", "NFC") == "Caf\u{e9} cr\u{e8}me"); println(normalize("\u{e9}", "NFD") == "e\u{301}");"#
+            ),
+            "true\ntrue\n"
+        );
+        assert_eq!(
+            panicked_builtin(error(r#"normalize("a", "NFX");"#)),
+            "normalize"
+        );
+    }
+
+    #[test]
+    fn wrap_fills_lines_up_to_the_width() {
+        assert_eq!(
+            output(
+                r#"println(to_json(wrap("the quick brown fox jumps over the lazy dog", 10))); println(to_json(wrap("  a\n\tb  ", 3))); println(wrap("", 5));"#
+            ),
+            "[\"the quick\",\"brown fox\",\"jumps over\",\"the lazy\",\"dog\"]\n[\"a b\"]\n[]\n"
+        );
+    }
+
+    #[test]
+    fn wrap_keeps_an_over_long_word_whole() {
+        assert_eq!(
+            output(r#"println(to_json(wrap("a extraordinarily b", 5)));"#),
+            "[\"a\",\"extraordinarily\",\"b\"]\n"
+        );
+        assert_eq!(
+            output_at_width::<Bignum>(
+                r#"println(to_json(wrap("a b", 170141183460469231731687303715884105727)));"#
+            ),
+            "[\"a b\"]\n"
+        );
+    }
+
+    #[test]
+    fn wrap_needs_a_positive_width() {
+        assert_eq!(panicked_builtin(error(r#"wrap("a", 0);"#)), "wrap");
+        assert_eq!(panicked_builtin(error("wrap(1, 2);")), "wrap");
+    }
+
+    #[test]
+    fn edit_distance_counts_single_char_edits() {
+        assert_eq!(
+            output(
+                r#"println(edit_distance("kitten", "kitten")); println(edit_distance("kitten", "sitten")); println(edit_distance("kitten", "sitting")); println(edit_distance("intention", "execution"));"#
+            ),
+            "0\n1\n3\n5\n"
+        );
+        assert_eq!(
+            output(r#"println(edit_distance("", "abc")); println(edit_distance("café", "cafe"));"#),
+            "3\n1\n"
+        );
+        assert_eq!(
+            panicked_builtin(error(r#"edit_distance("a", 1);"#)),
+            "edit_distance"
+        );
     }
 }
@@ -0,0 +1,1110 @@
+use {
+    crate::{
+        interpreter::{
+            binary_op, call_user_fn, call_value, catch_panic, get_valuetype_from, index_value,
+            int_arithmetic, int_literal, interpret_expr, is_special_form, post_increment,
+            target_symbol, undefined, Context, FnType, RuntimeError, Scope, TailCall, UserFn,
+            Value,
+        },
+        lexer::Token,
+        parser::{Expr, Operator},
+        symbol::Symbol,
+    },
+    indexmap::IndexMap,
+    std::{collections::HashMap, mem, rc::Rc},
+};
+
+// One step of the stack machine. Every expression compiles to code that
+// leaves exactly one value on the stack, and jump targets are indices into
+// the same instruction list
+#[derive(Debug, Clone, PartialEq)]
+pub enum Instruction {
+    // Pushes an entry of the chunk's constant pool
+    Constant(usize),
+    Load(Symbol),
+    // Assignments leave the value on the stack, since they evaluate to it
+    Store(Symbol),
+    CheckType(String),
+    Destructure(Vec<Symbol>),
+    Pop,
+    Binary(Operator),
+    Not,
+    Negate,
+    PostIncrement { name: Symbol, op: Operator },
+    // For &&, || and ?? the left side is the result if it settles the
+    // answer, otherwise it gets popped and the right side replaces it
+    ShortCircuit { op: Operator, target: usize },
+    CheckBool(Operator),
+    Call { name: Symbol, argc: usize },
+    // The function sits on top of its arguments
+    CallValue { argc: usize, piped: bool },
+    // A call that is returned straight away. To a user function it ends
+    // the frame and the call is made from the caller's loop instead
+    TailCall { name: Symbol, argc: usize },
+    TailCallValue { argc: usize },
+    Array(usize),
+    Tuple(usize),
+    Map(usize),
+    CheckKey,
+    Index,
+    Jump(usize),
+    JumpUnlessTrue(usize),
+    // Leaves the value it looks at on the stack
+    JumpUnlessType { pattern: String, target: usize },
+    EnterScope,
+    ExitScope,
+    LoopStart,
+    LoopEnd,
+    // Drops whatever a half evaluated expression left on the stack when
+    // break, continue or return leaves it early
+    Unwind,
+    IterStart,
+    IterNext { var: Symbol, target: usize },
+    IterEnd,
+    TryStart { error_name: Symbol, target: usize },
+    TryEnd,
+    Return,
+    Panic(String),
+    // Function definitions, lambdas and the special forms go to the
+    // tree-walker, they either only run once or need the expression itself
+    Interpret(Expr),
+}
+
+impl Instruction {
+    fn set_target(&mut self, to: usize) {
+        match self {
+            Instruction::Jump(target)
+            | Instruction::JumpUnlessTrue(target)
+            | Instruction::ShortCircuit { target, .. }
+            | Instruction::JumpUnlessType { target, .. }
+            | Instruction::IterNext { target, .. }
+            | Instruction::TryStart { target, .. } => *target = to,
+            other => unreachable!("{other:?} doesn't jump"),
+        }
+    }
+}
+
+// Compiled code along with the literals it uses. Each distinct literal is
+// stored once in the pool, however many times the program repeats it
+#[derive(Debug, Default)]
+pub struct Chunk {
+    pub code: Vec<Instruction>,
+    pub constants: Vec<Value>,
+}
+
+// Floats go by their bits, so 0.0 and -0.0 stay apart and NaN matches itself
+#[derive(PartialEq, Eq, Hash)]
+enum ConstantKey {
+    Int(i128),
+    Float(u64),
+    String(String),
+    Bool(bool),
+    Nothing,
+}
+
+// What has to be undone when break, continue or return jumps out of it
+enum Nested {
+    Scope,
+    Try,
+    Iter,
+    Loop,
+}
+
+struct Loop {
+    // How deep the nesting was once the loop itself was entered
+    depth: usize,
+    continue_target: Option<usize>,
+    continues: Vec<usize>,
+    breaks: Vec<usize>,
+}
+
+struct Compiler<'a> {
+    chunk: Chunk,
+    constant_indices: HashMap<ConstantKey, usize>,
+    nested: Vec<Nested>,
+    loops: Vec<Loop>,
+    // None at the top level
+    function: Option<&'a str>,
+    // The tree-walker drops a break or return that reaches the top level,
+    // which ends the statement it is in. These jump to that statement's end
+    exits: Vec<usize>,
+}
+
+pub fn compile(exprs: &[Expr]) -> Chunk {
+    let mut compiler = Compiler::new(None);
+    for expr in exprs {
+        compiler.expr(expr);
+        compiler.emit(Instruction::Pop);
+        for at in mem::take(&mut compiler.exits) {
+            compiler.patch(at);
+        }
+    }
+    compiler.chunk
+}
+
+pub(crate) fn compile_function(func: &UserFn) -> Chunk {
+    let mut compiler = Compiler::new(Some(&func.name));
+    compiler.statements(&func.body);
+    compiler.chunk
+}
+
+impl<'a> Compiler<'a> {
+    fn new(function: Option<&'a str>) -> Self {
+        Self {
+            chunk: Chunk::default(),
+            constant_indices: HashMap::new(),
+            nested: Vec::new(),
+            loops: Vec::new(),
+            function,
+            exits: Vec::new(),
+        }
+    }
+
+    fn emit(&mut self, instruction: Instruction) -> usize {
+        self.chunk.code.push(instruction);
+        self.chunk.code.len() - 1
+    }
+
+    fn constant(&mut self, value: Value) -> usize {
+        let key = match &value {
+            Value::Int(i) => ConstantKey::Int(*i),
+            Value::Float(f) => ConstantKey::Float(f.to_bits()),
+            Value::String(s) => ConstantKey::String(s.clone()),
+            Value::Bool(b) => ConstantKey::Bool(*b),
+            Value::Nothing => ConstantKey::Nothing,
+            other => unreachable!("{} is never a literal", other.get_type()),
+        };
+        let constants = &mut self.chunk.constants;
+        *self.constant_indices.entry(key).or_insert_with(|| {
+            constants.push(value);
+            constants.len() - 1
+        })
+    }
+
+    fn push(&mut self, value: Value) {
+        let index = self.constant(value);
+        self.emit(Instruction::Constant(index));
+    }
+
+    // Points the jump at `at` to the next instruction
+    fn patch(&mut self, at: usize) {
+        let to = self.chunk.code.len();
+        self.chunk.code[at].set_target(to);
+    }
+
+    fn statements(&mut self, body: &[Expr]) {
+        for expr in body {
+            self.expr(expr);
+            self.emit(Instruction::Pop);
+        }
+    }
+
+    // Leaves the last value of the block, like interpret_block
+    fn block(&mut self, body: &[Expr]) {
+        match body.split_last() {
+            Some((last, rest)) => {
+                self.statements(rest);
+                self.expr(last);
+            }
+            None => {
+                self.push(Value::Nothing);
+            }
+        }
+    }
+
+    // Blocks other than loop bodies run in a copy of the enclosing scope
+    fn scoped(&mut self, body: &[Expr], keep_value: bool) {
+        self.emit(Instruction::EnterScope);
+        self.nested.push(Nested::Scope);
+        if keep_value {
+            self.block(body);
+        } else {
+            self.statements(body);
+        }
+        self.nested.pop();
+        self.emit(Instruction::ExitScope);
+    }
+
+    fn unwind_to(&mut self, depth: usize) {
+        for index in (depth..self.nested.len()).rev() {
+            let instruction = match self.nested[index] {
+                Nested::Scope => Instruction::ExitScope,
+                Nested::Try => Instruction::TryEnd,
+                Nested::Iter => Instruction::IterEnd,
+                Nested::Loop => Instruction::LoopEnd,
+            };
+            self.emit(instruction);
+        }
+        self.emit(Instruction::Unwind);
+    }
+
+    fn exit_statement(&mut self) {
+        self.unwind_to(0);
+        let at = self.emit(Instruction::Jump(0));
+        self.exits.push(at);
+    }
+
+    fn loop_control(&mut self, is_break: bool) {
+        let (depth, continue_target) = match self.loops.last() {
+            Some(current) => (current.depth, current.continue_target),
+            None => {
+                match self.function {
+                    Some(name) => {
+                        self.emit(Instruction::Panic(format!(
+                            "Cannot break or continue outside of a loop in function {name}"
+                        )));
+                    }
+                    None => self.exit_statement(),
+                }
+                return;
+            }
+        };
+        self.unwind_to(depth);
+        match (is_break, continue_target) {
+            (false, Some(target)) => {
+                self.emit(Instruction::Jump(target));
+            }
+            _ => {
+                let at = self.emit(Instruction::Jump(0));
+                let current = self.loops.last_mut().unwrap();
+                if is_break {
+                    current.breaks.push(at);
+                } else {
+                    current.continues.push(at);
+                }
+            }
+        }
+    }
+
+    fn start_loop(&mut self, continue_target: Option<usize>) {
+        self.emit(Instruction::LoopStart);
+        self.nested.push(Nested::Loop);
+        self.loops.push(Loop {
+            depth: self.nested.len(),
+            continue_target,
+            continues: Vec::new(),
+            breaks: Vec::new(),
+        });
+    }
+
+    // Breaks land here, where the loop gets left
+    fn end_loop(&mut self) {
+        let current = self.loops.pop().unwrap();
+        for at in current.breaks {
+            self.patch(at);
+        }
+        self.nested.pop();
+        self.emit(Instruction::LoopEnd);
+    }
+
+    fn call(&mut self, callee: &Expr, argc: usize, piped: bool) {
+        match callee {
+            Expr::Token(Token::Identifier(name)) => {
+                self.emit(Instruction::Call { name: *name, argc });
+            }
+            callee => {
+                self.expr(callee);
+                self.emit(Instruction::CallValue { argc, piped });
+            }
+        }
+    }
+
+    fn expr(&mut self, expr: &Expr) {
+        match expr {
+            Expr::Token(Token::Identifier(name)) => {
+                self.emit(Instruction::Load(*name));
+            }
+            Expr::Token(token) => {
+                let value = match token {
+                    Token::Int(i) => Value::Int(*i),
+                    Token::Float(f) => Value::Float(*f),
+                    Token::String(s) => Value::String(s.clone()),
+                    Token::Bool(b) => Value::Bool(*b),
+                    _ => Value::Nothing,
+                };
+                self.push(value);
+            }
+            Expr::BinaryExpr {
+                op: Operator::SetVal(expected_type),
+                lhs,
+                rhs,
+            } => {
+                self.expr(rhs);
+                match (expected_type, lhs.as_ref()) {
+                    (Some(expected_type), _) => {
+                        self.emit(Instruction::CheckType(expected_type.clone()));
+                        self.emit(Instruction::Store(target_symbol(lhs)));
+                    }
+                    (None, Expr::Tuple { items }) => {
+                        self.emit(Instruction::Destructure(
+                            items.iter().map(target_symbol).collect(),
+                        ));
+                    }
+                    (None, lhs) => {
+                        self.emit(Instruction::Store(target_symbol(lhs)));
+                    }
+                }
+            }
+            Expr::BinaryExpr {
+                op: op @ (Operator::And | Operator::Or | Operator::Coalesce),
+                lhs,
+                rhs,
+            } => {
+                self.expr(lhs);
+                let skip = self.emit(Instruction::ShortCircuit {
+                    op: op.clone(),
+                    target: 0,
+                });
+                self.expr(rhs);
+                if *op != Operator::Coalesce {
+                    self.emit(Instruction::CheckBool(op.clone()));
+                }
+                self.patch(skip);
+            }
+            Expr::BinaryExpr {
+                op: Operator::Pipe,
+                lhs,
+                rhs,
+            } => {
+                self.expr(lhs);
+                let (callee, args) = match rhs.as_ref() {
+                    Expr::FnCall { callee, args } => (callee.as_ref(), args.as_slice()),
+                    callee => (callee, &[][..]),
+                };
+                for arg in args {
+                    self.expr(arg);
+                }
+                self.call(callee, args.len() + 1, true);
+            }
+            Expr::BinaryExpr { op, lhs, rhs } => {
+                self.expr(lhs);
+                self.expr(rhs);
+                self.emit(Instruction::Binary(op.clone()));
+            }
+            Expr::UnaryExpr {
+                op: Operator::Not,
+                operand,
+            } => {
+                self.expr(operand);
+                self.emit(Instruction::Not);
+            }
+            Expr::UnaryExpr {
+                op: Operator::Sub,
+                operand,
+            } => {
+                self.expr(operand);
+                self.emit(Instruction::Negate);
+            }
+            Expr::UnaryExpr {
+                op: op @ (Operator::Increment | Operator::Decrement),
+                operand,
+            } if matches!(operand.as_ref(), Expr::Token(Token::Identifier(_))) => {
+                self.emit(Instruction::PostIncrement {
+                    name: target_symbol(operand),
+                    op: op.clone(),
+                });
+            }
+            Expr::FnCall { callee, .. } if is_special_form(callee) => {
+                self.emit(Instruction::Interpret(expr.clone()));
+            }
+            Expr::FnCall { callee, args } => {
+                for arg in args {
+                    self.expr(arg);
+                }
+                self.call(callee, args.len(), false);
+            }
+            Expr::UnaryExpr { .. } | Expr::FnDef { .. } | Expr::Lambda { .. } => {
+                self.emit(Instruction::Interpret(expr.clone()));
+            }
+            Expr::If {
+                cond,
+                body,
+                else_body,
+            } => {
+                self.expr(cond);
+                let to_else = self.emit(Instruction::JumpUnlessTrue(0));
+                self.scoped(body, false);
+                match else_body {
+                    Some(else_body) => {
+                        let to_end = self.emit(Instruction::Jump(0));
+                        self.patch(to_else);
+                        self.scoped(else_body, false);
+                        self.patch(to_end);
+                    }
+                    None => self.patch(to_else),
+                }
+                self.push(Value::Nothing);
+            }
+            Expr::MatchType { subject, arms } => {
+                self.expr(subject);
+                let mut to_end = Vec::new();
+                for (pattern, body) in arms {
+                    let to_next = (pattern != "_").then(|| {
+                        self.emit(Instruction::JumpUnlessType {
+                            pattern: pattern.clone(),
+                            target: 0,
+                        })
+                    });
+                    self.emit(Instruction::Pop);
+                    self.scoped(body, true);
+                    to_end.push(self.emit(Instruction::Jump(0)));
+                    if let Some(to_next) = to_next {
+                        self.patch(to_next);
+                    }
+                }
+                self.emit(Instruction::Pop);
+                self.push(Value::Nothing);
+                for at in to_end {
+                    self.patch(at);
+                }
+            }
+            Expr::While {
+                cond,
+                body,
+                else_body,
+            } => {
+                let top = self.chunk.code.len() + 1;
+                self.start_loop(Some(top));
+                self.expr(cond);
+                let exit = self.emit(Instruction::JumpUnlessTrue(0));
+                self.statements(body);
+                self.emit(Instruction::Jump(top));
+                match else_body {
+                    None => {
+                        self.patch(exit);
+                        self.end_loop();
+                        self.push(Value::Nothing);
+                    }
+                    // Breaks jump over the else body, which is compiled once
+                    // the loop is left so its own breaks go to the outer loop
+                    Some(else_body) => {
+                        self.end_loop();
+                        let skip = self.emit(Instruction::Jump(0));
+                        self.patch(exit);
+                        self.emit(Instruction::LoopEnd);
+                        self.statements(else_body);
+                        self.patch(skip);
+                        self.push(Value::Nothing);
+                    }
+                }
+            }
+            Expr::DoWhile { body, cond } => {
+                self.start_loop(None);
+                let top = self.chunk.code.len();
+                self.statements(body);
+                for at in mem::take(&mut self.loops.last_mut().unwrap().continues) {
+                    self.patch(at);
+                }
+                self.expr(cond);
+                let exit = self.emit(Instruction::JumpUnlessTrue(0));
+                self.emit(Instruction::Jump(top));
+                self.patch(exit);
+                self.end_loop();
+                self.push(Value::Nothing);
+            }
+            Expr::For {
+                var,
+                iterable,
+                body,
+            } => {
+                self.expr(iterable);
+                self.emit(Instruction::IterStart);
+                self.nested.push(Nested::Iter);
+                let top = self.chunk.code.len() + 1;
+                self.start_loop(Some(top));
+                let exit = self.emit(Instruction::IterNext {
+                    var: *var,
+                    target: 0,
+                });
+                self.statements(body);
+                self.emit(Instruction::Jump(top));
+                self.patch(exit);
+                self.end_loop();
+                self.nested.pop();
+                self.emit(Instruction::IterEnd);
+                self.push(Value::Nothing);
+            }
+            // The VM enters the catch block's scope itself, with the error
+            // already in it
+            Expr::Try {
+                body,
+                error_name,
+                catch_body,
+            } => {
+                let to_catch = self.emit(Instruction::TryStart {
+                    error_name: *error_name,
+                    target: 0,
+                });
+                self.nested.push(Nested::Try);
+                self.scoped(body, true);
+                self.nested.pop();
+                self.emit(Instruction::TryEnd);
+                let to_end = self.emit(Instruction::Jump(0));
+                self.patch(to_catch);
+                self.nested.push(Nested::Scope);
+                self.block(catch_body);
+                self.nested.pop();
+                self.emit(Instruction::ExitScope);
+                self.patch(to_end);
+            }
+            Expr::Break => self.loop_control(true),
+            Expr::Continue => self.loop_control(false),
+            // Inside a try the call has to happen in this frame, so its errors
+            // can be caught
+            Expr::Return { inner } => match (inner.as_ref(), self.function) {
+                (Expr::FnCall { callee, args }, Some(_))
+                    if !is_special_form(callee)
+                        && !self.nested.iter().any(|n| matches!(n, Nested::Try)) =>
+                {
+                    for arg in args {
+                        self.expr(arg);
+                    }
+                    let argc = args.len();
+                    match callee.as_ref() {
+                        Expr::Token(Token::Identifier(name)) => {
+                            self.emit(Instruction::TailCall { name: *name, argc });
+                        }
+                        callee => {
+                            self.expr(callee);
+                            self.emit(Instruction::TailCallValue { argc });
+                        }
+                    }
+                }
+                (inner, Some(_)) => {
+                    self.expr(inner);
+                    self.emit(Instruction::Return);
+                }
+                (inner, None) => {
+                    self.expr(inner);
+                    self.exit_statement();
+                }
+            },
+            Expr::Array { items } => {
+                for item in items {
+                    self.expr(item);
+                }
+                self.emit(Instruction::Array(items.len()));
+            }
+            Expr::Tuple { items } => {
+                for item in items {
+                    self.expr(item);
+                }
+                self.emit(Instruction::Tuple(items.len()));
+            }
+            Expr::Map { entries } => {
+                for (key, value) in entries {
+                    self.expr(key);
+                    self.emit(Instruction::CheckKey);
+                    self.expr(value);
+                }
+                self.emit(Instruction::Map(entries.len()));
+            }
+            Expr::Index { target, index } => {
+                self.expr(target);
+                self.expr(index);
+                self.emit(Instruction::Index);
+            }
+        }
+    }
+}
+
+struct Handler {
+    target: usize,
+    error_name: Symbol,
+    stack: usize,
+    marks: usize,
+    iters: usize,
+    scopes: usize,
+}
+
+// Each function call gets its own frame, on top of the scope it runs in
+struct Frame<'a> {
+    base: &'a mut Scope,
+    scopes: Vec<Scope>,
+    stack: Vec<Value>,
+    // Stack heights at the start of each loop being run
+    marks: Vec<usize>,
+    iters: Vec<Box<dyn Iterator<Item = Value>>>,
+    handlers: Vec<Handler>,
+}
+
+impl Frame<'_> {
+    fn scope(&mut self) -> &mut Scope {
+        match self.scopes.last_mut() {
+            Some(scope) => scope,
+            None => self.base,
+        }
+    }
+
+    // Returns a tail call to a user function for the caller to make, and
+    // makes any other call here
+    fn tail_call(
+        &mut self,
+        func: FnType,
+        args: Vec<Value>,
+        running: Option<&UserFn>,
+        ctx: &mut Context,
+    ) -> Result<Value, RuntimeError> {
+        let running = running.expect("Tail call compiled outside of a function");
+        match func {
+            FnType::User(func) => Ok(Value::TailCall(Box::new(TailCall {
+                func,
+                args,
+                scope: self.scope().clone(),
+            }))),
+            func => {
+                let value = call_value(&func, args, self.scope(), ctx)?;
+                Ok(running.check_return(value))
+            }
+        }
+    }
+
+    fn pop(&mut self) -> Value {
+        self.stack.pop().expect("Bytecode popped an empty stack")
+    }
+
+    fn pop_many(&mut self, count: usize) -> Vec<Value> {
+        self.stack.split_off(self.stack.len() - count)
+    }
+}
+
+struct CompiledFn {
+    _body: Rc<Vec<Expr>>,
+    chunk: Rc<Chunk>,
+}
+
+// Runs compiled code with the same results as the tree-walker. The tracer
+// in the context only hears about what gets handed back to the tree-walker
+#[derive(Default)]
+pub struct Vm {
+    // Keyed on the address of the function body, which is kept alive here so
+    // that address can't be reused by a different function
+    functions: HashMap<*const Vec<Expr>, CompiledFn>,
+}
+
+impl Vm {
+    pub fn run(
+        &mut self,
+        chunk: &Chunk,
+        scope: &mut Scope,
+        ctx: &mut Context,
+    ) -> Result<(), RuntimeError> {
+        self.execute(chunk, None, scope, ctx).map(|_| ())
+    }
+
+    fn function_chunk(&mut self, func: &UserFn) -> Rc<Chunk> {
+        let compiled = self
+            .functions
+            .entry(Rc::as_ptr(&func.body))
+            .or_insert_with(|| CompiledFn {
+                _body: func.body.clone(),
+                chunk: Rc::new(compile_function(func)),
+            });
+        compiled.chunk.clone()
+    }
+
+    // User functions run here, anything else calls back into the interpreter
+    fn call(
+        &mut self,
+        func: FnType,
+        args: Vec<Value>,
+        scope: &mut Scope,
+        ctx: &mut Context,
+    ) -> Result<Value, RuntimeError> {
+        match func {
+            FnType::User(func) => call_user_fn(
+                &func,
+                args,
+                scope.clone(),
+                ctx,
+                &mut |func, new_scope, ctx| {
+                    let chunk = self.function_chunk(func);
+                    self.execute(&chunk, Some(func), new_scope, ctx)
+                },
+            ),
+            func => call_value(&func, args, scope, ctx),
+        }
+    }
+
+    fn execute(
+        &mut self,
+        chunk: &Chunk,
+        func: Option<&UserFn>,
+        scope: &mut Scope,
+        ctx: &mut Context,
+    ) -> Result<Value, RuntimeError> {
+        let mut frame = Frame {
+            base: scope,
+            scopes: Vec::new(),
+            stack: Vec::new(),
+            marks: Vec::new(),
+            iters: Vec::new(),
+            handlers: Vec::new(),
+        };
+        let mut pc = 0;
+        while let Some(instruction) = chunk.code.get(pc) {
+            pc += 1;
+            // Panics are only caught inside a try block, like the tree-walker
+            let result = if frame.handlers.is_empty() {
+                self.step(instruction, chunk, &mut frame, &mut pc, func, ctx)
+            } else {
+                catch_panic(|| self.step(instruction, chunk, &mut frame, &mut pc, func, ctx))
+            };
+            match result {
+                Ok(Some(value)) => return Ok(value),
+                Ok(None) => (),
+                Err(err) => match frame.handlers.pop() {
+                    Some(handler) => {
+                        frame.stack.truncate(handler.stack);
+                        frame.marks.truncate(handler.marks);
+                        frame.iters.truncate(handler.iters);
+                        frame.scopes.truncate(handler.scopes);
+                        let mut catch_scope = frame.scope().clone();
+                        catch_scope
+                            .insert(handler.error_name, Box::new(Value::String(err.to_string())));
+                        frame.scopes.push(catch_scope);
+                        pc = handler.target;
+                    }
+                    None => return Err(err),
+                },
+            }
+        }
+        Ok(Value::Nothing)
+    }
+
+    // Gives back a value once the function being run returns
+    fn step(
+        &mut self,
+        instruction: &Instruction,
+        chunk: &Chunk,
+        frame: &mut Frame,
+        pc: &mut usize,
+        running: Option<&UserFn>,
+        ctx: &mut Context,
+    ) -> Result<Option<Value>, RuntimeError> {
+        match instruction {
+            Instruction::Constant(index) => {
+                let value = match &chunk.constants[*index] {
+                    Value::Int(i) => int_literal(*i, ctx)?,
+                    constant => constant.clone(),
+                };
+                frame.stack.push(value);
+            }
+            Instruction::Load(name) => match frame.scope().get(name) {
+                Some(value) => {
+                    let value = value.as_ref().clone();
+                    frame.stack.push(value);
+                }
+                None => panic!("{}", undefined("variable", *name, frame.scope())),
+            },
+            // Reuses the variable's box when it already exists
+            Instruction::Store(name) => {
+                let value = frame.stack.last().unwrap().clone();
+                match frame.scope().get_mut(name) {
+                    Some(slot) => **slot = value,
+                    None => {
+                        frame.scope().insert(*name, Box::new(value));
+                    }
+                }
+            }
+            Instruction::CheckType(expected_type) => {
+                let found = frame.stack.last().unwrap().get_type();
+                if found != get_valuetype_from(expected_type) {
+                    panic!(
+                        "Invalid value type for set operation. Expected {expected_type}, got {found}"
+                    );
+                }
+            }
+            Instruction::Destructure(names) => match frame.pop() {
+                Value::Tuple(values) | Value::Array(values) => {
+                    if values.len() != names.len() {
+                        panic!(
+                            "Cannot destructure {} values into {} names",
+                            values.len(),
+                            names.len()
+                        );
+                    }
+                    for (name, value) in names.iter().zip(&values) {
+                        frame.scope().insert(*name, Box::new(value.clone()));
+                    }
+                    frame.stack.push(Value::Tuple(values));
+                }
+                other => panic!("Cannot destructure {}", other.get_type()),
+            },
+            Instruction::Pop => {
+                frame.pop();
+            }
+            Instruction::Binary(op) => {
+                let right_side = frame.pop();
+                let left_side = frame.pop();
+                frame.stack.push(binary_op(op, left_side, right_side, ctx)?);
+            }
+            Instruction::Not => match frame.pop() {
+                Value::Bool(b) => frame.stack.push(Value::Bool(!b)),
+                _ => panic!("Cannot negate non-boolean values"),
+            },
+            Instruction::Negate => {
+                let value = match frame.pop() {
+                    int @ (Value::Int(_) | Value::BigInt(_)) => {
+                        match int_arithmetic(&Operator::Sub, &Value::Int(0), &int, &*ctx.integers) {
+                            Some(negated) => negated,
+                            None => {
+                                return Err(RuntimeError::IntegerOverflow {
+                                    name: "-".to_string(),
+                                })
+                            }
+                        }
+                    }
+                    Value::Float(f) => Value::Float(-f),
+                    _ => panic!("Cannot negate non-numeric values"),
+                };
+                frame.stack.push(value);
+            }
+            Instruction::PostIncrement { name, op } => {
+                let value = post_increment(op, *name, frame.scope(), ctx)?;
+                frame.stack.push(value);
+            }
+            Instruction::ShortCircuit { op, target } => {
+                let settled = match (op, frame.stack.last().unwrap()) {
+                    (Operator::Coalesce, value) => *value != Value::Nothing,
+                    (Operator::And, Value::Bool(b)) => !b,
+                    (Operator::Or, Value::Bool(b)) => *b,
+                    (op, _) => panic!("Cannot use non-boolean values with {op}"),
+                };
+                if settled {
+                    *pc = *target;
+                } else {
+                    frame.pop();
+                }
+            }
+            Instruction::CheckBool(op) => {
+                if !matches!(frame.stack.last(), Some(Value::Bool(_))) {
+                    panic!("Cannot use non-boolean values with {op}");
+                }
+            }
+            Instruction::Call { name, argc } => {
+                let args = frame.pop_many(*argc);
+                let func = match frame.scope().get(name).map(|v| v.as_ref()) {
+                    Some(Value::Fn(func)) => func.clone(),
+                    Some(_) => panic!("Not a function: {name}"),
+                    None => panic!("{}", undefined("function", *name, frame.scope())),
+                };
+                let value = self.call(func, args, frame.scope(), ctx)?;
+                frame.stack.push(value);
+            }
+            Instruction::CallValue { argc, piped } => {
+                let callee = frame.pop();
+                let args = frame.pop_many(*argc);
+                let func = match callee {
+                    Value::Fn(func) => func,
+                    value if *piped => {
+                        panic!("Cannot pipe into a value of type {}", value.get_type())
+                    }
+                    value => panic!("Cannot call a value of type {}", value.get_type()),
+                };
+                let value = self.call(func, args, frame.scope(), ctx)?;
+                frame.stack.push(value);
+            }
+            Instruction::TailCall { name, argc } => {
+                let args = frame.pop_many(*argc);
+                let func = match frame.scope().get(name).map(|v| v.as_ref()) {
+                    Some(Value::Fn(func)) => func.clone(),
+                    Some(_) => panic!("Not a function: {name}"),
+                    None => panic!("{}", undefined("function", *name, frame.scope())),
+                };
+                return frame.tail_call(func, args, running, ctx).map(Some);
+            }
+            Instruction::TailCallValue { argc } => {
+                let callee = frame.pop();
+                let args = frame.pop_many(*argc);
+                let func = match callee {
+                    Value::Fn(func) => func,
+                    value => panic!("Cannot call a value of type {}", value.get_type()),
+                };
+                return frame.tail_call(func, args, running, ctx).map(Some);
+            }
+            Instruction::Array(count) => {
+                let items = frame.pop_many(*count);
+                frame.stack.push(Value::Array(items));
+            }
+            Instruction::Tuple(count) => {
+                let items = frame.pop_many(*count);
+                frame.stack.push(Value::Tuple(items));
+            }
+            Instruction::Map(count) => {
+                let mut map = IndexMap::new();
+                let mut entries = frame.pop_many(count * 2).into_iter();
+                while let (Some(Value::String(key)), Some(value)) = (entries.next(), entries.next())
+                {
+                    map.insert(key, value);
+                }
+                frame.stack.push(Value::Map(map));
+            }
+            Instruction::CheckKey => match frame.stack.last().unwrap() {
+                Value::String(_) => (),
+                other => panic!("Map keys must be strings, got {}", other.get_type()),
+            },
+            Instruction::Index => {
+                let index = frame.pop();
+                let target = frame.pop();
+                frame.stack.push(index_value(target, index));
+            }
+            Instruction::Jump(target) => *pc = *target,
+            Instruction::JumpUnlessTrue(target) => {
+                if frame.pop() != Value::Bool(true) {
+                    *pc = *target;
+                }
+            }
+            Instruction::JumpUnlessType { pattern, target } => {
+                if !frame.stack.last().unwrap().get_type().is_named(pattern) {
+                    *pc = *target;
+                }
+            }
+            Instruction::EnterScope => {
+                let scope = frame.scope().clone();
+                frame.scopes.push(scope);
+            }
+            Instruction::ExitScope => {
+                frame.scopes.pop();
+            }
+            Instruction::LoopStart => frame.marks.push(frame.stack.len()),
+            Instruction::LoopEnd => {
+                frame.marks.pop();
+            }
+            Instruction::Unwind => {
+                let height = frame.marks.last().copied().unwrap_or(0);
+                frame.stack.truncate(height);
+            }
+            Instruction::IterStart => {
+                let iterable = frame.pop();
+                let kind = iterable.get_type();
+                match iterable.into_items() {
+                    Some(items) => frame.iters.push(items),
+                    None => panic!("Cannot loop over a value of type {kind}"),
+                }
+            }
+            Instruction::IterNext { var, target } => match frame.iters.last_mut().unwrap().next() {
+                Some(item) => {
+                    frame.scope().insert(*var, Box::new(item));
+                }
+                None => *pc = *target,
+            },
+            Instruction::IterEnd => {
+                frame.iters.pop();
+            }
+            Instruction::TryStart { error_name, target } => frame.handlers.push(Handler {
+                target: *target,
+                error_name: *error_name,
+                stack: frame.stack.len(),
+                marks: frame.marks.len(),
+                iters: frame.iters.len(),
+                scopes: frame.scopes.len(),
+            }),
+            Instruction::TryEnd => {
+                frame.handlers.pop();
+            }
+            Instruction::Return => {
+                let value = frame.pop();
+                let running = running.expect("Return compiled outside of a function");
+                return Ok(Some(running.check_return(value)));
+            }
+            Instruction::Panic(message) => panic!("{message}"),
+            Instruction::Interpret(expr) => {
+                let value = interpret_expr(expr, frame.scope(), ctx)?;
+                frame.stack.push(value);
+            }
+        }
+        Ok(None)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        crate::testing::{output, parse, run, run_bytecode},
+    };
+
+    #[test]
+    fn statements_leave_nothing_on_the_stack() {
+        assert_eq!(
+            compile(&parse("x := 1 + 2;")).code,
+            [
+                Instruction::Constant(0),
+                Instruction::Constant(1),
+                Instruction::Binary(Operator::Add),
+                Instruction::Store(Symbol::intern("x")),
+                Instruction::Pop,
+            ]
+        );
+    }
+
+    #[test]
+    fn loops_and_jumps_match_the_tree_walker() {
+        assert_eq!(
+            output(
+                "total := 0; i := 0;
+while i < 10 { i++; if i % 2 == 0 { continue; } if i > 7 { break; } total = total + i; }
+println(total);
+do { i = i - 1; } while i > 5;
+println(i);
+for x in [1, 2, 3] { println(x * 10); }"
+            ),
+            "16\n5\n10\n20\n30\n"
+        );
+    }
+
+    #[test]
+    fn calls_and_control_flow_match_the_tree_walker() {
+        assert_eq!(
+            output(
+                r#"func fact(int n) -> int { if n < 2 { return 1; } return n * fact(n - 1); }
+println(fact(10));
+try { error("boom"); } catch e { println(e); }
+v := 2.5;
+println(match_type v { int => "int", float => "float", _ => "other" });
+println(true && false || !false);
+m := {"a": 1}; println(m["a"]); xs := [1, [2, 3]]; println(xs[1][0]);"#
+            ),
+            "3628800\nError: boom\nfloat\ntrue\n1\n2\n"
+        );
+    }
+
+    #[test]
+    fn a_top_level_break_only_ends_its_statement() {
+        let source = "println(1); break; println(2);";
+        assert_eq!(run(source), run_bytecode(source));
+        assert_eq!(output(source), "1\n2\n");
+    }
+
+    #[test]
+    fn panics_inside_try_become_errors_on_both_engines() {
+        assert_eq!(
+            output("try { println([1, 2][5]); } catch e { println(e); } try { 1 + true; } catch e { println(e); }"),
+            "Index 5 out of range for array of length 2\nInvalid type for addition\n"
+        );
+    }
+
+    #[test]
+    fn repeated_literals_share_one_pool_entry() {
+        let chunk = compile(&parse(
+            r#"x := 0; x = 0; x = 0; x = 0; x = 0; x = 0; x = 0; x = 0; x = 0; x = 0; s := ""; s = "";"#,
+        ));
+        assert_eq!(
+            chunk.constants,
+            [Value::Int(0), Value::String(String::new())]
+        );
+        let zeros = chunk
+            .code
+            .iter()
+            .filter(|inst| **inst == Instruction::Constant(0))
+            .count();
+        assert_eq!(zeros, 10);
+    }
+
+    #[test]
+    fn equal_ints_and_floats_are_separate_constants() {
+        let chunk = compile(&parse("a := 0.0; b := 0; c := 0.0; d := 0;"));
+        assert_eq!(chunk.constants, [Value::Float(0.0), Value::Int(0)]);
+    }
+}
@@ -1,50 +1,555 @@
 use {
     crate::{
+        bigint::BigInt,
         builtins,
-        lexer::Token,
-        parser::{Expr, Operator},
+        bytecode::{self, Vm},
+        lexer::{self, Token},
+        parser::{Expr, Operator, Parser},
+        symbol::Symbol,
+        visitor::{walk_exprs, ExprVisitor},
     },
+    indexmap::IndexMap,
+    logos::Logos,
+    serde::{Deserialize, Serialize},
     std::{
-        collections::HashMap,
+        cell::{Cell, RefCell},
+        collections::{BTreeMap, HashMap},
         fmt::{Display, Formatter},
+        io::{self, BufRead, BufReader, Write},
+        mem,
+        panic::{self, AssertUnwindSafe},
+        rc::Rc,
+        sync::Once,
+        thread,
+        time::{Duration, Instant},
     },
 };
 
 pub struct Interpreter {
     pub state: State,
     pub exprs: Vec<Expr>,
+    pub context: Context,
 }
 
-type Scope = HashMap<String, Box<Value>>;
+// Kept in insertion order so globals() can list names in the order they
+// were first defined
+pub(crate) type Scope = IndexMap<Symbol, Box<Value>>;
 pub struct State {
     pub toplevel_scope: Scope,
 }
 
+#[derive(Debug)]
+pub struct StateError {
+    pub message: String,
+}
+
+impl Display for StateError {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
+
+// Mirror of the data-carrying Value variants, so control flow markers and
+// functions can never end up in a saved state
+#[derive(Serialize, Deserialize)]
+enum StoredValue {
+    Int(i128),
+    // In decimal, past what an i128 holds
+    BigInt(String),
+    Float(f64),
+    String(String),
+    Bool(bool),
+    Array(Vec<StoredValue>),
+    Map(IndexMap<String, StoredValue>),
+    Tuple(Vec<StoredValue>),
+    Nothing,
+}
+
+impl StoredValue {
+    fn from_value(name: &str, value: &Value) -> Result<Self, StateError> {
+        let store_all = |items: &[Value]| {
+            items
+                .iter()
+                .map(|item| Self::from_value(name, item))
+                .collect::<Result<Vec<_>, _>>()
+        };
+        Ok(match value {
+            Value::Int(i) => Self::Int(*i),
+            Value::BigInt(n) => Self::BigInt(n.to_string()),
+            Value::Float(f) => Self::Float(*f),
+            Value::String(s) => Self::String(s.clone()),
+            Value::Bool(b) => Self::Bool(*b),
+            Value::Array(items) => Self::Array(store_all(items)?),
+            Value::Map(entries) => Self::Map(
+                entries
+                    .iter()
+                    .map(|(key, value)| Ok((key.clone(), Self::from_value(name, value)?)))
+                    .collect::<Result<_, StateError>>()?,
+            ),
+            Value::Tuple(items) => Self::Tuple(store_all(items)?),
+            Value::Nothing => Self::Nothing,
+            other => {
+                return Err(StateError {
+                    message: format!("Cannot save {name}, it holds a {}", other.get_type()),
+                })
+            }
+        })
+    }
+
+    fn into_value(self) -> Value {
+        match self {
+            Self::Int(i) => Value::Int(i),
+            Self::BigInt(n) => match BigInt::parse(&n) {
+                Some(n) => Value::from(n),
+                None => panic!("Saved state has an invalid int: {n}"),
+            },
+            Self::Float(f) => Value::Float(f),
+            Self::String(s) => Value::String(s),
+            Self::Bool(b) => Value::Bool(b),
+            Self::Array(items) => Value::Array(items.into_iter().map(Self::into_value).collect()),
+            Self::Map(entries) => Value::Map(
+                entries
+                    .into_iter()
+                    .map(|(key, value)| (key, value.into_value()))
+                    .collect(),
+            ),
+            Self::Tuple(items) => Value::Tuple(items.into_iter().map(Self::into_value).collect()),
+            Self::Nothing => Value::Nothing,
+        }
+    }
+}
+
+impl State {
+    // Builtins are left out since from_json always registers them again
+    pub fn to_json(&self) -> Result<String, StateError> {
+        let mut stored = BTreeMap::new();
+        for (name, value) in &self.toplevel_scope {
+            if let Value::Fn(FnType::Builtin(_)) = value.as_ref() {
+                continue;
+            }
+            stored.insert(
+                name.as_str(),
+                StoredValue::from_value(name.as_str(), value)?,
+            );
+        }
+        serde_json::to_string(&stored).map_err(|err| StateError {
+            message: format!("Failed to save state: {err}"),
+        })
+    }
+
+    pub fn from_json(json: &str) -> Result<Self, StateError> {
+        let stored: BTreeMap<String, StoredValue> =
+            serde_json::from_str(json).map_err(|err| StateError {
+                message: format!("Failed to load state: {err}"),
+            })?;
+        let mut toplevel_scope = Scope::new();
+        builtins::make_builtins(&mut toplevel_scope);
+        for (name, value) in stored {
+            toplevel_scope.insert(Symbol::intern(&name), Box::new(value.into_value()));
+        }
+        Ok(Self { toplevel_scope })
+    }
+}
+
+// A fake clock can override sleep to just move its own time forward
+pub trait Clock {
+    fn now(&mut self) -> Duration;
+
+    fn sleep(&mut self, duration: Duration) {
+        thread::sleep(duration);
+    }
+}
+
+pub struct SystemClock {
+    start: Instant,
+}
+
+impl Clock for SystemClock {
+    fn now(&mut self) -> Duration {
+        self.start.elapsed()
+    }
+}
+
+impl Default for SystemClock {
+    fn default() -> Self {
+        Self {
+            start: Instant::now(),
+        }
+    }
+}
+
+// Called around every expression the interpreter evaluates, exit is skipped
+// when the expression fails
+pub trait Tracer {
+    fn enter(&mut self, expr: &Expr);
+    fn exit(&mut self, expr: &Expr, value: &Value);
+}
+
+// Reading the host environment is off unless the embedder turns it on, so
+// untrusted scripts can't see it by default
+pub struct Capabilities {
+    pub stdin: bool,
+    pub env: bool,
+}
+
+impl Default for Capabilities {
+    fn default() -> Self {
+        Self {
+            stdin: true,
+            env: false,
+        }
+    }
+}
+
+// Int values are stored as i128, and the chosen width decides what counts
+// as overflow, checked 64-bit unless asked otherwise. Each method gives back
+// None when the result doesn't fit
+pub trait IntArithmetic {
+    fn add(&self, a: i128, b: i128) -> Option<i128>;
+    fn sub(&self, a: i128, b: i128) -> Option<i128>;
+    fn mul(&self, a: i128, b: i128) -> Option<i128>;
+    fn div(&self, a: i128, b: i128) -> Option<i128>;
+    fn rem(&self, a: i128, b: i128) -> Option<i128>;
+    // A value coming from outside the arithmetic, like a parsed or rounded
+    // number, as it would be stored at this width
+    fn bound(&self, n: i128) -> Option<i128>;
+    // The same arithmetic for results that don't fit an i128, with op one of
+    // the five above. Only a bignum width has any, the others have overflowed
+    // long before
+    fn big(&self, _op: &Operator, _a: &BigInt, _b: &BigInt) -> Option<BigInt> {
+        None
+    }
+    // bound, for a value past what an i128 holds
+    fn bound_big(&self, _n: BigInt) -> Option<BigInt> {
+        None
+    }
+}
+
+// Arbitrary precision. Ints that fit are still i128s and only results that
+// don't become BigInts, so nothing overflows but small ints stay cheap
+#[derive(Default)]
+pub struct Bignum;
+
+impl IntArithmetic for Bignum {
+    fn add(&self, a: i128, b: i128) -> Option<i128> {
+        a.checked_add(b)
+    }
+
+    fn sub(&self, a: i128, b: i128) -> Option<i128> {
+        a.checked_sub(b)
+    }
+
+    fn mul(&self, a: i128, b: i128) -> Option<i128> {
+        a.checked_mul(b)
+    }
+
+    fn div(&self, a: i128, b: i128) -> Option<i128> {
+        a.checked_div(b)
+    }
+
+    fn rem(&self, a: i128, b: i128) -> Option<i128> {
+        a.checked_rem(b)
+    }
+
+    fn bound(&self, n: i128) -> Option<i128> {
+        Some(n)
+    }
+
+    fn big(&self, op: &Operator, a: &BigInt, b: &BigInt) -> Option<BigInt> {
+        match op {
+            Operator::Add => Some(a + b),
+            Operator::Sub => Some(a - b),
+            Operator::Mul => Some(a * b),
+            Operator::Div => a.div_rem(b).map(|(quotient, _)| quotient),
+            Operator::Percent => a.div_rem(b).map(|(_, remainder)| remainder),
+            _ => None,
+        }
+    }
+
+    fn bound_big(&self, n: BigInt) -> Option<BigInt> {
+        Some(n)
+    }
+}
+
+// Operands that are already out of range count as an overflow too
+#[derive(Default)]
+pub struct Checked64;
+
+impl IntArithmetic for Checked64 {
+    fn add(&self, a: i128, b: i128) -> Option<i128> {
+        i64::try_from(a)
+            .ok()?
+            .checked_add(b.try_into().ok()?)
+            .map(i128::from)
+    }
+
+    fn sub(&self, a: i128, b: i128) -> Option<i128> {
+        i64::try_from(a)
+            .ok()?
+            .checked_sub(b.try_into().ok()?)
+            .map(i128::from)
+    }
+
+    fn mul(&self, a: i128, b: i128) -> Option<i128> {
+        i64::try_from(a)
+            .ok()?
+            .checked_mul(b.try_into().ok()?)
+            .map(i128::from)
+    }
+
+    fn div(&self, a: i128, b: i128) -> Option<i128> {
+        i64::try_from(a)
+            .ok()?
+            .checked_div(b.try_into().ok()?)
+            .map(i128::from)
+    }
+
+    fn rem(&self, a: i128, b: i128) -> Option<i128> {
+        i64::try_from(a)
+            .ok()?
+            .checked_rem(b.try_into().ok()?)
+            .map(i128::from)
+    }
+
+    fn bound(&self, n: i128) -> Option<i128> {
+        i64::try_from(n).ok().map(i128::from)
+    }
+}
+
+// Values are wrapped into range by bound as they are made, so only the
+// arithmetic wraps. An operand out of range never got bound and is an
+// overflow, the same as for Checked64
+#[derive(Default)]
+pub struct Wrapping32;
+
+impl IntArithmetic for Wrapping32 {
+    fn add(&self, a: i128, b: i128) -> Option<i128> {
+        Some(
+            i32::try_from(a)
+                .ok()?
+                .wrapping_add(b.try_into().ok()?)
+                .into(),
+        )
+    }
+
+    fn sub(&self, a: i128, b: i128) -> Option<i128> {
+        Some(
+            i32::try_from(a)
+                .ok()?
+                .wrapping_sub(b.try_into().ok()?)
+                .into(),
+        )
+    }
+
+    fn mul(&self, a: i128, b: i128) -> Option<i128> {
+        Some(
+            i32::try_from(a)
+                .ok()?
+                .wrapping_mul(b.try_into().ok()?)
+                .into(),
+        )
+    }
+
+    fn div(&self, a: i128, b: i128) -> Option<i128> {
+        Some(
+            i32::try_from(a)
+                .ok()?
+                .wrapping_div(b.try_into().ok()?)
+                .into(),
+        )
+    }
+
+    fn rem(&self, a: i128, b: i128) -> Option<i128> {
+        Some(
+            i32::try_from(a)
+                .ok()?
+                .wrapping_rem(b.try_into().ok()?)
+                .into(),
+        )
+    }
+
+    fn bound(&self, n: i128) -> Option<i128> {
+        Some(n as i32 as i128)
+    }
+}
+
+pub struct Context {
+    pub clock: Box<dyn Clock>,
+    pub input: Box<dyn BufRead>,
+    pub output: Box<dyn Write>,
+    pub capabilities: Capabilities,
+    pub max_output_bytes: Option<usize>,
+    pub output_bytes: usize,
+    pub tracer: Option<Box<dyn Tracer>>,
+    pub integers: Box<dyn IntArithmetic>,
+    // How many times each function has been called, by name. None unless
+    // profiling is turned on, so normal runs don't pay for the counting
+    pub call_counts: Option<IndexMap<String, i128>>,
+    // How many builtins are running, so user code they call back into can
+    // tell its own panics apart from the builtin's
+    pub builtin_depth: usize,
+}
+
+impl Default for Context {
+    fn default() -> Self {
+        Self {
+            clock: Box::new(SystemClock::default()),
+            input: Box::new(BufReader::new(io::stdin())),
+            output: Box::new(io::stdout()),
+            capabilities: Capabilities::default(),
+            max_output_bytes: None,
+            output_bytes: 0,
+            tracer: None,
+            integers: Box::new(Checked64),
+            call_counts: None,
+            builtin_depth: 0,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum RuntimeError {
+    BuiltinPanicked { name: String, message: String },
+    IntegerOverflow { name: String },
+    InvalidNumber { text: String },
+    ParseFailed { message: String },
+    UserError { message: String },
+    DivisionByZero,
+    // An argument a math builtin isn't defined for, like ln(0)
+    MathDomain { name: String, value: f64 },
+    OutputLimitExceeded { limit: usize },
+    NotSerializable { message: String },
+    InvalidRegex { message: String },
+    // Interpreter panics, like an undefined variable, only become errors
+    // inside a try block so that the catch block can handle them
+    Panicked { message: String },
+}
+
+impl Display for RuntimeError {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        match self {
+            RuntimeError::BuiltinPanicked { name, message } => {
+                write!(f, "Builtin function {name} panicked: {message}")
+            }
+            RuntimeError::IntegerOverflow { name } => write!(f, "Integer overflow in {name}"),
+            RuntimeError::InvalidNumber { text } => write!(f, "Invalid number: {text:?}"),
+            RuntimeError::ParseFailed { message } => write!(f, "Parse error: {message}"),
+            RuntimeError::UserError { message } => write!(f, "Error: {message}"),
+            RuntimeError::DivisionByZero => write!(f, "Division by zero"),
+            RuntimeError::MathDomain { name, value } => {
+                write!(f, "Math domain error: {value} is out of range for {name}")
+            }
+            RuntimeError::OutputLimitExceeded { limit } => {
+                write!(f, "Output limit of {limit} bytes exceeded")
+            }
+            RuntimeError::NotSerializable { message } => {
+                write!(f, "Cannot convert to JSON: {message}")
+            }
+            RuntimeError::InvalidRegex { message } => write!(f, "Invalid regex: {message}"),
+            RuntimeError::Panicked { message } => write!(f, "{message}"),
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq)]
 pub enum Value {
     Int(i128),
+    // Only for ints that don't fit an i128, which only the bignum width makes.
+    // Value::from(BigInt) picks between the two
+    BigInt(BigInt),
     Float(f64),
     String(String),
     Bool(bool),
+    Array(Vec<Self>),
+    Map(IndexMap<String, Self>),
+    Tuple(Vec<Self>),
     Fn(FnType),
+    Iterator(RangeIter),
     Return(Box<Self>),
+    // Only ever inside a Return, see TailCall
+    TailCall(Box<TailCall>),
+    Break,
+    Continue,
     Nothing,
 }
 
+// A call a function makes as it returns. It is handed back for the caller
+// to make instead of being made there, so a function recursing in tail
+// position runs in a loop rather than growing the host stack
+#[derive(Debug, Clone, PartialEq)]
+pub struct TailCall {
+    pub func: UserFn,
+    pub args: Vec<Value>,
+    // Where the call was made, since functions run in a copy of it
+    pub scope: Scope,
+}
+
+impl TailCall {
+    fn run(self, ctx: &mut Context) -> Result<Value, RuntimeError> {
+        let TailCall {
+            func,
+            args,
+            mut scope,
+        } = self;
+        call_value(&FnType::User(func), args, &mut scope, ctx)
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum ValueType {
     Int,    /* (i64) */
     Float,  /* (f64) */
     String, /* (String) */
     Bool,   /* (bool) */
-    Fn,     /* (FnType) */
+    Array,  /* (Vec<Value>) */
+    Map,    /* (IndexMap<String, Value>) */
+    Tuple(Vec<ValueType>),
+    Fn,       /* (FnType) */
+    Iterator, /* (RangeIter) */
+    Any,
     Nothing,
 }
 
+// Produces its ints one at a time, so a large range never has to exist as
+// an array. Consumers iterate over a copy, leaving the value itself reusable
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RangeIter {
+    pub start: i128,
+    pub end: i128,
+    pub step: i128,
+}
+
+impl Iterator for RangeIter {
+    type Item = i128;
+
+    fn next(&mut self) -> Option<i128> {
+        let in_range = if self.step > 0 {
+            self.start < self.end
+        } else {
+            self.start > self.end
+        };
+        if !in_range {
+            return None;
+        }
+        let value = self.start;
+        self.start = self.start.checked_add(self.step).unwrap_or(self.end);
+        Some(value)
+    }
+}
+
+impl Display for RangeIter {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "range({}, {}, {})", self.start, self.end, self.step)
+    }
+}
+
 #[derive(Debug, Clone, PartialEq)]
 pub enum FnType {
     Builtin(BuiltinFn),
     User(UserFn),
+    Memoized(MemoizedFn),
+    Partial(PartialFn),
+    Composed(ComposedFn),
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -56,16 +561,103 @@ pub struct BuiltinFn {
 #[derive(Debug, Clone, PartialEq)]
 pub struct UserFn {
     pub name: String,
-    pub args: HashMap<(usize, String), ValueType>,
-    pub body: Vec<Expr>,
+    pub args: HashMap<(usize, Symbol), ValueType>,
+    // Shared so looking a function up doesn't copy its whole body, which
+    // also gives the VM a stable key for the code it compiles from it
+    pub body: Rc<Vec<Expr>>,
     pub return_type: ValueType,
+    // Set for lambdas. The variables they use are copied out of the defining
+    // scope when the lambda is created, so later changes there aren't seen.
+    // Assignments the lambda makes to them are kept for its next call, and
+    // copies of the function value share them
+    pub captured: Option<Rc<RefCell<Scope>>>,
+}
+
+// Split out of call_value so the bytecode VM sets up and tears down a call
+// the same way, only running the body differently
+impl UserFn {
+    // Takes a copy of the caller's scope to run in, and puts the captures
+    // and arguments on top
+    pub(crate) fn enter(&self, passed_args: &[Value], mut new_scope: Scope) -> Scope {
+        if let Some(captured) = &self.captured {
+            for (var, value) in captured.borrow().iter() {
+                new_scope.insert(*var, value.clone());
+            }
+        }
+        self.args.iter().for_each(|((index, name), value_type)| {
+            if *value_type != ValueType::Any && value_type != &passed_args[*index].get_type() {
+                panic!(
+                    "Invalid value passed to function {name}. Expected {value_type}, got {}",
+                    passed_args[*index].get_type()
+                );
+            }
+            new_scope.insert(*name, Box::new(passed_args[*index].clone()));
+        });
+        new_scope
+    }
+
+    pub(crate) fn check_return(&self, value: Value) -> Value {
+        if self.return_type != ValueType::Any && self.return_type != value.get_type() {
+            panic!(
+                "Invalid value returned from function {}. Expected {}, got {}",
+                self.name,
+                self.return_type,
+                value.get_type()
+            );
+        }
+        value
+    }
+
+    // Hands assignments to captured variables back to the lambda
+    pub(crate) fn leave(&self, mut scope: Scope) {
+        if let Some(captured) = &self.captured {
+            for (var, value) in captured.borrow_mut().iter_mut() {
+                if let Some(new_value) = scope.swap_remove(var) {
+                    *value = new_value;
+                }
+            }
+        }
+    }
+}
+
+// The cache is shared by every copy of the value, so results are kept no
+// matter where the function gets passed
+#[derive(Debug, Clone, PartialEq)]
+pub struct MemoizedFn {
+    pub func: Box<FnType>,
+    pub cache: Rc<RefCell<HashMap<Vec<MemoKey>, Value>>>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum MemoKey {
+    Int(i128),
+    BigInt(BigInt),
+    String(String),
+    Bool(bool),
+}
+
+// Captured arguments go in front of the ones passed when it's called
+#[derive(Debug, Clone, PartialEq)]
+pub struct PartialFn {
+    pub func: Box<FnType>,
+    pub args: Vec<Value>,
+}
+
+// Calls inner first and hands its result to outer
+#[derive(Debug, Clone, PartialEq)]
+pub struct ComposedFn {
+    pub outer: Box<FnType>,
+    pub inner: Box<FnType>,
 }
 
 impl Display for FnType {
-    fn fmt(&self, _f: &mut Formatter) -> std::fmt::Result {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
         match self {
-            FnType::Builtin(_f) => Ok(()),
-            FnType::User(_f) => Ok(()),
+            FnType::Builtin(func) => func.fmt(f),
+            FnType::User(func) => func.fmt(f),
+            FnType::Memoized(func) => func.func.fmt(f),
+            FnType::Partial(func) => func.func.fmt(f),
+            FnType::Composed(func) => write!(f, "{} . {}", func.outer, func.inner),
         }
     }
 }
@@ -86,12 +678,46 @@ impl Display for Value {
     fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
         match self {
             Value::Int(i) => write!(f, "{i}"),
-            Value::Float(_) => Ok(()),
+            Value::BigInt(n) => write!(f, "{n}"),
+            Value::Float(x) => write!(f, "{x}"),
             Value::String(s) => write!(f, "{s}"),
             Value::Bool(b) => write!(f, "{b}"),
+            Value::Array(items) => {
+                write!(f, "[")?;
+                for (i, item) in items.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ", ")?;
+                    }
+                    write!(f, "{item}")?;
+                }
+                write!(f, "]")
+            }
+            Value::Map(entries) => {
+                write!(f, "{{")?;
+                for (i, (key, value)) in entries.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ", ")?;
+                    }
+                    write!(f, "{key}: {value}")?;
+                }
+                write!(f, "}}")
+            }
+            Value::Tuple(items) => {
+                write!(f, "(")?;
+                for (i, item) in items.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ", ")?;
+                    }
+                    write!(f, "{item}")?;
+                }
+                write!(f, ")")
+            }
             Value::Fn(_) => Ok(()),
+            Value::Iterator(range) => range.fmt(f),
             Value::Return(v) => v.fmt(f),
-            Value::Nothing => write!(f, "nothing"),
+            Value::TailCall(_) | Value::Break | Value::Continue | Value::Nothing => {
+                write!(f, "nothing")
+            }
         }
     }
 }
@@ -103,276 +729,1055 @@ impl Display for ValueType {
             ValueType::Float => write!(f, "float"),
             ValueType::String => write!(f, "string"),
             ValueType::Bool => write!(f, "bool"),
-            ValueType::Fn => write!(f, "fn"),
+            ValueType::Array => write!(f, "array"),
+            ValueType::Map => write!(f, "map"),
+            ValueType::Tuple(types) => {
+                write!(f, "(")?;
+                for (i, value_type) in types.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ", ")?;
+                    }
+                    write!(f, "{value_type}")?;
+                }
+                write!(f, ")")
+            }
+            ValueType::Fn => write!(f, "function"),
+            ValueType::Iterator => write!(f, "iterator"),
+            ValueType::Any => write!(f, "any"),
             ValueType::Nothing => write!(f, "nothing"),
         }
     }
 }
 
+impl ValueType {
+    pub(crate) fn is_named(&self, name: &str) -> bool {
+        let type_name = match self {
+            ValueType::Tuple(_) => "tuple".to_string(),
+            value_type => value_type.to_string(),
+        };
+        type_name.eq_ignore_ascii_case(name)
+    }
+}
+
+// Ints that fit an i128 stay Ints, so each int has only the one form
+impl From<BigInt> for Value {
+    fn from(n: BigInt) -> Self {
+        match n.to_i128() {
+            Some(i) => Value::Int(i),
+            None => Value::BigInt(n),
+        }
+    }
+}
+
 impl Value {
-    fn get_type(&self) -> ValueType {
+    pub fn get_type(&self) -> ValueType {
         match self {
-            Value::Int(_) => ValueType::Int,
+            Value::Int(_) | Value::BigInt(_) => ValueType::Int,
             Value::Float(_) => ValueType::Float,
             Value::String(_) => ValueType::String,
             Value::Bool(_) => ValueType::Bool,
+            Value::Array(_) => ValueType::Array,
+            Value::Map(_) => ValueType::Map,
+            Value::Tuple(items) => ValueType::Tuple(items.iter().map(Value::get_type).collect()),
             Value::Fn(_f) => ValueType::Fn,
+            Value::Iterator(_) => ValueType::Iterator,
             Value::Return(v) => v.get_type(),
-            Value::Nothing => ValueType::Nothing,
+            Value::TailCall(_) | Value::Break | Value::Continue | Value::Nothing => {
+                ValueType::Nothing
+            }
+        }
+    }
+
+    // Either kind of int as a BigInt, and None for anything else
+    pub(crate) fn as_big(&self) -> Option<BigInt> {
+        match self {
+            Value::Int(i) => Some(BigInt::from(*i)),
+            Value::BigInt(n) => Some(n.clone()),
+            _ => None,
+        }
+    }
+
+    pub fn is_truthy(&self) -> bool {
+        match self {
+            Value::Int(i) => *i != 0,
+            // Never zero, that would be an Int
+            Value::BigInt(_) => true,
+            Value::Float(f) => *f != 0.0,
+            Value::String(s) => !s.is_empty(),
+            Value::Bool(b) => *b,
+            Value::Return(v) => v.is_truthy(),
+            Value::TailCall(_) | Value::Break | Value::Continue | Value::Nothing => false,
+            _ => true,
+        }
+    }
+
+    fn is_control_flow(&self) -> bool {
+        matches!(self, Value::Return(_) | Value::Break | Value::Continue)
+    }
+
+    // Anything that can be looped over. Iterators hand out their items lazily
+    // instead of being collected into an array first
+    pub(crate) fn items(&self) -> Option<Box<dyn Iterator<Item = Value> + '_>> {
+        match self {
+            Value::Array(items) | Value::Tuple(items) => Some(Box::new(items.iter().cloned())),
+            Value::Iterator(range) => Some(Box::new(range.clone().map(Value::Int))),
+            Value::String(s) => Some(Box::new(s.chars().map(|c| Value::String(c.to_string())))),
+            _ => None,
+        }
+    }
+
+    // Like items, for when the iterator has to outlive the value
+    pub(crate) fn into_items(self) -> Option<Box<dyn Iterator<Item = Value>>> {
+        match self {
+            Value::Array(items) | Value::Tuple(items) => Some(Box::new(items.into_iter())),
+            Value::Iterator(range) => Some(Box::new(range.map(Value::Int))),
+            Value::String(s) => Some(Box::new(
+                s.chars()
+                    .map(|c| Value::String(c.to_string()))
+                    .collect::<Vec<_>>()
+                    .into_iter(),
+            )),
+            _ => None,
         }
     }
 }
 
-fn call_fn(name: &str, passed_args: Vec<Value>, scope: &mut Scope) -> Value {
-    match scope.get(name) {
+fn lookup_fn(name: Symbol, scope: &Scope) -> FnType {
+    match scope.get(&name) {
         Some(key) => match key.as_ref() {
-            Value::Fn(FnType::Builtin(BuiltinFn { name, return_type })) => {
-                let returned_value = builtins::call_builtin(name, passed_args);
-                if returned_value.get_type() == *return_type {
-                    returned_value
-                } else {
-                    panic!(
-                        "Invalid value returned from builtin function {name}. Expected {return_type}, got {}",
-                        returned_value.get_type()
-                    );
-                }
-            }
-            Value::Fn(FnType::User(UserFn {
-                name,
-                args,
-                body,
-                return_type,
-            })) => {
-                let mut new_scope = scope.clone();
-                args.iter().for_each(|((index, name), value_type)| {
-                    if value_type != &passed_args[*index].get_type() {
-                        panic!(
-                            "Invalid value passed to function {name}. Expected {value_type}, got {}",
-                            passed_args[*index].get_type()
-                        );
-                    }
-                    new_scope.insert(name.clone(), Box::new(passed_args[*index].clone()));
-                });
-                for expr in body {
-                    let returned_val_from_expr = interpret_expr(expr, &mut new_scope);
-                    if let Value::Return(val) = returned_val_from_expr {
-                        if *return_type != val.get_type() {
-                            panic!(
-                                "Invalid value returned from function {name}. Expected {return_type}, got {}",
-                                val.get_type()
-                            );
-                        }
-                        return *val;
-                    };
-                }
-                Value::Nothing
-            }
+            Value::Fn(func) => func.clone(),
             _ => {
                 panic!("Not a function: {name}");
             }
         },
-        _ => panic!("Undefined function: {name}"),
+        _ => panic!("{}", undefined("function", name, scope)),
     }
 }
 
-fn get_valuetype_from(name: &str) -> ValueType {
-    match name {
-        "bool" => ValueType::Bool,
-        "int" => ValueType::Int,
-        "float" => ValueType::Float,
-        "string" => ValueType::String,
-        "_none" => ValueType::Nothing,
-        _ => panic!("Invalid type name: {name}"),
-    }
+fn call_fn(
+    name: Symbol,
+    passed_args: Vec<Value>,
+    scope: &mut Scope,
+    ctx: &mut Context,
+) -> Result<Value, RuntimeError> {
+    let func = lookup_fn(name, scope);
+    call_value(&func, passed_args, scope, ctx)
 }
 
-fn interpret_expr(expr: &Expr, scope: &mut Scope) -> Value {
-    match expr {
-        Expr::BinaryExpr {
-            op: Operator::SetVal(expected_type),
-            lhs,
-            rhs,
-        } => {
-            if let Some(expected_type) = expected_type {
-                let rhs_value = interpret_expr(rhs, scope);
-                if rhs_value.get_type() == get_valuetype_from(expected_type) {
-                    scope.insert(lhs.to_string(), Box::new(rhs_value));
-                    Value::Nothing
-                } else {
-                    panic!(
-                        "Invalid value type for set operation. Expected {expected_type}, got {}",
-                        rhs_value.get_type()
-                    );
-                }
-            } else {
-                let rhs_value = interpret_expr(rhs, scope);
-                scope.insert(lhs.to_string(), Box::new(rhs_value));
-                Value::Nothing
+// What's left of a function that made a tail call: checking the value that
+// comes back, and handing captures back if it is a lambda
+struct PendingReturn {
+    func: UserFn,
+    scope: Option<Scope>,
+}
+
+// Runs a user function, then any function it tail calls, and so on, in a
+// loop. run_body runs one function's body, handing back a Value::TailCall
+// for the next one instead of making the call
+pub(crate) fn call_user_fn(
+    func: &UserFn,
+    passed_args: Vec<Value>,
+    scope: Scope,
+    ctx: &mut Context,
+    run_body: &mut dyn FnMut(&UserFn, &mut Scope, &mut Context) -> Result<Value, RuntimeError>,
+) -> Result<Value, RuntimeError> {
+    // Innermost last. A function calling itself only needs its return type
+    // checked once, so plain self recursion keeps this from growing
+    let mut pending: Vec<PendingReturn> = Vec::new();
+    let mut next;
+    let mut func = func;
+    let mut args = passed_args;
+    let mut scope = scope;
+    let mut value = loop {
+        count_call(&func.name, ctx);
+        let mut new_scope = func.enter(&args, scope);
+        let call = match run_body(func, &mut new_scope, ctx)? {
+            Value::TailCall(call) => call,
+            value => {
+                func.leave(new_scope);
+                break value;
             }
+        };
+        let has_captures = func
+            .captured
+            .as_ref()
+            .is_some_and(|captured| !captured.borrow().is_empty());
+        if has_captures {
+            pending.push(PendingReturn {
+                func: func.clone(),
+                scope: Some(new_scope),
+            });
+        } else if func.return_type != ValueType::Any
+            && !pending.last().is_some_and(|last| {
+                last.scope.is_none()
+                    && last.func.name == func.name
+                    && last.func.return_type == func.return_type
+            })
+        {
+            pending.push(PendingReturn {
+                func: func.clone(),
+                scope: None,
+            });
         }
-        Expr::BinaryExpr {
-            op: Operator::Add,
-            lhs,
-            rhs,
-        } => {
-            let left_side = interpret_expr(lhs, scope);
-            let right_side = interpret_expr(rhs, scope);
-            match left_side {
-                Value::Int(left) => match right_side {
-                    Value::Int(right) => Value::Int(left + right),
-                    Value::Float(right) => Value::Float(left as f64 + right),
-                    Value::String(right) => Value::String(left.to_string() + &right),
-                    _ => panic!("Invalid type for addition"),
-                },
-                Value::Float(left) => match right_side {
-                    Value::Float(right) => Value::Float(left + right),
-                    Value::Int(right) => Value::Float(left + right as f64),
-                    Value::String(right) => Value::String(left.to_string() + &right),
-                    _ => panic!("Invalid type for addition"),
-                },
-                Value::String(left) => match right_side {
-                    Value::Int(right) => Value::String(left + &right.to_string()),
-                    Value::Float(right) => Value::String(left + &right.to_string()),
-                    Value::String(right) => Value::String(left + &right),
-                    Value::Bool(right) => Value::String(left + &right.to_string()),
-                    _ => panic!("Invalid type for addition"),
-                },
-                Value::Bool(left) => match right_side {
-                    Value::Int(right) => Value::String(left.to_string() + &right.to_string()),
-                    Value::Float(right) => Value::String(left.to_string() + &right.to_string()),
-                    Value::String(right) => Value::String(left.to_string() + &right),
-                    Value::Bool(right) => Value::String(left.to_string() + &right.to_string()),
-                    _ => panic!("Invalid type for addition"),
+        let TailCall {
+            func: callee,
+            args: callee_args,
+            scope: callee_scope,
+        } = *call;
+        next = callee;
+        func = &next;
+        args = callee_args;
+        scope = callee_scope;
+    };
+    for PendingReturn { func, scope } in pending.into_iter().rev() {
+        value = func.check_return(value);
+        if let Some(scope) = scope {
+            func.leave(scope);
+        }
+    }
+    Ok(value)
+}
+
+fn count_call(name: &str, ctx: &mut Context) {
+    if let Some(counts) = ctx.call_counts.as_mut() {
+        *counts.entry(name.to_string()).or_insert(0) += 1;
+    }
+}
+
+// Makes the call a Return is waiting on, for the places a function's
+// result is used other than a function body
+fn finish_tail_call(value: Value, ctx: &mut Context) -> Result<Value, RuntimeError> {
+    match value {
+        Value::Return(inner) => match *inner {
+            Value::TailCall(call) => Ok(Value::Return(Box::new(call.run(ctx)?))),
+            inner => Ok(Value::Return(Box::new(inner))),
+        },
+        value => Ok(value),
+    }
+}
+
+pub(crate) fn call_value(
+    func: &FnType,
+    passed_args: Vec<Value>,
+    scope: &mut Scope,
+    ctx: &mut Context,
+) -> Result<Value, RuntimeError> {
+    match func {
+        FnType::Builtin(BuiltinFn { name, return_type }) => {
+            count_call(name, ctx);
+            // Builtins still report bad arguments by panicking, so catch that
+            // here instead of letting it unwind into whatever is embedding us
+            ctx.builtin_depth += 1;
+            let result = catch_quietly(|| builtins::call_builtin(name, passed_args, scope, ctx));
+            ctx.builtin_depth -= 1;
+            let returned_value = result.map_err(|message| RuntimeError::BuiltinPanicked {
+                name: name.clone(),
+                message,
+            })??;
+            if *return_type == ValueType::Any || returned_value.get_type() == *return_type {
+                Ok(returned_value)
+            } else {
+                panic!(
+                    "Invalid value returned from builtin function {name}. Expected {return_type}, got {}",
+                    returned_value.get_type()
+                );
+            }
+        }
+        FnType::User(func) => run_callback(ctx, |ctx| {
+            call_user_fn(
+                func,
+                passed_args,
+                scope.clone(),
+                ctx,
+                &mut |func, new_scope, ctx| {
+                    for expr in func.body.iter() {
+                        let returned_val_from_expr = interpret_expr(expr, new_scope, ctx)?;
+                        if let Value::Break | Value::Continue = returned_val_from_expr {
+                            panic!(
+                                "Cannot break or continue outside of a loop in function {}",
+                                func.name
+                            );
+                        }
+                        if let Value::Return(val) = returned_val_from_expr {
+                            return Ok(match *val {
+                                call @ Value::TailCall(_) => call,
+                                val => func.check_return(val),
+                            });
+                        };
+                    }
+                    Ok(Value::Nothing)
                 },
-                _ => panic!("Invalid type for addition"),
+            )
+        }),
+        FnType::Memoized(MemoizedFn { func, cache }) => {
+            let key = passed_args
+                .iter()
+                .map(|arg| match arg {
+                    Value::Int(i) => MemoKey::Int(*i),
+                    Value::BigInt(n) => MemoKey::BigInt(n.clone()),
+                    Value::String(s) => MemoKey::String(s.clone()),
+                    Value::Bool(b) => MemoKey::Bool(*b),
+                    other => panic!(
+                        "Memoized function {func} takes int, string or bool arguments, got {}",
+                        other.get_type()
+                    ),
+                })
+                .collect::<Vec<_>>();
+            if let Some(value) = cache.borrow().get(&key) {
+                return Ok(value.clone());
             }
+            // The borrow is let go before calling, so a recursive function can
+            // go through its own cache
+            let value = call_value(func, passed_args, scope, ctx)?;
+            cache.borrow_mut().insert(key, value.clone());
+            Ok(value)
         }
-        Expr::BinaryExpr {
-            op: Operator::Sub,
-            lhs,
-            rhs,
-        } => {
-            let left_side = interpret_expr(lhs, scope);
-            let right_side = interpret_expr(rhs, scope);
-            match (left_side, right_side) {
-                (Value::Int(left), Value::Int(right)) => Value::Int(left - right),
-                (Value::Float(left), Value::Float(right)) => Value::Float(left - right),
-                _ => panic!("Cannot subtract non-numeric values"),
+        FnType::Partial(PartialFn { func, args }) => {
+            let mut all_args = args.clone();
+            all_args.extend(passed_args);
+            let arity = builtins::fn_arity(func);
+            if arity >= 0 && all_args.len() != arity as usize {
+                panic!(
+                    "Function {func} takes {arity} arguments, got {}",
+                    all_args.len()
+                );
             }
+            call_value(func, all_args, scope, ctx)
         }
-        Expr::BinaryExpr {
-            op: Operator::Mul,
-            lhs,
-            rhs,
-        } => {
-            let left_side = interpret_expr(lhs, scope);
-            let right_side = interpret_expr(rhs, scope);
-            match (left_side, right_side) {
-                (Value::Int(left), Value::Int(right)) => Value::Int(left * right),
-                (Value::Float(left), Value::Float(right)) => Value::Float(left * right),
-                (Value::String(left), Value::Int(right)) => {
-                    Value::String(left.repeat(right.try_into().unwrap()))
-                }
-                _ => panic!("Cannot multiply non-numeric values"),
+        FnType::Composed(ComposedFn { outer, inner }) => {
+            let value = call_value(inner, passed_args, scope, ctx)?;
+            call_value(outer, vec![value], scope, ctx)
+        }
+    }
+}
+
+pub(crate) fn get_valuetype_from(name: &str) -> ValueType {
+    match name {
+        "bool" => ValueType::Bool,
+        "int" => ValueType::Int,
+        "float" => ValueType::Float,
+        "string" => ValueType::String,
+        "array" => ValueType::Array,
+        "function" => ValueType::Fn,
+        "any" => ValueType::Any,
+        "_none" => ValueType::Nothing,
+        _ => panic!("Invalid type name: {name}"),
+    }
+}
+
+pub(crate) fn parse_source(source: &str) -> Result<Vec<Expr>, RuntimeError> {
+    let mut source = source.trim_end().to_string();
+    if source.is_empty() {
+        return Ok(Vec::new());
+    }
+    // Let a lone expression like eval("1 + 2") leave off its semicolon
+    if !source.ends_with(';') && !source.ends_with('}') {
+        source.push(';');
+    }
+    lexer::check(&source).map_err(|err| RuntimeError::ParseFailed {
+        message: err.to_string(),
+    })?;
+    let mut tokens = Token::lexer(&source).peekable();
+    Parser::new(&mut tokens)
+        .parse()
+        .map_err(|err| RuntimeError::ParseFailed {
+            message: err.to_string(),
+        })
+}
+
+// Runs source code in the given scope and evaluates to its last value
+pub(crate) fn eval_source(
+    source: &str,
+    scope: &mut Scope,
+    ctx: &mut Context,
+) -> Result<Value, RuntimeError> {
+    let exprs = parse_source(source)?;
+    run_callback(ctx, |ctx| {
+        let mut value = Value::Nothing;
+        for expr in &exprs {
+            value = interpret_expr(expr, scope, ctx)?;
+            if let Value::Return(inner) = value {
+                return match *inner {
+                    Value::TailCall(call) => call.run(ctx),
+                    inner => Ok(inner),
+                };
             }
         }
-        Expr::BinaryExpr {
-            op: Operator::Div,
-            lhs,
-            rhs,
-        } => {
-            let left_side = interpret_expr(lhs, scope);
-            let right_side = interpret_expr(rhs, scope);
-            match (left_side, right_side) {
-                (Value::Int(left), Value::Int(right)) => Value::Int(left / right),
-                (Value::Float(left), Value::Float(right)) => Value::Float(left / right),
-                _ => panic!("Cannot divide non-numeric values"),
+        Ok(value)
+    })
+}
+
+thread_local! {
+    // How many catch_quietly calls are running on this thread
+    static CATCHING: Cell<usize> = const { Cell::new(0) };
+}
+
+// Runs body, giving back the message of any panic in it. A panic caught
+// here turns into an error the caller reports, so the panic hook is kept
+// from also printing it to stderr. Panics nothing catches still print
+fn catch_quietly<T>(body: impl FnOnce() -> T) -> Result<T, String> {
+    static QUIET_HOOK: Once = Once::new();
+    QUIET_HOOK.call_once(|| {
+        let default_hook = panic::take_hook();
+        panic::set_hook(Box::new(move |info| {
+            if CATCHING.with(Cell::get) == 0 {
+                default_hook(info);
+            }
+        }));
+    });
+    CATCHING.with(|catching| catching.set(catching.get() + 1));
+    let result = panic::catch_unwind(AssertUnwindSafe(body));
+    CATCHING.with(|catching| catching.set(catching.get() - 1));
+    result.map_err(|payload| match payload.downcast::<String>() {
+        Ok(message) => *message,
+        Err(payload) => match payload.downcast::<&str>() {
+            Ok(message) => message.to_string(),
+            Err(_) => "Unknown panic".to_string(),
+        },
+    })
+}
+
+// Runs the body of a try block, turning a panic into RuntimeError::Panicked
+// with the panic's message
+pub(crate) fn catch_panic<T>(
+    body: impl FnOnce() -> Result<T, RuntimeError>,
+) -> Result<T, RuntimeError> {
+    catch_quietly(body).unwrap_or_else(|message| Err(RuntimeError::Panicked { message }))
+}
+
+// User code that a builtin calls back into, like a map callback or eval'd
+// source, runs through here. A panic in it belongs to that code, so it comes
+// back as Panicked instead of being blamed on the builtin
+fn run_callback<T>(
+    ctx: &mut Context,
+    body: impl FnOnce(&mut Context) -> Result<T, RuntimeError>,
+) -> Result<T, RuntimeError> {
+    if ctx.builtin_depth == 0 {
+        return body(ctx);
+    }
+    let depth = mem::replace(&mut ctx.builtin_depth, 0);
+    let result = catch_panic(|| body(&mut *ctx));
+    ctx.builtin_depth = depth;
+    result
+}
+
+// printf-style directives: %d, %i, %f, %s, %x, %X and %% for a literal
+// percent sign, each taking an optional - or 0 flag, a width and a precision
+fn format_percent(format: &str, args: &[Value]) -> String {
+    let mut result = String::new();
+    let mut args = args.iter();
+    let mut chars = format.chars().peekable();
+    while let Some(c) = chars.next() {
+        if c != '%' {
+            result.push(c);
+            continue;
+        }
+        if chars.peek() == Some(&'%') {
+            chars.next();
+            result.push('%');
+            continue;
+        }
+        let mut left_align = false;
+        let mut zero_pad = false;
+        while let Some(flag @ ('-' | '0')) = chars.peek().copied() {
+            chars.next();
+            if flag == '-' {
+                left_align = true;
+            } else {
+                zero_pad = true;
+            }
+        }
+        let mut width = 0usize;
+        while let Some(digit) = chars.peek().and_then(|c| c.to_digit(10)) {
+            chars.next();
+            width = width.saturating_mul(10).saturating_add(digit as usize);
+        }
+        let mut precision = None;
+        if chars.peek() == Some(&'.') {
+            chars.next();
+            let mut digits = 0usize;
+            while let Some(digit) = chars.peek().and_then(|c| c.to_digit(10)) {
+                chars.next();
+                digits = digits.saturating_mul(10).saturating_add(digit as usize);
+            }
+            precision = Some(digits);
+        }
+        let conversion = chars
+            .next()
+            .unwrap_or_else(|| panic!("Incomplete format directive in {format:?}"));
+        let arg = args
+            .next()
+            .unwrap_or_else(|| panic!("Not enough values for format string {format:?}"));
+        let text = match (conversion, arg) {
+            ('d' | 'i', Value::Int(i)) => i.to_string(),
+            ('d' | 'i', Value::BigInt(n)) => n.to_string(),
+            ('f', Value::Float(f)) => format!("{f:.*}", precision.unwrap_or(6)),
+            ('f', Value::Int(i)) => format!("{:.*}", precision.unwrap_or(6), *i as f64),
+            ('f', Value::BigInt(n)) => format!("{:.*}", precision.unwrap_or(6), n.to_f64()),
+            ('x', Value::Int(i)) => format!("{i:x}"),
+            ('X', Value::Int(i)) => format!("{i:X}"),
+            ('s', value) => match precision {
+                Some(precision) => value.to_string().chars().take(precision).collect(),
+                None => value.to_string(),
+            },
+            ('d' | 'i' | 'f' | 'x' | 'X', value) => {
+                panic!("%{conversion} needs a number, got {}", value.get_type())
             }
+            _ => panic!("Unknown format directive %{conversion}"),
+        };
+        let padding = width.saturating_sub(text.chars().count());
+        if left_align {
+            result.push_str(&text);
+            result.push_str(&" ".repeat(padding));
+        } else if zero_pad && conversion != 's' {
+            // Zeros go after the sign so -5 becomes -005 rather than 00-5
+            let (sign, digits) = match text.strip_prefix('-') {
+                Some(digits) => ("-", digits),
+                None => ("", text.as_str()),
+            };
+            result.push_str(sign);
+            result.push_str(&"0".repeat(padding));
+            result.push_str(digits);
+        } else {
+            result.push_str(&" ".repeat(padding));
+            result.push_str(&text);
+        }
+    }
+    if args.next().is_some() {
+        panic!("Too many values for format string {format:?}");
+    }
+    result
+}
+
+// The parser only lets identifiers be assigned to, so the fallback is just
+// for safety
+pub(crate) fn target_symbol(expr: &Expr) -> Symbol {
+    match expr {
+        Expr::Token(Token::Identifier(name)) => *name,
+        other => Symbol::intern(&other.to_string()),
+    }
+}
+
+// Points at the closest name in scope when the missing one looks like a
+// typo of it. Short names need a closer match, or any one letter name
+// would suggest every other one. On a tie, names the program defined win
+// over builtins
+pub(crate) fn undefined(kind: &str, name: Symbol, scope: &Scope) -> String {
+    let missing = name.as_str();
+    let closest = scope
+        .iter()
+        .map(|(candidate, value)| {
+            let distance = builtins::levenshtein(missing, candidate.as_str());
+            let is_builtin = matches!(value.as_ref(), Value::Fn(FnType::Builtin(_)));
+            (distance, is_builtin, candidate)
+        })
+        .filter(|(distance, _, _)| *distance <= 2 && *distance < missing.chars().count())
+        .min_by_key(|(distance, is_builtin, _)| (*distance, *is_builtin));
+    match closest {
+        Some((_, _, candidate)) => format!("Undefined {kind}: {name}, did you mean `{candidate}`?"),
+        None => format!("Undefined {kind}: {name}"),
+    }
+}
+
+fn user_defined(scope: &Scope) -> impl Iterator<Item = (&Symbol, &Value)> {
+    scope
+        .iter()
+        .map(|(name, value)| (name, value.as_ref()))
+        .filter(|(_, value)| !matches!(value, Value::Fn(FnType::Builtin(_))))
+}
+
+#[derive(Default)]
+struct UsedNames(Vec<Symbol>);
+
+impl ExprVisitor for UsedNames {
+    fn visit_token(&mut self, token: &Token) {
+        if let Token::Identifier(name) = token {
+            self.0.push(*name);
+        }
+    }
+}
+
+// Int +, -, *, / or % at the width in use, None if the result doesn't fit
+// it. A result past an i128 is worked out again on BigInts, which only a
+// bignum width gives an answer for
+pub(crate) fn int_arithmetic(
+    op: &Operator,
+    left: &Value,
+    right: &Value,
+    integers: &dyn IntArithmetic,
+) -> Option<Value> {
+    if let (Value::Int(a), Value::Int(b)) = (left, right) {
+        let result = match op {
+            Operator::Add => integers.add(*a, *b),
+            Operator::Sub => integers.sub(*a, *b),
+            Operator::Mul => integers.mul(*a, *b),
+            Operator::Div => integers.div(*a, *b),
+            Operator::Percent => integers.rem(*a, *b),
+            op => panic!("Invalid int operator: {op}"),
+        };
+        if let Some(result) = result {
+            return Some(Value::Int(result));
+        }
+    }
+    integers
+        .big(op, &left.as_big()?, &right.as_big()?)
+        .map(Value::from)
+}
+
+fn int_result(
+    op: &Operator,
+    left: &Value,
+    right: &Value,
+    ctx: &Context,
+) -> Result<Value, RuntimeError> {
+    int_arithmetic(op, left, right, &*ctx.integers).ok_or_else(|| RuntimeError::IntegerOverflow {
+        name: op.to_string(),
+    })
+}
+
+// An int literal, which has to fit the width like any other new int
+pub(crate) fn int_literal(i: i128, ctx: &Context) -> Result<Value, RuntimeError> {
+    ctx.integers
+        .bound(i)
+        .map(Value::Int)
+        .ok_or_else(|| RuntimeError::IntegerOverflow {
+            name: i.to_string(),
+        })
+}
+
+fn is_int(value: &Value) -> bool {
+    matches!(value, Value::Int(_) | Value::BigInt(_))
+}
+
+// The operators that always evaluate both sides. The bytecode VM runs
+// these too, so both give the same results
+pub(crate) fn binary_op(
+    op: &Operator,
+    left_side: Value,
+    right_side: Value,
+    ctx: &mut Context,
+) -> Result<Value, RuntimeError> {
+    let arithmetic = matches!(
+        op,
+        Operator::Add | Operator::Sub | Operator::Mul | Operator::Div | Operator::Percent
+    );
+    if arithmetic && is_int(&left_side) && is_int(&right_side) {
+        if matches!(op, Operator::Div | Operator::Percent) && right_side == Value::Int(0) {
+            return Err(RuntimeError::DivisionByZero);
         }
+        return int_result(op, &left_side, &right_side, ctx);
+    }
+    Ok(match op {
+        Operator::Add => match left_side {
+            Value::Int(left) => match right_side {
+                Value::Float(right) => Value::Float(left as f64 + right),
+                Value::String(right) => Value::String(left.to_string() + &right),
+                _ => panic!("Invalid type for addition"),
+            },
+            Value::BigInt(left) => match right_side {
+                Value::Float(right) => Value::Float(left.to_f64() + right),
+                Value::String(right) => Value::String(left.to_string() + &right),
+                _ => panic!("Invalid type for addition"),
+            },
+            Value::Float(left) => match right_side {
+                Value::Float(right) => Value::Float(left + right),
+                Value::Int(right) => Value::Float(left + right as f64),
+                Value::BigInt(right) => Value::Float(left + right.to_f64()),
+                Value::String(right) => Value::String(left.to_string() + &right),
+                _ => panic!("Invalid type for addition"),
+            },
+            Value::String(left) => match right_side {
+                Value::Int(right) => Value::String(left + &right.to_string()),
+                Value::BigInt(right) => Value::String(left + &right.to_string()),
+                Value::Float(right) => Value::String(left + &right.to_string()),
+                Value::String(right) => Value::String(left + &right),
+                Value::Bool(right) => Value::String(left + &right.to_string()),
+                _ => panic!("Invalid type for addition"),
+            },
+            Value::Bool(left) => match right_side {
+                Value::Int(right) => Value::String(left.to_string() + &right.to_string()),
+                Value::BigInt(right) => Value::String(left.to_string() + &right.to_string()),
+                Value::Float(right) => Value::String(left.to_string() + &right.to_string()),
+                Value::String(right) => Value::String(left.to_string() + &right),
+                Value::Bool(right) => Value::String(left.to_string() + &right.to_string()),
+                _ => panic!("Invalid type for addition"),
+            },
+            _ => panic!("Invalid type for addition"),
+        },
+        Operator::Sub => match (left_side, right_side) {
+            (Value::Float(left), Value::Float(right)) => Value::Float(left - right),
+            _ => panic!("Cannot subtract non-numeric values"),
+        },
+        Operator::Mul => match (left_side, right_side) {
+            (Value::Float(left), Value::Float(right)) => Value::Float(left * right),
+            (Value::String(left), Value::Int(right)) => {
+                Value::String(left.repeat(right.try_into().unwrap()))
+            }
+            _ => panic!("Cannot multiply non-numeric values"),
+        },
+        Operator::Div => match (left_side, right_side) {
+            (Value::Float(left), Value::Float(right)) => Value::Float(left / right),
+            _ => panic!("Cannot divide non-numeric values"),
+        },
+        // A string on the left formats the right side into it, anything else
+        // is the remainder
+        Operator::Percent => match (left_side, right_side) {
+            (Value::String(format), Value::Array(args)) => {
+                Value::String(format_percent(&format, &args))
+            }
+            (Value::String(format), arg) => Value::String(format_percent(&format, &[arg])),
+            (Value::Float(left), Value::Float(right)) => Value::Float(left % right),
+            _ => panic!("Cannot take the remainder of non-numeric values"),
+        },
+        Operator::Eq => match (left_side, right_side) {
+            (Value::Int(left), Value::Int(right)) => Value::Bool(left == right),
+            (left, right) if is_int(&left) && is_int(&right) => Value::Bool(left == right),
+            (Value::Float(left), Value::Float(right)) => Value::Bool(left == right),
+            (Value::String(left), Value::String(right)) => Value::Bool(left == right),
+            (Value::Bool(left), Value::Bool(right)) => Value::Bool(left == right),
+            _ => panic!("Cannot compare non-numeric values"),
+        },
+        Operator::Neq => match (left_side, right_side) {
+            (Value::Int(left), Value::Int(right)) => Value::Bool(left != right),
+            (left, right) if is_int(&left) && is_int(&right) => Value::Bool(left != right),
+            (Value::Float(left), Value::Float(right)) => Value::Bool(left != right),
+            (Value::String(left), Value::String(right)) => Value::Bool(left != right),
+            (Value::Bool(left), Value::Bool(right)) => Value::Bool(left != right),
+            _ => panic!("Cannot compare non-numeric values"),
+        },
+        Operator::Lt => match (left_side, right_side) {
+            (Value::Int(left), Value::Int(right)) => Value::Bool(left < right),
+            (left, right) if is_int(&left) && is_int(&right) => {
+                Value::Bool(left.as_big() < right.as_big())
+            }
+            (Value::Float(left), Value::Float(right)) => Value::Bool(left < right),
+            _ => panic!("Cannot compare non-numeric values"),
+        },
+        Operator::Gt => match (left_side, right_side) {
+            (Value::Int(left), Value::Int(right)) => Value::Bool(left > right),
+            (left, right) if is_int(&left) && is_int(&right) => {
+                Value::Bool(left.as_big() > right.as_big())
+            }
+            (Value::Float(left), Value::Float(right)) => Value::Bool(left > right),
+            _ => panic!("Cannot compare non-numeric values"),
+        },
+        Operator::Le => match (left_side, right_side) {
+            (Value::Int(left), Value::Int(right)) => Value::Bool(left <= right),
+            (left, right) if is_int(&left) && is_int(&right) => {
+                Value::Bool(left.as_big() <= right.as_big())
+            }
+            (Value::Float(left), Value::Float(right)) => Value::Bool(left <= right),
+            _ => panic!("Cannot compare non-numeric values"),
+        },
+        Operator::Ge => match (left_side, right_side) {
+            (Value::Int(left), Value::Int(right)) => Value::Bool(left >= right),
+            (left, right) if is_int(&left) && is_int(&right) => {
+                Value::Bool(left.as_big() >= right.as_big())
+            }
+            (Value::Float(left), Value::Float(right)) => Value::Bool(left >= right),
+            _ => panic!("Cannot compare non-numeric values"),
+        },
+        Operator::In => match (left_side, right_side) {
+            (needle, Value::Array(items)) => Value::Bool(items.contains(&needle)),
+            (Value::String(needle), Value::String(s)) => Value::Bool(s.contains(&needle)),
+            (Value::String(key), Value::Map(entries)) => Value::Bool(entries.contains_key(&key)),
+            (needle, haystack) => panic!(
+                "Cannot check for {} in {}",
+                needle.get_type(),
+                haystack.get_type()
+            ),
+        },
+        op => panic!("Invalid binary operator: {op}"),
+    })
+}
+
+// Postfix, so this evaluates to the value from before the update
+pub(crate) fn post_increment(
+    op: &Operator,
+    name: Symbol,
+    scope: &mut Scope,
+    ctx: &mut Context,
+) -> Result<Value, RuntimeError> {
+    match scope.get(&name).map(|v| v.as_ref()) {
+        Some(old @ (Value::Int(_) | Value::BigInt(_))) => {
+            let old = old.clone();
+            let step = if *op == Operator::Increment {
+                Operator::Add
+            } else {
+                Operator::Sub
+            };
+            match int_arithmetic(&step, &old, &Value::Int(1), &*ctx.integers) {
+                Some(new) => scope.insert(name, Box::new(new)),
+                None => {
+                    return Err(RuntimeError::IntegerOverflow {
+                        name: op.to_string(),
+                    })
+                }
+            };
+            Ok(old)
+        }
+        Some(other) => panic!("Cannot use {op} on {}", other.get_type()),
+        None => panic!("{}", undefined("variable", name, scope)),
+    }
+}
+
+pub(crate) fn index_value(target: Value, index: Value) -> Value {
+    match (target, index) {
+        (Value::Array(items), Value::Int(i)) => {
+            let len = items.len() as i128;
+            // Negative indices count back from the end of the array
+            let resolved = if i < 0 { len + i } else { i };
+            if resolved < 0 || resolved >= len {
+                panic!("Index {i} out of range for array of length {len}");
+            }
+            items[resolved as usize].clone()
+        }
+        (Value::Map(entries), Value::String(key)) => match entries.get(&key) {
+            Some(value) => value.clone(),
+            None => panic!("Key not found in map: {key}"),
+        },
+        (target, index) => panic!(
+            "Cannot index {} with {}",
+            target.get_type(),
+            index.get_type()
+        ),
+    }
+}
+
+// Calls being returned become a TailCall when they go to a user function.
+// Anything else could change the caller's scope, so it's called right away
+fn returned_value(
+    inner: &Expr,
+    scope: &mut Scope,
+    ctx: &mut Context,
+) -> Result<Value, RuntimeError> {
+    let (callee, args) = match inner {
+        Expr::FnCall { callee, args } if !is_special_form(callee) => (callee.as_ref(), args),
+        inner => return interpret_expr(inner, scope, ctx),
+    };
+    let mut args_vec = Vec::new();
+    for arg in args {
+        args_vec.push(interpret_expr(arg, scope, ctx)?);
+    }
+    let func = match callee {
+        Expr::Token(Token::Identifier(name)) => lookup_fn(*name, scope),
+        callee => match interpret_expr(callee, scope, ctx)? {
+            Value::Fn(func) => func,
+            value => panic!("Cannot call a value of type {}", value.get_type()),
+        },
+    };
+    match func {
+        FnType::User(func) => Ok(Value::TailCall(Box::new(TailCall {
+            func,
+            args: args_vec,
+            scope: scope.clone(),
+        }))),
+        func => call_value(&func, args_vec, scope, ctx),
+    }
+}
+
+// These look at their arguments as names rather than values
+pub(crate) const SPECIAL_FORMS: [&str; 5] = ["defined", "delete", "globals", "dump_state", "swap"];
+
+pub(crate) fn is_special_form(callee: &Expr) -> bool {
+    matches!(callee, Expr::Token(Token::Identifier(name)) if SPECIAL_FORMS.contains(&name.as_str()))
+}
+
+// Evaluates to the last value in the block, stopping early on control flow
+fn interpret_block(
+    body: &[Expr],
+    scope: &mut Scope,
+    ctx: &mut Context,
+) -> Result<Value, RuntimeError> {
+    let mut value = Value::Nothing;
+    for expr in body {
+        value = interpret_expr(expr, scope, ctx)?;
+        if value.is_control_flow() {
+            break;
+        }
+    }
+    Ok(value)
+}
+
+// Loop bodies run in the enclosing scope so that updates to loop variables
+// are visible to the condition
+fn interpret_loop_body(
+    body: &[Expr],
+    scope: &mut Scope,
+    ctx: &mut Context,
+) -> Result<Value, RuntimeError> {
+    for expr in body {
+        let value = interpret_expr(expr, scope, ctx)?;
+        if value.is_control_flow() {
+            return Ok(value);
+        }
+    }
+    Ok(Value::Nothing)
+}
+
+pub(crate) fn interpret_expr(
+    expr: &Expr,
+    scope: &mut Scope,
+    ctx: &mut Context,
+) -> Result<Value, RuntimeError> {
+    if let Some(tracer) = ctx.tracer.as_mut() {
+        tracer.enter(expr);
+    }
+    let value = evaluate_expr(expr, scope, ctx)?;
+    if let Some(tracer) = ctx.tracer.as_mut() {
+        tracer.exit(expr, &value);
+    }
+    Ok(value)
+}
+
+fn evaluate_expr(expr: &Expr, scope: &mut Scope, ctx: &mut Context) -> Result<Value, RuntimeError> {
+    Ok(match expr {
         Expr::BinaryExpr {
-            op: Operator::Eq,
+            op: Operator::SetVal(expected_type),
             lhs,
             rhs,
         } => {
-            let left_side = interpret_expr(lhs, scope);
-            let right_side = interpret_expr(rhs, scope);
-            match (left_side, right_side) {
-                (Value::Int(left), Value::Int(right)) => Value::Bool(left == right),
-                (Value::Float(left), Value::Float(right)) => Value::Bool(left == right),
-                (Value::String(left), Value::String(right)) => Value::Bool(left == right),
-                (Value::Bool(left), Value::Bool(right)) => Value::Bool(left == right),
-                _ => panic!("Cannot compare non-numeric values"),
+            // Assignments evaluate to the assigned value so they can be chained
+            if let Some(expected_type) = expected_type {
+                let rhs_value = interpret_expr(rhs, scope, ctx)?;
+                if rhs_value.get_type() == get_valuetype_from(expected_type) {
+                    scope.insert(target_symbol(lhs), Box::new(rhs_value.clone()));
+                    rhs_value
+                } else {
+                    panic!(
+                        "Invalid value type for set operation. Expected {expected_type}, got {}",
+                        rhs_value.get_type()
+                    );
+                }
+            } else if let Expr::Tuple { items: names } = lhs.as_ref() {
+                // Arrays destructure the same way, so functions can return either
+                match interpret_expr(rhs, scope, ctx)? {
+                    Value::Tuple(values) | Value::Array(values) => {
+                        if values.len() != names.len() {
+                            panic!(
+                                "Cannot destructure {} values into {} names",
+                                values.len(),
+                                names.len()
+                            );
+                        }
+                        for (name, value) in names.iter().zip(&values) {
+                            scope.insert(target_symbol(name), Box::new(value.clone()));
+                        }
+                        Value::Tuple(values)
+                    }
+                    other => panic!("Cannot destructure {}", other.get_type()),
+                }
+            } else {
+                let rhs_value = interpret_expr(rhs, scope, ctx)?;
+                scope.insert(target_symbol(lhs), Box::new(rhs_value.clone()));
+                rhs_value
             }
         }
         Expr::BinaryExpr {
-            op: Operator::Neq,
+            op:
+                op @ (Operator::Add
+                | Operator::Sub
+                | Operator::Mul
+                | Operator::Div
+                | Operator::Percent
+                | Operator::Eq
+                | Operator::Neq
+                | Operator::Lt
+                | Operator::Gt
+                | Operator::Le
+                | Operator::Ge
+                | Operator::In),
             lhs,
             rhs,
         } => {
-            let left_side = interpret_expr(lhs, scope);
-            let right_side = interpret_expr(rhs, scope);
-            match (left_side, right_side) {
-                (Value::Int(left), Value::Int(right)) => Value::Bool(left != right),
-                (Value::Float(left), Value::Float(right)) => Value::Bool(left != right),
-                (Value::String(left), Value::String(right)) => Value::Bool(left != right),
-                (Value::Bool(left), Value::Bool(right)) => Value::Bool(left != right),
-                _ => panic!("Cannot compare non-numeric values"),
-            }
+            let left_side = interpret_expr(lhs, scope, ctx)?;
+            let right_side = interpret_expr(rhs, scope, ctx)?;
+            binary_op(op, left_side, right_side, ctx)?
         }
         Expr::BinaryExpr {
-            op: Operator::Lt,
+            op: Operator::And,
             lhs,
             rhs,
-        } => {
-            let left_side = interpret_expr(lhs, scope);
-            let right_side = interpret_expr(rhs, scope);
-            match (left_side, right_side) {
-                (Value::Int(left), Value::Int(right)) => Value::Bool(left < right),
-                (Value::Float(left), Value::Float(right)) => Value::Bool(left < right),
-                _ => panic!("Cannot compare non-numeric values"),
-            }
-        }
+        } => match interpret_expr(lhs, scope, ctx)? {
+            Value::Bool(false) => Value::Bool(false),
+            Value::Bool(true) => match interpret_expr(rhs, scope, ctx)? {
+                Value::Bool(right) => Value::Bool(right),
+                _ => panic!("Cannot use non-boolean values with &&"),
+            },
+            _ => panic!("Cannot use non-boolean values with &&"),
+        },
         Expr::BinaryExpr {
-            op: Operator::Gt,
+            op: Operator::Or,
             lhs,
             rhs,
-        } => {
-            let left_side = interpret_expr(lhs, scope);
-            let right_side = interpret_expr(rhs, scope);
-            match (left_side, right_side) {
-                (Value::Int(left), Value::Int(right)) => Value::Bool(left > right),
-                (Value::Float(left), Value::Float(right)) => Value::Bool(left > right),
-                _ => panic!("Cannot compare non-numeric values"),
-            }
-        }
+        } => match interpret_expr(lhs, scope, ctx)? {
+            Value::Bool(true) => Value::Bool(true),
+            Value::Bool(false) => match interpret_expr(rhs, scope, ctx)? {
+                Value::Bool(right) => Value::Bool(right),
+                _ => panic!("Cannot use non-boolean values with ||"),
+            },
+            _ => panic!("Cannot use non-boolean values with ||"),
+        },
         Expr::BinaryExpr {
-            op: Operator::Le,
+            op: Operator::Coalesce,
             lhs,
             rhs,
-        } => {
-            let left_side = interpret_expr(lhs, scope);
-            let right_side = interpret_expr(rhs, scope);
-            match (left_side, right_side) {
-                (Value::Int(left), Value::Int(right)) => Value::Bool(left <= right),
-                (Value::Float(left), Value::Float(right)) => Value::Bool(left <= right),
-                _ => panic!("Cannot compare non-numeric values"),
-            }
-        }
+        } => match interpret_expr(lhs, scope, ctx)? {
+            Value::Nothing => interpret_expr(rhs, scope, ctx)?,
+            value => value,
+        },
         Expr::BinaryExpr {
-            op: Operator::Ge,
+            op: Operator::Pipe,
             lhs,
             rhs,
         } => {
-            let left_side = interpret_expr(lhs, scope);
-            let right_side = interpret_expr(rhs, scope);
-            match (left_side, right_side) {
-                (Value::Int(left), Value::Int(right)) => Value::Bool(left >= right),
-                (Value::Float(left), Value::Float(right)) => Value::Bool(left >= right),
-                _ => panic!("Cannot compare non-numeric values"),
+            // The piped value becomes the first argument, so `x |> f(y)` is `f(x, y)`
+            let mut args_vec = vec![interpret_expr(lhs, scope, ctx)?];
+            let callee = match rhs.as_ref() {
+                Expr::FnCall { callee, args } => {
+                    for arg in args {
+                        args_vec.push(interpret_expr(arg, scope, ctx)?);
+                    }
+                    callee.as_ref()
+                }
+                callee => callee,
+            };
+            match callee {
+                Expr::Token(Token::Identifier(name)) => call_fn(*name, args_vec, scope, ctx)?,
+                callee => match interpret_expr(callee, scope, ctx)? {
+                    Value::Fn(func) => call_value(&func, args_vec, scope, ctx)?,
+                    value => panic!("Cannot pipe into a value of type {}", value.get_type()),
+                },
             }
         }
+        Expr::BinaryExpr { op, .. } => panic!("Invalid binary operator: {op}"),
+        Expr::UnaryExpr {
+            op: Operator::Not,
+            operand,
+        } => match interpret_expr(operand, scope, ctx)? {
+            Value::Bool(b) => Value::Bool(!b),
+            _ => panic!("Cannot negate non-boolean values"),
+        },
+        Expr::UnaryExpr {
+            op: Operator::Sub,
+            operand,
+        } => match interpret_expr(operand, scope, ctx)? {
+            int @ (Value::Int(_) | Value::BigInt(_)) => {
+                int_result(&Operator::Sub, &Value::Int(0), &int, ctx)?
+            }
+            Value::Float(f) => Value::Float(-f),
+            _ => panic!("Cannot negate non-numeric values"),
+        },
+        Expr::UnaryExpr {
+            op: op @ (Operator::Increment | Operator::Decrement),
+            operand,
+        } => match operand.as_ref() {
+            Expr::Token(Token::Identifier(name)) => post_increment(op, *name, scope, ctx)?,
+            _ => panic!("{op} takes a variable"),
+        },
+        Expr::UnaryExpr { op, .. } => panic!("Invalid unary operator: {op}"),
         Expr::Token(x) => match x {
-            Token::Int(x) => Value::Int(*x),
+            Token::Int(x) => int_literal(*x, ctx)?,
             Token::Float(x) => Value::Float(*x),
             Token::String(x) => Value::String(x.to_string()),
             Token::Bool(x) => Value::Bool(*x),
@@ -380,17 +1785,122 @@ fn interpret_expr(expr: &Expr, scope: &mut Scope) -> Value {
                 if let Some(val) = scope.get(x) {
                     *val.clone()
                 } else {
-                    panic!("Undefined variable: {x}")
+                    panic!("{}", undefined("variable", *x, scope))
                 }
             }
             _ => Value::Nothing,
         },
-        Expr::FnCall { name, args } => {
+        // A bare name is looked up rather than evaluated, so checking an
+        // undefined variable doesn't fail
+        Expr::FnCall { callee, args } if matches!(callee.as_ref(), Expr::Token(Token::Identifier(name)) if name == "defined") =>
+        {
+            match args.as_slice() {
+                [Expr::Token(Token::Identifier(name))] => Value::Bool(scope.contains_key(name)),
+                [arg] => match interpret_expr(arg, scope, ctx)? {
+                    // A name that was never interned can't be bound to anything
+                    Value::String(name) => Value::Bool(
+                        Symbol::get(&name).is_some_and(|name| scope.contains_key(&name)),
+                    ),
+                    other => panic!("defined takes a name, got {}", other.get_type()),
+                },
+                _ => panic!("defined takes one name"),
+            }
+        }
+        // Like defined, the variable is named rather than evaluated. A map is
+        // edited where it is stored, so the key is gone on later lookups
+        Expr::FnCall { callee, args } if matches!(callee.as_ref(), Expr::Token(Token::Identifier(name)) if name == "delete") =>
+        {
+            match args.as_slice() {
+                [Expr::Token(Token::Identifier(name))] => {
+                    scope.shift_remove(name);
+                }
+                [Expr::Token(Token::Identifier(name)), key] => {
+                    let key = match interpret_expr(key, scope, ctx)? {
+                        Value::String(key) => key,
+                        other => panic!("Map keys must be strings, got {}", other.get_type()),
+                    };
+                    match scope.get_mut(name).map(|v| v.as_mut()) {
+                        Some(Value::Map(entries)) => {
+                            entries.shift_remove(&key);
+                        }
+                        Some(other) => panic!("Cannot delete a key from {}", other.get_type()),
+                        None => panic!("{}", undefined("variable", *name, scope)),
+                    }
+                }
+                [arg] => match interpret_expr(arg, scope, ctx)? {
+                    Value::String(name) => {
+                        if let Some(name) = Symbol::get(&name) {
+                            scope.shift_remove(&name);
+                        }
+                    }
+                    other => panic!("delete takes a name, got {}", other.get_type()),
+                },
+                _ => panic!("delete takes a variable name, or a map variable and a key"),
+            }
+            Value::Nothing
+        }
+        // Inside a function this also lists its locals, since a function runs
+        // in a copy of the caller's scope
+        Expr::FnCall { callee, args } if matches!(callee.as_ref(), Expr::Token(Token::Identifier(name)) if name == "globals") =>
+        {
+            if !args.is_empty() {
+                panic!("globals takes no arguments");
+            }
+            Value::Array(
+                user_defined(scope)
+                    .map(|(name, _)| Value::String(name.to_string()))
+                    .collect(),
+            )
+        }
+        // One "name: type = value" line per name that globals() would list.
+        // Functions display as nothing, so their name is shown instead
+        Expr::FnCall { callee, args } if matches!(callee.as_ref(), Expr::Token(Token::Identifier(name)) if name == "dump_state") =>
+        {
+            if !args.is_empty() {
+                panic!("dump_state takes no arguments");
+            }
+            let lines: Vec<String> = user_defined(scope)
+                .map(|(name, value)| {
+                    let shown = match value {
+                        Value::Fn(func) => func.to_string(),
+                        value => value.to_string(),
+                    };
+                    format!("{name}: {} = {shown}", value.get_type())
+                })
+                .collect();
+            Value::String(lines.join("\n"))
+        }
+        // swap works on the variables themselves, so its arguments aren't evaluated
+        Expr::FnCall { callee, args } if matches!(callee.as_ref(), Expr::Token(Token::Identifier(name)) if name == "swap") => {
+            match args.as_slice() {
+                [Expr::Token(Token::Identifier(a)), Expr::Token(Token::Identifier(b))] => {
+                    for name in [a, b] {
+                        if !scope.contains_key(name) {
+                            panic!("{}", undefined("variable", *name, scope));
+                        }
+                    }
+                    if a != b {
+                        let a_value = scope.insert(*a, Box::new(Value::Nothing)).unwrap();
+                        let b_value = scope.insert(*b, a_value).unwrap();
+                        scope.insert(*a, b_value);
+                    }
+                    Value::Nothing
+                }
+                _ => panic!("swap takes two variable names"),
+            }
+        }
+        Expr::FnCall { callee, args } => {
             let mut args_vec = Vec::new();
             for arg in args {
-                args_vec.push(interpret_expr(arg, scope));
+                args_vec.push(interpret_expr(arg, scope, ctx)?);
+            }
+            match callee.as_ref() {
+                Expr::Token(Token::Identifier(name)) => call_fn(*name, args_vec, scope, ctx)?,
+                callee => match interpret_expr(callee, scope, ctx)? {
+                    Value::Fn(func) => call_value(&func, args_vec, scope, ctx)?,
+                    value => panic!("Cannot call a value of type {}", value.get_type()),
+                },
             }
-            call_fn(name, args_vec, scope)
         }
         Expr::FnDef {
             name,
@@ -400,43 +1910,73 @@ fn interpret_expr(expr: &Expr, scope: &mut Scope) -> Value {
         } => {
             let funcdef = Value::Fn(FnType::User(UserFn {
                 // FIXME: Is there a better way to do this?
-                name: name.clone(),
+                name: name.to_string(),
                 args: args
                     .iter()
                     .map(|((i, n), v)| {
                         if let Expr::Token(Token::Type(name)) = v {
-                            ((*i, n.clone()), get_valuetype_from(name))
+                            ((*i, *n), get_valuetype_from(name))
                         } else {
                             unreachable!("This should always a be a type token")
                         }
                     })
                     .collect(),
-                body: body.clone(),
+                body: Rc::new(body.clone()),
                 return_type: get_valuetype_from(return_type),
+                captured: None,
             }));
-            scope.insert(name.clone(), Box::new(funcdef));
+            scope.insert(*name, Box::new(funcdef));
             Value::Nothing
         }
+        Expr::Lambda { args, body } => {
+            let mut used = UsedNames::default();
+            walk_exprs(&mut used, body);
+            // Builtins are always in scope, so there's no point copying them
+            let captured = used
+                .0
+                .into_iter()
+                .filter(|name| !args.keys().any(|(_, arg)| arg == name))
+                .filter_map(|name| match scope.get(&name) {
+                    Some(value) if !matches!(value.as_ref(), Value::Fn(FnType::Builtin(_))) => {
+                        Some((name, value.clone()))
+                    }
+                    _ => None,
+                })
+                .collect();
+            Value::Fn(FnType::User(UserFn {
+                name: "lambda".to_string(),
+                args: args
+                    .iter()
+                    .map(|((i, n), v)| match v {
+                        Expr::Token(Token::Type(name)) => ((*i, *n), get_valuetype_from(name)),
+                        _ => unreachable!("This should always be a type token"),
+                    })
+                    .collect(),
+                body: Rc::new(body.clone()),
+                return_type: ValueType::Any,
+                captured: Some(Rc::new(RefCell::new(captured))),
+            }))
+        }
         Expr::If {
             cond,
             body,
             else_body,
         } => {
-            if interpret_expr(cond, scope) == Value::Bool(true) {
+            if interpret_expr(cond, scope, ctx)? == Value::Bool(true) {
                 let mut new_scope = scope.clone();
                 for expr in body {
-                    let value = interpret_expr(expr, &mut new_scope);
-                    if let Value::Return(_) = value {
-                        return value;
+                    let value = interpret_expr(expr, &mut new_scope, ctx)?;
+                    if value.is_control_flow() {
+                        return Ok(value);
                     }
                 }
                 Value::Nothing
             } else if else_body.is_some() {
                 let mut new_scope = scope.clone();
                 for expr in else_body.as_ref().unwrap() {
-                    let value = interpret_expr(expr, &mut new_scope);
-                    if let Value::Return(_) = value {
-                        return value;
+                    let value = interpret_expr(expr, &mut new_scope, ctx)?;
+                    if value.is_control_flow() {
+                        return Ok(value);
                     }
                 }
                 Value::Nothing
@@ -444,26 +1984,933 @@ fn interpret_expr(expr: &Expr, scope: &mut Scope) -> Value {
                 Value::Nothing
             }
         }
-        Expr::Return { inner } => {
-            let returned_value = interpret_expr(inner, scope);
-            Value::Return(Box::new(returned_value))
+        Expr::MatchType { subject, arms } => {
+            let value_type = interpret_expr(subject, scope, ctx)?.get_type();
+            let arm = arms
+                .iter()
+                .find(|(pattern, _)| pattern == "_" || value_type.is_named(pattern));
+            match arm {
+                Some((_, body)) => {
+                    let mut new_scope = scope.clone();
+                    let mut value = Value::Nothing;
+                    for expr in body {
+                        value = interpret_expr(expr, &mut new_scope, ctx)?;
+                        if value.is_control_flow() {
+                            return Ok(value);
+                        }
+                    }
+                    value
+                }
+                None => Value::Nothing,
+            }
         }
-    }
+        Expr::While {
+            cond,
+            body,
+            else_body,
+        } => {
+            let mut broke = false;
+            while interpret_expr(cond, scope, ctx)? == Value::Bool(true) {
+                match interpret_loop_body(body, scope, ctx)? {
+                    Value::Break => {
+                        broke = true;
+                        break;
+                    }
+                    value @ Value::Return(_) => return Ok(value),
+                    _ => (),
+                }
+            }
+            // Runs in the enclosing scope like the loop body, and a break or
+            // continue in it belongs to whatever loop is around this one
+            match else_body {
+                Some(else_body) if !broke => interpret_loop_body(else_body, scope, ctx)?,
+                _ => Value::Nothing,
+            }
+        }
+        Expr::DoWhile { body, cond } => {
+            loop {
+                match interpret_loop_body(body, scope, ctx)? {
+                    Value::Break => break,
+                    value @ Value::Return(_) => return Ok(value),
+                    _ => (),
+                }
+                if interpret_expr(cond, scope, ctx)? != Value::Bool(true) {
+                    break;
+                }
+            }
+            Value::Nothing
+        }
+        Expr::For {
+            var,
+            iterable,
+            body,
+        } => {
+            let iterable = interpret_expr(iterable, scope, ctx)?;
+            let items = match iterable.items() {
+                Some(items) => items,
+                None => panic!("Cannot loop over a value of type {}", iterable.get_type()),
+            };
+            for item in items {
+                scope.insert(*var, Box::new(item));
+                match interpret_loop_body(body, scope, ctx)? {
+                    Value::Break => break,
+                    value @ Value::Return(_) => return Ok(value),
+                    _ => (),
+                }
+            }
+            Value::Nothing
+        }
+        Expr::Try {
+            body,
+            error_name,
+            catch_body,
+        } => {
+            // Control flow comes back as an Ok value, so only real errors are
+            // caught here and return/break still reach the enclosing code
+            // A tail call in the body is made here, so its errors are caught
+            let mut new_scope = scope.clone();
+            match catch_panic(|| {
+                interpret_block(body, &mut new_scope, ctx)
+                    .and_then(|value| finish_tail_call(value, ctx))
+            }) {
+                Ok(value) => value,
+                Err(err) => {
+                    let mut new_scope = scope.clone();
+                    new_scope.insert(*error_name, Box::new(Value::String(err.to_string())));
+                    interpret_block(catch_body, &mut new_scope, ctx)?
+                }
+            }
+        }
+        Expr::Break => Value::Break,
+        Expr::Continue => Value::Continue,
+        Expr::Return { inner } => Value::Return(Box::new(returned_value(inner, scope, ctx)?)),
+        Expr::Array { items } => {
+            let mut values = Vec::new();
+            for item in items {
+                values.push(interpret_expr(item, scope, ctx)?);
+            }
+            Value::Array(values)
+        }
+        Expr::Map { entries } => {
+            let mut map = IndexMap::new();
+            for (key, value) in entries {
+                match interpret_expr(key, scope, ctx)? {
+                    Value::String(key) => {
+                        map.insert(key, interpret_expr(value, scope, ctx)?);
+                    }
+                    other => panic!("Map keys must be strings, got {}", other.get_type()),
+                }
+            }
+            Value::Map(map)
+        }
+        Expr::Tuple { items } => {
+            let mut values = Vec::new();
+            for item in items {
+                values.push(interpret_expr(item, scope, ctx)?);
+            }
+            Value::Tuple(values)
+        }
+        Expr::Index { target, index } => {
+            let target = interpret_expr(target, scope, ctx)?;
+            let index = interpret_expr(index, scope, ctx)?;
+            index_value(target, index)
+        }
+    })
 }
 
 impl Interpreter {
     pub fn new(exprs: Vec<Expr>) -> Self {
-        let mut toplevel_scope = HashMap::new();
+        let mut toplevel_scope = Scope::new();
         builtins::make_builtins(&mut toplevel_scope);
         Self {
             state: State { toplevel_scope },
             exprs,
+            context: Context::default(),
         }
     }
 
-    pub fn run(&mut self) {
+    // Profiling stays on if it was, but the counts and the bytes written
+    // towards max_output_bytes start again from zero
+    pub fn reset(&mut self) {
+        self.state.toplevel_scope.clear();
+        builtins::make_builtins(&mut self.state.toplevel_scope);
+        if let Some(counts) = self.context.call_counts.as_mut() {
+            counts.clear();
+        }
+        self.context.output_bytes = 0;
+    }
+
+    // Starts counting calls for profile(), from zero
+    pub fn enable_profiling(&mut self) {
+        self.context.call_counts = Some(IndexMap::new());
+    }
+
+    pub fn run(&mut self) -> Result<(), RuntimeError> {
         for expr in &self.exprs {
-            interpret_expr(expr, &mut self.state.toplevel_scope);
+            let value = interpret_expr(expr, &mut self.state.toplevel_scope, &mut self.context)?;
+            finish_tail_call(value, &mut self.context)?;
         }
+        Ok(())
+    }
+
+    // Same as run, but compiles the program to bytecode first
+    pub fn run_bytecode(&mut self) -> Result<(), RuntimeError> {
+        let chunk = bytecode::compile(&self.exprs);
+        Vm::default().run(&chunk, &mut self.state.toplevel_scope, &mut self.context)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        crate::testing::{
+            error, global, interpreter, output, output_at_width, parse, run_bytecode,
+        },
+        std::{cell::RefCell, rc::Rc},
+    };
+
+    #[test]
+    fn reset_clears_variables_but_keeps_builtins() {
+        let (mut interpreter, output) = interpreter(r#"x := 5; max(1, 2); print("abc");"#);
+        interpreter.enable_profiling();
+        interpreter.context.max_output_bytes = Some(4);
+        interpreter.run().unwrap();
+        assert_eq!(interpreter.context.output_bytes, 3);
+        assert!(interpreter
+            .state
+            .toplevel_scope
+            .contains_key(&Symbol::intern("x")));
+        interpreter.reset();
+        assert!(!interpreter
+            .state
+            .toplevel_scope
+            .contains_key(&Symbol::intern("x")));
+        assert_eq!(interpreter.context.call_counts, Some(IndexMap::new()));
+        assert_eq!(interpreter.context.output_bytes, 0);
+        interpreter.exprs = parse(r#"y := max(1, 2); print("abc");"#);
+        interpreter.run().unwrap();
+        assert_eq!(
+            *interpreter.state.toplevel_scope[&Symbol::intern("y")],
+            Value::Int(2)
+        );
+        assert_eq!(interpreter.context.call_counts.unwrap()["max"], 1);
+        assert_eq!(output.text(), "abcabc");
+    }
+
+    #[test]
+    fn logical_operators_bind_looser_than_comparisons() {
+        let source = "x := 1 < 2 && !(2 < 1); y := false || 1 + 1 == 3;";
+        assert_eq!(global(source, "x"), Value::Bool(true));
+        assert_eq!(global(source, "y"), Value::Bool(false));
+    }
+
+    #[test]
+    fn negative_index_counts_back_from_the_end() {
+        let source =
+            r#"arr := [4, 5, 6]; last := arr[-1]; first := arr[-3]; m := {"k": 1}; k := m["k"];"#;
+        assert_eq!(global(source, "last"), Value::Int(6));
+        assert_eq!(global(source, "first"), Value::Int(4));
+        assert_eq!(global(source, "k"), Value::Int(1));
+    }
+
+    #[test]
+    #[should_panic(expected = "Index -4 out of range for array of length 3")]
+    fn negative_index_past_the_start_panics() {
+        global("arr := [1, 2, 3]; x := arr[-4];", "x");
+    }
+
+    #[test]
+    fn tuple_literals_need_a_comma() {
+        let source = r#"pair := (1, "two"); grouped := (1 + 2) * 3;"#;
+        assert_eq!(global(source, "pair").to_string(), "(1, two)");
+        assert_eq!(global(source, "grouped"), Value::Int(9));
+    }
+
+    #[test]
+    fn tuples_destructure_into_variables() {
+        let source = "(a, b) := (1, 2);";
+        assert_eq!(global(source, "a"), Value::Int(1));
+        assert_eq!(global(source, "b"), Value::Int(2));
+    }
+
+    #[test]
+    #[should_panic(expected = "Cannot destructure 3 values into 2 names")]
+    fn destructuring_the_wrong_number_of_values_panics() {
+        global("(a, b) := (1, 2, 3);", "a");
+    }
+
+    #[test]
+    fn match_type_runs_the_arm_for_the_value_type() {
+        let source = r#"a := match_type 5 { Int => "int", String => "string", _ => "other" };
+            b := match_type 5 { string => "string", int => "int" };"#;
+        assert_eq!(global(source, "a"), Value::String("int".to_string()));
+        assert_eq!(global(source, "b"), Value::String("int".to_string()));
+    }
+
+    #[test]
+    fn match_type_wildcard_catches_other_types() {
+        let source = r#"a := match_type true { int => "int", _ => "other" };"#;
+        assert_eq!(global(source, "a"), Value::String("other".to_string()));
+    }
+
+    #[test]
+    fn do_while_runs_its_body_once_when_the_condition_starts_false() {
+        assert_eq!(
+            global("n := 0; do { n := n + 1; } while false;", "n"),
+            Value::Int(1)
+        );
+    }
+
+    #[test]
+    fn do_while_honors_break_and_continue() {
+        let source = "i := 0; seen := 0;
+            do { i := i + 1; if i == 2 { continue; } if i == 4 { break; } seen := seen + i; } while i < 10;";
+        assert_eq!(global(source, "i"), Value::Int(4));
+        assert_eq!(global(source, "seen"), Value::Int(4));
+    }
+
+    #[test]
+    fn while_checks_its_condition_first() {
+        let source = "n := 0; while n < 3 { n := n + 1; } m := 0; while false { m := 1; }";
+        assert_eq!(global(source, "n"), Value::Int(3));
+        assert_eq!(global(source, "m"), Value::Int(0));
+    }
+
+    #[test]
+    fn floats_display_their_value() {
+        assert_eq!(Value::Float(2.5).to_string(), "2.5");
+        assert_eq!(Value::Float(3.0).to_string(), "3");
+    }
+
+    #[test]
+    fn calls_a_function_returned_by_another_function() {
+        let source = "func double(int x) -> int { return x * 2; }
+            func get_fn() -> function { return double; }
+            x := get_fn()(3);";
+        assert_eq!(global(source, "x"), Value::Int(6));
+    }
+
+    #[test]
+    fn calls_a_function_picked_out_of_an_array() {
+        // There's no ternary operator, so indexing stands in for picking a callee
+        let source = "func inc(int x) -> int { return x + 1; }
+            func dec(int x) -> int { return x - 1; }
+            fns := [inc, dec];
+            x := fns[1](10);";
+        assert_eq!(global(source, "x"), Value::Int(9));
+    }
+
+    #[test]
+    #[should_panic(expected = "Cannot call a value of type int")]
+    fn calling_a_non_function_panics() {
+        global("xs := [5]; x := xs[0](1);", "x");
+    }
+
+    #[test]
+    fn builtin_panics_come_back_as_errors() {
+        // Builtins can't be registered from outside, so this uses one that
+        // panics on a bad argument
+        let mut interpreter = Interpreter::new(parse("x := 1; sum(5); y := 2;"));
+        assert_eq!(
+            interpreter.run(),
+            Err(RuntimeError::BuiltinPanicked {
+                name: "sum".to_string(),
+                message: "sum takes an array".to_string()
+            })
+        );
+        assert!(interpreter
+            .state
+            .toplevel_scope
+            .contains_key(&Symbol::intern("x")));
+        assert!(!interpreter
+            .state
+            .toplevel_scope
+            .contains_key(&Symbol::intern("y")));
+        interpreter.exprs = parse("z := 3;");
+        interpreter.run().unwrap();
+        assert_eq!(
+            *interpreter.state.toplevel_scope[&Symbol::intern("z")],
+            Value::Int(3)
+        );
+    }
+
+    #[test]
+    fn builtin_panics_keep_their_message() {
+        assert_eq!(
+            error("sum(5);").to_string(),
+            "Builtin function sum panicked: sum takes an array"
+        );
+    }
+
+    // The callback's own panic is reported, not one blamed on map, filter or
+    // eval for calling it
+    #[test]
+    fn panics_in_user_callbacks_are_not_blamed_on_the_builtin() {
+        let missing = RuntimeError::Panicked {
+            message: "Undefined variable: missing".to_string(),
+        };
+        assert_eq!(
+            error("func f(int x) -> int { return missing; } map([1], f);"),
+            missing
+        );
+        assert_eq!(error("filter([1], fn(x) { missing; });"), missing);
+        assert_eq!(error(r#"eval("missing;");"#), missing);
+        assert_eq!(
+            output(r#"try { map([1], fn(x) { [x][3]; }); } catch e { println(e); }"#),
+            "Index 3 out of range for array of length 1\n"
+        );
+        assert_eq!(
+            error("map([1], fn(x) { sum(x); });").to_string(),
+            "Builtin function sum panicked: sum takes an array"
+        );
+    }
+
+    #[test]
+    fn pipe_passes_the_left_value_as_the_first_argument() {
+        let source = "func add(int a, int b) -> int { return a + b; }
+            func double(int x) -> int { return x * 2; }
+            a := 3 |> double;
+            b := 3 |> add(5);";
+        assert_eq!(global(source, "a"), Value::Int(6));
+        assert_eq!(global(source, "b"), Value::Int(8));
+    }
+
+    #[test]
+    fn pipes_chain_left_to_right() {
+        let source = "func add(int a, int b) -> int { return a + b; }
+            func double(int x) -> int { return x * 2; }
+            a := 1 + 2 |> double |> add(1);";
+        assert_eq!(global(source, "a"), Value::Int(7));
+    }
+
+    #[test]
+    fn state_survives_a_json_round_trip() {
+        let (mut interpreter, _) = interpreter(
+            r#"n := 5; f := 2.0; s := "hi"; b := true; xs := [1, [2]]; m := {"k": (1, "v")};"#,
+        );
+        interpreter.run().unwrap();
+        let json = interpreter.state.to_json().unwrap();
+
+        let restored = State::from_json(&json).unwrap();
+        for name in ["n", "f", "s", "b", "xs", "m"] {
+            assert_eq!(
+                restored.toplevel_scope[&Symbol::intern(name)],
+                interpreter.state.toplevel_scope[&Symbol::intern(name)],
+                "{name}"
+            );
+        }
+        // A float that happens to be whole stays a float
+        assert_eq!(
+            *restored.toplevel_scope[&Symbol::intern("f")],
+            Value::Float(2.0)
+        );
+        // Builtins are registered again rather than saved
+        assert!(restored
+            .toplevel_scope
+            .contains_key(&Symbol::intern("println")));
+    }
+
+    #[test]
+    fn user_functions_cannot_be_saved() {
+        let (mut interpreter, _) = interpreter("func f() -> int { return 1; }");
+        interpreter.run().unwrap();
+        assert!(interpreter.state.to_json().is_err());
+    }
+
+    #[test]
+    fn chained_assignment_sets_every_target() {
+        let source = "a = b = 5; c := d := 6;";
+        for name in ["a", "b"] {
+            assert_eq!(global(source, name), Value::Int(5), "{name}");
+        }
+        for name in ["c", "d"] {
+            assert_eq!(global(source, name), Value::Int(6), "{name}");
+        }
+    }
+
+    #[test]
+    fn assignment_returns_the_assigned_value() {
+        let source = "x := (a := 3) + (b = 4);";
+        assert_eq!(global(source, "x"), Value::Int(7));
+    }
+
+    #[test]
+    fn coalesce_falls_back_only_for_nothing() {
+        let source = "func noop() { x := 1; } a := noop() ?? 5; b := 0 ?? 5;";
+        assert_eq!(global(source, "a"), Value::Int(5));
+        assert_eq!(global(source, "b"), Value::Int(0));
+    }
+
+    #[test]
+    fn coalesce_skips_the_right_side_when_it_isnt_needed() {
+        // Evaluating the right side would fail on the undefined function
+        assert_eq!(global("a := 1 ?? missing();", "a"), Value::Int(1));
+    }
+
+    #[test]
+    fn in_checks_array_membership() {
+        let source = "a := 2 in [1, 2, 3]; b := 4 in [1, 2, 3];";
+        assert_eq!(global(source, "a"), Value::Bool(true));
+        assert_eq!(global(source, "b"), Value::Bool(false));
+    }
+
+    #[test]
+    fn in_checks_for_a_substring() {
+        let source = r#"a := "ell" in "hello"; b := "xyz" in "hello";"#;
+        assert_eq!(global(source, "a"), Value::Bool(true));
+        assert_eq!(global(source, "b"), Value::Bool(false));
+    }
+
+    #[test]
+    fn in_checks_map_keys() {
+        let source = r#"m := {"a": 1}; a := "a" in m; b := "b" in m; index := 1;"#;
+        assert_eq!(global(source, "a"), Value::Bool(true));
+        assert_eq!(global(source, "b"), Value::Bool(false));
+        // Identifiers that start with in are still identifiers
+        assert_eq!(global(source, "index"), Value::Int(1));
+    }
+
+    #[test]
+    fn try_catches_division_by_zero() {
+        let source = "message := try { 1 / 0; } catch e { e; };";
+        assert_eq!(
+            global(source, "message"),
+            Value::String("Division by zero".to_string())
+        );
+    }
+
+    #[test]
+    fn clean_try_evaluates_to_its_body() {
+        assert_eq!(
+            global("x := try { 5; } catch e { 0; };", "x"),
+            Value::Int(5)
+        );
+    }
+
+    #[test]
+    fn try_catches_interpreter_panics() {
+        let source = "a := try { missing; } catch e { 0; };
+            b := try { [1, 2][5]; } catch e { e; };
+            c := try { 1 + true; } catch e { e; };
+            func f() -> int { return [1][3]; }
+            d := try { f(); } catch e { e; };";
+        assert_eq!(global(source, "a"), Value::Int(0));
+        let cases = [
+            ("b", "Index 5 out of range for array of length 2"),
+            ("c", "Invalid type for addition"),
+            ("d", "Index 3 out of range for array of length 1"),
+        ];
+        for (name, expected) in cases {
+            assert_eq!(
+                global(source, name),
+                Value::String(expected.to_string()),
+                "{name}"
+            );
+        }
+    }
+
+    #[test]
+    fn try_lets_return_through() {
+        let source = "func f() -> int { try { return 1; } catch e { return 2; } return 3; }
+            x := f();";
+        assert_eq!(global(source, "x"), Value::Int(1));
+    }
+
+    // Records enter and exit events as the kind of node plus, on exit, the value
+    struct Recorder(Rc<RefCell<Vec<String>>>);
+
+    fn kind(expr: &Expr) -> &'static str {
+        match expr {
+            Expr::BinaryExpr { .. } => "binary",
+            Expr::Token(_) => "token",
+            Expr::FnCall { .. } => "call",
+            _ => "other",
+        }
+    }
+
+    impl Tracer for Recorder {
+        fn enter(&mut self, expr: &Expr) {
+            self.0.borrow_mut().push(format!("enter {}", kind(expr)));
+        }
+
+        fn exit(&mut self, expr: &Expr, value: &Value) {
+            self.0
+                .borrow_mut()
+                .push(format!("exit {} {value}", kind(expr)));
+        }
+    }
+
+    #[test]
+    fn tracer_sees_every_expression_in_order() {
+        let (mut interpreter, _) = interpreter("x := 1 + 2;");
+        let events = Rc::new(RefCell::new(Vec::new()));
+        interpreter.context.tracer = Some(Box::new(Recorder(events.clone())));
+        interpreter.run().unwrap();
+        assert_eq!(
+            *events.borrow(),
+            [
+                "enter binary",
+                "enter binary",
+                "enter token",
+                "exit token 1",
+                "enter token",
+                "exit token 2",
+                "exit binary 3",
+                "exit binary 3",
+            ]
+        );
+    }
+
+    #[test]
+    fn swap_exchanges_two_variables() {
+        assert_eq!(
+            output(
+                r#"x := 1; y := "two"; swap(x, y); println(x); println(y); swap(x, x); println(x);"#
+            ),
+            "two\n1\ntwo\n"
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Undefined variable: zzz")]
+    fn swap_needs_both_variables_to_exist() {
+        output("x := 1; swap(x, zzz);");
+    }
+
+    #[test]
+    fn post_increment_returns_the_old_value() {
+        assert_eq!(
+            output("x := 5; println(x++); println(x); println(x--); println(x);"),
+            "5\n6\n6\n5\n"
+        );
+    }
+
+    #[test]
+    fn ints_are_64_bit_unless_widened() {
+        let source = "x := 3037000500; println(x * x);";
+        assert_eq!(
+            error(source),
+            RuntimeError::IntegerOverflow {
+                name: "*".to_string()
+            }
+        );
+        assert_eq!(output_at_width::<Bignum>(source), "9223372037000250000\n");
+    }
+
+    // The product has to be able to grow past any fixed number of bits
+    #[test]
+    fn repeated_multiplication_overflows_in_64_bits_but_not_as_a_bignum() {
+        let source = "n := 1; i := 1; while i <= 40 { n := n * i; i++; }
+println(n); println(n / 40 / 39 / 38); println(n % 1000003); println(-n < n);";
+        assert_eq!(
+            error(source),
+            RuntimeError::IntegerOverflow {
+                name: "*".to_string()
+            }
+        );
+        assert_eq!(
+            output_at_width::<Bignum>(source),
+            "815915283247897734345611269596115894272000000000\n\
+13763753091226345046315979581580902400000000\n879369\ntrue\n"
+        );
+    }
+
+    #[test]
+    fn bignum_carries_on_past_i128() {
+        assert_eq!(
+            output_at_width::<Bignum>(
+                r#"x := 170141183460469231731687303715884105727; println(x + 1);
+x++; x++; println(x); println(-x); println("x is " + x);
+println(x - 2 == 170141183460469231731687303715884105727); println(x > 1); x--; println(x);"#
+            ),
+            "170141183460469231731687303715884105728\n\
+170141183460469231731687303715884105729\n\
+-170141183460469231731687303715884105729\n\
+x is 170141183460469231731687303715884105729\ntrue\ntrue\n\
+170141183460469231731687303715884105728\n"
+        );
+    }
+
+    #[test]
+    fn post_increment_overflow_is_an_error() {
+        assert_eq!(
+            error("x := 9223372036854775807; x++;"),
+            RuntimeError::IntegerOverflow {
+                name: "++".to_string()
+            }
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Cannot use ++ on string")]
+    fn post_increment_needs_an_int() {
+        output(r#"x := "a"; x++;"#);
+    }
+
+    #[test]
+    fn defined_checks_whether_a_name_is_bound() {
+        assert_eq!(
+            output(
+                r#"x := 1; println(defined(x)); println(defined(nope));
+                name := "x"; println(defined(name)); println(defined("nope"));"#
+            ),
+            "true\nfalse\ntrue\nfalse\n"
+        );
+    }
+
+    #[test]
+    fn delete_unbinds_a_variable() {
+        assert_eq!(
+            output(
+                r#"x := 1; delete(x); println(defined(x)); delete(never_set); delete("also_never");"#
+            ),
+            "false\n"
+        );
+    }
+
+    #[test]
+    fn delete_removes_a_map_key() {
+        assert_eq!(
+            output(r#"m := {"a": 1, "b": 2}; delete(m, "a"); delete(m, "zzz"); println(m);"#),
+            "{b: 2}\n"
+        );
+    }
+
+    #[test]
+    fn wrapping32_wraps_at_32_bits() {
+        assert_eq!(
+            output_at_width::<Wrapping32>("println(2147483647 + 1); println(-2147483648 - 1);"),
+            "-2147483648\n2147483647\n"
+        );
+    }
+
+    // Literals are wrapped as they are read, so the arithmetic only ever sees
+    // ints that fit. One that somehow doesn't is an overflow, not truncated
+    #[test]
+    fn wrapping32_wraps_literals_but_not_operands() {
+        assert_eq!(
+            output_at_width::<Wrapping32>("println(4294967297 + 1); println(2147483648);"),
+            "2\n-2147483648\n"
+        );
+        assert_eq!(Wrapping32.add(1 << 32, 1), None);
+        assert_eq!(Wrapping32.mul(2, -(1 << 40)), None);
+    }
+
+    #[test]
+    fn closures_keep_their_own_state() {
+        assert_eq!(
+            output(
+                "func make_counter() -> function { n := 0; return fn() { n = n + 1; n }; }
+a := make_counter(); b := make_counter(); c := a;
+println(a()); println(a()); println(b()); println(c());"
+            ),
+            "1\n2\n1\n3\n"
+        );
+    }
+
+    #[test]
+    fn closures_capture_by_value() {
+        assert_eq!(
+            output("k := 1; f := fn() { k }; k = 2; println(f()); println(k);"),
+            "1\n2\n"
+        );
+    }
+
+    #[test]
+    fn percent_is_remainder_between_numbers() {
+        assert_eq!(
+            output("println(7 % 3); println(-7 % 3); println(7.5 % 2.0);"),
+            "1\n-1\n1.5\n"
+        );
+        assert_eq!(error("println(7 % 0);"), RuntimeError::DivisionByZero);
+    }
+
+    #[test]
+    fn percent_formats_a_string_on_the_left() {
+        assert_eq!(
+            output(
+                r#"println("%d items" % 3); println("%s has %d (%.2f%%)" % ["ada", 5, 12.345]); println("[%5d|%-5s|%05d|%x|%X|%.2s]" % [42, "ab", -42, 255, 255, "hello"]);"#
+            ),
+            "3 items\nada has 5 (12.35%)\n[   42|ab   |-0042|ff|FF|he]\n"
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Too many values for format string")]
+    fn format_values_must_all_be_used() {
+        output(r#"println("%d" % [1, 2]);"#);
+    }
+
+    #[test]
+    #[should_panic(expected = "%d needs a number, got string")]
+    fn format_directives_check_their_value() {
+        output(r#"println("%d" % "a");"#);
+    }
+
+    #[test]
+    fn globals_lists_defined_names_in_order() {
+        assert_eq!(
+            output(
+                "b := 1; a := 2; println(to_json(globals())); c := 3; swap(a, b); delete(b); println(to_json(globals()));"
+            ),
+            "[\"b\",\"a\"]\n[\"a\",\"c\"]\n"
+        );
+    }
+
+    #[test]
+    fn globals_inside_a_function_includes_its_locals() {
+        assert_eq!(
+            output(
+                "x := 1; func f() -> array { local := 1; return globals(); } println(to_json(f()));"
+            ),
+            "[\"x\",\"f\",\"local\"]\n"
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "globals takes no arguments")]
+    fn globals_takes_no_arguments() {
+        output("globals(1);");
+    }
+
+    #[test]
+    fn dump_state_lists_each_global_with_its_type() {
+        let dump = output(
+            r#"n := 1; s := "hi"; xs := [1, 2.5]; func f(int x) -> int { return x; } print(dump_state());"#,
+        );
+        assert_eq!(
+            dump.lines().collect::<Vec<_>>(),
+            [
+                "n: int = 1",
+                "s: string = hi",
+                "xs: array = [1, 2.5]",
+                "f: function = f"
+            ]
+        );
+        assert_eq!(output("print(dump_state());"), "");
+    }
+
+    #[test]
+    fn names_checked_at_runtime_are_not_interned() {
+        let name = "interpreter_test_never_bound";
+        assert_eq!(
+            output(&format!(
+                r#"println(defined("{name}")); delete("{name}"); println(defined("{name}"));"#
+            )),
+            "false\nfalse\n"
+        );
+        assert_eq!(Symbol::get(name), None);
+    }
+
+    // Far deeper than either engine could go if each call took a host frame
+    #[test]
+    fn tail_calls_run_in_constant_stack() {
+        assert_eq!(
+            output(
+                "func countdown(int n) -> int { if n == 0 { return 0; } return countdown(n - 1); }
+println(countdown(10000));"
+            ),
+            "0\n"
+        );
+    }
+
+    #[test]
+    fn mutual_tail_calls_run_in_constant_stack() {
+        assert_eq!(
+            output(
+                "func is_even(int n) -> bool { if n == 0 { return true; } return is_odd(n - 1); }
+func is_odd(int n) -> bool { if n == 0 { return false; } return is_even(n - 1); }
+println(is_even(10001));"
+            ),
+            "false\n"
+        );
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "Invalid value returned from function outer. Expected int, got string"
+    )]
+    fn every_function_in_a_tail_call_chain_checks_its_return_type() {
+        output(
+            r#"func inner() -> string { return "a"; } func outer() -> int { return inner(); } outer();"#,
+        );
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "Invalid value returned from function outer. Expected int, got string"
+    )]
+    fn the_vm_checks_return_types_across_tail_calls() {
+        let _ = run_bytecode(
+            r#"func inner() -> string { return "a"; } func outer() -> int { return inner(); } outer();"#,
+        );
+    }
+
+    #[test]
+    fn tail_calls_inside_try_are_still_caught() {
+        assert_eq!(
+            output(
+                r#"func fail() -> int { error("boom"); return 1; }
+func f() -> int { try { return fail(); } catch e { println(e); } return 2; }
+println(f());"#
+            ),
+            "Error: boom\n2\n"
+        );
+    }
+
+    #[test]
+    fn while_else_runs_when_the_loop_ends_normally() {
+        assert_eq!(
+            output(
+                r#"i := 0; while i < 3 { i++; } else { println("done"); }
+while false { println("never"); } else { println("not entered"); }"#
+            ),
+            "done\nnot entered\n"
+        );
+    }
+
+    #[test]
+    fn while_else_is_skipped_after_a_break() {
+        assert_eq!(
+            output(
+                r#"xs := [4, 7, 9]; i := 0;
+while i < 3 { if xs[i] == 7 { println("found"); break; } i++; } else { println("missing"); }
+j := 0;
+while j < 2 { j++; k := 0; while k < 1 { k++; } else { continue; } println("unreached"); }
+println(j);"#
+            ),
+            "found\n2\n"
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Undefined variable: cuont, did you mean `count`?")]
+    fn undefined_variables_suggest_a_close_name() {
+        output("count := 1; println(cuont);");
+    }
+
+    #[test]
+    #[should_panic(expected = "Undefined variable: cuont, did you mean `count`?")]
+    fn the_vm_suggests_a_close_name_too() {
+        let _ = run_bytecode("count := 1; println(cuont);");
+    }
+
+    #[test]
+    fn suggestions_prefer_close_user_defined_names() {
+        let (interpreter, _) = interpreter("");
+        let mut scope = interpreter.state.toplevel_scope;
+        scope.insert(Symbol::intern("area"), Box::new(Value::Int(1)));
+        let undefined = |name| undefined("function", Symbol::intern(name), &scope);
+        assert_eq!(
+            undefined("pritnln"),
+            "Undefined function: pritnln, did you mean `println`?"
+        );
+        assert_eq!(
+            undefined("arae"),
+            "Undefined function: arae, did you mean `area`?"
+        );
+        assert_eq!(undefined("xyzzy"), "Undefined function: xyzzy");
+        assert_eq!(undefined("x"), "Undefined function: x");
     }
 }
@@ -1,15 +1,28 @@
-use {logos::Logos, std::fmt::Display, substring::Substring};
+use {
+    crate::symbol::Symbol,
+    logos::{Filter, Lexer, Logos, Span},
+    std::fmt::Display,
+};
+
+#[derive(Debug, Default)]
+pub struct LexerOptions {
+    pub keep_comments: bool,
+}
 
 #[derive(Logos, Debug, PartialEq, Clone)]
+#[logos(extras = LexerOptions)]
 pub enum Token {
+    // A plain = is the same assignment as :=, and since the right side of an
+    // assignment is parsed as a whole expression, a = b = 5 assigns right to left
     #[token(":=")]
+    #[token("=")]
     SetVal,
 
-    #[regex(r#""([^"\\]|\\t|\\u|\\n|\\")*""#, |lex| lex.slice().parse::<String>().unwrap().substring(1, lex.slice().len() - 1).parse())]
+    #[regex(r#""([^"\\]|\\.)*""#, unescape)]
     String(String),
 
-    #[regex("[a-zA-AZ_]+", |lex| lex.slice().parse())]
-    Identifier(String),
+    #[regex(r"[\p{XID_Start}_]\p{XID_Continue}*", |lex| Symbol::intern(lex.slice()))]
+    Identifier(Symbol),
 
     #[regex("[0-9]+", |lex| lex.slice().parse())]
     Int(i128),
@@ -26,6 +39,27 @@ pub enum Token {
     #[token("else")]
     Else,
 
+    #[token("while")]
+    While,
+
+    #[token("do")]
+    Do,
+
+    #[token("for")]
+    For,
+
+    #[token("break")]
+    Break,
+
+    #[token("continue")]
+    Continue,
+
+    #[token("try")]
+    Try,
+
+    #[token("catch")]
+    Catch,
+
     #[token("(")]
     LParen,
 
@@ -50,21 +84,47 @@ pub enum Token {
     #[token(",")]
     Comma,
 
+    #[token(":")]
+    Colon,
+
     #[token("func")]
     Func,
 
+    #[token("fn")]
+    Fn,
+
+    #[token("match_type")]
+    MatchType,
+
+    #[token("=>")]
+    FatArrow,
+
     #[token("->")]
     ReturnType,
 
     #[token("return")]
     Return,
 
-    #[regex("bool|int|string|float", |lex| lex.slice().parse())]
+    #[regex("bool|int|string|float|array|function", |lex| lex.slice().parse())]
     Type(String),
 
-    #[regex(r"<|>|<=|>=|!=|==|[=+\-*/]", |lex| lex.slice().parse())]
+    #[regex(r"<|>|<=|>=|!=|==|&&|\|\||\|>|\?\?|[+\-*/%]", |lex| lex.slice().parse())]
+    // A keyword operator, which as a token wins over the identifier regex
+    #[token("in", |lex| lex.slice().parse())]
     Operator(String),
 
+    #[token("!")]
+    Not,
+
+    #[token("++")]
+    Increment,
+
+    #[token("--")]
+    Decrement,
+
+    #[regex(r"//[^\n]*", comment)]
+    Comment(String),
+
     #[error]
     #[regex(r"[ \n\t\f]+", logos::skip)]
     Error,
@@ -81,6 +141,13 @@ impl Display for Token {
             Token::Bool(b) => write!(f, "{b}"),
             Token::If => write!(f, "if"),
             Token::Else => write!(f, "else"),
+            Token::While => write!(f, "while"),
+            Token::Do => write!(f, "do"),
+            Token::For => write!(f, "for"),
+            Token::Break => write!(f, "break"),
+            Token::Continue => write!(f, "continue"),
+            Token::Try => write!(f, "try"),
+            Token::Catch => write!(f, "catch"),
             Token::LParen => write!(f, "("),
             Token::RParen => write!(f, ")"),
             Token::LBrace => write!(f, "{{"),
@@ -89,12 +156,198 @@ impl Display for Token {
             Token::RBracket => write!(f, "]"),
             Token::Semicolon => write!(f, ";"),
             Token::Comma => write!(f, ","),
+            Token::Colon => write!(f, ":"),
             Token::Func => write!(f, "func"),
+            Token::Fn => write!(f, "fn"),
+            Token::MatchType => write!(f, "match_type"),
+            Token::FatArrow => write!(f, "=>"),
             Token::ReturnType => write!(f, "->"),
             Token::Return => write!(f, "return"),
             Token::Type(s) => write!(f, "{s}"),
             Token::Operator(s) => write!(f, "{s}"),
+            Token::Not => write!(f, "!"),
+            Token::Increment => write!(f, "++"),
+            Token::Decrement => write!(f, "--"),
+            Token::Comment(s) => write!(f, "//{s}"),
             Token::Error => write!(f, "Error"),
         }
     }
 }
+
+// Comments are skipped unless keep_comments is set, so the parser never
+// has to deal with them
+pub fn lex(source: &str, keep_comments: bool) -> Lexer<'_, Token> {
+    Token::lexer_with_extras(source, LexerOptions { keep_comments })
+}
+
+fn comment(lex: &mut Lexer<Token>) -> Filter<String> {
+    if lex.extras.keep_comments {
+        Filter::Emit(lex.slice()[2..].to_string())
+    } else {
+        Filter::Skip
+    }
+}
+
+#[derive(Debug)]
+pub struct LexError {
+    pub message: String,
+    pub span: Span,
+}
+
+impl Display for LexError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{} at {:?}", self.message, self.span)
+    }
+}
+
+// Logos only hands back Token::Error, so this goes over the source again to
+// find where the first bad token is
+pub fn check(source: &str) -> Result<(), LexError> {
+    for (token, span) in Token::lexer(source).spanned() {
+        if token == Token::Error {
+            let message = if source[span.clone()].starts_with('"') {
+                "Invalid escape in string literal"
+            } else {
+                "Unexpected character"
+            };
+            return Err(LexError {
+                message: message.to_string(),
+                span,
+            });
+        }
+    }
+    Ok(())
+}
+
+fn unescape(lex: &mut Lexer<Token>) -> Option<String> {
+    let slice = lex.slice();
+    let mut chars = slice[1..slice.len() - 1].chars();
+    let mut result = String::new();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            result.push(c);
+            continue;
+        }
+        let escaped = match chars.next()? {
+            'n' => '\n',
+            't' => '\t',
+            'r' => '\r',
+            '0' => '\0',
+            '"' => '"',
+            '\\' => '\\',
+            // Like Rust, \x only covers ASCII so it can't be mistaken for a raw byte
+            'x' => {
+                let digits: String = chars.by_ref().take(2).collect();
+                match u8::from_str_radix(&digits, 16) {
+                    Ok(byte) if digits.len() == 2 && byte <= 0x7f => byte as char,
+                    _ => return None,
+                }
+            }
+            'u' => {
+                if chars.next()? != '{' {
+                    return None;
+                }
+                let mut digits = String::new();
+                loop {
+                    match chars.next()? {
+                        '}' => break,
+                        c => digits.push(c),
+                    }
+                }
+                if digits.is_empty() || digits.len() > 6 {
+                    return None;
+                }
+                char::from_u32(u32::from_str_radix(&digits, 16).ok()?)?
+            }
+            _ => return None,
+        };
+        result.push(escaped);
+    }
+    Some(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn lex(source: &str) -> Vec<Token> {
+        Token::lexer(source).collect()
+    }
+
+    fn ident(name: &str) -> Token {
+        Token::Identifier(Symbol::intern(name))
+    }
+
+    #[test]
+    fn identifiers_take_underscores_digits_and_unicode_letters() {
+        assert_eq!(lex("my_var2"), [ident("my_var2")]);
+        assert_eq!(lex("_tmp"), [ident("_tmp")]);
+        assert_eq!(lex("Count"), [ident("Count")]);
+        assert_eq!(lex("café"), [ident("café")]);
+        assert_eq!(lex("2cool"), [Token::Int(2), ident("cool")]);
+    }
+
+    #[test]
+    fn keywords_are_not_identifiers() {
+        assert_eq!(lex("if"), [Token::If]);
+        assert_eq!(lex("while"), [Token::While]);
+        assert_eq!(lex("return"), [Token::Return]);
+        assert_eq!(lex("iffy returned"), [ident("iffy"), ident("returned")]);
+    }
+
+    #[test]
+    fn plain_equals_lexes_as_assignment() {
+        assert_eq!(lex("a = 1"), [ident("a"), Token::SetVal, Token::Int(1)]);
+        assert_eq!(
+            lex("a == 1"),
+            [ident("a"), Token::Operator("==".to_string()), Token::Int(1)]
+        );
+    }
+
+    fn string(text: &str) -> Vec<Token> {
+        vec![Token::String(text.to_string())]
+    }
+
+    #[test]
+    fn hex_and_unicode_escapes() {
+        assert_eq!(lex(r#""\x41""#), string("A"));
+        assert_eq!(lex(r#""\u{1F600}""#), string("😀"));
+        assert_eq!(lex(r#""a\tb\n\"\\""#), string("a\tb\n\"\\"));
+    }
+
+    #[test]
+    fn bad_escapes_are_errors_with_a_position() {
+        for bad in [r#""\u{D800}""#, r#""\u{110000}""#, r#""\xZZ""#, r#""\q""#] {
+            let source = format!("x := {bad};");
+            let err = check(&source).unwrap_err();
+            assert_eq!(err.span, 5..5 + bad.len(), "for {bad}");
+        }
+        assert!(check(r#"x := "\x41";"#).is_ok());
+    }
+
+    #[test]
+    fn comments_are_tokens_only_when_kept() {
+        let source = "x := 1; // the answer\ny";
+        assert_eq!(
+            super::lex(source, true).collect::<Vec<_>>(),
+            [
+                ident("x"),
+                Token::SetVal,
+                Token::Int(1),
+                Token::Semicolon,
+                Token::Comment(" the answer".to_string()),
+                ident("y"),
+            ]
+        );
+        assert_eq!(
+            super::lex(source, false).collect::<Vec<_>>(),
+            [
+                ident("x"),
+                Token::SetVal,
+                Token::Int(1),
+                Token::Semicolon,
+                ident("y")
+            ]
+        );
+    }
+}
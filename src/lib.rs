@@ -0,0 +1,15 @@
+pub mod bigint;
+mod builtins;
+pub mod bytecode;
+pub mod interpreter;
+pub mod lexer;
+mod normalize;
+pub mod optimize;
+pub mod parser;
+mod regex;
+pub mod symbol;
+#[cfg(test)]
+mod testing;
+pub mod typecheck;
+pub mod visitor;
+mod width;
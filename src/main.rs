@@ -1,15 +1,13 @@
-use parser::ParseError;
-
-mod builtins;
-mod interpreter;
-mod lexer;
-mod parser;
-
 use {
     clap::Parser,
-    interpreter::Interpreter,
-    lexer::Token,
     logos::Logos,
+    pulsar::{
+        interpreter::{Bignum, Checked64, IntArithmetic, Interpreter, Wrapping32},
+        lexer::{self, Token},
+        optimize::fold_constants_with,
+        parser::ParseError,
+        typecheck,
+    },
     std::{fs::File, io::Read},
 };
 
@@ -18,11 +16,28 @@ use {
 struct Args {
     #[clap(short, long, value_parser)]
     file: String,
+
+    #[clap(long)]
+    fold_constants: bool,
+
+    #[clap(long)]
+    strict: bool,
+
+    // 32 wraps, 64 reports overflow, and big (or 128, which it covers) is
+    // arbitrary precision
+    #[clap(long, default_value = "64")]
+    int_width: String,
+
+    #[clap(long)]
+    bytecode: bool,
+
+    #[clap(long)]
+    profile: bool,
 }
 
-fn read_file() -> String {
+fn read_file(path: &str) -> String {
     let mut contents = String::new();
-    File::open(Args::parse().file)
+    File::open(path)
         .unwrap()
         .read_to_string(&mut contents)
         .unwrap();
@@ -30,15 +45,58 @@ fn read_file() -> String {
 }
 
 fn main() -> Result<(), ParseError> {
-    use parser::Parser;
-    let file_contents = read_file();
+    use pulsar::parser::Parser;
+    let args = Args::parse();
+    let file_contents = read_file(&args.file);
+    if let Err(err) = lexer::check(&file_contents) {
+        println!("{err}");
+        std::process::exit(1);
+    }
     let mut tokens = Token::lexer(&file_contents).peekable();
-    let ast = Parser::new(&mut tokens).parse();
-    if ast.is_err() {
-        println!("{:?}", ast.unwrap_err());
+    let ast = match Parser::new(&mut tokens).parse() {
+        Ok(ast) => ast,
+        Err(err) => {
+            println!("{err}");
+            std::process::exit(1);
+        }
+    };
+    if let Err(err) = typecheck::check(
+        &ast,
+        typecheck::Options {
+            strict: args.strict,
+        },
+    ) {
+        println!("{err}");
+        std::process::exit(1);
+    }
+    let integers: Box<dyn IntArithmetic> = match args.int_width.as_str() {
+        "32" => Box::new(Wrapping32),
+        "64" => Box::new(Checked64),
+        "128" | "big" => Box::new(Bignum),
+        width => {
+            println!("Unsupported int width: {width}, expected 32, 64, 128 or big");
+            std::process::exit(1);
+        }
+    };
+    let ast = if args.fold_constants {
+        fold_constants_with(ast, &*integers)
+    } else {
+        ast
+    };
+    let mut interpreter = Interpreter::new(ast);
+    interpreter.context.capabilities.env = true;
+    interpreter.context.integers = integers;
+    if args.profile {
+        interpreter.enable_profiling();
+    }
+    let result = if args.bytecode {
+        interpreter.run_bytecode()
+    } else {
+        interpreter.run()
+    };
+    if let Err(err) = result {
+        println!("{err}");
         std::process::exit(1);
     }
-    let mut interpreter = Interpreter::new(ast.unwrap());
-    interpreter.run();
     Ok(())
 }
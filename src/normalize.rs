@@ -0,0 +1,2134 @@
+// Unicode normalization, following UAX #15. Strings are fully decomposed,
+// combining marks are put in canonical order, and for the composed forms
+// the result is composed back together pair by pair
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum Form {
+    Nfc,
+    Nfd,
+    Nfkc,
+    Nfkd,
+}
+
+impl Form {
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "NFC" => Some(Self::Nfc),
+            "NFD" => Some(Self::Nfd),
+            "NFKC" => Some(Self::Nfkc),
+            "NFKD" => Some(Self::Nfkd),
+            _ => None,
+        }
+    }
+}
+
+pub fn normalize(s: &str, form: Form) -> String {
+    let chars = decompose(s, matches!(form, Form::Nfkc | Form::Nfkd));
+    match form {
+        Form::Nfc | Form::Nfkc => compose(&chars),
+        Form::Nfd | Form::Nfkd => chars.into_iter().collect(),
+    }
+}
+
+// Hangul syllables are laid out so they can be split into jamo and put back
+// together with arithmetic instead of a table
+const S_BASE: u32 = 0xac00;
+const L_BASE: u32 = 0x1100;
+const V_BASE: u32 = 0x1161;
+const T_BASE: u32 = 0x11a7;
+const L_COUNT: u32 = 19;
+const V_COUNT: u32 = 21;
+const T_COUNT: u32 = 28;
+const S_COUNT: u32 = L_COUNT * V_COUNT * T_COUNT;
+
+fn decompose(s: &str, compatibility: bool) -> Vec<char> {
+    let mut chars = Vec::with_capacity(s.len());
+    for c in s.chars() {
+        let code = c as u32;
+        if (S_BASE..S_BASE + S_COUNT).contains(&code) {
+            let index = code - S_BASE;
+            chars.push(to_char(L_BASE + index / (V_COUNT * T_COUNT)));
+            chars.push(to_char(V_BASE + index % (V_COUNT * T_COUNT) / T_COUNT));
+            if !index.is_multiple_of(T_COUNT) {
+                chars.push(to_char(T_BASE + index % T_COUNT));
+            }
+            continue;
+        }
+        let decomposition = compatibility
+            .then(|| lookup(COMPATIBILITY, code))
+            .flatten()
+            .or_else(|| lookup(CANONICAL, code));
+        match decomposition {
+            Some(decomposition) => chars.extend(decomposition.chars()),
+            None => chars.push(c),
+        }
+    }
+    // A stable sort, so marks of the same class keep the order they came in
+    let mut start = 0;
+    while start < chars.len() {
+        let run = chars[start..]
+            .iter()
+            .take_while(|&&c| combining_class(c) != 0)
+            .count();
+        if run == 0 {
+            start += 1;
+            continue;
+        }
+        chars[start..start + run].sort_by_key(|&c| combining_class(c));
+        start += run;
+    }
+    chars
+}
+
+// Each char joins the last starter unless something between them blocks
+// it, which is a starter or a mark of the same or a higher class
+fn compose(chars: &[char]) -> String {
+    let mut result: Vec<char> = Vec::with_capacity(chars.len());
+    let mut starter = None;
+    let mut last_class = 0;
+    for &c in chars {
+        let class = combining_class(c);
+        if let Some(starter) = starter {
+            let blocked = result.len() > starter + 1 && (last_class == 0 || last_class >= class);
+            if !blocked {
+                if let Some(composed) = compose_pair(result[starter], c) {
+                    result[starter] = composed;
+                    continue;
+                }
+            }
+        }
+        if class == 0 {
+            starter = Some(result.len());
+        }
+        last_class = class;
+        result.push(c);
+    }
+    result.into_iter().collect()
+}
+
+fn compose_pair(a: char, b: char) -> Option<char> {
+    let (a, b) = (a as u32, b as u32);
+    if (L_BASE..L_BASE + L_COUNT).contains(&a) && (V_BASE..V_BASE + V_COUNT).contains(&b) {
+        return Some(to_char(
+            S_BASE + ((a - L_BASE) * V_COUNT + (b - V_BASE)) * T_COUNT,
+        ));
+    }
+    if (S_BASE..S_BASE + S_COUNT).contains(&a)
+        && (a - S_BASE).is_multiple_of(T_COUNT)
+        && (T_BASE + 1..T_BASE + T_COUNT).contains(&b)
+    {
+        return Some(to_char(a + b - T_BASE));
+    }
+    COMPOSITIONS
+        .binary_search_by_key(&(a, b), |&(first, second, _)| (first, second))
+        .ok()
+        .map(|index| to_char(COMPOSITIONS[index].2))
+}
+
+fn combining_class(c: char) -> u8 {
+    let code = c as u32;
+    COMBINING_CLASSES
+        .binary_search_by(|&(start, end, _)| {
+            if end < code {
+                std::cmp::Ordering::Less
+            } else if start > code {
+                std::cmp::Ordering::Greater
+            } else {
+                std::cmp::Ordering::Equal
+            }
+        })
+        .map_or(0, |index| COMBINING_CLASSES[index].2)
+}
+
+fn lookup(table: &[(u32, &'static str)], code: u32) -> Option<&'static str> {
+    table
+        .binary_search_by_key(&code, |&(c, _)| c)
+        .ok()
+        .map(|index| table[index].1)
+}
+
+// Only ever called on code points from the tables or the Hangul ranges
+fn to_char(code: u32) -> char {
+    char::from_u32(code).unwrap()
+}
+
+// Generated from the Unicode 14.0.0 character database. Hangul syllables are
+// left out, they are decomposed and composed arithmetically
+
+// Each char with its full canonical decomposition
+#[rustfmt::skip]
+const CANONICAL: &[(u32, &str)] = &[
+    (0xc0, "A\u{300}"), (0xc1, "A\u{301}"), (0xc2, "A\u{302}"), (0xc3, "A\u{303}"),
+    (0xc4, "A\u{308}"), (0xc5, "A\u{30a}"), (0xc7, "C\u{327}"), (0xc8, "E\u{300}"),
+    (0xc9, "E\u{301}"), (0xca, "E\u{302}"), (0xcb, "E\u{308}"), (0xcc, "I\u{300}"),
+    (0xcd, "I\u{301}"), (0xce, "I\u{302}"), (0xcf, "I\u{308}"), (0xd1, "N\u{303}"),
+    (0xd2, "O\u{300}"), (0xd3, "O\u{301}"), (0xd4, "O\u{302}"), (0xd5, "O\u{303}"),
+    (0xd6, "O\u{308}"), (0xd9, "U\u{300}"), (0xda, "U\u{301}"), (0xdb, "U\u{302}"),
+    (0xdc, "U\u{308}"), (0xdd, "Y\u{301}"), (0xe0, "a\u{300}"), (0xe1, "a\u{301}"),
+    (0xe2, "a\u{302}"), (0xe3, "a\u{303}"), (0xe4, "a\u{308}"), (0xe5, "a\u{30a}"),
+    (0xe7, "c\u{327}"), (0xe8, "e\u{300}"), (0xe9, "e\u{301}"), (0xea, "e\u{302}"),
+    (0xeb, "e\u{308}"), (0xec, "i\u{300}"), (0xed, "i\u{301}"), (0xee, "i\u{302}"),
+    (0xef, "i\u{308}"), (0xf1, "n\u{303}"), (0xf2, "o\u{300}"), (0xf3, "o\u{301}"),
+    (0xf4, "o\u{302}"), (0xf5, "o\u{303}"), (0xf6, "o\u{308}"), (0xf9, "u\u{300}"),
+    (0xfa, "u\u{301}"), (0xfb, "u\u{302}"), (0xfc, "u\u{308}"), (0xfd, "y\u{301}"),
+    (0xff, "y\u{308}"), (0x100, "A\u{304}"), (0x101, "a\u{304}"), (0x102, "A\u{306}"),
+    (0x103, "a\u{306}"), (0x104, "A\u{328}"), (0x105, "a\u{328}"), (0x106, "C\u{301}"),
+    (0x107, "c\u{301}"), (0x108, "C\u{302}"), (0x109, "c\u{302}"), (0x10a, "C\u{307}"),
+    (0x10b, "c\u{307}"), (0x10c, "C\u{30c}"), (0x10d, "c\u{30c}"), (0x10e, "D\u{30c}"),
+    (0x10f, "d\u{30c}"), (0x112, "E\u{304}"), (0x113, "e\u{304}"), (0x114, "E\u{306}"),
+    (0x115, "e\u{306}"), (0x116, "E\u{307}"), (0x117, "e\u{307}"), (0x118, "E\u{328}"),
+    (0x119, "e\u{328}"), (0x11a, "E\u{30c}"), (0x11b, "e\u{30c}"), (0x11c, "G\u{302}"),
+    (0x11d, "g\u{302}"), (0x11e, "G\u{306}"), (0x11f, "g\u{306}"), (0x120, "G\u{307}"),
+    (0x121, "g\u{307}"), (0x122, "G\u{327}"), (0x123, "g\u{327}"), (0x124, "H\u{302}"),
+    (0x125, "h\u{302}"), (0x128, "I\u{303}"), (0x129, "i\u{303}"), (0x12a, "I\u{304}"),
+    (0x12b, "i\u{304}"), (0x12c, "I\u{306}"), (0x12d, "i\u{306}"), (0x12e, "I\u{328}"),
+    (0x12f, "i\u{328}"), (0x130, "I\u{307}"), (0x134, "J\u{302}"), (0x135, "j\u{302}"),
+    (0x136, "K\u{327}"), (0x137, "k\u{327}"), (0x139, "L\u{301}"), (0x13a, "l\u{301}"),
+    (0x13b, "L\u{327}"), (0x13c, "l\u{327}"), (0x13d, "L\u{30c}"), (0x13e, "l\u{30c}"),
+    (0x143, "N\u{301}"), (0x144, "n\u{301}"), (0x145, "N\u{327}"), (0x146, "n\u{327}"),
+    (0x147, "N\u{30c}"), (0x148, "n\u{30c}"), (0x14c, "O\u{304}"), (0x14d, "o\u{304}"),
+    (0x14e, "O\u{306}"), (0x14f, "o\u{306}"), (0x150, "O\u{30b}"), (0x151, "o\u{30b}"),
+    (0x154, "R\u{301}"), (0x155, "r\u{301}"), (0x156, "R\u{327}"), (0x157, "r\u{327}"),
+    (0x158, "R\u{30c}"), (0x159, "r\u{30c}"), (0x15a, "S\u{301}"), (0x15b, "s\u{301}"),
+    (0x15c, "S\u{302}"), (0x15d, "s\u{302}"), (0x15e, "S\u{327}"), (0x15f, "s\u{327}"),
+    (0x160, "S\u{30c}"), (0x161, "s\u{30c}"), (0x162, "T\u{327}"), (0x163, "t\u{327}"),
+    (0x164, "T\u{30c}"), (0x165, "t\u{30c}"), (0x168, "U\u{303}"), (0x169, "u\u{303}"),
+    (0x16a, "U\u{304}"), (0x16b, "u\u{304}"), (0x16c, "U\u{306}"), (0x16d, "u\u{306}"),
+    (0x16e, "U\u{30a}"), (0x16f, "u\u{30a}"), (0x170, "U\u{30b}"), (0x171, "u\u{30b}"),
+    (0x172, "U\u{328}"), (0x173, "u\u{328}"), (0x174, "W\u{302}"), (0x175, "w\u{302}"),
+    (0x176, "Y\u{302}"), (0x177, "y\u{302}"), (0x178, "Y\u{308}"), (0x179, "Z\u{301}"),
+    (0x17a, "z\u{301}"), (0x17b, "Z\u{307}"), (0x17c, "z\u{307}"), (0x17d, "Z\u{30c}"),
+    (0x17e, "z\u{30c}"), (0x1a0, "O\u{31b}"), (0x1a1, "o\u{31b}"), (0x1af, "U\u{31b}"),
+    (0x1b0, "u\u{31b}"), (0x1cd, "A\u{30c}"), (0x1ce, "a\u{30c}"), (0x1cf, "I\u{30c}"),
+    (0x1d0, "i\u{30c}"), (0x1d1, "O\u{30c}"), (0x1d2, "o\u{30c}"), (0x1d3, "U\u{30c}"),
+    (0x1d4, "u\u{30c}"), (0x1d5, "U\u{308}\u{304}"), (0x1d6, "u\u{308}\u{304}"),
+    (0x1d7, "U\u{308}\u{301}"), (0x1d8, "u\u{308}\u{301}"), (0x1d9, "U\u{308}\u{30c}"),
+    (0x1da, "u\u{308}\u{30c}"), (0x1db, "U\u{308}\u{300}"), (0x1dc, "u\u{308}\u{300}"),
+    (0x1de, "A\u{308}\u{304}"), (0x1df, "a\u{308}\u{304}"), (0x1e0, "A\u{307}\u{304}"),
+    (0x1e1, "a\u{307}\u{304}"), (0x1e2, "\u{c6}\u{304}"), (0x1e3, "\u{e6}\u{304}"),
+    (0x1e6, "G\u{30c}"), (0x1e7, "g\u{30c}"), (0x1e8, "K\u{30c}"), (0x1e9, "k\u{30c}"),
+    (0x1ea, "O\u{328}"), (0x1eb, "o\u{328}"), (0x1ec, "O\u{328}\u{304}"),
+    (0x1ed, "o\u{328}\u{304}"), (0x1ee, "\u{1b7}\u{30c}"), (0x1ef, "\u{292}\u{30c}"),
+    (0x1f0, "j\u{30c}"), (0x1f4, "G\u{301}"), (0x1f5, "g\u{301}"), (0x1f8, "N\u{300}"),
+    (0x1f9, "n\u{300}"), (0x1fa, "A\u{30a}\u{301}"), (0x1fb, "a\u{30a}\u{301}"),
+    (0x1fc, "\u{c6}\u{301}"), (0x1fd, "\u{e6}\u{301}"), (0x1fe, "\u{d8}\u{301}"),
+    (0x1ff, "\u{f8}\u{301}"), (0x200, "A\u{30f}"), (0x201, "a\u{30f}"), (0x202, "A\u{311}"),
+    (0x203, "a\u{311}"), (0x204, "E\u{30f}"), (0x205, "e\u{30f}"), (0x206, "E\u{311}"),
+    (0x207, "e\u{311}"), (0x208, "I\u{30f}"), (0x209, "i\u{30f}"), (0x20a, "I\u{311}"),
+    (0x20b, "i\u{311}"), (0x20c, "O\u{30f}"), (0x20d, "o\u{30f}"), (0x20e, "O\u{311}"),
+    (0x20f, "o\u{311}"), (0x210, "R\u{30f}"), (0x211, "r\u{30f}"), (0x212, "R\u{311}"),
+    (0x213, "r\u{311}"), (0x214, "U\u{30f}"), (0x215, "u\u{30f}"), (0x216, "U\u{311}"),
+    (0x217, "u\u{311}"), (0x218, "S\u{326}"), (0x219, "s\u{326}"), (0x21a, "T\u{326}"),
+    (0x21b, "t\u{326}"), (0x21e, "H\u{30c}"), (0x21f, "h\u{30c}"), (0x226, "A\u{307}"),
+    (0x227, "a\u{307}"), (0x228, "E\u{327}"), (0x229, "e\u{327}"), (0x22a, "O\u{308}\u{304}"),
+    (0x22b, "o\u{308}\u{304}"), (0x22c, "O\u{303}\u{304}"), (0x22d, "o\u{303}\u{304}"),
+    (0x22e, "O\u{307}"), (0x22f, "o\u{307}"), (0x230, "O\u{307}\u{304}"),
+    (0x231, "o\u{307}\u{304}"), (0x232, "Y\u{304}"), (0x233, "y\u{304}"), (0x340, "\u{300}"),
+    (0x341, "\u{301}"), (0x343, "\u{313}"), (0x344, "\u{308}\u{301}"), (0x374, "\u{2b9}"),
+    (0x37e, ";"), (0x385, "\u{a8}\u{301}"), (0x386, "\u{391}\u{301}"), (0x387, "\u{b7}"),
+    (0x388, "\u{395}\u{301}"), (0x389, "\u{397}\u{301}"), (0x38a, "\u{399}\u{301}"),
+    (0x38c, "\u{39f}\u{301}"), (0x38e, "\u{3a5}\u{301}"), (0x38f, "\u{3a9}\u{301}"),
+    (0x390, "\u{3b9}\u{308}\u{301}"), (0x3aa, "\u{399}\u{308}"), (0x3ab, "\u{3a5}\u{308}"),
+    (0x3ac, "\u{3b1}\u{301}"), (0x3ad, "\u{3b5}\u{301}"), (0x3ae, "\u{3b7}\u{301}"),
+    (0x3af, "\u{3b9}\u{301}"), (0x3b0, "\u{3c5}\u{308}\u{301}"), (0x3ca, "\u{3b9}\u{308}"),
+    (0x3cb, "\u{3c5}\u{308}"), (0x3cc, "\u{3bf}\u{301}"), (0x3cd, "\u{3c5}\u{301}"),
+    (0x3ce, "\u{3c9}\u{301}"), (0x3d3, "\u{3d2}\u{301}"), (0x3d4, "\u{3d2}\u{308}"),
+    (0x400, "\u{415}\u{300}"), (0x401, "\u{415}\u{308}"), (0x403, "\u{413}\u{301}"),
+    (0x407, "\u{406}\u{308}"), (0x40c, "\u{41a}\u{301}"), (0x40d, "\u{418}\u{300}"),
+    (0x40e, "\u{423}\u{306}"), (0x419, "\u{418}\u{306}"), (0x439, "\u{438}\u{306}"),
+    (0x450, "\u{435}\u{300}"), (0x451, "\u{435}\u{308}"), (0x453, "\u{433}\u{301}"),
+    (0x457, "\u{456}\u{308}"), (0x45c, "\u{43a}\u{301}"), (0x45d, "\u{438}\u{300}"),
+    (0x45e, "\u{443}\u{306}"), (0x476, "\u{474}\u{30f}"), (0x477, "\u{475}\u{30f}"),
+    (0x4c1, "\u{416}\u{306}"), (0x4c2, "\u{436}\u{306}"), (0x4d0, "\u{410}\u{306}"),
+    (0x4d1, "\u{430}\u{306}"), (0x4d2, "\u{410}\u{308}"), (0x4d3, "\u{430}\u{308}"),
+    (0x4d6, "\u{415}\u{306}"), (0x4d7, "\u{435}\u{306}"), (0x4da, "\u{4d8}\u{308}"),
+    (0x4db, "\u{4d9}\u{308}"), (0x4dc, "\u{416}\u{308}"), (0x4dd, "\u{436}\u{308}"),
+    (0x4de, "\u{417}\u{308}"), (0x4df, "\u{437}\u{308}"), (0x4e2, "\u{418}\u{304}"),
+    (0x4e3, "\u{438}\u{304}"), (0x4e4, "\u{418}\u{308}"), (0x4e5, "\u{438}\u{308}"),
+    (0x4e6, "\u{41e}\u{308}"), (0x4e7, "\u{43e}\u{308}"), (0x4ea, "\u{4e8}\u{308}"),
+    (0x4eb, "\u{4e9}\u{308}"), (0x4ec, "\u{42d}\u{308}"), (0x4ed, "\u{44d}\u{308}"),
+    (0x4ee, "\u{423}\u{304}"), (0x4ef, "\u{443}\u{304}"), (0x4f0, "\u{423}\u{308}"),
+    (0x4f1, "\u{443}\u{308}"), (0x4f2, "\u{423}\u{30b}"), (0x4f3, "\u{443}\u{30b}"),
+    (0x4f4, "\u{427}\u{308}"), (0x4f5, "\u{447}\u{308}"), (0x4f8, "\u{42b}\u{308}"),
+    (0x4f9, "\u{44b}\u{308}"), (0x622, "\u{627}\u{653}"), (0x623, "\u{627}\u{654}"),
+    (0x624, "\u{648}\u{654}"), (0x625, "\u{627}\u{655}"), (0x626, "\u{64a}\u{654}"),
+    (0x6c0, "\u{6d5}\u{654}"), (0x6c2, "\u{6c1}\u{654}"), (0x6d3, "\u{6d2}\u{654}"),
+    (0x929, "\u{928}\u{93c}"), (0x931, "\u{930}\u{93c}"), (0x934, "\u{933}\u{93c}"),
+    (0x958, "\u{915}\u{93c}"), (0x959, "\u{916}\u{93c}"), (0x95a, "\u{917}\u{93c}"),
+    (0x95b, "\u{91c}\u{93c}"), (0x95c, "\u{921}\u{93c}"), (0x95d, "\u{922}\u{93c}"),
+    (0x95e, "\u{92b}\u{93c}"), (0x95f, "\u{92f}\u{93c}"), (0x9cb, "\u{9c7}\u{9be}"),
+    (0x9cc, "\u{9c7}\u{9d7}"), (0x9dc, "\u{9a1}\u{9bc}"), (0x9dd, "\u{9a2}\u{9bc}"),
+    (0x9df, "\u{9af}\u{9bc}"), (0xa33, "\u{a32}\u{a3c}"), (0xa36, "\u{a38}\u{a3c}"),
+    (0xa59, "\u{a16}\u{a3c}"), (0xa5a, "\u{a17}\u{a3c}"), (0xa5b, "\u{a1c}\u{a3c}"),
+    (0xa5e, "\u{a2b}\u{a3c}"), (0xb48, "\u{b47}\u{b56}"), (0xb4b, "\u{b47}\u{b3e}"),
+    (0xb4c, "\u{b47}\u{b57}"), (0xb5c, "\u{b21}\u{b3c}"), (0xb5d, "\u{b22}\u{b3c}"),
+    (0xb94, "\u{b92}\u{bd7}"), (0xbca, "\u{bc6}\u{bbe}"), (0xbcb, "\u{bc7}\u{bbe}"),
+    (0xbcc, "\u{bc6}\u{bd7}"), (0xc48, "\u{c46}\u{c56}"), (0xcc0, "\u{cbf}\u{cd5}"),
+    (0xcc7, "\u{cc6}\u{cd5}"), (0xcc8, "\u{cc6}\u{cd6}"), (0xcca, "\u{cc6}\u{cc2}"),
+    (0xccb, "\u{cc6}\u{cc2}\u{cd5}"), (0xd4a, "\u{d46}\u{d3e}"), (0xd4b, "\u{d47}\u{d3e}"),
+    (0xd4c, "\u{d46}\u{d57}"), (0xdda, "\u{dd9}\u{dca}"), (0xddc, "\u{dd9}\u{dcf}"),
+    (0xddd, "\u{dd9}\u{dcf}\u{dca}"), (0xdde, "\u{dd9}\u{ddf}"), (0xf43, "\u{f42}\u{fb7}"),
+    (0xf4d, "\u{f4c}\u{fb7}"), (0xf52, "\u{f51}\u{fb7}"), (0xf57, "\u{f56}\u{fb7}"),
+    (0xf5c, "\u{f5b}\u{fb7}"), (0xf69, "\u{f40}\u{fb5}"), (0xf73, "\u{f71}\u{f72}"),
+    (0xf75, "\u{f71}\u{f74}"), (0xf76, "\u{fb2}\u{f80}"), (0xf78, "\u{fb3}\u{f80}"),
+    (0xf81, "\u{f71}\u{f80}"), (0xf93, "\u{f92}\u{fb7}"), (0xf9d, "\u{f9c}\u{fb7}"),
+    (0xfa2, "\u{fa1}\u{fb7}"), (0xfa7, "\u{fa6}\u{fb7}"), (0xfac, "\u{fab}\u{fb7}"),
+    (0xfb9, "\u{f90}\u{fb5}"), (0x1026, "\u{1025}\u{102e}"), (0x1b06, "\u{1b05}\u{1b35}"),
+    (0x1b08, "\u{1b07}\u{1b35}"), (0x1b0a, "\u{1b09}\u{1b35}"), (0x1b0c, "\u{1b0b}\u{1b35}"),
+    (0x1b0e, "\u{1b0d}\u{1b35}"), (0x1b12, "\u{1b11}\u{1b35}"), (0x1b3b, "\u{1b3a}\u{1b35}"),
+    (0x1b3d, "\u{1b3c}\u{1b35}"), (0x1b40, "\u{1b3e}\u{1b35}"), (0x1b41, "\u{1b3f}\u{1b35}"),
+    (0x1b43, "\u{1b42}\u{1b35}"), (0x1e00, "A\u{325}"), (0x1e01, "a\u{325}"), (0x1e02, "B\u{307}"),
+    (0x1e03, "b\u{307}"), (0x1e04, "B\u{323}"), (0x1e05, "b\u{323}"), (0x1e06, "B\u{331}"),
+    (0x1e07, "b\u{331}"), (0x1e08, "C\u{327}\u{301}"), (0x1e09, "c\u{327}\u{301}"),
+    (0x1e0a, "D\u{307}"), (0x1e0b, "d\u{307}"), (0x1e0c, "D\u{323}"), (0x1e0d, "d\u{323}"),
+    (0x1e0e, "D\u{331}"), (0x1e0f, "d\u{331}"), (0x1e10, "D\u{327}"), (0x1e11, "d\u{327}"),
+    (0x1e12, "D\u{32d}"), (0x1e13, "d\u{32d}"), (0x1e14, "E\u{304}\u{300}"),
+    (0x1e15, "e\u{304}\u{300}"), (0x1e16, "E\u{304}\u{301}"), (0x1e17, "e\u{304}\u{301}"),
+    (0x1e18, "E\u{32d}"), (0x1e19, "e\u{32d}"), (0x1e1a, "E\u{330}"), (0x1e1b, "e\u{330}"),
+    (0x1e1c, "E\u{327}\u{306}"), (0x1e1d, "e\u{327}\u{306}"), (0x1e1e, "F\u{307}"),
+    (0x1e1f, "f\u{307}"), (0x1e20, "G\u{304}"), (0x1e21, "g\u{304}"), (0x1e22, "H\u{307}"),
+    (0x1e23, "h\u{307}"), (0x1e24, "H\u{323}"), (0x1e25, "h\u{323}"), (0x1e26, "H\u{308}"),
+    (0x1e27, "h\u{308}"), (0x1e28, "H\u{327}"), (0x1e29, "h\u{327}"), (0x1e2a, "H\u{32e}"),
+    (0x1e2b, "h\u{32e}"), (0x1e2c, "I\u{330}"), (0x1e2d, "i\u{330}"), (0x1e2e, "I\u{308}\u{301}"),
+    (0x1e2f, "i\u{308}\u{301}"), (0x1e30, "K\u{301}"), (0x1e31, "k\u{301}"), (0x1e32, "K\u{323}"),
+    (0x1e33, "k\u{323}"), (0x1e34, "K\u{331}"), (0x1e35, "k\u{331}"), (0x1e36, "L\u{323}"),
+    (0x1e37, "l\u{323}"), (0x1e38, "L\u{323}\u{304}"), (0x1e39, "l\u{323}\u{304}"),
+    (0x1e3a, "L\u{331}"), (0x1e3b, "l\u{331}"), (0x1e3c, "L\u{32d}"), (0x1e3d, "l\u{32d}"),
+    (0x1e3e, "M\u{301}"), (0x1e3f, "m\u{301}"), (0x1e40, "M\u{307}"), (0x1e41, "m\u{307}"),
+    (0x1e42, "M\u{323}"), (0x1e43, "m\u{323}"), (0x1e44, "N\u{307}"), (0x1e45, "n\u{307}"),
+    (0x1e46, "N\u{323}"), (0x1e47, "n\u{323}"), (0x1e48, "N\u{331}"), (0x1e49, "n\u{331}"),
+    (0x1e4a, "N\u{32d}"), (0x1e4b, "n\u{32d}"), (0x1e4c, "O\u{303}\u{301}"),
+    (0x1e4d, "o\u{303}\u{301}"), (0x1e4e, "O\u{303}\u{308}"), (0x1e4f, "o\u{303}\u{308}"),
+    (0x1e50, "O\u{304}\u{300}"), (0x1e51, "o\u{304}\u{300}"), (0x1e52, "O\u{304}\u{301}"),
+    (0x1e53, "o\u{304}\u{301}"), (0x1e54, "P\u{301}"), (0x1e55, "p\u{301}"), (0x1e56, "P\u{307}"),
+    (0x1e57, "p\u{307}"), (0x1e58, "R\u{307}"), (0x1e59, "r\u{307}"), (0x1e5a, "R\u{323}"),
+    (0x1e5b, "r\u{323}"), (0x1e5c, "R\u{323}\u{304}"), (0x1e5d, "r\u{323}\u{304}"),
+    (0x1e5e, "R\u{331}"), (0x1e5f, "r\u{331}"), (0x1e60, "S\u{307}"), (0x1e61, "s\u{307}"),
+    (0x1e62, "S\u{323}"), (0x1e63, "s\u{323}"), (0x1e64, "S\u{301}\u{307}"),
+    (0x1e65, "s\u{301}\u{307}"), (0x1e66, "S\u{30c}\u{307}"), (0x1e67, "s\u{30c}\u{307}"),
+    (0x1e68, "S\u{323}\u{307}"), (0x1e69, "s\u{323}\u{307}"), (0x1e6a, "T\u{307}"),
+    (0x1e6b, "t\u{307}"), (0x1e6c, "T\u{323}"), (0x1e6d, "t\u{323}"), (0x1e6e, "T\u{331}"),
+    (0x1e6f, "t\u{331}"), (0x1e70, "T\u{32d}"), (0x1e71, "t\u{32d}"), (0x1e72, "U\u{324}"),
+    (0x1e73, "u\u{324}"), (0x1e74, "U\u{330}"), (0x1e75, "u\u{330}"), (0x1e76, "U\u{32d}"),
+    (0x1e77, "u\u{32d}"), (0x1e78, "U\u{303}\u{301}"), (0x1e79, "u\u{303}\u{301}"),
+    (0x1e7a, "U\u{304}\u{308}"), (0x1e7b, "u\u{304}\u{308}"), (0x1e7c, "V\u{303}"),
+    (0x1e7d, "v\u{303}"), (0x1e7e, "V\u{323}"), (0x1e7f, "v\u{323}"), (0x1e80, "W\u{300}"),
+    (0x1e81, "w\u{300}"), (0x1e82, "W\u{301}"), (0x1e83, "w\u{301}"), (0x1e84, "W\u{308}"),
+    (0x1e85, "w\u{308}"), (0x1e86, "W\u{307}"), (0x1e87, "w\u{307}"), (0x1e88, "W\u{323}"),
+    (0x1e89, "w\u{323}"), (0x1e8a, "X\u{307}"), (0x1e8b, "x\u{307}"), (0x1e8c, "X\u{308}"),
+    (0x1e8d, "x\u{308}"), (0x1e8e, "Y\u{307}"), (0x1e8f, "y\u{307}"), (0x1e90, "Z\u{302}"),
+    (0x1e91, "z\u{302}"), (0x1e92, "Z\u{323}"), (0x1e93, "z\u{323}"), (0x1e94, "Z\u{331}"),
+    (0x1e95, "z\u{331}"), (0x1e96, "h\u{331}"), (0x1e97, "t\u{308}"), (0x1e98, "w\u{30a}"),
+    (0x1e99, "y\u{30a}"), (0x1e9b, "\u{17f}\u{307}"), (0x1ea0, "A\u{323}"), (0x1ea1, "a\u{323}"),
+    (0x1ea2, "A\u{309}"), (0x1ea3, "a\u{309}"), (0x1ea4, "A\u{302}\u{301}"),
+    (0x1ea5, "a\u{302}\u{301}"), (0x1ea6, "A\u{302}\u{300}"), (0x1ea7, "a\u{302}\u{300}"),
+    (0x1ea8, "A\u{302}\u{309}"), (0x1ea9, "a\u{302}\u{309}"), (0x1eaa, "A\u{302}\u{303}"),
+    (0x1eab, "a\u{302}\u{303}"), (0x1eac, "A\u{323}\u{302}"), (0x1ead, "a\u{323}\u{302}"),
+    (0x1eae, "A\u{306}\u{301}"), (0x1eaf, "a\u{306}\u{301}"), (0x1eb0, "A\u{306}\u{300}"),
+    (0x1eb1, "a\u{306}\u{300}"), (0x1eb2, "A\u{306}\u{309}"), (0x1eb3, "a\u{306}\u{309}"),
+    (0x1eb4, "A\u{306}\u{303}"), (0x1eb5, "a\u{306}\u{303}"), (0x1eb6, "A\u{323}\u{306}"),
+    (0x1eb7, "a\u{323}\u{306}"), (0x1eb8, "E\u{323}"), (0x1eb9, "e\u{323}"), (0x1eba, "E\u{309}"),
+    (0x1ebb, "e\u{309}"), (0x1ebc, "E\u{303}"), (0x1ebd, "e\u{303}"), (0x1ebe, "E\u{302}\u{301}"),
+    (0x1ebf, "e\u{302}\u{301}"), (0x1ec0, "E\u{302}\u{300}"), (0x1ec1, "e\u{302}\u{300}"),
+    (0x1ec2, "E\u{302}\u{309}"), (0x1ec3, "e\u{302}\u{309}"), (0x1ec4, "E\u{302}\u{303}"),
+    (0x1ec5, "e\u{302}\u{303}"), (0x1ec6, "E\u{323}\u{302}"), (0x1ec7, "e\u{323}\u{302}"),
+    (0x1ec8, "I\u{309}"), (0x1ec9, "i\u{309}"), (0x1eca, "I\u{323}"), (0x1ecb, "i\u{323}"),
+    (0x1ecc, "O\u{323}"), (0x1ecd, "o\u{323}"), (0x1ece, "O\u{309}"), (0x1ecf, "o\u{309}"),
+    (0x1ed0, "O\u{302}\u{301}"), (0x1ed1, "o\u{302}\u{301}"), (0x1ed2, "O\u{302}\u{300}"),
+    (0x1ed3, "o\u{302}\u{300}"), (0x1ed4, "O\u{302}\u{309}"), (0x1ed5, "o\u{302}\u{309}"),
+    (0x1ed6, "O\u{302}\u{303}"), (0x1ed7, "o\u{302}\u{303}"), (0x1ed8, "O\u{323}\u{302}"),
+    (0x1ed9, "o\u{323}\u{302}"), (0x1eda, "O\u{31b}\u{301}"), (0x1edb, "o\u{31b}\u{301}"),
+    (0x1edc, "O\u{31b}\u{300}"), (0x1edd, "o\u{31b}\u{300}"), (0x1ede, "O\u{31b}\u{309}"),
+    (0x1edf, "o\u{31b}\u{309}"), (0x1ee0, "O\u{31b}\u{303}"), (0x1ee1, "o\u{31b}\u{303}"),
+    (0x1ee2, "O\u{31b}\u{323}"), (0x1ee3, "o\u{31b}\u{323}"), (0x1ee4, "U\u{323}"),
+    (0x1ee5, "u\u{323}"), (0x1ee6, "U\u{309}"), (0x1ee7, "u\u{309}"), (0x1ee8, "U\u{31b}\u{301}"),
+    (0x1ee9, "u\u{31b}\u{301}"), (0x1eea, "U\u{31b}\u{300}"), (0x1eeb, "u\u{31b}\u{300}"),
+    (0x1eec, "U\u{31b}\u{309}"), (0x1eed, "u\u{31b}\u{309}"), (0x1eee, "U\u{31b}\u{303}"),
+    (0x1eef, "u\u{31b}\u{303}"), (0x1ef0, "U\u{31b}\u{323}"), (0x1ef1, "u\u{31b}\u{323}"),
+    (0x1ef2, "Y\u{300}"), (0x1ef3, "y\u{300}"), (0x1ef4, "Y\u{323}"), (0x1ef5, "y\u{323}"),
+    (0x1ef6, "Y\u{309}"), (0x1ef7, "y\u{309}"), (0x1ef8, "Y\u{303}"), (0x1ef9, "y\u{303}"),
+    (0x1f00, "\u{3b1}\u{313}"), (0x1f01, "\u{3b1}\u{314}"), (0x1f02, "\u{3b1}\u{313}\u{300}"),
+    (0x1f03, "\u{3b1}\u{314}\u{300}"), (0x1f04, "\u{3b1}\u{313}\u{301}"),
+    (0x1f05, "\u{3b1}\u{314}\u{301}"), (0x1f06, "\u{3b1}\u{313}\u{342}"),
+    (0x1f07, "\u{3b1}\u{314}\u{342}"), (0x1f08, "\u{391}\u{313}"), (0x1f09, "\u{391}\u{314}"),
+    (0x1f0a, "\u{391}\u{313}\u{300}"), (0x1f0b, "\u{391}\u{314}\u{300}"),
+    (0x1f0c, "\u{391}\u{313}\u{301}"), (0x1f0d, "\u{391}\u{314}\u{301}"),
+    (0x1f0e, "\u{391}\u{313}\u{342}"), (0x1f0f, "\u{391}\u{314}\u{342}"),
+    (0x1f10, "\u{3b5}\u{313}"), (0x1f11, "\u{3b5}\u{314}"), (0x1f12, "\u{3b5}\u{313}\u{300}"),
+    (0x1f13, "\u{3b5}\u{314}\u{300}"), (0x1f14, "\u{3b5}\u{313}\u{301}"),
+    (0x1f15, "\u{3b5}\u{314}\u{301}"), (0x1f18, "\u{395}\u{313}"), (0x1f19, "\u{395}\u{314}"),
+    (0x1f1a, "\u{395}\u{313}\u{300}"), (0x1f1b, "\u{395}\u{314}\u{300}"),
+    (0x1f1c, "\u{395}\u{313}\u{301}"), (0x1f1d, "\u{395}\u{314}\u{301}"),
+    (0x1f20, "\u{3b7}\u{313}"), (0x1f21, "\u{3b7}\u{314}"), (0x1f22, "\u{3b7}\u{313}\u{300}"),
+    (0x1f23, "\u{3b7}\u{314}\u{300}"), (0x1f24, "\u{3b7}\u{313}\u{301}"),
+    (0x1f25, "\u{3b7}\u{314}\u{301}"), (0x1f26, "\u{3b7}\u{313}\u{342}"),
+    (0x1f27, "\u{3b7}\u{314}\u{342}"), (0x1f28, "\u{397}\u{313}"), (0x1f29, "\u{397}\u{314}"),
+    (0x1f2a, "\u{397}\u{313}\u{300}"), (0x1f2b, "\u{397}\u{314}\u{300}"),
+    (0x1f2c, "\u{397}\u{313}\u{301}"), (0x1f2d, "\u{397}\u{314}\u{301}"),
+    (0x1f2e, "\u{397}\u{313}\u{342}"), (0x1f2f, "\u{397}\u{314}\u{342}"),
+    (0x1f30, "\u{3b9}\u{313}"), (0x1f31, "\u{3b9}\u{314}"), (0x1f32, "\u{3b9}\u{313}\u{300}"),
+    (0x1f33, "\u{3b9}\u{314}\u{300}"), (0x1f34, "\u{3b9}\u{313}\u{301}"),
+    (0x1f35, "\u{3b9}\u{314}\u{301}"), (0x1f36, "\u{3b9}\u{313}\u{342}"),
+    (0x1f37, "\u{3b9}\u{314}\u{342}"), (0x1f38, "\u{399}\u{313}"), (0x1f39, "\u{399}\u{314}"),
+    (0x1f3a, "\u{399}\u{313}\u{300}"), (0x1f3b, "\u{399}\u{314}\u{300}"),
+    (0x1f3c, "\u{399}\u{313}\u{301}"), (0x1f3d, "\u{399}\u{314}\u{301}"),
+    (0x1f3e, "\u{399}\u{313}\u{342}"), (0x1f3f, "\u{399}\u{314}\u{342}"),
+    (0x1f40, "\u{3bf}\u{313}"), (0x1f41, "\u{3bf}\u{314}"), (0x1f42, "\u{3bf}\u{313}\u{300}"),
+    (0x1f43, "\u{3bf}\u{314}\u{300}"), (0x1f44, "\u{3bf}\u{313}\u{301}"),
+    (0x1f45, "\u{3bf}\u{314}\u{301}"), (0x1f48, "\u{39f}\u{313}"), (0x1f49, "\u{39f}\u{314}"),
+    (0x1f4a, "\u{39f}\u{313}\u{300}"), (0x1f4b, "\u{39f}\u{314}\u{300}"),
+    (0x1f4c, "\u{39f}\u{313}\u{301}"), (0x1f4d, "\u{39f}\u{314}\u{301}"),
+    (0x1f50, "\u{3c5}\u{313}"), (0x1f51, "\u{3c5}\u{314}"), (0x1f52, "\u{3c5}\u{313}\u{300}"),
+    (0x1f53, "\u{3c5}\u{314}\u{300}"), (0x1f54, "\u{3c5}\u{313}\u{301}"),
+    (0x1f55, "\u{3c5}\u{314}\u{301}"), (0x1f56, "\u{3c5}\u{313}\u{342}"),
+    (0x1f57, "\u{3c5}\u{314}\u{342}"), (0x1f59, "\u{3a5}\u{314}"),
+    (0x1f5b, "\u{3a5}\u{314}\u{300}"), (0x1f5d, "\u{3a5}\u{314}\u{301}"),
+    (0x1f5f, "\u{3a5}\u{314}\u{342}"), (0x1f60, "\u{3c9}\u{313}"), (0x1f61, "\u{3c9}\u{314}"),
+    (0x1f62, "\u{3c9}\u{313}\u{300}"), (0x1f63, "\u{3c9}\u{314}\u{300}"),
+    (0x1f64, "\u{3c9}\u{313}\u{301}"), (0x1f65, "\u{3c9}\u{314}\u{301}"),
+    (0x1f66, "\u{3c9}\u{313}\u{342}"), (0x1f67, "\u{3c9}\u{314}\u{342}"),
+    (0x1f68, "\u{3a9}\u{313}"), (0x1f69, "\u{3a9}\u{314}"), (0x1f6a, "\u{3a9}\u{313}\u{300}"),
+    (0x1f6b, "\u{3a9}\u{314}\u{300}"), (0x1f6c, "\u{3a9}\u{313}\u{301}"),
+    (0x1f6d, "\u{3a9}\u{314}\u{301}"), (0x1f6e, "\u{3a9}\u{313}\u{342}"),
+    (0x1f6f, "\u{3a9}\u{314}\u{342}"), (0x1f70, "\u{3b1}\u{300}"), (0x1f71, "\u{3b1}\u{301}"),
+    (0x1f72, "\u{3b5}\u{300}"), (0x1f73, "\u{3b5}\u{301}"), (0x1f74, "\u{3b7}\u{300}"),
+    (0x1f75, "\u{3b7}\u{301}"), (0x1f76, "\u{3b9}\u{300}"), (0x1f77, "\u{3b9}\u{301}"),
+    (0x1f78, "\u{3bf}\u{300}"), (0x1f79, "\u{3bf}\u{301}"), (0x1f7a, "\u{3c5}\u{300}"),
+    (0x1f7b, "\u{3c5}\u{301}"), (0x1f7c, "\u{3c9}\u{300}"), (0x1f7d, "\u{3c9}\u{301}"),
+    (0x1f80, "\u{3b1}\u{313}\u{345}"), (0x1f81, "\u{3b1}\u{314}\u{345}"),
+    (0x1f82, "\u{3b1}\u{313}\u{300}\u{345}"), (0x1f83, "\u{3b1}\u{314}\u{300}\u{345}"),
+    (0x1f84, "\u{3b1}\u{313}\u{301}\u{345}"), (0x1f85, "\u{3b1}\u{314}\u{301}\u{345}"),
+    (0x1f86, "\u{3b1}\u{313}\u{342}\u{345}"), (0x1f87, "\u{3b1}\u{314}\u{342}\u{345}"),
+    (0x1f88, "\u{391}\u{313}\u{345}"), (0x1f89, "\u{391}\u{314}\u{345}"),
+    (0x1f8a, "\u{391}\u{313}\u{300}\u{345}"), (0x1f8b, "\u{391}\u{314}\u{300}\u{345}"),
+    (0x1f8c, "\u{391}\u{313}\u{301}\u{345}"), (0x1f8d, "\u{391}\u{314}\u{301}\u{345}"),
+    (0x1f8e, "\u{391}\u{313}\u{342}\u{345}"), (0x1f8f, "\u{391}\u{314}\u{342}\u{345}"),
+    (0x1f90, "\u{3b7}\u{313}\u{345}"), (0x1f91, "\u{3b7}\u{314}\u{345}"),
+    (0x1f92, "\u{3b7}\u{313}\u{300}\u{345}"), (0x1f93, "\u{3b7}\u{314}\u{300}\u{345}"),
+    (0x1f94, "\u{3b7}\u{313}\u{301}\u{345}"), (0x1f95, "\u{3b7}\u{314}\u{301}\u{345}"),
+    (0x1f96, "\u{3b7}\u{313}\u{342}\u{345}"), (0x1f97, "\u{3b7}\u{314}\u{342}\u{345}"),
+    (0x1f98, "\u{397}\u{313}\u{345}"), (0x1f99, "\u{397}\u{314}\u{345}"),
+    (0x1f9a, "\u{397}\u{313}\u{300}\u{345}"), (0x1f9b, "\u{397}\u{314}\u{300}\u{345}"),
+    (0x1f9c, "\u{397}\u{313}\u{301}\u{345}"), (0x1f9d, "\u{397}\u{314}\u{301}\u{345}"),
+    (0x1f9e, "\u{397}\u{313}\u{342}\u{345}"), (0x1f9f, "\u{397}\u{314}\u{342}\u{345}"),
+    (0x1fa0, "\u{3c9}\u{313}\u{345}"), (0x1fa1, "\u{3c9}\u{314}\u{345}"),
+    (0x1fa2, "\u{3c9}\u{313}\u{300}\u{345}"), (0x1fa3, "\u{3c9}\u{314}\u{300}\u{345}"),
+    (0x1fa4, "\u{3c9}\u{313}\u{301}\u{345}"), (0x1fa5, "\u{3c9}\u{314}\u{301}\u{345}"),
+    (0x1fa6, "\u{3c9}\u{313}\u{342}\u{345}"), (0x1fa7, "\u{3c9}\u{314}\u{342}\u{345}"),
+    (0x1fa8, "\u{3a9}\u{313}\u{345}"), (0x1fa9, "\u{3a9}\u{314}\u{345}"),
+    (0x1faa, "\u{3a9}\u{313}\u{300}\u{345}"), (0x1fab, "\u{3a9}\u{314}\u{300}\u{345}"),
+    (0x1fac, "\u{3a9}\u{313}\u{301}\u{345}"), (0x1fad, "\u{3a9}\u{314}\u{301}\u{345}"),
+    (0x1fae, "\u{3a9}\u{313}\u{342}\u{345}"), (0x1faf, "\u{3a9}\u{314}\u{342}\u{345}"),
+    (0x1fb0, "\u{3b1}\u{306}"), (0x1fb1, "\u{3b1}\u{304}"), (0x1fb2, "\u{3b1}\u{300}\u{345}"),
+    (0x1fb3, "\u{3b1}\u{345}"), (0x1fb4, "\u{3b1}\u{301}\u{345}"), (0x1fb6, "\u{3b1}\u{342}"),
+    (0x1fb7, "\u{3b1}\u{342}\u{345}"), (0x1fb8, "\u{391}\u{306}"), (0x1fb9, "\u{391}\u{304}"),
+    (0x1fba, "\u{391}\u{300}"), (0x1fbb, "\u{391}\u{301}"), (0x1fbc, "\u{391}\u{345}"),
+    (0x1fbe, "\u{3b9}"), (0x1fc1, "\u{a8}\u{342}"), (0x1fc2, "\u{3b7}\u{300}\u{345}"),
+    (0x1fc3, "\u{3b7}\u{345}"), (0x1fc4, "\u{3b7}\u{301}\u{345}"), (0x1fc6, "\u{3b7}\u{342}"),
+    (0x1fc7, "\u{3b7}\u{342}\u{345}"), (0x1fc8, "\u{395}\u{300}"), (0x1fc9, "\u{395}\u{301}"),
+    (0x1fca, "\u{397}\u{300}"), (0x1fcb, "\u{397}\u{301}"), (0x1fcc, "\u{397}\u{345}"),
+    (0x1fcd, "\u{1fbf}\u{300}"), (0x1fce, "\u{1fbf}\u{301}"), (0x1fcf, "\u{1fbf}\u{342}"),
+    (0x1fd0, "\u{3b9}\u{306}"), (0x1fd1, "\u{3b9}\u{304}"), (0x1fd2, "\u{3b9}\u{308}\u{300}"),
+    (0x1fd3, "\u{3b9}\u{308}\u{301}"), (0x1fd6, "\u{3b9}\u{342}"),
+    (0x1fd7, "\u{3b9}\u{308}\u{342}"), (0x1fd8, "\u{399}\u{306}"), (0x1fd9, "\u{399}\u{304}"),
+    (0x1fda, "\u{399}\u{300}"), (0x1fdb, "\u{399}\u{301}"), (0x1fdd, "\u{1ffe}\u{300}"),
+    (0x1fde, "\u{1ffe}\u{301}"), (0x1fdf, "\u{1ffe}\u{342}"), (0x1fe0, "\u{3c5}\u{306}"),
+    (0x1fe1, "\u{3c5}\u{304}"), (0x1fe2, "\u{3c5}\u{308}\u{300}"),
+    (0x1fe3, "\u{3c5}\u{308}\u{301}"), (0x1fe4, "\u{3c1}\u{313}"), (0x1fe5, "\u{3c1}\u{314}"),
+    (0x1fe6, "\u{3c5}\u{342}"), (0x1fe7, "\u{3c5}\u{308}\u{342}"), (0x1fe8, "\u{3a5}\u{306}"),
+    (0x1fe9, "\u{3a5}\u{304}"), (0x1fea, "\u{3a5}\u{300}"), (0x1feb, "\u{3a5}\u{301}"),
+    (0x1fec, "\u{3a1}\u{314}"), (0x1fed, "\u{a8}\u{300}"), (0x1fee, "\u{a8}\u{301}"),
+    (0x1fef, "`"), (0x1ff2, "\u{3c9}\u{300}\u{345}"), (0x1ff3, "\u{3c9}\u{345}"),
+    (0x1ff4, "\u{3c9}\u{301}\u{345}"), (0x1ff6, "\u{3c9}\u{342}"),
+    (0x1ff7, "\u{3c9}\u{342}\u{345}"), (0x1ff8, "\u{39f}\u{300}"), (0x1ff9, "\u{39f}\u{301}"),
+    (0x1ffa, "\u{3a9}\u{300}"), (0x1ffb, "\u{3a9}\u{301}"), (0x1ffc, "\u{3a9}\u{345}"),
+    (0x1ffd, "\u{b4}"), (0x2000, "\u{2002}"), (0x2001, "\u{2003}"), (0x2126, "\u{3a9}"),
+    (0x212a, "K"), (0x212b, "A\u{30a}"), (0x219a, "\u{2190}\u{338}"), (0x219b, "\u{2192}\u{338}"),
+    (0x21ae, "\u{2194}\u{338}"), (0x21cd, "\u{21d0}\u{338}"), (0x21ce, "\u{21d4}\u{338}"),
+    (0x21cf, "\u{21d2}\u{338}"), (0x2204, "\u{2203}\u{338}"), (0x2209, "\u{2208}\u{338}"),
+    (0x220c, "\u{220b}\u{338}"), (0x2224, "\u{2223}\u{338}"), (0x2226, "\u{2225}\u{338}"),
+    (0x2241, "\u{223c}\u{338}"), (0x2244, "\u{2243}\u{338}"), (0x2247, "\u{2245}\u{338}"),
+    (0x2249, "\u{2248}\u{338}"), (0x2260, "=\u{338}"), (0x2262, "\u{2261}\u{338}"),
+    (0x226d, "\u{224d}\u{338}"), (0x226e, "<\u{338}"), (0x226f, ">\u{338}"),
+    (0x2270, "\u{2264}\u{338}"), (0x2271, "\u{2265}\u{338}"), (0x2274, "\u{2272}\u{338}"),
+    (0x2275, "\u{2273}\u{338}"), (0x2278, "\u{2276}\u{338}"), (0x2279, "\u{2277}\u{338}"),
+    (0x2280, "\u{227a}\u{338}"), (0x2281, "\u{227b}\u{338}"), (0x2284, "\u{2282}\u{338}"),
+    (0x2285, "\u{2283}\u{338}"), (0x2288, "\u{2286}\u{338}"), (0x2289, "\u{2287}\u{338}"),
+    (0x22ac, "\u{22a2}\u{338}"), (0x22ad, "\u{22a8}\u{338}"), (0x22ae, "\u{22a9}\u{338}"),
+    (0x22af, "\u{22ab}\u{338}"), (0x22e0, "\u{227c}\u{338}"), (0x22e1, "\u{227d}\u{338}"),
+    (0x22e2, "\u{2291}\u{338}"), (0x22e3, "\u{2292}\u{338}"), (0x22ea, "\u{22b2}\u{338}"),
+    (0x22eb, "\u{22b3}\u{338}"), (0x22ec, "\u{22b4}\u{338}"), (0x22ed, "\u{22b5}\u{338}"),
+    (0x2329, "\u{3008}"), (0x232a, "\u{3009}"), (0x2adc, "\u{2add}\u{338}"),
+    (0x304c, "\u{304b}\u{3099}"), (0x304e, "\u{304d}\u{3099}"), (0x3050, "\u{304f}\u{3099}"),
+    (0x3052, "\u{3051}\u{3099}"), (0x3054, "\u{3053}\u{3099}"), (0x3056, "\u{3055}\u{3099}"),
+    (0x3058, "\u{3057}\u{3099}"), (0x305a, "\u{3059}\u{3099}"), (0x305c, "\u{305b}\u{3099}"),
+    (0x305e, "\u{305d}\u{3099}"), (0x3060, "\u{305f}\u{3099}"), (0x3062, "\u{3061}\u{3099}"),
+    (0x3065, "\u{3064}\u{3099}"), (0x3067, "\u{3066}\u{3099}"), (0x3069, "\u{3068}\u{3099}"),
+    (0x3070, "\u{306f}\u{3099}"), (0x3071, "\u{306f}\u{309a}"), (0x3073, "\u{3072}\u{3099}"),
+    (0x3074, "\u{3072}\u{309a}"), (0x3076, "\u{3075}\u{3099}"), (0x3077, "\u{3075}\u{309a}"),
+    (0x3079, "\u{3078}\u{3099}"), (0x307a, "\u{3078}\u{309a}"), (0x307c, "\u{307b}\u{3099}"),
+    (0x307d, "\u{307b}\u{309a}"), (0x3094, "\u{3046}\u{3099}"), (0x309e, "\u{309d}\u{3099}"),
+    (0x30ac, "\u{30ab}\u{3099}"), (0x30ae, "\u{30ad}\u{3099}"), (0x30b0, "\u{30af}\u{3099}"),
+    (0x30b2, "\u{30b1}\u{3099}"), (0x30b4, "\u{30b3}\u{3099}"), (0x30b6, "\u{30b5}\u{3099}"),
+    (0x30b8, "\u{30b7}\u{3099}"), (0x30ba, "\u{30b9}\u{3099}"), (0x30bc, "\u{30bb}\u{3099}"),
+    (0x30be, "\u{30bd}\u{3099}"), (0x30c0, "\u{30bf}\u{3099}"), (0x30c2, "\u{30c1}\u{3099}"),
+    (0x30c5, "\u{30c4}\u{3099}"), (0x30c7, "\u{30c6}\u{3099}"), (0x30c9, "\u{30c8}\u{3099}"),
+    (0x30d0, "\u{30cf}\u{3099}"), (0x30d1, "\u{30cf}\u{309a}"), (0x30d3, "\u{30d2}\u{3099}"),
+    (0x30d4, "\u{30d2}\u{309a}"), (0x30d6, "\u{30d5}\u{3099}"), (0x30d7, "\u{30d5}\u{309a}"),
+    (0x30d9, "\u{30d8}\u{3099}"), (0x30da, "\u{30d8}\u{309a}"), (0x30dc, "\u{30db}\u{3099}"),
+    (0x30dd, "\u{30db}\u{309a}"), (0x30f4, "\u{30a6}\u{3099}"), (0x30f7, "\u{30ef}\u{3099}"),
+    (0x30f8, "\u{30f0}\u{3099}"), (0x30f9, "\u{30f1}\u{3099}"), (0x30fa, "\u{30f2}\u{3099}"),
+    (0x30fe, "\u{30fd}\u{3099}"), (0xf900, "\u{8c48}"), (0xf901, "\u{66f4}"), (0xf902, "\u{8eca}"),
+    (0xf903, "\u{8cc8}"), (0xf904, "\u{6ed1}"), (0xf905, "\u{4e32}"), (0xf906, "\u{53e5}"),
+    (0xf907, "\u{9f9c}"), (0xf908, "\u{9f9c}"), (0xf909, "\u{5951}"), (0xf90a, "\u{91d1}"),
+    (0xf90b, "\u{5587}"), (0xf90c, "\u{5948}"), (0xf90d, "\u{61f6}"), (0xf90e, "\u{7669}"),
+    (0xf90f, "\u{7f85}"), (0xf910, "\u{863f}"), (0xf911, "\u{87ba}"), (0xf912, "\u{88f8}"),
+    (0xf913, "\u{908f}"), (0xf914, "\u{6a02}"), (0xf915, "\u{6d1b}"), (0xf916, "\u{70d9}"),
+    (0xf917, "\u{73de}"), (0xf918, "\u{843d}"), (0xf919, "\u{916a}"), (0xf91a, "\u{99f1}"),
+    (0xf91b, "\u{4e82}"), (0xf91c, "\u{5375}"), (0xf91d, "\u{6b04}"), (0xf91e, "\u{721b}"),
+    (0xf91f, "\u{862d}"), (0xf920, "\u{9e1e}"), (0xf921, "\u{5d50}"), (0xf922, "\u{6feb}"),
+    (0xf923, "\u{85cd}"), (0xf924, "\u{8964}"), (0xf925, "\u{62c9}"), (0xf926, "\u{81d8}"),
+    (0xf927, "\u{881f}"), (0xf928, "\u{5eca}"), (0xf929, "\u{6717}"), (0xf92a, "\u{6d6a}"),
+    (0xf92b, "\u{72fc}"), (0xf92c, "\u{90ce}"), (0xf92d, "\u{4f86}"), (0xf92e, "\u{51b7}"),
+    (0xf92f, "\u{52de}"), (0xf930, "\u{64c4}"), (0xf931, "\u{6ad3}"), (0xf932, "\u{7210}"),
+    (0xf933, "\u{76e7}"), (0xf934, "\u{8001}"), (0xf935, "\u{8606}"), (0xf936, "\u{865c}"),
+    (0xf937, "\u{8def}"), (0xf938, "\u{9732}"), (0xf939, "\u{9b6f}"), (0xf93a, "\u{9dfa}"),
+    (0xf93b, "\u{788c}"), (0xf93c, "\u{797f}"), (0xf93d, "\u{7da0}"), (0xf93e, "\u{83c9}"),
+    (0xf93f, "\u{9304}"), (0xf940, "\u{9e7f}"), (0xf941, "\u{8ad6}"), (0xf942, "\u{58df}"),
+    (0xf943, "\u{5f04}"), (0xf944, "\u{7c60}"), (0xf945, "\u{807e}"), (0xf946, "\u{7262}"),
+    (0xf947, "\u{78ca}"), (0xf948, "\u{8cc2}"), (0xf949, "\u{96f7}"), (0xf94a, "\u{58d8}"),
+    (0xf94b, "\u{5c62}"), (0xf94c, "\u{6a13}"), (0xf94d, "\u{6dda}"), (0xf94e, "\u{6f0f}"),
+    (0xf94f, "\u{7d2f}"), (0xf950, "\u{7e37}"), (0xf951, "\u{964b}"), (0xf952, "\u{52d2}"),
+    (0xf953, "\u{808b}"), (0xf954, "\u{51dc}"), (0xf955, "\u{51cc}"), (0xf956, "\u{7a1c}"),
+    (0xf957, "\u{7dbe}"), (0xf958, "\u{83f1}"), (0xf959, "\u{9675}"), (0xf95a, "\u{8b80}"),
+    (0xf95b, "\u{62cf}"), (0xf95c, "\u{6a02}"), (0xf95d, "\u{8afe}"), (0xf95e, "\u{4e39}"),
+    (0xf95f, "\u{5be7}"), (0xf960, "\u{6012}"), (0xf961, "\u{7387}"), (0xf962, "\u{7570}"),
+    (0xf963, "\u{5317}"), (0xf964, "\u{78fb}"), (0xf965, "\u{4fbf}"), (0xf966, "\u{5fa9}"),
+    (0xf967, "\u{4e0d}"), (0xf968, "\u{6ccc}"), (0xf969, "\u{6578}"), (0xf96a, "\u{7d22}"),
+    (0xf96b, "\u{53c3}"), (0xf96c, "\u{585e}"), (0xf96d, "\u{7701}"), (0xf96e, "\u{8449}"),
+    (0xf96f, "\u{8aaa}"), (0xf970, "\u{6bba}"), (0xf971, "\u{8fb0}"), (0xf972, "\u{6c88}"),
+    (0xf973, "\u{62fe}"), (0xf974, "\u{82e5}"), (0xf975, "\u{63a0}"), (0xf976, "\u{7565}"),
+    (0xf977, "\u{4eae}"), (0xf978, "\u{5169}"), (0xf979, "\u{51c9}"), (0xf97a, "\u{6881}"),
+    (0xf97b, "\u{7ce7}"), (0xf97c, "\u{826f}"), (0xf97d, "\u{8ad2}"), (0xf97e, "\u{91cf}"),
+    (0xf97f, "\u{52f5}"), (0xf980, "\u{5442}"), (0xf981, "\u{5973}"), (0xf982, "\u{5eec}"),
+    (0xf983, "\u{65c5}"), (0xf984, "\u{6ffe}"), (0xf985, "\u{792a}"), (0xf986, "\u{95ad}"),
+    (0xf987, "\u{9a6a}"), (0xf988, "\u{9e97}"), (0xf989, "\u{9ece}"), (0xf98a, "\u{529b}"),
+    (0xf98b, "\u{66c6}"), (0xf98c, "\u{6b77}"), (0xf98d, "\u{8f62}"), (0xf98e, "\u{5e74}"),
+    (0xf98f, "\u{6190}"), (0xf990, "\u{6200}"), (0xf991, "\u{649a}"), (0xf992, "\u{6f23}"),
+    (0xf993, "\u{7149}"), (0xf994, "\u{7489}"), (0xf995, "\u{79ca}"), (0xf996, "\u{7df4}"),
+    (0xf997, "\u{806f}"), (0xf998, "\u{8f26}"), (0xf999, "\u{84ee}"), (0xf99a, "\u{9023}"),
+    (0xf99b, "\u{934a}"), (0xf99c, "\u{5217}"), (0xf99d, "\u{52a3}"), (0xf99e, "\u{54bd}"),
+    (0xf99f, "\u{70c8}"), (0xf9a0, "\u{88c2}"), (0xf9a1, "\u{8aaa}"), (0xf9a2, "\u{5ec9}"),
+    (0xf9a3, "\u{5ff5}"), (0xf9a4, "\u{637b}"), (0xf9a5, "\u{6bae}"), (0xf9a6, "\u{7c3e}"),
+    (0xf9a7, "\u{7375}"), (0xf9a8, "\u{4ee4}"), (0xf9a9, "\u{56f9}"), (0xf9aa, "\u{5be7}"),
+    (0xf9ab, "\u{5dba}"), (0xf9ac, "\u{601c}"), (0xf9ad, "\u{73b2}"), (0xf9ae, "\u{7469}"),
+    (0xf9af, "\u{7f9a}"), (0xf9b0, "\u{8046}"), (0xf9b1, "\u{9234}"), (0xf9b2, "\u{96f6}"),
+    (0xf9b3, "\u{9748}"), (0xf9b4, "\u{9818}"), (0xf9b5, "\u{4f8b}"), (0xf9b6, "\u{79ae}"),
+    (0xf9b7, "\u{91b4}"), (0xf9b8, "\u{96b8}"), (0xf9b9, "\u{60e1}"), (0xf9ba, "\u{4e86}"),
+    (0xf9bb, "\u{50da}"), (0xf9bc, "\u{5bee}"), (0xf9bd, "\u{5c3f}"), (0xf9be, "\u{6599}"),
+    (0xf9bf, "\u{6a02}"), (0xf9c0, "\u{71ce}"), (0xf9c1, "\u{7642}"), (0xf9c2, "\u{84fc}"),
+    (0xf9c3, "\u{907c}"), (0xf9c4, "\u{9f8d}"), (0xf9c5, "\u{6688}"), (0xf9c6, "\u{962e}"),
+    (0xf9c7, "\u{5289}"), (0xf9c8, "\u{677b}"), (0xf9c9, "\u{67f3}"), (0xf9ca, "\u{6d41}"),
+    (0xf9cb, "\u{6e9c}"), (0xf9cc, "\u{7409}"), (0xf9cd, "\u{7559}"), (0xf9ce, "\u{786b}"),
+    (0xf9cf, "\u{7d10}"), (0xf9d0, "\u{985e}"), (0xf9d1, "\u{516d}"), (0xf9d2, "\u{622e}"),
+    (0xf9d3, "\u{9678}"), (0xf9d4, "\u{502b}"), (0xf9d5, "\u{5d19}"), (0xf9d6, "\u{6dea}"),
+    (0xf9d7, "\u{8f2a}"), (0xf9d8, "\u{5f8b}"), (0xf9d9, "\u{6144}"), (0xf9da, "\u{6817}"),
+    (0xf9db, "\u{7387}"), (0xf9dc, "\u{9686}"), (0xf9dd, "\u{5229}"), (0xf9de, "\u{540f}"),
+    (0xf9df, "\u{5c65}"), (0xf9e0, "\u{6613}"), (0xf9e1, "\u{674e}"), (0xf9e2, "\u{68a8}"),
+    (0xf9e3, "\u{6ce5}"), (0xf9e4, "\u{7406}"), (0xf9e5, "\u{75e2}"), (0xf9e6, "\u{7f79}"),
+    (0xf9e7, "\u{88cf}"), (0xf9e8, "\u{88e1}"), (0xf9e9, "\u{91cc}"), (0xf9ea, "\u{96e2}"),
+    (0xf9eb, "\u{533f}"), (0xf9ec, "\u{6eba}"), (0xf9ed, "\u{541d}"), (0xf9ee, "\u{71d0}"),
+    (0xf9ef, "\u{7498}"), (0xf9f0, "\u{85fa}"), (0xf9f1, "\u{96a3}"), (0xf9f2, "\u{9c57}"),
+    (0xf9f3, "\u{9e9f}"), (0xf9f4, "\u{6797}"), (0xf9f5, "\u{6dcb}"), (0xf9f6, "\u{81e8}"),
+    (0xf9f7, "\u{7acb}"), (0xf9f8, "\u{7b20}"), (0xf9f9, "\u{7c92}"), (0xf9fa, "\u{72c0}"),
+    (0xf9fb, "\u{7099}"), (0xf9fc, "\u{8b58}"), (0xf9fd, "\u{4ec0}"), (0xf9fe, "\u{8336}"),
+    (0xf9ff, "\u{523a}"), (0xfa00, "\u{5207}"), (0xfa01, "\u{5ea6}"), (0xfa02, "\u{62d3}"),
+    (0xfa03, "\u{7cd6}"), (0xfa04, "\u{5b85}"), (0xfa05, "\u{6d1e}"), (0xfa06, "\u{66b4}"),
+    (0xfa07, "\u{8f3b}"), (0xfa08, "\u{884c}"), (0xfa09, "\u{964d}"), (0xfa0a, "\u{898b}"),
+    (0xfa0b, "\u{5ed3}"), (0xfa0c, "\u{5140}"), (0xfa0d, "\u{55c0}"), (0xfa10, "\u{585a}"),
+    (0xfa12, "\u{6674}"), (0xfa15, "\u{51de}"), (0xfa16, "\u{732a}"), (0xfa17, "\u{76ca}"),
+    (0xfa18, "\u{793c}"), (0xfa19, "\u{795e}"), (0xfa1a, "\u{7965}"), (0xfa1b, "\u{798f}"),
+    (0xfa1c, "\u{9756}"), (0xfa1d, "\u{7cbe}"), (0xfa1e, "\u{7fbd}"), (0xfa20, "\u{8612}"),
+    (0xfa22, "\u{8af8}"), (0xfa25, "\u{9038}"), (0xfa26, "\u{90fd}"), (0xfa2a, "\u{98ef}"),
+    (0xfa2b, "\u{98fc}"), (0xfa2c, "\u{9928}"), (0xfa2d, "\u{9db4}"), (0xfa2e, "\u{90de}"),
+    (0xfa2f, "\u{96b7}"), (0xfa30, "\u{4fae}"), (0xfa31, "\u{50e7}"), (0xfa32, "\u{514d}"),
+    (0xfa33, "\u{52c9}"), (0xfa34, "\u{52e4}"), (0xfa35, "\u{5351}"), (0xfa36, "\u{559d}"),
+    (0xfa37, "\u{5606}"), (0xfa38, "\u{5668}"), (0xfa39, "\u{5840}"), (0xfa3a, "\u{58a8}"),
+    (0xfa3b, "\u{5c64}"), (0xfa3c, "\u{5c6e}"), (0xfa3d, "\u{6094}"), (0xfa3e, "\u{6168}"),
+    (0xfa3f, "\u{618e}"), (0xfa40, "\u{61f2}"), (0xfa41, "\u{654f}"), (0xfa42, "\u{65e2}"),
+    (0xfa43, "\u{6691}"), (0xfa44, "\u{6885}"), (0xfa45, "\u{6d77}"), (0xfa46, "\u{6e1a}"),
+    (0xfa47, "\u{6f22}"), (0xfa48, "\u{716e}"), (0xfa49, "\u{722b}"), (0xfa4a, "\u{7422}"),
+    (0xfa4b, "\u{7891}"), (0xfa4c, "\u{793e}"), (0xfa4d, "\u{7949}"), (0xfa4e, "\u{7948}"),
+    (0xfa4f, "\u{7950}"), (0xfa50, "\u{7956}"), (0xfa51, "\u{795d}"), (0xfa52, "\u{798d}"),
+    (0xfa53, "\u{798e}"), (0xfa54, "\u{7a40}"), (0xfa55, "\u{7a81}"), (0xfa56, "\u{7bc0}"),
+    (0xfa57, "\u{7df4}"), (0xfa58, "\u{7e09}"), (0xfa59, "\u{7e41}"), (0xfa5a, "\u{7f72}"),
+    (0xfa5b, "\u{8005}"), (0xfa5c, "\u{81ed}"), (0xfa5d, "\u{8279}"), (0xfa5e, "\u{8279}"),
+    (0xfa5f, "\u{8457}"), (0xfa60, "\u{8910}"), (0xfa61, "\u{8996}"), (0xfa62, "\u{8b01}"),
+    (0xfa63, "\u{8b39}"), (0xfa64, "\u{8cd3}"), (0xfa65, "\u{8d08}"), (0xfa66, "\u{8fb6}"),
+    (0xfa67, "\u{9038}"), (0xfa68, "\u{96e3}"), (0xfa69, "\u{97ff}"), (0xfa6a, "\u{983b}"),
+    (0xfa6b, "\u{6075}"), (0xfa6c, "\u{242ee}"), (0xfa6d, "\u{8218}"), (0xfa70, "\u{4e26}"),
+    (0xfa71, "\u{51b5}"), (0xfa72, "\u{5168}"), (0xfa73, "\u{4f80}"), (0xfa74, "\u{5145}"),
+    (0xfa75, "\u{5180}"), (0xfa76, "\u{52c7}"), (0xfa77, "\u{52fa}"), (0xfa78, "\u{559d}"),
+    (0xfa79, "\u{5555}"), (0xfa7a, "\u{5599}"), (0xfa7b, "\u{55e2}"), (0xfa7c, "\u{585a}"),
+    (0xfa7d, "\u{58b3}"), (0xfa7e, "\u{5944}"), (0xfa7f, "\u{5954}"), (0xfa80, "\u{5a62}"),
+    (0xfa81, "\u{5b28}"), (0xfa82, "\u{5ed2}"), (0xfa83, "\u{5ed9}"), (0xfa84, "\u{5f69}"),
+    (0xfa85, "\u{5fad}"), (0xfa86, "\u{60d8}"), (0xfa87, "\u{614e}"), (0xfa88, "\u{6108}"),
+    (0xfa89, "\u{618e}"), (0xfa8a, "\u{6160}"), (0xfa8b, "\u{61f2}"), (0xfa8c, "\u{6234}"),
+    (0xfa8d, "\u{63c4}"), (0xfa8e, "\u{641c}"), (0xfa8f, "\u{6452}"), (0xfa90, "\u{6556}"),
+    (0xfa91, "\u{6674}"), (0xfa92, "\u{6717}"), (0xfa93, "\u{671b}"), (0xfa94, "\u{6756}"),
+    (0xfa95, "\u{6b79}"), (0xfa96, "\u{6bba}"), (0xfa97, "\u{6d41}"), (0xfa98, "\u{6edb}"),
+    (0xfa99, "\u{6ecb}"), (0xfa9a, "\u{6f22}"), (0xfa9b, "\u{701e}"), (0xfa9c, "\u{716e}"),
+    (0xfa9d, "\u{77a7}"), (0xfa9e, "\u{7235}"), (0xfa9f, "\u{72af}"), (0xfaa0, "\u{732a}"),
+    (0xfaa1, "\u{7471}"), (0xfaa2, "\u{7506}"), (0xfaa3, "\u{753b}"), (0xfaa4, "\u{761d}"),
+    (0xfaa5, "\u{761f}"), (0xfaa6, "\u{76ca}"), (0xfaa7, "\u{76db}"), (0xfaa8, "\u{76f4}"),
+    (0xfaa9, "\u{774a}"), (0xfaaa, "\u{7740}"), (0xfaab, "\u{78cc}"), (0xfaac, "\u{7ab1}"),
+    (0xfaad, "\u{7bc0}"), (0xfaae, "\u{7c7b}"), (0xfaaf, "\u{7d5b}"), (0xfab0, "\u{7df4}"),
+    (0xfab1, "\u{7f3e}"), (0xfab2, "\u{8005}"), (0xfab3, "\u{8352}"), (0xfab4, "\u{83ef}"),
+    (0xfab5, "\u{8779}"), (0xfab6, "\u{8941}"), (0xfab7, "\u{8986}"), (0xfab8, "\u{8996}"),
+    (0xfab9, "\u{8abf}"), (0xfaba, "\u{8af8}"), (0xfabb, "\u{8acb}"), (0xfabc, "\u{8b01}"),
+    (0xfabd, "\u{8afe}"), (0xfabe, "\u{8aed}"), (0xfabf, "\u{8b39}"), (0xfac0, "\u{8b8a}"),
+    (0xfac1, "\u{8d08}"), (0xfac2, "\u{8f38}"), (0xfac3, "\u{9072}"), (0xfac4, "\u{9199}"),
+    (0xfac5, "\u{9276}"), (0xfac6, "\u{967c}"), (0xfac7, "\u{96e3}"), (0xfac8, "\u{9756}"),
+    (0xfac9, "\u{97db}"), (0xfaca, "\u{97ff}"), (0xfacb, "\u{980b}"), (0xfacc, "\u{983b}"),
+    (0xfacd, "\u{9b12}"), (0xface, "\u{9f9c}"), (0xfacf, "\u{2284a}"), (0xfad0, "\u{22844}"),
+    (0xfad1, "\u{233d5}"), (0xfad2, "\u{3b9d}"), (0xfad3, "\u{4018}"), (0xfad4, "\u{4039}"),
+    (0xfad5, "\u{25249}"), (0xfad6, "\u{25cd0}"), (0xfad7, "\u{27ed3}"), (0xfad8, "\u{9f43}"),
+    (0xfad9, "\u{9f8e}"), (0xfb1d, "\u{5d9}\u{5b4}"), (0xfb1f, "\u{5f2}\u{5b7}"),
+    (0xfb2a, "\u{5e9}\u{5c1}"), (0xfb2b, "\u{5e9}\u{5c2}"), (0xfb2c, "\u{5e9}\u{5bc}\u{5c1}"),
+    (0xfb2d, "\u{5e9}\u{5bc}\u{5c2}"), (0xfb2e, "\u{5d0}\u{5b7}"), (0xfb2f, "\u{5d0}\u{5b8}"),
+    (0xfb30, "\u{5d0}\u{5bc}"), (0xfb31, "\u{5d1}\u{5bc}"), (0xfb32, "\u{5d2}\u{5bc}"),
+    (0xfb33, "\u{5d3}\u{5bc}"), (0xfb34, "\u{5d4}\u{5bc}"), (0xfb35, "\u{5d5}\u{5bc}"),
+    (0xfb36, "\u{5d6}\u{5bc}"), (0xfb38, "\u{5d8}\u{5bc}"), (0xfb39, "\u{5d9}\u{5bc}"),
+    (0xfb3a, "\u{5da}\u{5bc}"), (0xfb3b, "\u{5db}\u{5bc}"), (0xfb3c, "\u{5dc}\u{5bc}"),
+    (0xfb3e, "\u{5de}\u{5bc}"), (0xfb40, "\u{5e0}\u{5bc}"), (0xfb41, "\u{5e1}\u{5bc}"),
+    (0xfb43, "\u{5e3}\u{5bc}"), (0xfb44, "\u{5e4}\u{5bc}"), (0xfb46, "\u{5e6}\u{5bc}"),
+    (0xfb47, "\u{5e7}\u{5bc}"), (0xfb48, "\u{5e8}\u{5bc}"), (0xfb49, "\u{5e9}\u{5bc}"),
+    (0xfb4a, "\u{5ea}\u{5bc}"), (0xfb4b, "\u{5d5}\u{5b9}"), (0xfb4c, "\u{5d1}\u{5bf}"),
+    (0xfb4d, "\u{5db}\u{5bf}"), (0xfb4e, "\u{5e4}\u{5bf}"), (0x1109a, "\u{11099}\u{110ba}"),
+    (0x1109c, "\u{1109b}\u{110ba}"), (0x110ab, "\u{110a5}\u{110ba}"),
+    (0x1112e, "\u{11131}\u{11127}"), (0x1112f, "\u{11132}\u{11127}"),
+    (0x1134b, "\u{11347}\u{1133e}"), (0x1134c, "\u{11347}\u{11357}"),
+    (0x114bb, "\u{114b9}\u{114ba}"), (0x114bc, "\u{114b9}\u{114b0}"),
+    (0x114be, "\u{114b9}\u{114bd}"), (0x115ba, "\u{115b8}\u{115af}"),
+    (0x115bb, "\u{115b9}\u{115af}"), (0x11938, "\u{11935}\u{11930}"),
+    (0x1d15e, "\u{1d157}\u{1d165}"), (0x1d15f, "\u{1d158}\u{1d165}"),
+    (0x1d160, "\u{1d158}\u{1d165}\u{1d16e}"), (0x1d161, "\u{1d158}\u{1d165}\u{1d16f}"),
+    (0x1d162, "\u{1d158}\u{1d165}\u{1d170}"), (0x1d163, "\u{1d158}\u{1d165}\u{1d171}"),
+    (0x1d164, "\u{1d158}\u{1d165}\u{1d172}"), (0x1d1bb, "\u{1d1b9}\u{1d165}"),
+    (0x1d1bc, "\u{1d1ba}\u{1d165}"), (0x1d1bd, "\u{1d1b9}\u{1d165}\u{1d16e}"),
+    (0x1d1be, "\u{1d1ba}\u{1d165}\u{1d16e}"), (0x1d1bf, "\u{1d1b9}\u{1d165}\u{1d16f}"),
+    (0x1d1c0, "\u{1d1ba}\u{1d165}\u{1d16f}"), (0x2f800, "\u{4e3d}"), (0x2f801, "\u{4e38}"),
+    (0x2f802, "\u{4e41}"), (0x2f803, "\u{20122}"), (0x2f804, "\u{4f60}"), (0x2f805, "\u{4fae}"),
+    (0x2f806, "\u{4fbb}"), (0x2f807, "\u{5002}"), (0x2f808, "\u{507a}"), (0x2f809, "\u{5099}"),
+    (0x2f80a, "\u{50e7}"), (0x2f80b, "\u{50cf}"), (0x2f80c, "\u{349e}"), (0x2f80d, "\u{2063a}"),
+    (0x2f80e, "\u{514d}"), (0x2f80f, "\u{5154}"), (0x2f810, "\u{5164}"), (0x2f811, "\u{5177}"),
+    (0x2f812, "\u{2051c}"), (0x2f813, "\u{34b9}"), (0x2f814, "\u{5167}"), (0x2f815, "\u{518d}"),
+    (0x2f816, "\u{2054b}"), (0x2f817, "\u{5197}"), (0x2f818, "\u{51a4}"), (0x2f819, "\u{4ecc}"),
+    (0x2f81a, "\u{51ac}"), (0x2f81b, "\u{51b5}"), (0x2f81c, "\u{291df}"), (0x2f81d, "\u{51f5}"),
+    (0x2f81e, "\u{5203}"), (0x2f81f, "\u{34df}"), (0x2f820, "\u{523b}"), (0x2f821, "\u{5246}"),
+    (0x2f822, "\u{5272}"), (0x2f823, "\u{5277}"), (0x2f824, "\u{3515}"), (0x2f825, "\u{52c7}"),
+    (0x2f826, "\u{52c9}"), (0x2f827, "\u{52e4}"), (0x2f828, "\u{52fa}"), (0x2f829, "\u{5305}"),
+    (0x2f82a, "\u{5306}"), (0x2f82b, "\u{5317}"), (0x2f82c, "\u{5349}"), (0x2f82d, "\u{5351}"),
+    (0x2f82e, "\u{535a}"), (0x2f82f, "\u{5373}"), (0x2f830, "\u{537d}"), (0x2f831, "\u{537f}"),
+    (0x2f832, "\u{537f}"), (0x2f833, "\u{537f}"), (0x2f834, "\u{20a2c}"), (0x2f835, "\u{7070}"),
+    (0x2f836, "\u{53ca}"), (0x2f837, "\u{53df}"), (0x2f838, "\u{20b63}"), (0x2f839, "\u{53eb}"),
+    (0x2f83a, "\u{53f1}"), (0x2f83b, "\u{5406}"), (0x2f83c, "\u{549e}"), (0x2f83d, "\u{5438}"),
+    (0x2f83e, "\u{5448}"), (0x2f83f, "\u{5468}"), (0x2f840, "\u{54a2}"), (0x2f841, "\u{54f6}"),
+    (0x2f842, "\u{5510}"), (0x2f843, "\u{5553}"), (0x2f844, "\u{5563}"), (0x2f845, "\u{5584}"),
+    (0x2f846, "\u{5584}"), (0x2f847, "\u{5599}"), (0x2f848, "\u{55ab}"), (0x2f849, "\u{55b3}"),
+    (0x2f84a, "\u{55c2}"), (0x2f84b, "\u{5716}"), (0x2f84c, "\u{5606}"), (0x2f84d, "\u{5717}"),
+    (0x2f84e, "\u{5651}"), (0x2f84f, "\u{5674}"), (0x2f850, "\u{5207}"), (0x2f851, "\u{58ee}"),
+    (0x2f852, "\u{57ce}"), (0x2f853, "\u{57f4}"), (0x2f854, "\u{580d}"), (0x2f855, "\u{578b}"),
+    (0x2f856, "\u{5832}"), (0x2f857, "\u{5831}"), (0x2f858, "\u{58ac}"), (0x2f859, "\u{214e4}"),
+    (0x2f85a, "\u{58f2}"), (0x2f85b, "\u{58f7}"), (0x2f85c, "\u{5906}"), (0x2f85d, "\u{591a}"),
+    (0x2f85e, "\u{5922}"), (0x2f85f, "\u{5962}"), (0x2f860, "\u{216a8}"), (0x2f861, "\u{216ea}"),
+    (0x2f862, "\u{59ec}"), (0x2f863, "\u{5a1b}"), (0x2f864, "\u{5a27}"), (0x2f865, "\u{59d8}"),
+    (0x2f866, "\u{5a66}"), (0x2f867, "\u{36ee}"), (0x2f868, "\u{36fc}"), (0x2f869, "\u{5b08}"),
+    (0x2f86a, "\u{5b3e}"), (0x2f86b, "\u{5b3e}"), (0x2f86c, "\u{219c8}"), (0x2f86d, "\u{5bc3}"),
+    (0x2f86e, "\u{5bd8}"), (0x2f86f, "\u{5be7}"), (0x2f870, "\u{5bf3}"), (0x2f871, "\u{21b18}"),
+    (0x2f872, "\u{5bff}"), (0x2f873, "\u{5c06}"), (0x2f874, "\u{5f53}"), (0x2f875, "\u{5c22}"),
+    (0x2f876, "\u{3781}"), (0x2f877, "\u{5c60}"), (0x2f878, "\u{5c6e}"), (0x2f879, "\u{5cc0}"),
+    (0x2f87a, "\u{5c8d}"), (0x2f87b, "\u{21de4}"), (0x2f87c, "\u{5d43}"), (0x2f87d, "\u{21de6}"),
+    (0x2f87e, "\u{5d6e}"), (0x2f87f, "\u{5d6b}"), (0x2f880, "\u{5d7c}"), (0x2f881, "\u{5de1}"),
+    (0x2f882, "\u{5de2}"), (0x2f883, "\u{382f}"), (0x2f884, "\u{5dfd}"), (0x2f885, "\u{5e28}"),
+    (0x2f886, "\u{5e3d}"), (0x2f887, "\u{5e69}"), (0x2f888, "\u{3862}"), (0x2f889, "\u{22183}"),
+    (0x2f88a, "\u{387c}"), (0x2f88b, "\u{5eb0}"), (0x2f88c, "\u{5eb3}"), (0x2f88d, "\u{5eb6}"),
+    (0x2f88e, "\u{5eca}"), (0x2f88f, "\u{2a392}"), (0x2f890, "\u{5efe}"), (0x2f891, "\u{22331}"),
+    (0x2f892, "\u{22331}"), (0x2f893, "\u{8201}"), (0x2f894, "\u{5f22}"), (0x2f895, "\u{5f22}"),
+    (0x2f896, "\u{38c7}"), (0x2f897, "\u{232b8}"), (0x2f898, "\u{261da}"), (0x2f899, "\u{5f62}"),
+    (0x2f89a, "\u{5f6b}"), (0x2f89b, "\u{38e3}"), (0x2f89c, "\u{5f9a}"), (0x2f89d, "\u{5fcd}"),
+    (0x2f89e, "\u{5fd7}"), (0x2f89f, "\u{5ff9}"), (0x2f8a0, "\u{6081}"), (0x2f8a1, "\u{393a}"),
+    (0x2f8a2, "\u{391c}"), (0x2f8a3, "\u{6094}"), (0x2f8a4, "\u{226d4}"), (0x2f8a5, "\u{60c7}"),
+    (0x2f8a6, "\u{6148}"), (0x2f8a7, "\u{614c}"), (0x2f8a8, "\u{614e}"), (0x2f8a9, "\u{614c}"),
+    (0x2f8aa, "\u{617a}"), (0x2f8ab, "\u{618e}"), (0x2f8ac, "\u{61b2}"), (0x2f8ad, "\u{61a4}"),
+    (0x2f8ae, "\u{61af}"), (0x2f8af, "\u{61de}"), (0x2f8b0, "\u{61f2}"), (0x2f8b1, "\u{61f6}"),
+    (0x2f8b2, "\u{6210}"), (0x2f8b3, "\u{621b}"), (0x2f8b4, "\u{625d}"), (0x2f8b5, "\u{62b1}"),
+    (0x2f8b6, "\u{62d4}"), (0x2f8b7, "\u{6350}"), (0x2f8b8, "\u{22b0c}"), (0x2f8b9, "\u{633d}"),
+    (0x2f8ba, "\u{62fc}"), (0x2f8bb, "\u{6368}"), (0x2f8bc, "\u{6383}"), (0x2f8bd, "\u{63e4}"),
+    (0x2f8be, "\u{22bf1}"), (0x2f8bf, "\u{6422}"), (0x2f8c0, "\u{63c5}"), (0x2f8c1, "\u{63a9}"),
+    (0x2f8c2, "\u{3a2e}"), (0x2f8c3, "\u{6469}"), (0x2f8c4, "\u{647e}"), (0x2f8c5, "\u{649d}"),
+    (0x2f8c6, "\u{6477}"), (0x2f8c7, "\u{3a6c}"), (0x2f8c8, "\u{654f}"), (0x2f8c9, "\u{656c}"),
+    (0x2f8ca, "\u{2300a}"), (0x2f8cb, "\u{65e3}"), (0x2f8cc, "\u{66f8}"), (0x2f8cd, "\u{6649}"),
+    (0x2f8ce, "\u{3b19}"), (0x2f8cf, "\u{6691}"), (0x2f8d0, "\u{3b08}"), (0x2f8d1, "\u{3ae4}"),
+    (0x2f8d2, "\u{5192}"), (0x2f8d3, "\u{5195}"), (0x2f8d4, "\u{6700}"), (0x2f8d5, "\u{669c}"),
+    (0x2f8d6, "\u{80ad}"), (0x2f8d7, "\u{43d9}"), (0x2f8d8, "\u{6717}"), (0x2f8d9, "\u{671b}"),
+    (0x2f8da, "\u{6721}"), (0x2f8db, "\u{675e}"), (0x2f8dc, "\u{6753}"), (0x2f8dd, "\u{233c3}"),
+    (0x2f8de, "\u{3b49}"), (0x2f8df, "\u{67fa}"), (0x2f8e0, "\u{6785}"), (0x2f8e1, "\u{6852}"),
+    (0x2f8e2, "\u{6885}"), (0x2f8e3, "\u{2346d}"), (0x2f8e4, "\u{688e}"), (0x2f8e5, "\u{681f}"),
+    (0x2f8e6, "\u{6914}"), (0x2f8e7, "\u{3b9d}"), (0x2f8e8, "\u{6942}"), (0x2f8e9, "\u{69a3}"),
+    (0x2f8ea, "\u{69ea}"), (0x2f8eb, "\u{6aa8}"), (0x2f8ec, "\u{236a3}"), (0x2f8ed, "\u{6adb}"),
+    (0x2f8ee, "\u{3c18}"), (0x2f8ef, "\u{6b21}"), (0x2f8f0, "\u{238a7}"), (0x2f8f1, "\u{6b54}"),
+    (0x2f8f2, "\u{3c4e}"), (0x2f8f3, "\u{6b72}"), (0x2f8f4, "\u{6b9f}"), (0x2f8f5, "\u{6bba}"),
+    (0x2f8f6, "\u{6bbb}"), (0x2f8f7, "\u{23a8d}"), (0x2f8f8, "\u{21d0b}"), (0x2f8f9, "\u{23afa}"),
+    (0x2f8fa, "\u{6c4e}"), (0x2f8fb, "\u{23cbc}"), (0x2f8fc, "\u{6cbf}"), (0x2f8fd, "\u{6ccd}"),
+    (0x2f8fe, "\u{6c67}"), (0x2f8ff, "\u{6d16}"), (0x2f900, "\u{6d3e}"), (0x2f901, "\u{6d77}"),
+    (0x2f902, "\u{6d41}"), (0x2f903, "\u{6d69}"), (0x2f904, "\u{6d78}"), (0x2f905, "\u{6d85}"),
+    (0x2f906, "\u{23d1e}"), (0x2f907, "\u{6d34}"), (0x2f908, "\u{6e2f}"), (0x2f909, "\u{6e6e}"),
+    (0x2f90a, "\u{3d33}"), (0x2f90b, "\u{6ecb}"), (0x2f90c, "\u{6ec7}"), (0x2f90d, "\u{23ed1}"),
+    (0x2f90e, "\u{6df9}"), (0x2f90f, "\u{6f6e}"), (0x2f910, "\u{23f5e}"), (0x2f911, "\u{23f8e}"),
+    (0x2f912, "\u{6fc6}"), (0x2f913, "\u{7039}"), (0x2f914, "\u{701e}"), (0x2f915, "\u{701b}"),
+    (0x2f916, "\u{3d96}"), (0x2f917, "\u{704a}"), (0x2f918, "\u{707d}"), (0x2f919, "\u{7077}"),
+    (0x2f91a, "\u{70ad}"), (0x2f91b, "\u{20525}"), (0x2f91c, "\u{7145}"), (0x2f91d, "\u{24263}"),
+    (0x2f91e, "\u{719c}"), (0x2f91f, "\u{243ab}"), (0x2f920, "\u{7228}"), (0x2f921, "\u{7235}"),
+    (0x2f922, "\u{7250}"), (0x2f923, "\u{24608}"), (0x2f924, "\u{7280}"), (0x2f925, "\u{7295}"),
+    (0x2f926, "\u{24735}"), (0x2f927, "\u{24814}"), (0x2f928, "\u{737a}"), (0x2f929, "\u{738b}"),
+    (0x2f92a, "\u{3eac}"), (0x2f92b, "\u{73a5}"), (0x2f92c, "\u{3eb8}"), (0x2f92d, "\u{3eb8}"),
+    (0x2f92e, "\u{7447}"), (0x2f92f, "\u{745c}"), (0x2f930, "\u{7471}"), (0x2f931, "\u{7485}"),
+    (0x2f932, "\u{74ca}"), (0x2f933, "\u{3f1b}"), (0x2f934, "\u{7524}"), (0x2f935, "\u{24c36}"),
+    (0x2f936, "\u{753e}"), (0x2f937, "\u{24c92}"), (0x2f938, "\u{7570}"), (0x2f939, "\u{2219f}"),
+    (0x2f93a, "\u{7610}"), (0x2f93b, "\u{24fa1}"), (0x2f93c, "\u{24fb8}"), (0x2f93d, "\u{25044}"),
+    (0x2f93e, "\u{3ffc}"), (0x2f93f, "\u{4008}"), (0x2f940, "\u{76f4}"), (0x2f941, "\u{250f3}"),
+    (0x2f942, "\u{250f2}"), (0x2f943, "\u{25119}"), (0x2f944, "\u{25133}"), (0x2f945, "\u{771e}"),
+    (0x2f946, "\u{771f}"), (0x2f947, "\u{771f}"), (0x2f948, "\u{774a}"), (0x2f949, "\u{4039}"),
+    (0x2f94a, "\u{778b}"), (0x2f94b, "\u{4046}"), (0x2f94c, "\u{4096}"), (0x2f94d, "\u{2541d}"),
+    (0x2f94e, "\u{784e}"), (0x2f94f, "\u{788c}"), (0x2f950, "\u{78cc}"), (0x2f951, "\u{40e3}"),
+    (0x2f952, "\u{25626}"), (0x2f953, "\u{7956}"), (0x2f954, "\u{2569a}"), (0x2f955, "\u{256c5}"),
+    (0x2f956, "\u{798f}"), (0x2f957, "\u{79eb}"), (0x2f958, "\u{412f}"), (0x2f959, "\u{7a40}"),
+    (0x2f95a, "\u{7a4a}"), (0x2f95b, "\u{7a4f}"), (0x2f95c, "\u{2597c}"), (0x2f95d, "\u{25aa7}"),
+    (0x2f95e, "\u{25aa7}"), (0x2f95f, "\u{7aee}"), (0x2f960, "\u{4202}"), (0x2f961, "\u{25bab}"),
+    (0x2f962, "\u{7bc6}"), (0x2f963, "\u{7bc9}"), (0x2f964, "\u{4227}"), (0x2f965, "\u{25c80}"),
+    (0x2f966, "\u{7cd2}"), (0x2f967, "\u{42a0}"), (0x2f968, "\u{7ce8}"), (0x2f969, "\u{7ce3}"),
+    (0x2f96a, "\u{7d00}"), (0x2f96b, "\u{25f86}"), (0x2f96c, "\u{7d63}"), (0x2f96d, "\u{4301}"),
+    (0x2f96e, "\u{7dc7}"), (0x2f96f, "\u{7e02}"), (0x2f970, "\u{7e45}"), (0x2f971, "\u{4334}"),
+    (0x2f972, "\u{26228}"), (0x2f973, "\u{26247}"), (0x2f974, "\u{4359}"), (0x2f975, "\u{262d9}"),
+    (0x2f976, "\u{7f7a}"), (0x2f977, "\u{2633e}"), (0x2f978, "\u{7f95}"), (0x2f979, "\u{7ffa}"),
+    (0x2f97a, "\u{8005}"), (0x2f97b, "\u{264da}"), (0x2f97c, "\u{26523}"), (0x2f97d, "\u{8060}"),
+    (0x2f97e, "\u{265a8}"), (0x2f97f, "\u{8070}"), (0x2f980, "\u{2335f}"), (0x2f981, "\u{43d5}"),
+    (0x2f982, "\u{80b2}"), (0x2f983, "\u{8103}"), (0x2f984, "\u{440b}"), (0x2f985, "\u{813e}"),
+    (0x2f986, "\u{5ab5}"), (0x2f987, "\u{267a7}"), (0x2f988, "\u{267b5}"), (0x2f989, "\u{23393}"),
+    (0x2f98a, "\u{2339c}"), (0x2f98b, "\u{8201}"), (0x2f98c, "\u{8204}"), (0x2f98d, "\u{8f9e}"),
+    (0x2f98e, "\u{446b}"), (0x2f98f, "\u{8291}"), (0x2f990, "\u{828b}"), (0x2f991, "\u{829d}"),
+    (0x2f992, "\u{52b3}"), (0x2f993, "\u{82b1}"), (0x2f994, "\u{82b3}"), (0x2f995, "\u{82bd}"),
+    (0x2f996, "\u{82e6}"), (0x2f997, "\u{26b3c}"), (0x2f998, "\u{82e5}"), (0x2f999, "\u{831d}"),
+    (0x2f99a, "\u{8363}"), (0x2f99b, "\u{83ad}"), (0x2f99c, "\u{8323}"), (0x2f99d, "\u{83bd}"),
+    (0x2f99e, "\u{83e7}"), (0x2f99f, "\u{8457}"), (0x2f9a0, "\u{8353}"), (0x2f9a1, "\u{83ca}"),
+    (0x2f9a2, "\u{83cc}"), (0x2f9a3, "\u{83dc}"), (0x2f9a4, "\u{26c36}"), (0x2f9a5, "\u{26d6b}"),
+    (0x2f9a6, "\u{26cd5}"), (0x2f9a7, "\u{452b}"), (0x2f9a8, "\u{84f1}"), (0x2f9a9, "\u{84f3}"),
+    (0x2f9aa, "\u{8516}"), (0x2f9ab, "\u{273ca}"), (0x2f9ac, "\u{8564}"), (0x2f9ad, "\u{26f2c}"),
+    (0x2f9ae, "\u{455d}"), (0x2f9af, "\u{4561}"), (0x2f9b0, "\u{26fb1}"), (0x2f9b1, "\u{270d2}"),
+    (0x2f9b2, "\u{456b}"), (0x2f9b3, "\u{8650}"), (0x2f9b4, "\u{865c}"), (0x2f9b5, "\u{8667}"),
+    (0x2f9b6, "\u{8669}"), (0x2f9b7, "\u{86a9}"), (0x2f9b8, "\u{8688}"), (0x2f9b9, "\u{870e}"),
+    (0x2f9ba, "\u{86e2}"), (0x2f9bb, "\u{8779}"), (0x2f9bc, "\u{8728}"), (0x2f9bd, "\u{876b}"),
+    (0x2f9be, "\u{8786}"), (0x2f9bf, "\u{45d7}"), (0x2f9c0, "\u{87e1}"), (0x2f9c1, "\u{8801}"),
+    (0x2f9c2, "\u{45f9}"), (0x2f9c3, "\u{8860}"), (0x2f9c4, "\u{8863}"), (0x2f9c5, "\u{27667}"),
+    (0x2f9c6, "\u{88d7}"), (0x2f9c7, "\u{88de}"), (0x2f9c8, "\u{4635}"), (0x2f9c9, "\u{88fa}"),
+    (0x2f9ca, "\u{34bb}"), (0x2f9cb, "\u{278ae}"), (0x2f9cc, "\u{27966}"), (0x2f9cd, "\u{46be}"),
+    (0x2f9ce, "\u{46c7}"), (0x2f9cf, "\u{8aa0}"), (0x2f9d0, "\u{8aed}"), (0x2f9d1, "\u{8b8a}"),
+    (0x2f9d2, "\u{8c55}"), (0x2f9d3, "\u{27ca8}"), (0x2f9d4, "\u{8cab}"), (0x2f9d5, "\u{8cc1}"),
+    (0x2f9d6, "\u{8d1b}"), (0x2f9d7, "\u{8d77}"), (0x2f9d8, "\u{27f2f}"), (0x2f9d9, "\u{20804}"),
+    (0x2f9da, "\u{8dcb}"), (0x2f9db, "\u{8dbc}"), (0x2f9dc, "\u{8df0}"), (0x2f9dd, "\u{208de}"),
+    (0x2f9de, "\u{8ed4}"), (0x2f9df, "\u{8f38}"), (0x2f9e0, "\u{285d2}"), (0x2f9e1, "\u{285ed}"),
+    (0x2f9e2, "\u{9094}"), (0x2f9e3, "\u{90f1}"), (0x2f9e4, "\u{9111}"), (0x2f9e5, "\u{2872e}"),
+    (0x2f9e6, "\u{911b}"), (0x2f9e7, "\u{9238}"), (0x2f9e8, "\u{92d7}"), (0x2f9e9, "\u{92d8}"),
+    (0x2f9ea, "\u{927c}"), (0x2f9eb, "\u{93f9}"), (0x2f9ec, "\u{9415}"), (0x2f9ed, "\u{28bfa}"),
+    (0x2f9ee, "\u{958b}"), (0x2f9ef, "\u{4995}"), (0x2f9f0, "\u{95b7}"), (0x2f9f1, "\u{28d77}"),
+    (0x2f9f2, "\u{49e6}"), (0x2f9f3, "\u{96c3}"), (0x2f9f4, "\u{5db2}"), (0x2f9f5, "\u{9723}"),
+    (0x2f9f6, "\u{29145}"), (0x2f9f7, "\u{2921a}"), (0x2f9f8, "\u{4a6e}"), (0x2f9f9, "\u{4a76}"),
+    (0x2f9fa, "\u{97e0}"), (0x2f9fb, "\u{2940a}"), (0x2f9fc, "\u{4ab2}"), (0x2f9fd, "\u{29496}"),
+    (0x2f9fe, "\u{980b}"), (0x2f9ff, "\u{980b}"), (0x2fa00, "\u{9829}"), (0x2fa01, "\u{295b6}"),
+    (0x2fa02, "\u{98e2}"), (0x2fa03, "\u{4b33}"), (0x2fa04, "\u{9929}"), (0x2fa05, "\u{99a7}"),
+    (0x2fa06, "\u{99c2}"), (0x2fa07, "\u{99fe}"), (0x2fa08, "\u{4bce}"), (0x2fa09, "\u{29b30}"),
+    (0x2fa0a, "\u{9b12}"), (0x2fa0b, "\u{9c40}"), (0x2fa0c, "\u{9cfd}"), (0x2fa0d, "\u{4cce}"),
+    (0x2fa0e, "\u{4ced}"), (0x2fa0f, "\u{9d67}"), (0x2fa10, "\u{2a0ce}"), (0x2fa11, "\u{4cf8}"),
+    (0x2fa12, "\u{2a105}"), (0x2fa13, "\u{2a20e}"), (0x2fa14, "\u{2a291}"), (0x2fa15, "\u{9ebb}"),
+    (0x2fa16, "\u{4d56}"), (0x2fa17, "\u{9ef9}"), (0x2fa18, "\u{9efe}"), (0x2fa19, "\u{9f05}"),
+    (0x2fa1a, "\u{9f0f}"), (0x2fa1b, "\u{9f16}"), (0x2fa1c, "\u{9f3b}"), (0x2fa1d, "\u{2a600}"),
+];
+
+// Chars whose full compatibility decomposition differs from the canonical one
+#[rustfmt::skip]
+const COMPATIBILITY: &[(u32, &str)] = &[
+    (0xa0, " "), (0xa8, " \u{308}"), (0xaa, "a"), (0xaf, " \u{304}"), (0xb2, "2"), (0xb3, "3"),
+    (0xb4, " \u{301}"), (0xb5, "\u{3bc}"), (0xb8, " \u{327}"), (0xb9, "1"), (0xba, "o"),
+    (0xbc, "1\u{2044}4"), (0xbd, "1\u{2044}2"), (0xbe, "3\u{2044}4"), (0x132, "IJ"), (0x133, "ij"),
+    (0x13f, "L\u{b7}"), (0x140, "l\u{b7}"), (0x149, "\u{2bc}n"), (0x17f, "s"),
+    (0x1c4, "DZ\u{30c}"), (0x1c5, "Dz\u{30c}"), (0x1c6, "dz\u{30c}"), (0x1c7, "LJ"), (0x1c8, "Lj"),
+    (0x1c9, "lj"), (0x1ca, "NJ"), (0x1cb, "Nj"), (0x1cc, "nj"), (0x1f1, "DZ"), (0x1f2, "Dz"),
+    (0x1f3, "dz"), (0x2b0, "h"), (0x2b1, "\u{266}"), (0x2b2, "j"), (0x2b3, "r"),
+    (0x2b4, "\u{279}"), (0x2b5, "\u{27b}"), (0x2b6, "\u{281}"), (0x2b7, "w"), (0x2b8, "y"),
+    (0x2d8, " \u{306}"), (0x2d9, " \u{307}"), (0x2da, " \u{30a}"), (0x2db, " \u{328}"),
+    (0x2dc, " \u{303}"), (0x2dd, " \u{30b}"), (0x2e0, "\u{263}"), (0x2e1, "l"), (0x2e2, "s"),
+    (0x2e3, "x"), (0x2e4, "\u{295}"), (0x37a, " \u{345}"), (0x384, " \u{301}"),
+    (0x385, " \u{308}\u{301}"), (0x3d0, "\u{3b2}"), (0x3d1, "\u{3b8}"), (0x3d2, "\u{3a5}"),
+    (0x3d3, "\u{3a5}\u{301}"), (0x3d4, "\u{3a5}\u{308}"), (0x3d5, "\u{3c6}"), (0x3d6, "\u{3c0}"),
+    (0x3f0, "\u{3ba}"), (0x3f1, "\u{3c1}"), (0x3f2, "\u{3c2}"), (0x3f4, "\u{398}"),
+    (0x3f5, "\u{3b5}"), (0x3f9, "\u{3a3}"), (0x587, "\u{565}\u{582}"), (0x675, "\u{627}\u{674}"),
+    (0x676, "\u{648}\u{674}"), (0x677, "\u{6c7}\u{674}"), (0x678, "\u{64a}\u{674}"),
+    (0xe33, "\u{e4d}\u{e32}"), (0xeb3, "\u{ecd}\u{eb2}"), (0xedc, "\u{eab}\u{e99}"),
+    (0xedd, "\u{eab}\u{ea1}"), (0xf0c, "\u{f0b}"), (0xf77, "\u{fb2}\u{f71}\u{f80}"),
+    (0xf79, "\u{fb3}\u{f71}\u{f80}"), (0x10fc, "\u{10dc}"), (0x1d2c, "A"), (0x1d2d, "\u{c6}"),
+    (0x1d2e, "B"), (0x1d30, "D"), (0x1d31, "E"), (0x1d32, "\u{18e}"), (0x1d33, "G"), (0x1d34, "H"),
+    (0x1d35, "I"), (0x1d36, "J"), (0x1d37, "K"), (0x1d38, "L"), (0x1d39, "M"), (0x1d3a, "N"),
+    (0x1d3c, "O"), (0x1d3d, "\u{222}"), (0x1d3e, "P"), (0x1d3f, "R"), (0x1d40, "T"), (0x1d41, "U"),
+    (0x1d42, "W"), (0x1d43, "a"), (0x1d44, "\u{250}"), (0x1d45, "\u{251}"), (0x1d46, "\u{1d02}"),
+    (0x1d47, "b"), (0x1d48, "d"), (0x1d49, "e"), (0x1d4a, "\u{259}"), (0x1d4b, "\u{25b}"),
+    (0x1d4c, "\u{25c}"), (0x1d4d, "g"), (0x1d4f, "k"), (0x1d50, "m"), (0x1d51, "\u{14b}"),
+    (0x1d52, "o"), (0x1d53, "\u{254}"), (0x1d54, "\u{1d16}"), (0x1d55, "\u{1d17}"), (0x1d56, "p"),
+    (0x1d57, "t"), (0x1d58, "u"), (0x1d59, "\u{1d1d}"), (0x1d5a, "\u{26f}"), (0x1d5b, "v"),
+    (0x1d5c, "\u{1d25}"), (0x1d5d, "\u{3b2}"), (0x1d5e, "\u{3b3}"), (0x1d5f, "\u{3b4}"),
+    (0x1d60, "\u{3c6}"), (0x1d61, "\u{3c7}"), (0x1d62, "i"), (0x1d63, "r"), (0x1d64, "u"),
+    (0x1d65, "v"), (0x1d66, "\u{3b2}"), (0x1d67, "\u{3b3}"), (0x1d68, "\u{3c1}"),
+    (0x1d69, "\u{3c6}"), (0x1d6a, "\u{3c7}"), (0x1d78, "\u{43d}"), (0x1d9b, "\u{252}"),
+    (0x1d9c, "c"), (0x1d9d, "\u{255}"), (0x1d9e, "\u{f0}"), (0x1d9f, "\u{25c}"), (0x1da0, "f"),
+    (0x1da1, "\u{25f}"), (0x1da2, "\u{261}"), (0x1da3, "\u{265}"), (0x1da4, "\u{268}"),
+    (0x1da5, "\u{269}"), (0x1da6, "\u{26a}"), (0x1da7, "\u{1d7b}"), (0x1da8, "\u{29d}"),
+    (0x1da9, "\u{26d}"), (0x1daa, "\u{1d85}"), (0x1dab, "\u{29f}"), (0x1dac, "\u{271}"),
+    (0x1dad, "\u{270}"), (0x1dae, "\u{272}"), (0x1daf, "\u{273}"), (0x1db0, "\u{274}"),
+    (0x1db1, "\u{275}"), (0x1db2, "\u{278}"), (0x1db3, "\u{282}"), (0x1db4, "\u{283}"),
+    (0x1db5, "\u{1ab}"), (0x1db6, "\u{289}"), (0x1db7, "\u{28a}"), (0x1db8, "\u{1d1c}"),
+    (0x1db9, "\u{28b}"), (0x1dba, "\u{28c}"), (0x1dbb, "z"), (0x1dbc, "\u{290}"),
+    (0x1dbd, "\u{291}"), (0x1dbe, "\u{292}"), (0x1dbf, "\u{3b8}"), (0x1e9a, "a\u{2be}"),
+    (0x1e9b, "s\u{307}"), (0x1fbd, " \u{313}"), (0x1fbf, " \u{313}"), (0x1fc0, " \u{342}"),
+    (0x1fc1, " \u{308}\u{342}"), (0x1fcd, " \u{313}\u{300}"), (0x1fce, " \u{313}\u{301}"),
+    (0x1fcf, " \u{313}\u{342}"), (0x1fdd, " \u{314}\u{300}"), (0x1fde, " \u{314}\u{301}"),
+    (0x1fdf, " \u{314}\u{342}"), (0x1fed, " \u{308}\u{300}"), (0x1fee, " \u{308}\u{301}"),
+    (0x1ffd, " \u{301}"), (0x1ffe, " \u{314}"), (0x2000, " "), (0x2001, " "), (0x2002, " "),
+    (0x2003, " "), (0x2004, " "), (0x2005, " "), (0x2006, " "), (0x2007, " "), (0x2008, " "),
+    (0x2009, " "), (0x200a, " "), (0x2011, "\u{2010}"), (0x2017, " \u{333}"), (0x2024, "."),
+    (0x2025, ".."), (0x2026, "..."), (0x202f, " "), (0x2033, "\u{2032}\u{2032}"),
+    (0x2034, "\u{2032}\u{2032}\u{2032}"), (0x2036, "\u{2035}\u{2035}"),
+    (0x2037, "\u{2035}\u{2035}\u{2035}"), (0x203c, "!!"), (0x203e, " \u{305}"), (0x2047, "??"),
+    (0x2048, "?!"), (0x2049, "!?"), (0x2057, "\u{2032}\u{2032}\u{2032}\u{2032}"), (0x205f, " "),
+    (0x2070, "0"), (0x2071, "i"), (0x2074, "4"), (0x2075, "5"), (0x2076, "6"), (0x2077, "7"),
+    (0x2078, "8"), (0x2079, "9"), (0x207a, "+"), (0x207b, "\u{2212}"), (0x207c, "="),
+    (0x207d, "("), (0x207e, ")"), (0x207f, "n"), (0x2080, "0"), (0x2081, "1"), (0x2082, "2"),
+    (0x2083, "3"), (0x2084, "4"), (0x2085, "5"), (0x2086, "6"), (0x2087, "7"), (0x2088, "8"),
+    (0x2089, "9"), (0x208a, "+"), (0x208b, "\u{2212}"), (0x208c, "="), (0x208d, "("),
+    (0x208e, ")"), (0x2090, "a"), (0x2091, "e"), (0x2092, "o"), (0x2093, "x"), (0x2094, "\u{259}"),
+    (0x2095, "h"), (0x2096, "k"), (0x2097, "l"), (0x2098, "m"), (0x2099, "n"), (0x209a, "p"),
+    (0x209b, "s"), (0x209c, "t"), (0x20a8, "Rs"), (0x2100, "a/c"), (0x2101, "a/s"), (0x2102, "C"),
+    (0x2103, "\u{b0}C"), (0x2105, "c/o"), (0x2106, "c/u"), (0x2107, "\u{190}"),
+    (0x2109, "\u{b0}F"), (0x210a, "g"), (0x210b, "H"), (0x210c, "H"), (0x210d, "H"), (0x210e, "h"),
+    (0x210f, "\u{127}"), (0x2110, "I"), (0x2111, "I"), (0x2112, "L"), (0x2113, "l"), (0x2115, "N"),
+    (0x2116, "No"), (0x2119, "P"), (0x211a, "Q"), (0x211b, "R"), (0x211c, "R"), (0x211d, "R"),
+    (0x2120, "SM"), (0x2121, "TEL"), (0x2122, "TM"), (0x2124, "Z"), (0x2128, "Z"), (0x212c, "B"),
+    (0x212d, "C"), (0x212f, "e"), (0x2130, "E"), (0x2131, "F"), (0x2133, "M"), (0x2134, "o"),
+    (0x2135, "\u{5d0}"), (0x2136, "\u{5d1}"), (0x2137, "\u{5d2}"), (0x2138, "\u{5d3}"),
+    (0x2139, "i"), (0x213b, "FAX"), (0x213c, "\u{3c0}"), (0x213d, "\u{3b3}"), (0x213e, "\u{393}"),
+    (0x213f, "\u{3a0}"), (0x2140, "\u{2211}"), (0x2145, "D"), (0x2146, "d"), (0x2147, "e"),
+    (0x2148, "i"), (0x2149, "j"), (0x2150, "1\u{2044}7"), (0x2151, "1\u{2044}9"),
+    (0x2152, "1\u{2044}10"), (0x2153, "1\u{2044}3"), (0x2154, "2\u{2044}3"),
+    (0x2155, "1\u{2044}5"), (0x2156, "2\u{2044}5"), (0x2157, "3\u{2044}5"), (0x2158, "4\u{2044}5"),
+    (0x2159, "1\u{2044}6"), (0x215a, "5\u{2044}6"), (0x215b, "1\u{2044}8"), (0x215c, "3\u{2044}8"),
+    (0x215d, "5\u{2044}8"), (0x215e, "7\u{2044}8"), (0x215f, "1\u{2044}"), (0x2160, "I"),
+    (0x2161, "II"), (0x2162, "III"), (0x2163, "IV"), (0x2164, "V"), (0x2165, "VI"),
+    (0x2166, "VII"), (0x2167, "VIII"), (0x2168, "IX"), (0x2169, "X"), (0x216a, "XI"),
+    (0x216b, "XII"), (0x216c, "L"), (0x216d, "C"), (0x216e, "D"), (0x216f, "M"), (0x2170, "i"),
+    (0x2171, "ii"), (0x2172, "iii"), (0x2173, "iv"), (0x2174, "v"), (0x2175, "vi"),
+    (0x2176, "vii"), (0x2177, "viii"), (0x2178, "ix"), (0x2179, "x"), (0x217a, "xi"),
+    (0x217b, "xii"), (0x217c, "l"), (0x217d, "c"), (0x217e, "d"), (0x217f, "m"),
+    (0x2189, "0\u{2044}3"), (0x222c, "\u{222b}\u{222b}"), (0x222d, "\u{222b}\u{222b}\u{222b}"),
+    (0x222f, "\u{222e}\u{222e}"), (0x2230, "\u{222e}\u{222e}\u{222e}"), (0x2460, "1"),
+    (0x2461, "2"), (0x2462, "3"), (0x2463, "4"), (0x2464, "5"), (0x2465, "6"), (0x2466, "7"),
+    (0x2467, "8"), (0x2468, "9"), (0x2469, "10"), (0x246a, "11"), (0x246b, "12"), (0x246c, "13"),
+    (0x246d, "14"), (0x246e, "15"), (0x246f, "16"), (0x2470, "17"), (0x2471, "18"), (0x2472, "19"),
+    (0x2473, "20"), (0x2474, "(1)"), (0x2475, "(2)"), (0x2476, "(3)"), (0x2477, "(4)"),
+    (0x2478, "(5)"), (0x2479, "(6)"), (0x247a, "(7)"), (0x247b, "(8)"), (0x247c, "(9)"),
+    (0x247d, "(10)"), (0x247e, "(11)"), (0x247f, "(12)"), (0x2480, "(13)"), (0x2481, "(14)"),
+    (0x2482, "(15)"), (0x2483, "(16)"), (0x2484, "(17)"), (0x2485, "(18)"), (0x2486, "(19)"),
+    (0x2487, "(20)"), (0x2488, "1."), (0x2489, "2."), (0x248a, "3."), (0x248b, "4."),
+    (0x248c, "5."), (0x248d, "6."), (0x248e, "7."), (0x248f, "8."), (0x2490, "9."),
+    (0x2491, "10."), (0x2492, "11."), (0x2493, "12."), (0x2494, "13."), (0x2495, "14."),
+    (0x2496, "15."), (0x2497, "16."), (0x2498, "17."), (0x2499, "18."), (0x249a, "19."),
+    (0x249b, "20."), (0x249c, "(a)"), (0x249d, "(b)"), (0x249e, "(c)"), (0x249f, "(d)"),
+    (0x24a0, "(e)"), (0x24a1, "(f)"), (0x24a2, "(g)"), (0x24a3, "(h)"), (0x24a4, "(i)"),
+    (0x24a5, "(j)"), (0x24a6, "(k)"), (0x24a7, "(l)"), (0x24a8, "(m)"), (0x24a9, "(n)"),
+    (0x24aa, "(o)"), (0x24ab, "(p)"), (0x24ac, "(q)"), (0x24ad, "(r)"), (0x24ae, "(s)"),
+    (0x24af, "(t)"), (0x24b0, "(u)"), (0x24b1, "(v)"), (0x24b2, "(w)"), (0x24b3, "(x)"),
+    (0x24b4, "(y)"), (0x24b5, "(z)"), (0x24b6, "A"), (0x24b7, "B"), (0x24b8, "C"), (0x24b9, "D"),
+    (0x24ba, "E"), (0x24bb, "F"), (0x24bc, "G"), (0x24bd, "H"), (0x24be, "I"), (0x24bf, "J"),
+    (0x24c0, "K"), (0x24c1, "L"), (0x24c2, "M"), (0x24c3, "N"), (0x24c4, "O"), (0x24c5, "P"),
+    (0x24c6, "Q"), (0x24c7, "R"), (0x24c8, "S"), (0x24c9, "T"), (0x24ca, "U"), (0x24cb, "V"),
+    (0x24cc, "W"), (0x24cd, "X"), (0x24ce, "Y"), (0x24cf, "Z"), (0x24d0, "a"), (0x24d1, "b"),
+    (0x24d2, "c"), (0x24d3, "d"), (0x24d4, "e"), (0x24d5, "f"), (0x24d6, "g"), (0x24d7, "h"),
+    (0x24d8, "i"), (0x24d9, "j"), (0x24da, "k"), (0x24db, "l"), (0x24dc, "m"), (0x24dd, "n"),
+    (0x24de, "o"), (0x24df, "p"), (0x24e0, "q"), (0x24e1, "r"), (0x24e2, "s"), (0x24e3, "t"),
+    (0x24e4, "u"), (0x24e5, "v"), (0x24e6, "w"), (0x24e7, "x"), (0x24e8, "y"), (0x24e9, "z"),
+    (0x24ea, "0"), (0x2a0c, "\u{222b}\u{222b}\u{222b}\u{222b}"), (0x2a74, "::="), (0x2a75, "=="),
+    (0x2a76, "==="), (0x2c7c, "j"), (0x2c7d, "V"), (0x2d6f, "\u{2d61}"), (0x2e9f, "\u{6bcd}"),
+    (0x2ef3, "\u{9f9f}"), (0x2f00, "\u{4e00}"), (0x2f01, "\u{4e28}"), (0x2f02, "\u{4e36}"),
+    (0x2f03, "\u{4e3f}"), (0x2f04, "\u{4e59}"), (0x2f05, "\u{4e85}"), (0x2f06, "\u{4e8c}"),
+    (0x2f07, "\u{4ea0}"), (0x2f08, "\u{4eba}"), (0x2f09, "\u{513f}"), (0x2f0a, "\u{5165}"),
+    (0x2f0b, "\u{516b}"), (0x2f0c, "\u{5182}"), (0x2f0d, "\u{5196}"), (0x2f0e, "\u{51ab}"),
+    (0x2f0f, "\u{51e0}"), (0x2f10, "\u{51f5}"), (0x2f11, "\u{5200}"), (0x2f12, "\u{529b}"),
+    (0x2f13, "\u{52f9}"), (0x2f14, "\u{5315}"), (0x2f15, "\u{531a}"), (0x2f16, "\u{5338}"),
+    (0x2f17, "\u{5341}"), (0x2f18, "\u{535c}"), (0x2f19, "\u{5369}"), (0x2f1a, "\u{5382}"),
+    (0x2f1b, "\u{53b6}"), (0x2f1c, "\u{53c8}"), (0x2f1d, "\u{53e3}"), (0x2f1e, "\u{56d7}"),
+    (0x2f1f, "\u{571f}"), (0x2f20, "\u{58eb}"), (0x2f21, "\u{5902}"), (0x2f22, "\u{590a}"),
+    (0x2f23, "\u{5915}"), (0x2f24, "\u{5927}"), (0x2f25, "\u{5973}"), (0x2f26, "\u{5b50}"),
+    (0x2f27, "\u{5b80}"), (0x2f28, "\u{5bf8}"), (0x2f29, "\u{5c0f}"), (0x2f2a, "\u{5c22}"),
+    (0x2f2b, "\u{5c38}"), (0x2f2c, "\u{5c6e}"), (0x2f2d, "\u{5c71}"), (0x2f2e, "\u{5ddb}"),
+    (0x2f2f, "\u{5de5}"), (0x2f30, "\u{5df1}"), (0x2f31, "\u{5dfe}"), (0x2f32, "\u{5e72}"),
+    (0x2f33, "\u{5e7a}"), (0x2f34, "\u{5e7f}"), (0x2f35, "\u{5ef4}"), (0x2f36, "\u{5efe}"),
+    (0x2f37, "\u{5f0b}"), (0x2f38, "\u{5f13}"), (0x2f39, "\u{5f50}"), (0x2f3a, "\u{5f61}"),
+    (0x2f3b, "\u{5f73}"), (0x2f3c, "\u{5fc3}"), (0x2f3d, "\u{6208}"), (0x2f3e, "\u{6236}"),
+    (0x2f3f, "\u{624b}"), (0x2f40, "\u{652f}"), (0x2f41, "\u{6534}"), (0x2f42, "\u{6587}"),
+    (0x2f43, "\u{6597}"), (0x2f44, "\u{65a4}"), (0x2f45, "\u{65b9}"), (0x2f46, "\u{65e0}"),
+    (0x2f47, "\u{65e5}"), (0x2f48, "\u{66f0}"), (0x2f49, "\u{6708}"), (0x2f4a, "\u{6728}"),
+    (0x2f4b, "\u{6b20}"), (0x2f4c, "\u{6b62}"), (0x2f4d, "\u{6b79}"), (0x2f4e, "\u{6bb3}"),
+    (0x2f4f, "\u{6bcb}"), (0x2f50, "\u{6bd4}"), (0x2f51, "\u{6bdb}"), (0x2f52, "\u{6c0f}"),
+    (0x2f53, "\u{6c14}"), (0x2f54, "\u{6c34}"), (0x2f55, "\u{706b}"), (0x2f56, "\u{722a}"),
+    (0x2f57, "\u{7236}"), (0x2f58, "\u{723b}"), (0x2f59, "\u{723f}"), (0x2f5a, "\u{7247}"),
+    (0x2f5b, "\u{7259}"), (0x2f5c, "\u{725b}"), (0x2f5d, "\u{72ac}"), (0x2f5e, "\u{7384}"),
+    (0x2f5f, "\u{7389}"), (0x2f60, "\u{74dc}"), (0x2f61, "\u{74e6}"), (0x2f62, "\u{7518}"),
+    (0x2f63, "\u{751f}"), (0x2f64, "\u{7528}"), (0x2f65, "\u{7530}"), (0x2f66, "\u{758b}"),
+    (0x2f67, "\u{7592}"), (0x2f68, "\u{7676}"), (0x2f69, "\u{767d}"), (0x2f6a, "\u{76ae}"),
+    (0x2f6b, "\u{76bf}"), (0x2f6c, "\u{76ee}"), (0x2f6d, "\u{77db}"), (0x2f6e, "\u{77e2}"),
+    (0x2f6f, "\u{77f3}"), (0x2f70, "\u{793a}"), (0x2f71, "\u{79b8}"), (0x2f72, "\u{79be}"),
+    (0x2f73, "\u{7a74}"), (0x2f74, "\u{7acb}"), (0x2f75, "\u{7af9}"), (0x2f76, "\u{7c73}"),
+    (0x2f77, "\u{7cf8}"), (0x2f78, "\u{7f36}"), (0x2f79, "\u{7f51}"), (0x2f7a, "\u{7f8a}"),
+    (0x2f7b, "\u{7fbd}"), (0x2f7c, "\u{8001}"), (0x2f7d, "\u{800c}"), (0x2f7e, "\u{8012}"),
+    (0x2f7f, "\u{8033}"), (0x2f80, "\u{807f}"), (0x2f81, "\u{8089}"), (0x2f82, "\u{81e3}"),
+    (0x2f83, "\u{81ea}"), (0x2f84, "\u{81f3}"), (0x2f85, "\u{81fc}"), (0x2f86, "\u{820c}"),
+    (0x2f87, "\u{821b}"), (0x2f88, "\u{821f}"), (0x2f89, "\u{826e}"), (0x2f8a, "\u{8272}"),
+    (0x2f8b, "\u{8278}"), (0x2f8c, "\u{864d}"), (0x2f8d, "\u{866b}"), (0x2f8e, "\u{8840}"),
+    (0x2f8f, "\u{884c}"), (0x2f90, "\u{8863}"), (0x2f91, "\u{897e}"), (0x2f92, "\u{898b}"),
+    (0x2f93, "\u{89d2}"), (0x2f94, "\u{8a00}"), (0x2f95, "\u{8c37}"), (0x2f96, "\u{8c46}"),
+    (0x2f97, "\u{8c55}"), (0x2f98, "\u{8c78}"), (0x2f99, "\u{8c9d}"), (0x2f9a, "\u{8d64}"),
+    (0x2f9b, "\u{8d70}"), (0x2f9c, "\u{8db3}"), (0x2f9d, "\u{8eab}"), (0x2f9e, "\u{8eca}"),
+    (0x2f9f, "\u{8f9b}"), (0x2fa0, "\u{8fb0}"), (0x2fa1, "\u{8fb5}"), (0x2fa2, "\u{9091}"),
+    (0x2fa3, "\u{9149}"), (0x2fa4, "\u{91c6}"), (0x2fa5, "\u{91cc}"), (0x2fa6, "\u{91d1}"),
+    (0x2fa7, "\u{9577}"), (0x2fa8, "\u{9580}"), (0x2fa9, "\u{961c}"), (0x2faa, "\u{96b6}"),
+    (0x2fab, "\u{96b9}"), (0x2fac, "\u{96e8}"), (0x2fad, "\u{9751}"), (0x2fae, "\u{975e}"),
+    (0x2faf, "\u{9762}"), (0x2fb0, "\u{9769}"), (0x2fb1, "\u{97cb}"), (0x2fb2, "\u{97ed}"),
+    (0x2fb3, "\u{97f3}"), (0x2fb4, "\u{9801}"), (0x2fb5, "\u{98a8}"), (0x2fb6, "\u{98db}"),
+    (0x2fb7, "\u{98df}"), (0x2fb8, "\u{9996}"), (0x2fb9, "\u{9999}"), (0x2fba, "\u{99ac}"),
+    (0x2fbb, "\u{9aa8}"), (0x2fbc, "\u{9ad8}"), (0x2fbd, "\u{9adf}"), (0x2fbe, "\u{9b25}"),
+    (0x2fbf, "\u{9b2f}"), (0x2fc0, "\u{9b32}"), (0x2fc1, "\u{9b3c}"), (0x2fc2, "\u{9b5a}"),
+    (0x2fc3, "\u{9ce5}"), (0x2fc4, "\u{9e75}"), (0x2fc5, "\u{9e7f}"), (0x2fc6, "\u{9ea5}"),
+    (0x2fc7, "\u{9ebb}"), (0x2fc8, "\u{9ec3}"), (0x2fc9, "\u{9ecd}"), (0x2fca, "\u{9ed1}"),
+    (0x2fcb, "\u{9ef9}"), (0x2fcc, "\u{9efd}"), (0x2fcd, "\u{9f0e}"), (0x2fce, "\u{9f13}"),
+    (0x2fcf, "\u{9f20}"), (0x2fd0, "\u{9f3b}"), (0x2fd1, "\u{9f4a}"), (0x2fd2, "\u{9f52}"),
+    (0x2fd3, "\u{9f8d}"), (0x2fd4, "\u{9f9c}"), (0x2fd5, "\u{9fa0}"), (0x3000, " "),
+    (0x3036, "\u{3012}"), (0x3038, "\u{5341}"), (0x3039, "\u{5344}"), (0x303a, "\u{5345}"),
+    (0x309b, " \u{3099}"), (0x309c, " \u{309a}"), (0x309f, "\u{3088}\u{308a}"),
+    (0x30ff, "\u{30b3}\u{30c8}"), (0x3131, "\u{1100}"), (0x3132, "\u{1101}"), (0x3133, "\u{11aa}"),
+    (0x3134, "\u{1102}"), (0x3135, "\u{11ac}"), (0x3136, "\u{11ad}"), (0x3137, "\u{1103}"),
+    (0x3138, "\u{1104}"), (0x3139, "\u{1105}"), (0x313a, "\u{11b0}"), (0x313b, "\u{11b1}"),
+    (0x313c, "\u{11b2}"), (0x313d, "\u{11b3}"), (0x313e, "\u{11b4}"), (0x313f, "\u{11b5}"),
+    (0x3140, "\u{111a}"), (0x3141, "\u{1106}"), (0x3142, "\u{1107}"), (0x3143, "\u{1108}"),
+    (0x3144, "\u{1121}"), (0x3145, "\u{1109}"), (0x3146, "\u{110a}"), (0x3147, "\u{110b}"),
+    (0x3148, "\u{110c}"), (0x3149, "\u{110d}"), (0x314a, "\u{110e}"), (0x314b, "\u{110f}"),
+    (0x314c, "\u{1110}"), (0x314d, "\u{1111}"), (0x314e, "\u{1112}"), (0x314f, "\u{1161}"),
+    (0x3150, "\u{1162}"), (0x3151, "\u{1163}"), (0x3152, "\u{1164}"), (0x3153, "\u{1165}"),
+    (0x3154, "\u{1166}"), (0x3155, "\u{1167}"), (0x3156, "\u{1168}"), (0x3157, "\u{1169}"),
+    (0x3158, "\u{116a}"), (0x3159, "\u{116b}"), (0x315a, "\u{116c}"), (0x315b, "\u{116d}"),
+    (0x315c, "\u{116e}"), (0x315d, "\u{116f}"), (0x315e, "\u{1170}"), (0x315f, "\u{1171}"),
+    (0x3160, "\u{1172}"), (0x3161, "\u{1173}"), (0x3162, "\u{1174}"), (0x3163, "\u{1175}"),
+    (0x3164, "\u{1160}"), (0x3165, "\u{1114}"), (0x3166, "\u{1115}"), (0x3167, "\u{11c7}"),
+    (0x3168, "\u{11c8}"), (0x3169, "\u{11cc}"), (0x316a, "\u{11ce}"), (0x316b, "\u{11d3}"),
+    (0x316c, "\u{11d7}"), (0x316d, "\u{11d9}"), (0x316e, "\u{111c}"), (0x316f, "\u{11dd}"),
+    (0x3170, "\u{11df}"), (0x3171, "\u{111d}"), (0x3172, "\u{111e}"), (0x3173, "\u{1120}"),
+    (0x3174, "\u{1122}"), (0x3175, "\u{1123}"), (0x3176, "\u{1127}"), (0x3177, "\u{1129}"),
+    (0x3178, "\u{112b}"), (0x3179, "\u{112c}"), (0x317a, "\u{112d}"), (0x317b, "\u{112e}"),
+    (0x317c, "\u{112f}"), (0x317d, "\u{1132}"), (0x317e, "\u{1136}"), (0x317f, "\u{1140}"),
+    (0x3180, "\u{1147}"), (0x3181, "\u{114c}"), (0x3182, "\u{11f1}"), (0x3183, "\u{11f2}"),
+    (0x3184, "\u{1157}"), (0x3185, "\u{1158}"), (0x3186, "\u{1159}"), (0x3187, "\u{1184}"),
+    (0x3188, "\u{1185}"), (0x3189, "\u{1188}"), (0x318a, "\u{1191}"), (0x318b, "\u{1192}"),
+    (0x318c, "\u{1194}"), (0x318d, "\u{119e}"), (0x318e, "\u{11a1}"), (0x3192, "\u{4e00}"),
+    (0x3193, "\u{4e8c}"), (0x3194, "\u{4e09}"), (0x3195, "\u{56db}"), (0x3196, "\u{4e0a}"),
+    (0x3197, "\u{4e2d}"), (0x3198, "\u{4e0b}"), (0x3199, "\u{7532}"), (0x319a, "\u{4e59}"),
+    (0x319b, "\u{4e19}"), (0x319c, "\u{4e01}"), (0x319d, "\u{5929}"), (0x319e, "\u{5730}"),
+    (0x319f, "\u{4eba}"), (0x3200, "(\u{1100})"), (0x3201, "(\u{1102})"), (0x3202, "(\u{1103})"),
+    (0x3203, "(\u{1105})"), (0x3204, "(\u{1106})"), (0x3205, "(\u{1107})"), (0x3206, "(\u{1109})"),
+    (0x3207, "(\u{110b})"), (0x3208, "(\u{110c})"), (0x3209, "(\u{110e})"), (0x320a, "(\u{110f})"),
+    (0x320b, "(\u{1110})"), (0x320c, "(\u{1111})"), (0x320d, "(\u{1112})"),
+    (0x320e, "(\u{1100}\u{1161})"), (0x320f, "(\u{1102}\u{1161})"), (0x3210, "(\u{1103}\u{1161})"),
+    (0x3211, "(\u{1105}\u{1161})"), (0x3212, "(\u{1106}\u{1161})"), (0x3213, "(\u{1107}\u{1161})"),
+    (0x3214, "(\u{1109}\u{1161})"), (0x3215, "(\u{110b}\u{1161})"), (0x3216, "(\u{110c}\u{1161})"),
+    (0x3217, "(\u{110e}\u{1161})"), (0x3218, "(\u{110f}\u{1161})"), (0x3219, "(\u{1110}\u{1161})"),
+    (0x321a, "(\u{1111}\u{1161})"), (0x321b, "(\u{1112}\u{1161})"), (0x321c, "(\u{110c}\u{116e})"),
+    (0x321d, "(\u{110b}\u{1169}\u{110c}\u{1165}\u{11ab})"),
+    (0x321e, "(\u{110b}\u{1169}\u{1112}\u{116e})"), (0x3220, "(\u{4e00})"), (0x3221, "(\u{4e8c})"),
+    (0x3222, "(\u{4e09})"), (0x3223, "(\u{56db})"), (0x3224, "(\u{4e94})"), (0x3225, "(\u{516d})"),
+    (0x3226, "(\u{4e03})"), (0x3227, "(\u{516b})"), (0x3228, "(\u{4e5d})"), (0x3229, "(\u{5341})"),
+    (0x322a, "(\u{6708})"), (0x322b, "(\u{706b})"), (0x322c, "(\u{6c34})"), (0x322d, "(\u{6728})"),
+    (0x322e, "(\u{91d1})"), (0x322f, "(\u{571f})"), (0x3230, "(\u{65e5})"), (0x3231, "(\u{682a})"),
+    (0x3232, "(\u{6709})"), (0x3233, "(\u{793e})"), (0x3234, "(\u{540d})"), (0x3235, "(\u{7279})"),
+    (0x3236, "(\u{8ca1})"), (0x3237, "(\u{795d})"), (0x3238, "(\u{52b4})"), (0x3239, "(\u{4ee3})"),
+    (0x323a, "(\u{547c})"), (0x323b, "(\u{5b66})"), (0x323c, "(\u{76e3})"), (0x323d, "(\u{4f01})"),
+    (0x323e, "(\u{8cc7})"), (0x323f, "(\u{5354})"), (0x3240, "(\u{796d})"), (0x3241, "(\u{4f11})"),
+    (0x3242, "(\u{81ea})"), (0x3243, "(\u{81f3})"), (0x3244, "\u{554f}"), (0x3245, "\u{5e7c}"),
+    (0x3246, "\u{6587}"), (0x3247, "\u{7b8f}"), (0x3250, "PTE"), (0x3251, "21"), (0x3252, "22"),
+    (0x3253, "23"), (0x3254, "24"), (0x3255, "25"), (0x3256, "26"), (0x3257, "27"), (0x3258, "28"),
+    (0x3259, "29"), (0x325a, "30"), (0x325b, "31"), (0x325c, "32"), (0x325d, "33"), (0x325e, "34"),
+    (0x325f, "35"), (0x3260, "\u{1100}"), (0x3261, "\u{1102}"), (0x3262, "\u{1103}"),
+    (0x3263, "\u{1105}"), (0x3264, "\u{1106}"), (0x3265, "\u{1107}"), (0x3266, "\u{1109}"),
+    (0x3267, "\u{110b}"), (0x3268, "\u{110c}"), (0x3269, "\u{110e}"), (0x326a, "\u{110f}"),
+    (0x326b, "\u{1110}"), (0x326c, "\u{1111}"), (0x326d, "\u{1112}"), (0x326e, "\u{1100}\u{1161}"),
+    (0x326f, "\u{1102}\u{1161}"), (0x3270, "\u{1103}\u{1161}"), (0x3271, "\u{1105}\u{1161}"),
+    (0x3272, "\u{1106}\u{1161}"), (0x3273, "\u{1107}\u{1161}"), (0x3274, "\u{1109}\u{1161}"),
+    (0x3275, "\u{110b}\u{1161}"), (0x3276, "\u{110c}\u{1161}"), (0x3277, "\u{110e}\u{1161}"),
+    (0x3278, "\u{110f}\u{1161}"), (0x3279, "\u{1110}\u{1161}"), (0x327a, "\u{1111}\u{1161}"),
+    (0x327b, "\u{1112}\u{1161}"), (0x327c, "\u{110e}\u{1161}\u{11b7}\u{1100}\u{1169}"),
+    (0x327d, "\u{110c}\u{116e}\u{110b}\u{1174}"), (0x327e, "\u{110b}\u{116e}"),
+    (0x3280, "\u{4e00}"), (0x3281, "\u{4e8c}"), (0x3282, "\u{4e09}"), (0x3283, "\u{56db}"),
+    (0x3284, "\u{4e94}"), (0x3285, "\u{516d}"), (0x3286, "\u{4e03}"), (0x3287, "\u{516b}"),
+    (0x3288, "\u{4e5d}"), (0x3289, "\u{5341}"), (0x328a, "\u{6708}"), (0x328b, "\u{706b}"),
+    (0x328c, "\u{6c34}"), (0x328d, "\u{6728}"), (0x328e, "\u{91d1}"), (0x328f, "\u{571f}"),
+    (0x3290, "\u{65e5}"), (0x3291, "\u{682a}"), (0x3292, "\u{6709}"), (0x3293, "\u{793e}"),
+    (0x3294, "\u{540d}"), (0x3295, "\u{7279}"), (0x3296, "\u{8ca1}"), (0x3297, "\u{795d}"),
+    (0x3298, "\u{52b4}"), (0x3299, "\u{79d8}"), (0x329a, "\u{7537}"), (0x329b, "\u{5973}"),
+    (0x329c, "\u{9069}"), (0x329d, "\u{512a}"), (0x329e, "\u{5370}"), (0x329f, "\u{6ce8}"),
+    (0x32a0, "\u{9805}"), (0x32a1, "\u{4f11}"), (0x32a2, "\u{5199}"), (0x32a3, "\u{6b63}"),
+    (0x32a4, "\u{4e0a}"), (0x32a5, "\u{4e2d}"), (0x32a6, "\u{4e0b}"), (0x32a7, "\u{5de6}"),
+    (0x32a8, "\u{53f3}"), (0x32a9, "\u{533b}"), (0x32aa, "\u{5b97}"), (0x32ab, "\u{5b66}"),
+    (0x32ac, "\u{76e3}"), (0x32ad, "\u{4f01}"), (0x32ae, "\u{8cc7}"), (0x32af, "\u{5354}"),
+    (0x32b0, "\u{591c}"), (0x32b1, "36"), (0x32b2, "37"), (0x32b3, "38"), (0x32b4, "39"),
+    (0x32b5, "40"), (0x32b6, "41"), (0x32b7, "42"), (0x32b8, "43"), (0x32b9, "44"), (0x32ba, "45"),
+    (0x32bb, "46"), (0x32bc, "47"), (0x32bd, "48"), (0x32be, "49"), (0x32bf, "50"),
+    (0x32c0, "1\u{6708}"), (0x32c1, "2\u{6708}"), (0x32c2, "3\u{6708}"), (0x32c3, "4\u{6708}"),
+    (0x32c4, "5\u{6708}"), (0x32c5, "6\u{6708}"), (0x32c6, "7\u{6708}"), (0x32c7, "8\u{6708}"),
+    (0x32c8, "9\u{6708}"), (0x32c9, "10\u{6708}"), (0x32ca, "11\u{6708}"), (0x32cb, "12\u{6708}"),
+    (0x32cc, "Hg"), (0x32cd, "erg"), (0x32ce, "eV"), (0x32cf, "LTD"), (0x32d0, "\u{30a2}"),
+    (0x32d1, "\u{30a4}"), (0x32d2, "\u{30a6}"), (0x32d3, "\u{30a8}"), (0x32d4, "\u{30aa}"),
+    (0x32d5, "\u{30ab}"), (0x32d6, "\u{30ad}"), (0x32d7, "\u{30af}"), (0x32d8, "\u{30b1}"),
+    (0x32d9, "\u{30b3}"), (0x32da, "\u{30b5}"), (0x32db, "\u{30b7}"), (0x32dc, "\u{30b9}"),
+    (0x32dd, "\u{30bb}"), (0x32de, "\u{30bd}"), (0x32df, "\u{30bf}"), (0x32e0, "\u{30c1}"),
+    (0x32e1, "\u{30c4}"), (0x32e2, "\u{30c6}"), (0x32e3, "\u{30c8}"), (0x32e4, "\u{30ca}"),
+    (0x32e5, "\u{30cb}"), (0x32e6, "\u{30cc}"), (0x32e7, "\u{30cd}"), (0x32e8, "\u{30ce}"),
+    (0x32e9, "\u{30cf}"), (0x32ea, "\u{30d2}"), (0x32eb, "\u{30d5}"), (0x32ec, "\u{30d8}"),
+    (0x32ed, "\u{30db}"), (0x32ee, "\u{30de}"), (0x32ef, "\u{30df}"), (0x32f0, "\u{30e0}"),
+    (0x32f1, "\u{30e1}"), (0x32f2, "\u{30e2}"), (0x32f3, "\u{30e4}"), (0x32f4, "\u{30e6}"),
+    (0x32f5, "\u{30e8}"), (0x32f6, "\u{30e9}"), (0x32f7, "\u{30ea}"), (0x32f8, "\u{30eb}"),
+    (0x32f9, "\u{30ec}"), (0x32fa, "\u{30ed}"), (0x32fb, "\u{30ef}"), (0x32fc, "\u{30f0}"),
+    (0x32fd, "\u{30f1}"), (0x32fe, "\u{30f2}"), (0x32ff, "\u{4ee4}\u{548c}"),
+    (0x3300, "\u{30a2}\u{30cf}\u{309a}\u{30fc}\u{30c8}"),
+    (0x3301, "\u{30a2}\u{30eb}\u{30d5}\u{30a1}"),
+    (0x3302, "\u{30a2}\u{30f3}\u{30d8}\u{309a}\u{30a2}"), (0x3303, "\u{30a2}\u{30fc}\u{30eb}"),
+    (0x3304, "\u{30a4}\u{30cb}\u{30f3}\u{30af}\u{3099}"), (0x3305, "\u{30a4}\u{30f3}\u{30c1}"),
+    (0x3306, "\u{30a6}\u{30a9}\u{30f3}"),
+    (0x3307, "\u{30a8}\u{30b9}\u{30af}\u{30fc}\u{30c8}\u{3099}"),
+    (0x3308, "\u{30a8}\u{30fc}\u{30ab}\u{30fc}"), (0x3309, "\u{30aa}\u{30f3}\u{30b9}"),
+    (0x330a, "\u{30aa}\u{30fc}\u{30e0}"), (0x330b, "\u{30ab}\u{30a4}\u{30ea}"),
+    (0x330c, "\u{30ab}\u{30e9}\u{30c3}\u{30c8}"), (0x330d, "\u{30ab}\u{30ed}\u{30ea}\u{30fc}"),
+    (0x330e, "\u{30ab}\u{3099}\u{30ed}\u{30f3}"), (0x330f, "\u{30ab}\u{3099}\u{30f3}\u{30de}"),
+    (0x3310, "\u{30ad}\u{3099}\u{30ab}\u{3099}"), (0x3311, "\u{30ad}\u{3099}\u{30cb}\u{30fc}"),
+    (0x3312, "\u{30ad}\u{30e5}\u{30ea}\u{30fc}"),
+    (0x3313, "\u{30ad}\u{3099}\u{30eb}\u{30bf}\u{3099}\u{30fc}"), (0x3314, "\u{30ad}\u{30ed}"),
+    (0x3315, "\u{30ad}\u{30ed}\u{30af}\u{3099}\u{30e9}\u{30e0}"),
+    (0x3316, "\u{30ad}\u{30ed}\u{30e1}\u{30fc}\u{30c8}\u{30eb}"),
+    (0x3317, "\u{30ad}\u{30ed}\u{30ef}\u{30c3}\u{30c8}"),
+    (0x3318, "\u{30af}\u{3099}\u{30e9}\u{30e0}"),
+    (0x3319, "\u{30af}\u{3099}\u{30e9}\u{30e0}\u{30c8}\u{30f3}"),
+    (0x331a, "\u{30af}\u{30eb}\u{30bb}\u{3099}\u{30a4}\u{30ed}"),
+    (0x331b, "\u{30af}\u{30ed}\u{30fc}\u{30cd}"), (0x331c, "\u{30b1}\u{30fc}\u{30b9}"),
+    (0x331d, "\u{30b3}\u{30eb}\u{30ca}"), (0x331e, "\u{30b3}\u{30fc}\u{30db}\u{309a}"),
+    (0x331f, "\u{30b5}\u{30a4}\u{30af}\u{30eb}"),
+    (0x3320, "\u{30b5}\u{30f3}\u{30c1}\u{30fc}\u{30e0}"),
+    (0x3321, "\u{30b7}\u{30ea}\u{30f3}\u{30af}\u{3099}"), (0x3322, "\u{30bb}\u{30f3}\u{30c1}"),
+    (0x3323, "\u{30bb}\u{30f3}\u{30c8}"), (0x3324, "\u{30bf}\u{3099}\u{30fc}\u{30b9}"),
+    (0x3325, "\u{30c6}\u{3099}\u{30b7}"), (0x3326, "\u{30c8}\u{3099}\u{30eb}"),
+    (0x3327, "\u{30c8}\u{30f3}"), (0x3328, "\u{30ca}\u{30ce}"),
+    (0x3329, "\u{30ce}\u{30c3}\u{30c8}"), (0x332a, "\u{30cf}\u{30a4}\u{30c4}"),
+    (0x332b, "\u{30cf}\u{309a}\u{30fc}\u{30bb}\u{30f3}\u{30c8}"),
+    (0x332c, "\u{30cf}\u{309a}\u{30fc}\u{30c4}"),
+    (0x332d, "\u{30cf}\u{3099}\u{30fc}\u{30ec}\u{30eb}"),
+    (0x332e, "\u{30d2}\u{309a}\u{30a2}\u{30b9}\u{30c8}\u{30eb}"),
+    (0x332f, "\u{30d2}\u{309a}\u{30af}\u{30eb}"), (0x3330, "\u{30d2}\u{309a}\u{30b3}"),
+    (0x3331, "\u{30d2}\u{3099}\u{30eb}"),
+    (0x3332, "\u{30d5}\u{30a1}\u{30e9}\u{30c3}\u{30c8}\u{3099}"),
+    (0x3333, "\u{30d5}\u{30a3}\u{30fc}\u{30c8}"),
+    (0x3334, "\u{30d5}\u{3099}\u{30c3}\u{30b7}\u{30a7}\u{30eb}"),
+    (0x3335, "\u{30d5}\u{30e9}\u{30f3}"), (0x3336, "\u{30d8}\u{30af}\u{30bf}\u{30fc}\u{30eb}"),
+    (0x3337, "\u{30d8}\u{309a}\u{30bd}"), (0x3338, "\u{30d8}\u{309a}\u{30cb}\u{30d2}"),
+    (0x3339, "\u{30d8}\u{30eb}\u{30c4}"), (0x333a, "\u{30d8}\u{309a}\u{30f3}\u{30b9}"),
+    (0x333b, "\u{30d8}\u{309a}\u{30fc}\u{30b7}\u{3099}"),
+    (0x333c, "\u{30d8}\u{3099}\u{30fc}\u{30bf}"),
+    (0x333d, "\u{30db}\u{309a}\u{30a4}\u{30f3}\u{30c8}"),
+    (0x333e, "\u{30db}\u{3099}\u{30eb}\u{30c8}"), (0x333f, "\u{30db}\u{30f3}"),
+    (0x3340, "\u{30db}\u{309a}\u{30f3}\u{30c8}\u{3099}"), (0x3341, "\u{30db}\u{30fc}\u{30eb}"),
+    (0x3342, "\u{30db}\u{30fc}\u{30f3}"), (0x3343, "\u{30de}\u{30a4}\u{30af}\u{30ed}"),
+    (0x3344, "\u{30de}\u{30a4}\u{30eb}"), (0x3345, "\u{30de}\u{30c3}\u{30cf}"),
+    (0x3346, "\u{30de}\u{30eb}\u{30af}"), (0x3347, "\u{30de}\u{30f3}\u{30b7}\u{30e7}\u{30f3}"),
+    (0x3348, "\u{30df}\u{30af}\u{30ed}\u{30f3}"), (0x3349, "\u{30df}\u{30ea}"),
+    (0x334a, "\u{30df}\u{30ea}\u{30cf}\u{3099}\u{30fc}\u{30eb}"),
+    (0x334b, "\u{30e1}\u{30ab}\u{3099}"), (0x334c, "\u{30e1}\u{30ab}\u{3099}\u{30c8}\u{30f3}"),
+    (0x334d, "\u{30e1}\u{30fc}\u{30c8}\u{30eb}"), (0x334e, "\u{30e4}\u{30fc}\u{30c8}\u{3099}"),
+    (0x334f, "\u{30e4}\u{30fc}\u{30eb}"), (0x3350, "\u{30e6}\u{30a2}\u{30f3}"),
+    (0x3351, "\u{30ea}\u{30c3}\u{30c8}\u{30eb}"), (0x3352, "\u{30ea}\u{30e9}"),
+    (0x3353, "\u{30eb}\u{30d2}\u{309a}\u{30fc}"),
+    (0x3354, "\u{30eb}\u{30fc}\u{30d5}\u{3099}\u{30eb}"), (0x3355, "\u{30ec}\u{30e0}"),
+    (0x3356, "\u{30ec}\u{30f3}\u{30c8}\u{30b1}\u{3099}\u{30f3}"),
+    (0x3357, "\u{30ef}\u{30c3}\u{30c8}"), (0x3358, "0\u{70b9}"), (0x3359, "1\u{70b9}"),
+    (0x335a, "2\u{70b9}"), (0x335b, "3\u{70b9}"), (0x335c, "4\u{70b9}"), (0x335d, "5\u{70b9}"),
+    (0x335e, "6\u{70b9}"), (0x335f, "7\u{70b9}"), (0x3360, "8\u{70b9}"), (0x3361, "9\u{70b9}"),
+    (0x3362, "10\u{70b9}"), (0x3363, "11\u{70b9}"), (0x3364, "12\u{70b9}"), (0x3365, "13\u{70b9}"),
+    (0x3366, "14\u{70b9}"), (0x3367, "15\u{70b9}"), (0x3368, "16\u{70b9}"), (0x3369, "17\u{70b9}"),
+    (0x336a, "18\u{70b9}"), (0x336b, "19\u{70b9}"), (0x336c, "20\u{70b9}"), (0x336d, "21\u{70b9}"),
+    (0x336e, "22\u{70b9}"), (0x336f, "23\u{70b9}"), (0x3370, "24\u{70b9}"), (0x3371, "hPa"),
+    (0x3372, "da"), (0x3373, "AU"), (0x3374, "bar"), (0x3375, "oV"), (0x3376, "pc"),
+    (0x3377, "dm"), (0x3378, "dm2"), (0x3379, "dm3"), (0x337a, "IU"), (0x337b, "\u{5e73}\u{6210}"),
+    (0x337c, "\u{662d}\u{548c}"), (0x337d, "\u{5927}\u{6b63}"), (0x337e, "\u{660e}\u{6cbb}"),
+    (0x337f, "\u{682a}\u{5f0f}\u{4f1a}\u{793e}"), (0x3380, "pA"), (0x3381, "nA"),
+    (0x3382, "\u{3bc}A"), (0x3383, "mA"), (0x3384, "kA"), (0x3385, "KB"), (0x3386, "MB"),
+    (0x3387, "GB"), (0x3388, "cal"), (0x3389, "kcal"), (0x338a, "pF"), (0x338b, "nF"),
+    (0x338c, "\u{3bc}F"), (0x338d, "\u{3bc}g"), (0x338e, "mg"), (0x338f, "kg"), (0x3390, "Hz"),
+    (0x3391, "kHz"), (0x3392, "MHz"), (0x3393, "GHz"), (0x3394, "THz"), (0x3395, "\u{3bc}l"),
+    (0x3396, "ml"), (0x3397, "dl"), (0x3398, "kl"), (0x3399, "fm"), (0x339a, "nm"),
+    (0x339b, "\u{3bc}m"), (0x339c, "mm"), (0x339d, "cm"), (0x339e, "km"), (0x339f, "mm2"),
+    (0x33a0, "cm2"), (0x33a1, "m2"), (0x33a2, "km2"), (0x33a3, "mm3"), (0x33a4, "cm3"),
+    (0x33a5, "m3"), (0x33a6, "km3"), (0x33a7, "m\u{2215}s"), (0x33a8, "m\u{2215}s2"),
+    (0x33a9, "Pa"), (0x33aa, "kPa"), (0x33ab, "MPa"), (0x33ac, "GPa"), (0x33ad, "rad"),
+    (0x33ae, "rad\u{2215}s"), (0x33af, "rad\u{2215}s2"), (0x33b0, "ps"), (0x33b1, "ns"),
+    (0x33b2, "\u{3bc}s"), (0x33b3, "ms"), (0x33b4, "pV"), (0x33b5, "nV"), (0x33b6, "\u{3bc}V"),
+    (0x33b7, "mV"), (0x33b8, "kV"), (0x33b9, "MV"), (0x33ba, "pW"), (0x33bb, "nW"),
+    (0x33bc, "\u{3bc}W"), (0x33bd, "mW"), (0x33be, "kW"), (0x33bf, "MW"), (0x33c0, "k\u{3a9}"),
+    (0x33c1, "M\u{3a9}"), (0x33c2, "a.m."), (0x33c3, "Bq"), (0x33c4, "cc"), (0x33c5, "cd"),
+    (0x33c6, "C\u{2215}kg"), (0x33c7, "Co."), (0x33c8, "dB"), (0x33c9, "Gy"), (0x33ca, "ha"),
+    (0x33cb, "HP"), (0x33cc, "in"), (0x33cd, "KK"), (0x33ce, "KM"), (0x33cf, "kt"), (0x33d0, "lm"),
+    (0x33d1, "ln"), (0x33d2, "log"), (0x33d3, "lx"), (0x33d4, "mb"), (0x33d5, "mil"),
+    (0x33d6, "mol"), (0x33d7, "PH"), (0x33d8, "p.m."), (0x33d9, "PPM"), (0x33da, "PR"),
+    (0x33db, "sr"), (0x33dc, "Sv"), (0x33dd, "Wb"), (0x33de, "V\u{2215}m"), (0x33df, "A\u{2215}m"),
+    (0x33e0, "1\u{65e5}"), (0x33e1, "2\u{65e5}"), (0x33e2, "3\u{65e5}"), (0x33e3, "4\u{65e5}"),
+    (0x33e4, "5\u{65e5}"), (0x33e5, "6\u{65e5}"), (0x33e6, "7\u{65e5}"), (0x33e7, "8\u{65e5}"),
+    (0x33e8, "9\u{65e5}"), (0x33e9, "10\u{65e5}"), (0x33ea, "11\u{65e5}"), (0x33eb, "12\u{65e5}"),
+    (0x33ec, "13\u{65e5}"), (0x33ed, "14\u{65e5}"), (0x33ee, "15\u{65e5}"), (0x33ef, "16\u{65e5}"),
+    (0x33f0, "17\u{65e5}"), (0x33f1, "18\u{65e5}"), (0x33f2, "19\u{65e5}"), (0x33f3, "20\u{65e5}"),
+    (0x33f4, "21\u{65e5}"), (0x33f5, "22\u{65e5}"), (0x33f6, "23\u{65e5}"), (0x33f7, "24\u{65e5}"),
+    (0x33f8, "25\u{65e5}"), (0x33f9, "26\u{65e5}"), (0x33fa, "27\u{65e5}"), (0x33fb, "28\u{65e5}"),
+    (0x33fc, "29\u{65e5}"), (0x33fd, "30\u{65e5}"), (0x33fe, "31\u{65e5}"), (0x33ff, "gal"),
+    (0xa69c, "\u{44a}"), (0xa69d, "\u{44c}"), (0xa770, "\u{a76f}"), (0xa7f2, "C"), (0xa7f3, "F"),
+    (0xa7f4, "Q"), (0xa7f8, "\u{126}"), (0xa7f9, "\u{153}"), (0xab5c, "\u{a727}"),
+    (0xab5d, "\u{ab37}"), (0xab5e, "\u{26b}"), (0xab5f, "\u{ab52}"), (0xab69, "\u{28d}"),
+    (0xfb00, "ff"), (0xfb01, "fi"), (0xfb02, "fl"), (0xfb03, "ffi"), (0xfb04, "ffl"),
+    (0xfb05, "st"), (0xfb06, "st"), (0xfb13, "\u{574}\u{576}"), (0xfb14, "\u{574}\u{565}"),
+    (0xfb15, "\u{574}\u{56b}"), (0xfb16, "\u{57e}\u{576}"), (0xfb17, "\u{574}\u{56d}"),
+    (0xfb20, "\u{5e2}"), (0xfb21, "\u{5d0}"), (0xfb22, "\u{5d3}"), (0xfb23, "\u{5d4}"),
+    (0xfb24, "\u{5db}"), (0xfb25, "\u{5dc}"), (0xfb26, "\u{5dd}"), (0xfb27, "\u{5e8}"),
+    (0xfb28, "\u{5ea}"), (0xfb29, "+"), (0xfb4f, "\u{5d0}\u{5dc}"), (0xfb50, "\u{671}"),
+    (0xfb51, "\u{671}"), (0xfb52, "\u{67b}"), (0xfb53, "\u{67b}"), (0xfb54, "\u{67b}"),
+    (0xfb55, "\u{67b}"), (0xfb56, "\u{67e}"), (0xfb57, "\u{67e}"), (0xfb58, "\u{67e}"),
+    (0xfb59, "\u{67e}"), (0xfb5a, "\u{680}"), (0xfb5b, "\u{680}"), (0xfb5c, "\u{680}"),
+    (0xfb5d, "\u{680}"), (0xfb5e, "\u{67a}"), (0xfb5f, "\u{67a}"), (0xfb60, "\u{67a}"),
+    (0xfb61, "\u{67a}"), (0xfb62, "\u{67f}"), (0xfb63, "\u{67f}"), (0xfb64, "\u{67f}"),
+    (0xfb65, "\u{67f}"), (0xfb66, "\u{679}"), (0xfb67, "\u{679}"), (0xfb68, "\u{679}"),
+    (0xfb69, "\u{679}"), (0xfb6a, "\u{6a4}"), (0xfb6b, "\u{6a4}"), (0xfb6c, "\u{6a4}"),
+    (0xfb6d, "\u{6a4}"), (0xfb6e, "\u{6a6}"), (0xfb6f, "\u{6a6}"), (0xfb70, "\u{6a6}"),
+    (0xfb71, "\u{6a6}"), (0xfb72, "\u{684}"), (0xfb73, "\u{684}"), (0xfb74, "\u{684}"),
+    (0xfb75, "\u{684}"), (0xfb76, "\u{683}"), (0xfb77, "\u{683}"), (0xfb78, "\u{683}"),
+    (0xfb79, "\u{683}"), (0xfb7a, "\u{686}"), (0xfb7b, "\u{686}"), (0xfb7c, "\u{686}"),
+    (0xfb7d, "\u{686}"), (0xfb7e, "\u{687}"), (0xfb7f, "\u{687}"), (0xfb80, "\u{687}"),
+    (0xfb81, "\u{687}"), (0xfb82, "\u{68d}"), (0xfb83, "\u{68d}"), (0xfb84, "\u{68c}"),
+    (0xfb85, "\u{68c}"), (0xfb86, "\u{68e}"), (0xfb87, "\u{68e}"), (0xfb88, "\u{688}"),
+    (0xfb89, "\u{688}"), (0xfb8a, "\u{698}"), (0xfb8b, "\u{698}"), (0xfb8c, "\u{691}"),
+    (0xfb8d, "\u{691}"), (0xfb8e, "\u{6a9}"), (0xfb8f, "\u{6a9}"), (0xfb90, "\u{6a9}"),
+    (0xfb91, "\u{6a9}"), (0xfb92, "\u{6af}"), (0xfb93, "\u{6af}"), (0xfb94, "\u{6af}"),
+    (0xfb95, "\u{6af}"), (0xfb96, "\u{6b3}"), (0xfb97, "\u{6b3}"), (0xfb98, "\u{6b3}"),
+    (0xfb99, "\u{6b3}"), (0xfb9a, "\u{6b1}"), (0xfb9b, "\u{6b1}"), (0xfb9c, "\u{6b1}"),
+    (0xfb9d, "\u{6b1}"), (0xfb9e, "\u{6ba}"), (0xfb9f, "\u{6ba}"), (0xfba0, "\u{6bb}"),
+    (0xfba1, "\u{6bb}"), (0xfba2, "\u{6bb}"), (0xfba3, "\u{6bb}"), (0xfba4, "\u{6d5}\u{654}"),
+    (0xfba5, "\u{6d5}\u{654}"), (0xfba6, "\u{6c1}"), (0xfba7, "\u{6c1}"), (0xfba8, "\u{6c1}"),
+    (0xfba9, "\u{6c1}"), (0xfbaa, "\u{6be}"), (0xfbab, "\u{6be}"), (0xfbac, "\u{6be}"),
+    (0xfbad, "\u{6be}"), (0xfbae, "\u{6d2}"), (0xfbaf, "\u{6d2}"), (0xfbb0, "\u{6d2}\u{654}"),
+    (0xfbb1, "\u{6d2}\u{654}"), (0xfbd3, "\u{6ad}"), (0xfbd4, "\u{6ad}"), (0xfbd5, "\u{6ad}"),
+    (0xfbd6, "\u{6ad}"), (0xfbd7, "\u{6c7}"), (0xfbd8, "\u{6c7}"), (0xfbd9, "\u{6c6}"),
+    (0xfbda, "\u{6c6}"), (0xfbdb, "\u{6c8}"), (0xfbdc, "\u{6c8}"), (0xfbdd, "\u{6c7}\u{674}"),
+    (0xfbde, "\u{6cb}"), (0xfbdf, "\u{6cb}"), (0xfbe0, "\u{6c5}"), (0xfbe1, "\u{6c5}"),
+    (0xfbe2, "\u{6c9}"), (0xfbe3, "\u{6c9}"), (0xfbe4, "\u{6d0}"), (0xfbe5, "\u{6d0}"),
+    (0xfbe6, "\u{6d0}"), (0xfbe7, "\u{6d0}"), (0xfbe8, "\u{649}"), (0xfbe9, "\u{649}"),
+    (0xfbea, "\u{64a}\u{654}\u{627}"), (0xfbeb, "\u{64a}\u{654}\u{627}"),
+    (0xfbec, "\u{64a}\u{654}\u{6d5}"), (0xfbed, "\u{64a}\u{654}\u{6d5}"),
+    (0xfbee, "\u{64a}\u{654}\u{648}"), (0xfbef, "\u{64a}\u{654}\u{648}"),
+    (0xfbf0, "\u{64a}\u{654}\u{6c7}"), (0xfbf1, "\u{64a}\u{654}\u{6c7}"),
+    (0xfbf2, "\u{64a}\u{654}\u{6c6}"), (0xfbf3, "\u{64a}\u{654}\u{6c6}"),
+    (0xfbf4, "\u{64a}\u{654}\u{6c8}"), (0xfbf5, "\u{64a}\u{654}\u{6c8}"),
+    (0xfbf6, "\u{64a}\u{654}\u{6d0}"), (0xfbf7, "\u{64a}\u{654}\u{6d0}"),
+    (0xfbf8, "\u{64a}\u{654}\u{6d0}"), (0xfbf9, "\u{64a}\u{654}\u{649}"),
+    (0xfbfa, "\u{64a}\u{654}\u{649}"), (0xfbfb, "\u{64a}\u{654}\u{649}"), (0xfbfc, "\u{6cc}"),
+    (0xfbfd, "\u{6cc}"), (0xfbfe, "\u{6cc}"), (0xfbff, "\u{6cc}"),
+    (0xfc00, "\u{64a}\u{654}\u{62c}"), (0xfc01, "\u{64a}\u{654}\u{62d}"),
+    (0xfc02, "\u{64a}\u{654}\u{645}"), (0xfc03, "\u{64a}\u{654}\u{649}"),
+    (0xfc04, "\u{64a}\u{654}\u{64a}"), (0xfc05, "\u{628}\u{62c}"), (0xfc06, "\u{628}\u{62d}"),
+    (0xfc07, "\u{628}\u{62e}"), (0xfc08, "\u{628}\u{645}"), (0xfc09, "\u{628}\u{649}"),
+    (0xfc0a, "\u{628}\u{64a}"), (0xfc0b, "\u{62a}\u{62c}"), (0xfc0c, "\u{62a}\u{62d}"),
+    (0xfc0d, "\u{62a}\u{62e}"), (0xfc0e, "\u{62a}\u{645}"), (0xfc0f, "\u{62a}\u{649}"),
+    (0xfc10, "\u{62a}\u{64a}"), (0xfc11, "\u{62b}\u{62c}"), (0xfc12, "\u{62b}\u{645}"),
+    (0xfc13, "\u{62b}\u{649}"), (0xfc14, "\u{62b}\u{64a}"), (0xfc15, "\u{62c}\u{62d}"),
+    (0xfc16, "\u{62c}\u{645}"), (0xfc17, "\u{62d}\u{62c}"), (0xfc18, "\u{62d}\u{645}"),
+    (0xfc19, "\u{62e}\u{62c}"), (0xfc1a, "\u{62e}\u{62d}"), (0xfc1b, "\u{62e}\u{645}"),
+    (0xfc1c, "\u{633}\u{62c}"), (0xfc1d, "\u{633}\u{62d}"), (0xfc1e, "\u{633}\u{62e}"),
+    (0xfc1f, "\u{633}\u{645}"), (0xfc20, "\u{635}\u{62d}"), (0xfc21, "\u{635}\u{645}"),
+    (0xfc22, "\u{636}\u{62c}"), (0xfc23, "\u{636}\u{62d}"), (0xfc24, "\u{636}\u{62e}"),
+    (0xfc25, "\u{636}\u{645}"), (0xfc26, "\u{637}\u{62d}"), (0xfc27, "\u{637}\u{645}"),
+    (0xfc28, "\u{638}\u{645}"), (0xfc29, "\u{639}\u{62c}"), (0xfc2a, "\u{639}\u{645}"),
+    (0xfc2b, "\u{63a}\u{62c}"), (0xfc2c, "\u{63a}\u{645}"), (0xfc2d, "\u{641}\u{62c}"),
+    (0xfc2e, "\u{641}\u{62d}"), (0xfc2f, "\u{641}\u{62e}"), (0xfc30, "\u{641}\u{645}"),
+    (0xfc31, "\u{641}\u{649}"), (0xfc32, "\u{641}\u{64a}"), (0xfc33, "\u{642}\u{62d}"),
+    (0xfc34, "\u{642}\u{645}"), (0xfc35, "\u{642}\u{649}"), (0xfc36, "\u{642}\u{64a}"),
+    (0xfc37, "\u{643}\u{627}"), (0xfc38, "\u{643}\u{62c}"), (0xfc39, "\u{643}\u{62d}"),
+    (0xfc3a, "\u{643}\u{62e}"), (0xfc3b, "\u{643}\u{644}"), (0xfc3c, "\u{643}\u{645}"),
+    (0xfc3d, "\u{643}\u{649}"), (0xfc3e, "\u{643}\u{64a}"), (0xfc3f, "\u{644}\u{62c}"),
+    (0xfc40, "\u{644}\u{62d}"), (0xfc41, "\u{644}\u{62e}"), (0xfc42, "\u{644}\u{645}"),
+    (0xfc43, "\u{644}\u{649}"), (0xfc44, "\u{644}\u{64a}"), (0xfc45, "\u{645}\u{62c}"),
+    (0xfc46, "\u{645}\u{62d}"), (0xfc47, "\u{645}\u{62e}"), (0xfc48, "\u{645}\u{645}"),
+    (0xfc49, "\u{645}\u{649}"), (0xfc4a, "\u{645}\u{64a}"), (0xfc4b, "\u{646}\u{62c}"),
+    (0xfc4c, "\u{646}\u{62d}"), (0xfc4d, "\u{646}\u{62e}"), (0xfc4e, "\u{646}\u{645}"),
+    (0xfc4f, "\u{646}\u{649}"), (0xfc50, "\u{646}\u{64a}"), (0xfc51, "\u{647}\u{62c}"),
+    (0xfc52, "\u{647}\u{645}"), (0xfc53, "\u{647}\u{649}"), (0xfc54, "\u{647}\u{64a}"),
+    (0xfc55, "\u{64a}\u{62c}"), (0xfc56, "\u{64a}\u{62d}"), (0xfc57, "\u{64a}\u{62e}"),
+    (0xfc58, "\u{64a}\u{645}"), (0xfc59, "\u{64a}\u{649}"), (0xfc5a, "\u{64a}\u{64a}"),
+    (0xfc5b, "\u{630}\u{670}"), (0xfc5c, "\u{631}\u{670}"), (0xfc5d, "\u{649}\u{670}"),
+    (0xfc5e, " \u{64c}\u{651}"), (0xfc5f, " \u{64d}\u{651}"), (0xfc60, " \u{64e}\u{651}"),
+    (0xfc61, " \u{64f}\u{651}"), (0xfc62, " \u{650}\u{651}"), (0xfc63, " \u{651}\u{670}"),
+    (0xfc64, "\u{64a}\u{654}\u{631}"), (0xfc65, "\u{64a}\u{654}\u{632}"),
+    (0xfc66, "\u{64a}\u{654}\u{645}"), (0xfc67, "\u{64a}\u{654}\u{646}"),
+    (0xfc68, "\u{64a}\u{654}\u{649}"), (0xfc69, "\u{64a}\u{654}\u{64a}"),
+    (0xfc6a, "\u{628}\u{631}"), (0xfc6b, "\u{628}\u{632}"), (0xfc6c, "\u{628}\u{645}"),
+    (0xfc6d, "\u{628}\u{646}"), (0xfc6e, "\u{628}\u{649}"), (0xfc6f, "\u{628}\u{64a}"),
+    (0xfc70, "\u{62a}\u{631}"), (0xfc71, "\u{62a}\u{632}"), (0xfc72, "\u{62a}\u{645}"),
+    (0xfc73, "\u{62a}\u{646}"), (0xfc74, "\u{62a}\u{649}"), (0xfc75, "\u{62a}\u{64a}"),
+    (0xfc76, "\u{62b}\u{631}"), (0xfc77, "\u{62b}\u{632}"), (0xfc78, "\u{62b}\u{645}"),
+    (0xfc79, "\u{62b}\u{646}"), (0xfc7a, "\u{62b}\u{649}"), (0xfc7b, "\u{62b}\u{64a}"),
+    (0xfc7c, "\u{641}\u{649}"), (0xfc7d, "\u{641}\u{64a}"), (0xfc7e, "\u{642}\u{649}"),
+    (0xfc7f, "\u{642}\u{64a}"), (0xfc80, "\u{643}\u{627}"), (0xfc81, "\u{643}\u{644}"),
+    (0xfc82, "\u{643}\u{645}"), (0xfc83, "\u{643}\u{649}"), (0xfc84, "\u{643}\u{64a}"),
+    (0xfc85, "\u{644}\u{645}"), (0xfc86, "\u{644}\u{649}"), (0xfc87, "\u{644}\u{64a}"),
+    (0xfc88, "\u{645}\u{627}"), (0xfc89, "\u{645}\u{645}"), (0xfc8a, "\u{646}\u{631}"),
+    (0xfc8b, "\u{646}\u{632}"), (0xfc8c, "\u{646}\u{645}"), (0xfc8d, "\u{646}\u{646}"),
+    (0xfc8e, "\u{646}\u{649}"), (0xfc8f, "\u{646}\u{64a}"), (0xfc90, "\u{649}\u{670}"),
+    (0xfc91, "\u{64a}\u{631}"), (0xfc92, "\u{64a}\u{632}"), (0xfc93, "\u{64a}\u{645}"),
+    (0xfc94, "\u{64a}\u{646}"), (0xfc95, "\u{64a}\u{649}"), (0xfc96, "\u{64a}\u{64a}"),
+    (0xfc97, "\u{64a}\u{654}\u{62c}"), (0xfc98, "\u{64a}\u{654}\u{62d}"),
+    (0xfc99, "\u{64a}\u{654}\u{62e}"), (0xfc9a, "\u{64a}\u{654}\u{645}"),
+    (0xfc9b, "\u{64a}\u{654}\u{647}"), (0xfc9c, "\u{628}\u{62c}"), (0xfc9d, "\u{628}\u{62d}"),
+    (0xfc9e, "\u{628}\u{62e}"), (0xfc9f, "\u{628}\u{645}"), (0xfca0, "\u{628}\u{647}"),
+    (0xfca1, "\u{62a}\u{62c}"), (0xfca2, "\u{62a}\u{62d}"), (0xfca3, "\u{62a}\u{62e}"),
+    (0xfca4, "\u{62a}\u{645}"), (0xfca5, "\u{62a}\u{647}"), (0xfca6, "\u{62b}\u{645}"),
+    (0xfca7, "\u{62c}\u{62d}"), (0xfca8, "\u{62c}\u{645}"), (0xfca9, "\u{62d}\u{62c}"),
+    (0xfcaa, "\u{62d}\u{645}"), (0xfcab, "\u{62e}\u{62c}"), (0xfcac, "\u{62e}\u{645}"),
+    (0xfcad, "\u{633}\u{62c}"), (0xfcae, "\u{633}\u{62d}"), (0xfcaf, "\u{633}\u{62e}"),
+    (0xfcb0, "\u{633}\u{645}"), (0xfcb1, "\u{635}\u{62d}"), (0xfcb2, "\u{635}\u{62e}"),
+    (0xfcb3, "\u{635}\u{645}"), (0xfcb4, "\u{636}\u{62c}"), (0xfcb5, "\u{636}\u{62d}"),
+    (0xfcb6, "\u{636}\u{62e}"), (0xfcb7, "\u{636}\u{645}"), (0xfcb8, "\u{637}\u{62d}"),
+    (0xfcb9, "\u{638}\u{645}"), (0xfcba, "\u{639}\u{62c}"), (0xfcbb, "\u{639}\u{645}"),
+    (0xfcbc, "\u{63a}\u{62c}"), (0xfcbd, "\u{63a}\u{645}"), (0xfcbe, "\u{641}\u{62c}"),
+    (0xfcbf, "\u{641}\u{62d}"), (0xfcc0, "\u{641}\u{62e}"), (0xfcc1, "\u{641}\u{645}"),
+    (0xfcc2, "\u{642}\u{62d}"), (0xfcc3, "\u{642}\u{645}"), (0xfcc4, "\u{643}\u{62c}"),
+    (0xfcc5, "\u{643}\u{62d}"), (0xfcc6, "\u{643}\u{62e}"), (0xfcc7, "\u{643}\u{644}"),
+    (0xfcc8, "\u{643}\u{645}"), (0xfcc9, "\u{644}\u{62c}"), (0xfcca, "\u{644}\u{62d}"),
+    (0xfccb, "\u{644}\u{62e}"), (0xfccc, "\u{644}\u{645}"), (0xfccd, "\u{644}\u{647}"),
+    (0xfcce, "\u{645}\u{62c}"), (0xfccf, "\u{645}\u{62d}"), (0xfcd0, "\u{645}\u{62e}"),
+    (0xfcd1, "\u{645}\u{645}"), (0xfcd2, "\u{646}\u{62c}"), (0xfcd3, "\u{646}\u{62d}"),
+    (0xfcd4, "\u{646}\u{62e}"), (0xfcd5, "\u{646}\u{645}"), (0xfcd6, "\u{646}\u{647}"),
+    (0xfcd7, "\u{647}\u{62c}"), (0xfcd8, "\u{647}\u{645}"), (0xfcd9, "\u{647}\u{670}"),
+    (0xfcda, "\u{64a}\u{62c}"), (0xfcdb, "\u{64a}\u{62d}"), (0xfcdc, "\u{64a}\u{62e}"),
+    (0xfcdd, "\u{64a}\u{645}"), (0xfcde, "\u{64a}\u{647}"), (0xfcdf, "\u{64a}\u{654}\u{645}"),
+    (0xfce0, "\u{64a}\u{654}\u{647}"), (0xfce1, "\u{628}\u{645}"), (0xfce2, "\u{628}\u{647}"),
+    (0xfce3, "\u{62a}\u{645}"), (0xfce4, "\u{62a}\u{647}"), (0xfce5, "\u{62b}\u{645}"),
+    (0xfce6, "\u{62b}\u{647}"), (0xfce7, "\u{633}\u{645}"), (0xfce8, "\u{633}\u{647}"),
+    (0xfce9, "\u{634}\u{645}"), (0xfcea, "\u{634}\u{647}"), (0xfceb, "\u{643}\u{644}"),
+    (0xfcec, "\u{643}\u{645}"), (0xfced, "\u{644}\u{645}"), (0xfcee, "\u{646}\u{645}"),
+    (0xfcef, "\u{646}\u{647}"), (0xfcf0, "\u{64a}\u{645}"), (0xfcf1, "\u{64a}\u{647}"),
+    (0xfcf2, "\u{640}\u{64e}\u{651}"), (0xfcf3, "\u{640}\u{64f}\u{651}"),
+    (0xfcf4, "\u{640}\u{650}\u{651}"), (0xfcf5, "\u{637}\u{649}"), (0xfcf6, "\u{637}\u{64a}"),
+    (0xfcf7, "\u{639}\u{649}"), (0xfcf8, "\u{639}\u{64a}"), (0xfcf9, "\u{63a}\u{649}"),
+    (0xfcfa, "\u{63a}\u{64a}"), (0xfcfb, "\u{633}\u{649}"), (0xfcfc, "\u{633}\u{64a}"),
+    (0xfcfd, "\u{634}\u{649}"), (0xfcfe, "\u{634}\u{64a}"), (0xfcff, "\u{62d}\u{649}"),
+    (0xfd00, "\u{62d}\u{64a}"), (0xfd01, "\u{62c}\u{649}"), (0xfd02, "\u{62c}\u{64a}"),
+    (0xfd03, "\u{62e}\u{649}"), (0xfd04, "\u{62e}\u{64a}"), (0xfd05, "\u{635}\u{649}"),
+    (0xfd06, "\u{635}\u{64a}"), (0xfd07, "\u{636}\u{649}"), (0xfd08, "\u{636}\u{64a}"),
+    (0xfd09, "\u{634}\u{62c}"), (0xfd0a, "\u{634}\u{62d}"), (0xfd0b, "\u{634}\u{62e}"),
+    (0xfd0c, "\u{634}\u{645}"), (0xfd0d, "\u{634}\u{631}"), (0xfd0e, "\u{633}\u{631}"),
+    (0xfd0f, "\u{635}\u{631}"), (0xfd10, "\u{636}\u{631}"), (0xfd11, "\u{637}\u{649}"),
+    (0xfd12, "\u{637}\u{64a}"), (0xfd13, "\u{639}\u{649}"), (0xfd14, "\u{639}\u{64a}"),
+    (0xfd15, "\u{63a}\u{649}"), (0xfd16, "\u{63a}\u{64a}"), (0xfd17, "\u{633}\u{649}"),
+    (0xfd18, "\u{633}\u{64a}"), (0xfd19, "\u{634}\u{649}"), (0xfd1a, "\u{634}\u{64a}"),
+    (0xfd1b, "\u{62d}\u{649}"), (0xfd1c, "\u{62d}\u{64a}"), (0xfd1d, "\u{62c}\u{649}"),
+    (0xfd1e, "\u{62c}\u{64a}"), (0xfd1f, "\u{62e}\u{649}"), (0xfd20, "\u{62e}\u{64a}"),
+    (0xfd21, "\u{635}\u{649}"), (0xfd22, "\u{635}\u{64a}"), (0xfd23, "\u{636}\u{649}"),
+    (0xfd24, "\u{636}\u{64a}"), (0xfd25, "\u{634}\u{62c}"), (0xfd26, "\u{634}\u{62d}"),
+    (0xfd27, "\u{634}\u{62e}"), (0xfd28, "\u{634}\u{645}"), (0xfd29, "\u{634}\u{631}"),
+    (0xfd2a, "\u{633}\u{631}"), (0xfd2b, "\u{635}\u{631}"), (0xfd2c, "\u{636}\u{631}"),
+    (0xfd2d, "\u{634}\u{62c}"), (0xfd2e, "\u{634}\u{62d}"), (0xfd2f, "\u{634}\u{62e}"),
+    (0xfd30, "\u{634}\u{645}"), (0xfd31, "\u{633}\u{647}"), (0xfd32, "\u{634}\u{647}"),
+    (0xfd33, "\u{637}\u{645}"), (0xfd34, "\u{633}\u{62c}"), (0xfd35, "\u{633}\u{62d}"),
+    (0xfd36, "\u{633}\u{62e}"), (0xfd37, "\u{634}\u{62c}"), (0xfd38, "\u{634}\u{62d}"),
+    (0xfd39, "\u{634}\u{62e}"), (0xfd3a, "\u{637}\u{645}"), (0xfd3b, "\u{638}\u{645}"),
+    (0xfd3c, "\u{627}\u{64b}"), (0xfd3d, "\u{627}\u{64b}"), (0xfd50, "\u{62a}\u{62c}\u{645}"),
+    (0xfd51, "\u{62a}\u{62d}\u{62c}"), (0xfd52, "\u{62a}\u{62d}\u{62c}"),
+    (0xfd53, "\u{62a}\u{62d}\u{645}"), (0xfd54, "\u{62a}\u{62e}\u{645}"),
+    (0xfd55, "\u{62a}\u{645}\u{62c}"), (0xfd56, "\u{62a}\u{645}\u{62d}"),
+    (0xfd57, "\u{62a}\u{645}\u{62e}"), (0xfd58, "\u{62c}\u{645}\u{62d}"),
+    (0xfd59, "\u{62c}\u{645}\u{62d}"), (0xfd5a, "\u{62d}\u{645}\u{64a}"),
+    (0xfd5b, "\u{62d}\u{645}\u{649}"), (0xfd5c, "\u{633}\u{62d}\u{62c}"),
+    (0xfd5d, "\u{633}\u{62c}\u{62d}"), (0xfd5e, "\u{633}\u{62c}\u{649}"),
+    (0xfd5f, "\u{633}\u{645}\u{62d}"), (0xfd60, "\u{633}\u{645}\u{62d}"),
+    (0xfd61, "\u{633}\u{645}\u{62c}"), (0xfd62, "\u{633}\u{645}\u{645}"),
+    (0xfd63, "\u{633}\u{645}\u{645}"), (0xfd64, "\u{635}\u{62d}\u{62d}"),
+    (0xfd65, "\u{635}\u{62d}\u{62d}"), (0xfd66, "\u{635}\u{645}\u{645}"),
+    (0xfd67, "\u{634}\u{62d}\u{645}"), (0xfd68, "\u{634}\u{62d}\u{645}"),
+    (0xfd69, "\u{634}\u{62c}\u{64a}"), (0xfd6a, "\u{634}\u{645}\u{62e}"),
+    (0xfd6b, "\u{634}\u{645}\u{62e}"), (0xfd6c, "\u{634}\u{645}\u{645}"),
+    (0xfd6d, "\u{634}\u{645}\u{645}"), (0xfd6e, "\u{636}\u{62d}\u{649}"),
+    (0xfd6f, "\u{636}\u{62e}\u{645}"), (0xfd70, "\u{636}\u{62e}\u{645}"),
+    (0xfd71, "\u{637}\u{645}\u{62d}"), (0xfd72, "\u{637}\u{645}\u{62d}"),
+    (0xfd73, "\u{637}\u{645}\u{645}"), (0xfd74, "\u{637}\u{645}\u{64a}"),
+    (0xfd75, "\u{639}\u{62c}\u{645}"), (0xfd76, "\u{639}\u{645}\u{645}"),
+    (0xfd77, "\u{639}\u{645}\u{645}"), (0xfd78, "\u{639}\u{645}\u{649}"),
+    (0xfd79, "\u{63a}\u{645}\u{645}"), (0xfd7a, "\u{63a}\u{645}\u{64a}"),
+    (0xfd7b, "\u{63a}\u{645}\u{649}"), (0xfd7c, "\u{641}\u{62e}\u{645}"),
+    (0xfd7d, "\u{641}\u{62e}\u{645}"), (0xfd7e, "\u{642}\u{645}\u{62d}"),
+    (0xfd7f, "\u{642}\u{645}\u{645}"), (0xfd80, "\u{644}\u{62d}\u{645}"),
+    (0xfd81, "\u{644}\u{62d}\u{64a}"), (0xfd82, "\u{644}\u{62d}\u{649}"),
+    (0xfd83, "\u{644}\u{62c}\u{62c}"), (0xfd84, "\u{644}\u{62c}\u{62c}"),
+    (0xfd85, "\u{644}\u{62e}\u{645}"), (0xfd86, "\u{644}\u{62e}\u{645}"),
+    (0xfd87, "\u{644}\u{645}\u{62d}"), (0xfd88, "\u{644}\u{645}\u{62d}"),
+    (0xfd89, "\u{645}\u{62d}\u{62c}"), (0xfd8a, "\u{645}\u{62d}\u{645}"),
+    (0xfd8b, "\u{645}\u{62d}\u{64a}"), (0xfd8c, "\u{645}\u{62c}\u{62d}"),
+    (0xfd8d, "\u{645}\u{62c}\u{645}"), (0xfd8e, "\u{645}\u{62e}\u{62c}"),
+    (0xfd8f, "\u{645}\u{62e}\u{645}"), (0xfd92, "\u{645}\u{62c}\u{62e}"),
+    (0xfd93, "\u{647}\u{645}\u{62c}"), (0xfd94, "\u{647}\u{645}\u{645}"),
+    (0xfd95, "\u{646}\u{62d}\u{645}"), (0xfd96, "\u{646}\u{62d}\u{649}"),
+    (0xfd97, "\u{646}\u{62c}\u{645}"), (0xfd98, "\u{646}\u{62c}\u{645}"),
+    (0xfd99, "\u{646}\u{62c}\u{649}"), (0xfd9a, "\u{646}\u{645}\u{64a}"),
+    (0xfd9b, "\u{646}\u{645}\u{649}"), (0xfd9c, "\u{64a}\u{645}\u{645}"),
+    (0xfd9d, "\u{64a}\u{645}\u{645}"), (0xfd9e, "\u{628}\u{62e}\u{64a}"),
+    (0xfd9f, "\u{62a}\u{62c}\u{64a}"), (0xfda0, "\u{62a}\u{62c}\u{649}"),
+    (0xfda1, "\u{62a}\u{62e}\u{64a}"), (0xfda2, "\u{62a}\u{62e}\u{649}"),
+    (0xfda3, "\u{62a}\u{645}\u{64a}"), (0xfda4, "\u{62a}\u{645}\u{649}"),
+    (0xfda5, "\u{62c}\u{645}\u{64a}"), (0xfda6, "\u{62c}\u{62d}\u{649}"),
+    (0xfda7, "\u{62c}\u{645}\u{649}"), (0xfda8, "\u{633}\u{62e}\u{649}"),
+    (0xfda9, "\u{635}\u{62d}\u{64a}"), (0xfdaa, "\u{634}\u{62d}\u{64a}"),
+    (0xfdab, "\u{636}\u{62d}\u{64a}"), (0xfdac, "\u{644}\u{62c}\u{64a}"),
+    (0xfdad, "\u{644}\u{645}\u{64a}"), (0xfdae, "\u{64a}\u{62d}\u{64a}"),
+    (0xfdaf, "\u{64a}\u{62c}\u{64a}"), (0xfdb0, "\u{64a}\u{645}\u{64a}"),
+    (0xfdb1, "\u{645}\u{645}\u{64a}"), (0xfdb2, "\u{642}\u{645}\u{64a}"),
+    (0xfdb3, "\u{646}\u{62d}\u{64a}"), (0xfdb4, "\u{642}\u{645}\u{62d}"),
+    (0xfdb5, "\u{644}\u{62d}\u{645}"), (0xfdb6, "\u{639}\u{645}\u{64a}"),
+    (0xfdb7, "\u{643}\u{645}\u{64a}"), (0xfdb8, "\u{646}\u{62c}\u{62d}"),
+    (0xfdb9, "\u{645}\u{62e}\u{64a}"), (0xfdba, "\u{644}\u{62c}\u{645}"),
+    (0xfdbb, "\u{643}\u{645}\u{645}"), (0xfdbc, "\u{644}\u{62c}\u{645}"),
+    (0xfdbd, "\u{646}\u{62c}\u{62d}"), (0xfdbe, "\u{62c}\u{62d}\u{64a}"),
+    (0xfdbf, "\u{62d}\u{62c}\u{64a}"), (0xfdc0, "\u{645}\u{62c}\u{64a}"),
+    (0xfdc1, "\u{641}\u{645}\u{64a}"), (0xfdc2, "\u{628}\u{62d}\u{64a}"),
+    (0xfdc3, "\u{643}\u{645}\u{645}"), (0xfdc4, "\u{639}\u{62c}\u{645}"),
+    (0xfdc5, "\u{635}\u{645}\u{645}"), (0xfdc6, "\u{633}\u{62e}\u{64a}"),
+    (0xfdc7, "\u{646}\u{62c}\u{64a}"), (0xfdf0, "\u{635}\u{644}\u{6d2}"),
+    (0xfdf1, "\u{642}\u{644}\u{6d2}"), (0xfdf2, "\u{627}\u{644}\u{644}\u{647}"),
+    (0xfdf3, "\u{627}\u{643}\u{628}\u{631}"), (0xfdf4, "\u{645}\u{62d}\u{645}\u{62f}"),
+    (0xfdf5, "\u{635}\u{644}\u{639}\u{645}"), (0xfdf6, "\u{631}\u{633}\u{648}\u{644}"),
+    (0xfdf7, "\u{639}\u{644}\u{64a}\u{647}"), (0xfdf8, "\u{648}\u{633}\u{644}\u{645}"),
+    (0xfdf9, "\u{635}\u{644}\u{649}"),
+    (0xfdfa, "\u{635}\u{644}\u{649} \u{627}\u{644}\u{644}\u{647} \u{639}\u{644}\u{64a}\u{647} \u{648}\u{633}\u{644}\u{645}"),
+    (0xfdfb, "\u{62c}\u{644} \u{62c}\u{644}\u{627}\u{644}\u{647}"),
+    (0xfdfc, "\u{631}\u{6cc}\u{627}\u{644}"), (0xfe10, ","), (0xfe11, "\u{3001}"),
+    (0xfe12, "\u{3002}"), (0xfe13, ":"), (0xfe14, ";"), (0xfe15, "!"), (0xfe16, "?"),
+    (0xfe17, "\u{3016}"), (0xfe18, "\u{3017}"), (0xfe19, "..."), (0xfe30, ".."),
+    (0xfe31, "\u{2014}"), (0xfe32, "\u{2013}"), (0xfe33, "_"), (0xfe34, "_"), (0xfe35, "("),
+    (0xfe36, ")"), (0xfe37, "{"), (0xfe38, "}"), (0xfe39, "\u{3014}"), (0xfe3a, "\u{3015}"),
+    (0xfe3b, "\u{3010}"), (0xfe3c, "\u{3011}"), (0xfe3d, "\u{300a}"), (0xfe3e, "\u{300b}"),
+    (0xfe3f, "\u{3008}"), (0xfe40, "\u{3009}"), (0xfe41, "\u{300c}"), (0xfe42, "\u{300d}"),
+    (0xfe43, "\u{300e}"), (0xfe44, "\u{300f}"), (0xfe47, "["), (0xfe48, "]"), (0xfe49, " \u{305}"),
+    (0xfe4a, " \u{305}"), (0xfe4b, " \u{305}"), (0xfe4c, " \u{305}"), (0xfe4d, "_"), (0xfe4e, "_"),
+    (0xfe4f, "_"), (0xfe50, ","), (0xfe51, "\u{3001}"), (0xfe52, "."), (0xfe54, ";"),
+    (0xfe55, ":"), (0xfe56, "?"), (0xfe57, "!"), (0xfe58, "\u{2014}"), (0xfe59, "("),
+    (0xfe5a, ")"), (0xfe5b, "{"), (0xfe5c, "}"), (0xfe5d, "\u{3014}"), (0xfe5e, "\u{3015}"),
+    (0xfe5f, "#"), (0xfe60, "&"), (0xfe61, "*"), (0xfe62, "+"), (0xfe63, "-"), (0xfe64, "<"),
+    (0xfe65, ">"), (0xfe66, "="), (0xfe68, "\u{5c}"), (0xfe69, "$"), (0xfe6a, "%"), (0xfe6b, "@"),
+    (0xfe70, " \u{64b}"), (0xfe71, "\u{640}\u{64b}"), (0xfe72, " \u{64c}"), (0xfe74, " \u{64d}"),
+    (0xfe76, " \u{64e}"), (0xfe77, "\u{640}\u{64e}"), (0xfe78, " \u{64f}"),
+    (0xfe79, "\u{640}\u{64f}"), (0xfe7a, " \u{650}"), (0xfe7b, "\u{640}\u{650}"),
+    (0xfe7c, " \u{651}"), (0xfe7d, "\u{640}\u{651}"), (0xfe7e, " \u{652}"),
+    (0xfe7f, "\u{640}\u{652}"), (0xfe80, "\u{621}"), (0xfe81, "\u{627}\u{653}"),
+    (0xfe82, "\u{627}\u{653}"), (0xfe83, "\u{627}\u{654}"), (0xfe84, "\u{627}\u{654}"),
+    (0xfe85, "\u{648}\u{654}"), (0xfe86, "\u{648}\u{654}"), (0xfe87, "\u{627}\u{655}"),
+    (0xfe88, "\u{627}\u{655}"), (0xfe89, "\u{64a}\u{654}"), (0xfe8a, "\u{64a}\u{654}"),
+    (0xfe8b, "\u{64a}\u{654}"), (0xfe8c, "\u{64a}\u{654}"), (0xfe8d, "\u{627}"),
+    (0xfe8e, "\u{627}"), (0xfe8f, "\u{628}"), (0xfe90, "\u{628}"), (0xfe91, "\u{628}"),
+    (0xfe92, "\u{628}"), (0xfe93, "\u{629}"), (0xfe94, "\u{629}"), (0xfe95, "\u{62a}"),
+    (0xfe96, "\u{62a}"), (0xfe97, "\u{62a}"), (0xfe98, "\u{62a}"), (0xfe99, "\u{62b}"),
+    (0xfe9a, "\u{62b}"), (0xfe9b, "\u{62b}"), (0xfe9c, "\u{62b}"), (0xfe9d, "\u{62c}"),
+    (0xfe9e, "\u{62c}"), (0xfe9f, "\u{62c}"), (0xfea0, "\u{62c}"), (0xfea1, "\u{62d}"),
+    (0xfea2, "\u{62d}"), (0xfea3, "\u{62d}"), (0xfea4, "\u{62d}"), (0xfea5, "\u{62e}"),
+    (0xfea6, "\u{62e}"), (0xfea7, "\u{62e}"), (0xfea8, "\u{62e}"), (0xfea9, "\u{62f}"),
+    (0xfeaa, "\u{62f}"), (0xfeab, "\u{630}"), (0xfeac, "\u{630}"), (0xfead, "\u{631}"),
+    (0xfeae, "\u{631}"), (0xfeaf, "\u{632}"), (0xfeb0, "\u{632}"), (0xfeb1, "\u{633}"),
+    (0xfeb2, "\u{633}"), (0xfeb3, "\u{633}"), (0xfeb4, "\u{633}"), (0xfeb5, "\u{634}"),
+    (0xfeb6, "\u{634}"), (0xfeb7, "\u{634}"), (0xfeb8, "\u{634}"), (0xfeb9, "\u{635}"),
+    (0xfeba, "\u{635}"), (0xfebb, "\u{635}"), (0xfebc, "\u{635}"), (0xfebd, "\u{636}"),
+    (0xfebe, "\u{636}"), (0xfebf, "\u{636}"), (0xfec0, "\u{636}"), (0xfec1, "\u{637}"),
+    (0xfec2, "\u{637}"), (0xfec3, "\u{637}"), (0xfec4, "\u{637}"), (0xfec5, "\u{638}"),
+    (0xfec6, "\u{638}"), (0xfec7, "\u{638}"), (0xfec8, "\u{638}"), (0xfec9, "\u{639}"),
+    (0xfeca, "\u{639}"), (0xfecb, "\u{639}"), (0xfecc, "\u{639}"), (0xfecd, "\u{63a}"),
+    (0xfece, "\u{63a}"), (0xfecf, "\u{63a}"), (0xfed0, "\u{63a}"), (0xfed1, "\u{641}"),
+    (0xfed2, "\u{641}"), (0xfed3, "\u{641}"), (0xfed4, "\u{641}"), (0xfed5, "\u{642}"),
+    (0xfed6, "\u{642}"), (0xfed7, "\u{642}"), (0xfed8, "\u{642}"), (0xfed9, "\u{643}"),
+    (0xfeda, "\u{643}"), (0xfedb, "\u{643}"), (0xfedc, "\u{643}"), (0xfedd, "\u{644}"),
+    (0xfede, "\u{644}"), (0xfedf, "\u{644}"), (0xfee0, "\u{644}"), (0xfee1, "\u{645}"),
+    (0xfee2, "\u{645}"), (0xfee3, "\u{645}"), (0xfee4, "\u{645}"), (0xfee5, "\u{646}"),
+    (0xfee6, "\u{646}"), (0xfee7, "\u{646}"), (0xfee8, "\u{646}"), (0xfee9, "\u{647}"),
+    (0xfeea, "\u{647}"), (0xfeeb, "\u{647}"), (0xfeec, "\u{647}"), (0xfeed, "\u{648}"),
+    (0xfeee, "\u{648}"), (0xfeef, "\u{649}"), (0xfef0, "\u{649}"), (0xfef1, "\u{64a}"),
+    (0xfef2, "\u{64a}"), (0xfef3, "\u{64a}"), (0xfef4, "\u{64a}"),
+    (0xfef5, "\u{644}\u{627}\u{653}"), (0xfef6, "\u{644}\u{627}\u{653}"),
+    (0xfef7, "\u{644}\u{627}\u{654}"), (0xfef8, "\u{644}\u{627}\u{654}"),
+    (0xfef9, "\u{644}\u{627}\u{655}"), (0xfefa, "\u{644}\u{627}\u{655}"),
+    (0xfefb, "\u{644}\u{627}"), (0xfefc, "\u{644}\u{627}"), (0xff01, "!"), (0xff02, "\u{22}"),
+    (0xff03, "#"), (0xff04, "$"), (0xff05, "%"), (0xff06, "&"), (0xff07, "'"), (0xff08, "("),
+    (0xff09, ")"), (0xff0a, "*"), (0xff0b, "+"), (0xff0c, ","), (0xff0d, "-"), (0xff0e, "."),
+    (0xff0f, "/"), (0xff10, "0"), (0xff11, "1"), (0xff12, "2"), (0xff13, "3"), (0xff14, "4"),
+    (0xff15, "5"), (0xff16, "6"), (0xff17, "7"), (0xff18, "8"), (0xff19, "9"), (0xff1a, ":"),
+    (0xff1b, ";"), (0xff1c, "<"), (0xff1d, "="), (0xff1e, ">"), (0xff1f, "?"), (0xff20, "@"),
+    (0xff21, "A"), (0xff22, "B"), (0xff23, "C"), (0xff24, "D"), (0xff25, "E"), (0xff26, "F"),
+    (0xff27, "G"), (0xff28, "H"), (0xff29, "I"), (0xff2a, "J"), (0xff2b, "K"), (0xff2c, "L"),
+    (0xff2d, "M"), (0xff2e, "N"), (0xff2f, "O"), (0xff30, "P"), (0xff31, "Q"), (0xff32, "R"),
+    (0xff33, "S"), (0xff34, "T"), (0xff35, "U"), (0xff36, "V"), (0xff37, "W"), (0xff38, "X"),
+    (0xff39, "Y"), (0xff3a, "Z"), (0xff3b, "["), (0xff3c, "\u{5c}"), (0xff3d, "]"), (0xff3e, "^"),
+    (0xff3f, "_"), (0xff40, "`"), (0xff41, "a"), (0xff42, "b"), (0xff43, "c"), (0xff44, "d"),
+    (0xff45, "e"), (0xff46, "f"), (0xff47, "g"), (0xff48, "h"), (0xff49, "i"), (0xff4a, "j"),
+    (0xff4b, "k"), (0xff4c, "l"), (0xff4d, "m"), (0xff4e, "n"), (0xff4f, "o"), (0xff50, "p"),
+    (0xff51, "q"), (0xff52, "r"), (0xff53, "s"), (0xff54, "t"), (0xff55, "u"), (0xff56, "v"),
+    (0xff57, "w"), (0xff58, "x"), (0xff59, "y"), (0xff5a, "z"), (0xff5b, "{"), (0xff5c, "|"),
+    (0xff5d, "}"), (0xff5e, "~"), (0xff5f, "\u{2985}"), (0xff60, "\u{2986}"), (0xff61, "\u{3002}"),
+    (0xff62, "\u{300c}"), (0xff63, "\u{300d}"), (0xff64, "\u{3001}"), (0xff65, "\u{30fb}"),
+    (0xff66, "\u{30f2}"), (0xff67, "\u{30a1}"), (0xff68, "\u{30a3}"), (0xff69, "\u{30a5}"),
+    (0xff6a, "\u{30a7}"), (0xff6b, "\u{30a9}"), (0xff6c, "\u{30e3}"), (0xff6d, "\u{30e5}"),
+    (0xff6e, "\u{30e7}"), (0xff6f, "\u{30c3}"), (0xff70, "\u{30fc}"), (0xff71, "\u{30a2}"),
+    (0xff72, "\u{30a4}"), (0xff73, "\u{30a6}"), (0xff74, "\u{30a8}"), (0xff75, "\u{30aa}"),
+    (0xff76, "\u{30ab}"), (0xff77, "\u{30ad}"), (0xff78, "\u{30af}"), (0xff79, "\u{30b1}"),
+    (0xff7a, "\u{30b3}"), (0xff7b, "\u{30b5}"), (0xff7c, "\u{30b7}"), (0xff7d, "\u{30b9}"),
+    (0xff7e, "\u{30bb}"), (0xff7f, "\u{30bd}"), (0xff80, "\u{30bf}"), (0xff81, "\u{30c1}"),
+    (0xff82, "\u{30c4}"), (0xff83, "\u{30c6}"), (0xff84, "\u{30c8}"), (0xff85, "\u{30ca}"),
+    (0xff86, "\u{30cb}"), (0xff87, "\u{30cc}"), (0xff88, "\u{30cd}"), (0xff89, "\u{30ce}"),
+    (0xff8a, "\u{30cf}"), (0xff8b, "\u{30d2}"), (0xff8c, "\u{30d5}"), (0xff8d, "\u{30d8}"),
+    (0xff8e, "\u{30db}"), (0xff8f, "\u{30de}"), (0xff90, "\u{30df}"), (0xff91, "\u{30e0}"),
+    (0xff92, "\u{30e1}"), (0xff93, "\u{30e2}"), (0xff94, "\u{30e4}"), (0xff95, "\u{30e6}"),
+    (0xff96, "\u{30e8}"), (0xff97, "\u{30e9}"), (0xff98, "\u{30ea}"), (0xff99, "\u{30eb}"),
+    (0xff9a, "\u{30ec}"), (0xff9b, "\u{30ed}"), (0xff9c, "\u{30ef}"), (0xff9d, "\u{30f3}"),
+    (0xff9e, "\u{3099}"), (0xff9f, "\u{309a}"), (0xffa0, "\u{1160}"), (0xffa1, "\u{1100}"),
+    (0xffa2, "\u{1101}"), (0xffa3, "\u{11aa}"), (0xffa4, "\u{1102}"), (0xffa5, "\u{11ac}"),
+    (0xffa6, "\u{11ad}"), (0xffa7, "\u{1103}"), (0xffa8, "\u{1104}"), (0xffa9, "\u{1105}"),
+    (0xffaa, "\u{11b0}"), (0xffab, "\u{11b1}"), (0xffac, "\u{11b2}"), (0xffad, "\u{11b3}"),
+    (0xffae, "\u{11b4}"), (0xffaf, "\u{11b5}"), (0xffb0, "\u{111a}"), (0xffb1, "\u{1106}"),
+    (0xffb2, "\u{1107}"), (0xffb3, "\u{1108}"), (0xffb4, "\u{1121}"), (0xffb5, "\u{1109}"),
+    (0xffb6, "\u{110a}"), (0xffb7, "\u{110b}"), (0xffb8, "\u{110c}"), (0xffb9, "\u{110d}"),
+    (0xffba, "\u{110e}"), (0xffbb, "\u{110f}"), (0xffbc, "\u{1110}"), (0xffbd, "\u{1111}"),
+    (0xffbe, "\u{1112}"), (0xffc2, "\u{1161}"), (0xffc3, "\u{1162}"), (0xffc4, "\u{1163}"),
+    (0xffc5, "\u{1164}"), (0xffc6, "\u{1165}"), (0xffc7, "\u{1166}"), (0xffca, "\u{1167}"),
+    (0xffcb, "\u{1168}"), (0xffcc, "\u{1169}"), (0xffcd, "\u{116a}"), (0xffce, "\u{116b}"),
+    (0xffcf, "\u{116c}"), (0xffd2, "\u{116d}"), (0xffd3, "\u{116e}"), (0xffd4, "\u{116f}"),
+    (0xffd5, "\u{1170}"), (0xffd6, "\u{1171}"), (0xffd7, "\u{1172}"), (0xffda, "\u{1173}"),
+    (0xffdb, "\u{1174}"), (0xffdc, "\u{1175}"), (0xffe0, "\u{a2}"), (0xffe1, "\u{a3}"),
+    (0xffe2, "\u{ac}"), (0xffe3, " \u{304}"), (0xffe4, "\u{a6}"), (0xffe5, "\u{a5}"),
+    (0xffe6, "\u{20a9}"), (0xffe8, "\u{2502}"), (0xffe9, "\u{2190}"), (0xffea, "\u{2191}"),
+    (0xffeb, "\u{2192}"), (0xffec, "\u{2193}"), (0xffed, "\u{25a0}"), (0xffee, "\u{25cb}"),
+    (0x10781, "\u{2d0}"), (0x10782, "\u{2d1}"), (0x10783, "\u{e6}"), (0x10784, "\u{299}"),
+    (0x10785, "\u{253}"), (0x10787, "\u{2a3}"), (0x10788, "\u{ab66}"), (0x10789, "\u{2a5}"),
+    (0x1078a, "\u{2a4}"), (0x1078b, "\u{256}"), (0x1078c, "\u{257}"), (0x1078d, "\u{1d91}"),
+    (0x1078e, "\u{258}"), (0x1078f, "\u{25e}"), (0x10790, "\u{2a9}"), (0x10791, "\u{264}"),
+    (0x10792, "\u{262}"), (0x10793, "\u{260}"), (0x10794, "\u{29b}"), (0x10795, "\u{127}"),
+    (0x10796, "\u{29c}"), (0x10797, "\u{267}"), (0x10798, "\u{284}"), (0x10799, "\u{2aa}"),
+    (0x1079a, "\u{2ab}"), (0x1079b, "\u{26c}"), (0x1079c, "\u{1df04}"), (0x1079d, "\u{a78e}"),
+    (0x1079e, "\u{26e}"), (0x1079f, "\u{1df05}"), (0x107a0, "\u{28e}"), (0x107a1, "\u{1df06}"),
+    (0x107a2, "\u{f8}"), (0x107a3, "\u{276}"), (0x107a4, "\u{277}"), (0x107a5, "q"),
+    (0x107a6, "\u{27a}"), (0x107a7, "\u{1df08}"), (0x107a8, "\u{27d}"), (0x107a9, "\u{27e}"),
+    (0x107aa, "\u{280}"), (0x107ab, "\u{2a8}"), (0x107ac, "\u{2a6}"), (0x107ad, "\u{ab67}"),
+    (0x107ae, "\u{2a7}"), (0x107af, "\u{288}"), (0x107b0, "\u{2c71}"), (0x107b2, "\u{28f}"),
+    (0x107b3, "\u{2a1}"), (0x107b4, "\u{2a2}"), (0x107b5, "\u{298}"), (0x107b6, "\u{1c0}"),
+    (0x107b7, "\u{1c1}"), (0x107b8, "\u{1c2}"), (0x107b9, "\u{1df0a}"), (0x107ba, "\u{1df1e}"),
+    (0x1d400, "A"), (0x1d401, "B"), (0x1d402, "C"), (0x1d403, "D"), (0x1d404, "E"), (0x1d405, "F"),
+    (0x1d406, "G"), (0x1d407, "H"), (0x1d408, "I"), (0x1d409, "J"), (0x1d40a, "K"), (0x1d40b, "L"),
+    (0x1d40c, "M"), (0x1d40d, "N"), (0x1d40e, "O"), (0x1d40f, "P"), (0x1d410, "Q"), (0x1d411, "R"),
+    (0x1d412, "S"), (0x1d413, "T"), (0x1d414, "U"), (0x1d415, "V"), (0x1d416, "W"), (0x1d417, "X"),
+    (0x1d418, "Y"), (0x1d419, "Z"), (0x1d41a, "a"), (0x1d41b, "b"), (0x1d41c, "c"), (0x1d41d, "d"),
+    (0x1d41e, "e"), (0x1d41f, "f"), (0x1d420, "g"), (0x1d421, "h"), (0x1d422, "i"), (0x1d423, "j"),
+    (0x1d424, "k"), (0x1d425, "l"), (0x1d426, "m"), (0x1d427, "n"), (0x1d428, "o"), (0x1d429, "p"),
+    (0x1d42a, "q"), (0x1d42b, "r"), (0x1d42c, "s"), (0x1d42d, "t"), (0x1d42e, "u"), (0x1d42f, "v"),
+    (0x1d430, "w"), (0x1d431, "x"), (0x1d432, "y"), (0x1d433, "z"), (0x1d434, "A"), (0x1d435, "B"),
+    (0x1d436, "C"), (0x1d437, "D"), (0x1d438, "E"), (0x1d439, "F"), (0x1d43a, "G"), (0x1d43b, "H"),
+    (0x1d43c, "I"), (0x1d43d, "J"), (0x1d43e, "K"), (0x1d43f, "L"), (0x1d440, "M"), (0x1d441, "N"),
+    (0x1d442, "O"), (0x1d443, "P"), (0x1d444, "Q"), (0x1d445, "R"), (0x1d446, "S"), (0x1d447, "T"),
+    (0x1d448, "U"), (0x1d449, "V"), (0x1d44a, "W"), (0x1d44b, "X"), (0x1d44c, "Y"), (0x1d44d, "Z"),
+    (0x1d44e, "a"), (0x1d44f, "b"), (0x1d450, "c"), (0x1d451, "d"), (0x1d452, "e"), (0x1d453, "f"),
+    (0x1d454, "g"), (0x1d456, "i"), (0x1d457, "j"), (0x1d458, "k"), (0x1d459, "l"), (0x1d45a, "m"),
+    (0x1d45b, "n"), (0x1d45c, "o"), (0x1d45d, "p"), (0x1d45e, "q"), (0x1d45f, "r"), (0x1d460, "s"),
+    (0x1d461, "t"), (0x1d462, "u"), (0x1d463, "v"), (0x1d464, "w"), (0x1d465, "x"), (0x1d466, "y"),
+    (0x1d467, "z"), (0x1d468, "A"), (0x1d469, "B"), (0x1d46a, "C"), (0x1d46b, "D"), (0x1d46c, "E"),
+    (0x1d46d, "F"), (0x1d46e, "G"), (0x1d46f, "H"), (0x1d470, "I"), (0x1d471, "J"), (0x1d472, "K"),
+    (0x1d473, "L"), (0x1d474, "M"), (0x1d475, "N"), (0x1d476, "O"), (0x1d477, "P"), (0x1d478, "Q"),
+    (0x1d479, "R"), (0x1d47a, "S"), (0x1d47b, "T"), (0x1d47c, "U"), (0x1d47d, "V"), (0x1d47e, "W"),
+    (0x1d47f, "X"), (0x1d480, "Y"), (0x1d481, "Z"), (0x1d482, "a"), (0x1d483, "b"), (0x1d484, "c"),
+    (0x1d485, "d"), (0x1d486, "e"), (0x1d487, "f"), (0x1d488, "g"), (0x1d489, "h"), (0x1d48a, "i"),
+    (0x1d48b, "j"), (0x1d48c, "k"), (0x1d48d, "l"), (0x1d48e, "m"), (0x1d48f, "n"), (0x1d490, "o"),
+    (0x1d491, "p"), (0x1d492, "q"), (0x1d493, "r"), (0x1d494, "s"), (0x1d495, "t"), (0x1d496, "u"),
+    (0x1d497, "v"), (0x1d498, "w"), (0x1d499, "x"), (0x1d49a, "y"), (0x1d49b, "z"), (0x1d49c, "A"),
+    (0x1d49e, "C"), (0x1d49f, "D"), (0x1d4a2, "G"), (0x1d4a5, "J"), (0x1d4a6, "K"), (0x1d4a9, "N"),
+    (0x1d4aa, "O"), (0x1d4ab, "P"), (0x1d4ac, "Q"), (0x1d4ae, "S"), (0x1d4af, "T"), (0x1d4b0, "U"),
+    (0x1d4b1, "V"), (0x1d4b2, "W"), (0x1d4b3, "X"), (0x1d4b4, "Y"), (0x1d4b5, "Z"), (0x1d4b6, "a"),
+    (0x1d4b7, "b"), (0x1d4b8, "c"), (0x1d4b9, "d"), (0x1d4bb, "f"), (0x1d4bd, "h"), (0x1d4be, "i"),
+    (0x1d4bf, "j"), (0x1d4c0, "k"), (0x1d4c1, "l"), (0x1d4c2, "m"), (0x1d4c3, "n"), (0x1d4c5, "p"),
+    (0x1d4c6, "q"), (0x1d4c7, "r"), (0x1d4c8, "s"), (0x1d4c9, "t"), (0x1d4ca, "u"), (0x1d4cb, "v"),
+    (0x1d4cc, "w"), (0x1d4cd, "x"), (0x1d4ce, "y"), (0x1d4cf, "z"), (0x1d4d0, "A"), (0x1d4d1, "B"),
+    (0x1d4d2, "C"), (0x1d4d3, "D"), (0x1d4d4, "E"), (0x1d4d5, "F"), (0x1d4d6, "G"), (0x1d4d7, "H"),
+    (0x1d4d8, "I"), (0x1d4d9, "J"), (0x1d4da, "K"), (0x1d4db, "L"), (0x1d4dc, "M"), (0x1d4dd, "N"),
+    (0x1d4de, "O"), (0x1d4df, "P"), (0x1d4e0, "Q"), (0x1d4e1, "R"), (0x1d4e2, "S"), (0x1d4e3, "T"),
+    (0x1d4e4, "U"), (0x1d4e5, "V"), (0x1d4e6, "W"), (0x1d4e7, "X"), (0x1d4e8, "Y"), (0x1d4e9, "Z"),
+    (0x1d4ea, "a"), (0x1d4eb, "b"), (0x1d4ec, "c"), (0x1d4ed, "d"), (0x1d4ee, "e"), (0x1d4ef, "f"),
+    (0x1d4f0, "g"), (0x1d4f1, "h"), (0x1d4f2, "i"), (0x1d4f3, "j"), (0x1d4f4, "k"), (0x1d4f5, "l"),
+    (0x1d4f6, "m"), (0x1d4f7, "n"), (0x1d4f8, "o"), (0x1d4f9, "p"), (0x1d4fa, "q"), (0x1d4fb, "r"),
+    (0x1d4fc, "s"), (0x1d4fd, "t"), (0x1d4fe, "u"), (0x1d4ff, "v"), (0x1d500, "w"), (0x1d501, "x"),
+    (0x1d502, "y"), (0x1d503, "z"), (0x1d504, "A"), (0x1d505, "B"), (0x1d507, "D"), (0x1d508, "E"),
+    (0x1d509, "F"), (0x1d50a, "G"), (0x1d50d, "J"), (0x1d50e, "K"), (0x1d50f, "L"), (0x1d510, "M"),
+    (0x1d511, "N"), (0x1d512, "O"), (0x1d513, "P"), (0x1d514, "Q"), (0x1d516, "S"), (0x1d517, "T"),
+    (0x1d518, "U"), (0x1d519, "V"), (0x1d51a, "W"), (0x1d51b, "X"), (0x1d51c, "Y"), (0x1d51e, "a"),
+    (0x1d51f, "b"), (0x1d520, "c"), (0x1d521, "d"), (0x1d522, "e"), (0x1d523, "f"), (0x1d524, "g"),
+    (0x1d525, "h"), (0x1d526, "i"), (0x1d527, "j"), (0x1d528, "k"), (0x1d529, "l"), (0x1d52a, "m"),
+    (0x1d52b, "n"), (0x1d52c, "o"), (0x1d52d, "p"), (0x1d52e, "q"), (0x1d52f, "r"), (0x1d530, "s"),
+    (0x1d531, "t"), (0x1d532, "u"), (0x1d533, "v"), (0x1d534, "w"), (0x1d535, "x"), (0x1d536, "y"),
+    (0x1d537, "z"), (0x1d538, "A"), (0x1d539, "B"), (0x1d53b, "D"), (0x1d53c, "E"), (0x1d53d, "F"),
+    (0x1d53e, "G"), (0x1d540, "I"), (0x1d541, "J"), (0x1d542, "K"), (0x1d543, "L"), (0x1d544, "M"),
+    (0x1d546, "O"), (0x1d54a, "S"), (0x1d54b, "T"), (0x1d54c, "U"), (0x1d54d, "V"), (0x1d54e, "W"),
+    (0x1d54f, "X"), (0x1d550, "Y"), (0x1d552, "a"), (0x1d553, "b"), (0x1d554, "c"), (0x1d555, "d"),
+    (0x1d556, "e"), (0x1d557, "f"), (0x1d558, "g"), (0x1d559, "h"), (0x1d55a, "i"), (0x1d55b, "j"),
+    (0x1d55c, "k"), (0x1d55d, "l"), (0x1d55e, "m"), (0x1d55f, "n"), (0x1d560, "o"), (0x1d561, "p"),
+    (0x1d562, "q"), (0x1d563, "r"), (0x1d564, "s"), (0x1d565, "t"), (0x1d566, "u"), (0x1d567, "v"),
+    (0x1d568, "w"), (0x1d569, "x"), (0x1d56a, "y"), (0x1d56b, "z"), (0x1d56c, "A"), (0x1d56d, "B"),
+    (0x1d56e, "C"), (0x1d56f, "D"), (0x1d570, "E"), (0x1d571, "F"), (0x1d572, "G"), (0x1d573, "H"),
+    (0x1d574, "I"), (0x1d575, "J"), (0x1d576, "K"), (0x1d577, "L"), (0x1d578, "M"), (0x1d579, "N"),
+    (0x1d57a, "O"), (0x1d57b, "P"), (0x1d57c, "Q"), (0x1d57d, "R"), (0x1d57e, "S"), (0x1d57f, "T"),
+    (0x1d580, "U"), (0x1d581, "V"), (0x1d582, "W"), (0x1d583, "X"), (0x1d584, "Y"), (0x1d585, "Z"),
+    (0x1d586, "a"), (0x1d587, "b"), (0x1d588, "c"), (0x1d589, "d"), (0x1d58a, "e"), (0x1d58b, "f"),
+    (0x1d58c, "g"), (0x1d58d, "h"), (0x1d58e, "i"), (0x1d58f, "j"), (0x1d590, "k"), (0x1d591, "l"),
+    (0x1d592, "m"), (0x1d593, "n"), (0x1d594, "o"), (0x1d595, "p"), (0x1d596, "q"), (0x1d597, "r"),
+    (0x1d598, "s"), (0x1d599, "t"), (0x1d59a, "u"), (0x1d59b, "v"), (0x1d59c, "w"), (0x1d59d, "x"),
+    (0x1d59e, "y"), (0x1d59f, "z"), (0x1d5a0, "A"), (0x1d5a1, "B"), (0x1d5a2, "C"), (0x1d5a3, "D"),
+    (0x1d5a4, "E"), (0x1d5a5, "F"), (0x1d5a6, "G"), (0x1d5a7, "H"), (0x1d5a8, "I"), (0x1d5a9, "J"),
+    (0x1d5aa, "K"), (0x1d5ab, "L"), (0x1d5ac, "M"), (0x1d5ad, "N"), (0x1d5ae, "O"), (0x1d5af, "P"),
+    (0x1d5b0, "Q"), (0x1d5b1, "R"), (0x1d5b2, "S"), (0x1d5b3, "T"), (0x1d5b4, "U"), (0x1d5b5, "V"),
+    (0x1d5b6, "W"), (0x1d5b7, "X"), (0x1d5b8, "Y"), (0x1d5b9, "Z"), (0x1d5ba, "a"), (0x1d5bb, "b"),
+    (0x1d5bc, "c"), (0x1d5bd, "d"), (0x1d5be, "e"), (0x1d5bf, "f"), (0x1d5c0, "g"), (0x1d5c1, "h"),
+    (0x1d5c2, "i"), (0x1d5c3, "j"), (0x1d5c4, "k"), (0x1d5c5, "l"), (0x1d5c6, "m"), (0x1d5c7, "n"),
+    (0x1d5c8, "o"), (0x1d5c9, "p"), (0x1d5ca, "q"), (0x1d5cb, "r"), (0x1d5cc, "s"), (0x1d5cd, "t"),
+    (0x1d5ce, "u"), (0x1d5cf, "v"), (0x1d5d0, "w"), (0x1d5d1, "x"), (0x1d5d2, "y"), (0x1d5d3, "z"),
+    (0x1d5d4, "A"), (0x1d5d5, "B"), (0x1d5d6, "C"), (0x1d5d7, "D"), (0x1d5d8, "E"), (0x1d5d9, "F"),
+    (0x1d5da, "G"), (0x1d5db, "H"), (0x1d5dc, "I"), (0x1d5dd, "J"), (0x1d5de, "K"), (0x1d5df, "L"),
+    (0x1d5e0, "M"), (0x1d5e1, "N"), (0x1d5e2, "O"), (0x1d5e3, "P"), (0x1d5e4, "Q"), (0x1d5e5, "R"),
+    (0x1d5e6, "S"), (0x1d5e7, "T"), (0x1d5e8, "U"), (0x1d5e9, "V"), (0x1d5ea, "W"), (0x1d5eb, "X"),
+    (0x1d5ec, "Y"), (0x1d5ed, "Z"), (0x1d5ee, "a"), (0x1d5ef, "b"), (0x1d5f0, "c"), (0x1d5f1, "d"),
+    (0x1d5f2, "e"), (0x1d5f3, "f"), (0x1d5f4, "g"), (0x1d5f5, "h"), (0x1d5f6, "i"), (0x1d5f7, "j"),
+    (0x1d5f8, "k"), (0x1d5f9, "l"), (0x1d5fa, "m"), (0x1d5fb, "n"), (0x1d5fc, "o"), (0x1d5fd, "p"),
+    (0x1d5fe, "q"), (0x1d5ff, "r"), (0x1d600, "s"), (0x1d601, "t"), (0x1d602, "u"), (0x1d603, "v"),
+    (0x1d604, "w"), (0x1d605, "x"), (0x1d606, "y"), (0x1d607, "z"), (0x1d608, "A"), (0x1d609, "B"),
+    (0x1d60a, "C"), (0x1d60b, "D"), (0x1d60c, "E"), (0x1d60d, "F"), (0x1d60e, "G"), (0x1d60f, "H"),
+    (0x1d610, "I"), (0x1d611, "J"), (0x1d612, "K"), (0x1d613, "L"), (0x1d614, "M"), (0x1d615, "N"),
+    (0x1d616, "O"), (0x1d617, "P"), (0x1d618, "Q"), (0x1d619, "R"), (0x1d61a, "S"), (0x1d61b, "T"),
+    (0x1d61c, "U"), (0x1d61d, "V"), (0x1d61e, "W"), (0x1d61f, "X"), (0x1d620, "Y"), (0x1d621, "Z"),
+    (0x1d622, "a"), (0x1d623, "b"), (0x1d624, "c"), (0x1d625, "d"), (0x1d626, "e"), (0x1d627, "f"),
+    (0x1d628, "g"), (0x1d629, "h"), (0x1d62a, "i"), (0x1d62b, "j"), (0x1d62c, "k"), (0x1d62d, "l"),
+    (0x1d62e, "m"), (0x1d62f, "n"), (0x1d630, "o"), (0x1d631, "p"), (0x1d632, "q"), (0x1d633, "r"),
+    (0x1d634, "s"), (0x1d635, "t"), (0x1d636, "u"), (0x1d637, "v"), (0x1d638, "w"), (0x1d639, "x"),
+    (0x1d63a, "y"), (0x1d63b, "z"), (0x1d63c, "A"), (0x1d63d, "B"), (0x1d63e, "C"), (0x1d63f, "D"),
+    (0x1d640, "E"), (0x1d641, "F"), (0x1d642, "G"), (0x1d643, "H"), (0x1d644, "I"), (0x1d645, "J"),
+    (0x1d646, "K"), (0x1d647, "L"), (0x1d648, "M"), (0x1d649, "N"), (0x1d64a, "O"), (0x1d64b, "P"),
+    (0x1d64c, "Q"), (0x1d64d, "R"), (0x1d64e, "S"), (0x1d64f, "T"), (0x1d650, "U"), (0x1d651, "V"),
+    (0x1d652, "W"), (0x1d653, "X"), (0x1d654, "Y"), (0x1d655, "Z"), (0x1d656, "a"), (0x1d657, "b"),
+    (0x1d658, "c"), (0x1d659, "d"), (0x1d65a, "e"), (0x1d65b, "f"), (0x1d65c, "g"), (0x1d65d, "h"),
+    (0x1d65e, "i"), (0x1d65f, "j"), (0x1d660, "k"), (0x1d661, "l"), (0x1d662, "m"), (0x1d663, "n"),
+    (0x1d664, "o"), (0x1d665, "p"), (0x1d666, "q"), (0x1d667, "r"), (0x1d668, "s"), (0x1d669, "t"),
+    (0x1d66a, "u"), (0x1d66b, "v"), (0x1d66c, "w"), (0x1d66d, "x"), (0x1d66e, "y"), (0x1d66f, "z"),
+    (0x1d670, "A"), (0x1d671, "B"), (0x1d672, "C"), (0x1d673, "D"), (0x1d674, "E"), (0x1d675, "F"),
+    (0x1d676, "G"), (0x1d677, "H"), (0x1d678, "I"), (0x1d679, "J"), (0x1d67a, "K"), (0x1d67b, "L"),
+    (0x1d67c, "M"), (0x1d67d, "N"), (0x1d67e, "O"), (0x1d67f, "P"), (0x1d680, "Q"), (0x1d681, "R"),
+    (0x1d682, "S"), (0x1d683, "T"), (0x1d684, "U"), (0x1d685, "V"), (0x1d686, "W"), (0x1d687, "X"),
+    (0x1d688, "Y"), (0x1d689, "Z"), (0x1d68a, "a"), (0x1d68b, "b"), (0x1d68c, "c"), (0x1d68d, "d"),
+    (0x1d68e, "e"), (0x1d68f, "f"), (0x1d690, "g"), (0x1d691, "h"), (0x1d692, "i"), (0x1d693, "j"),
+    (0x1d694, "k"), (0x1d695, "l"), (0x1d696, "m"), (0x1d697, "n"), (0x1d698, "o"), (0x1d699, "p"),
+    (0x1d69a, "q"), (0x1d69b, "r"), (0x1d69c, "s"), (0x1d69d, "t"), (0x1d69e, "u"), (0x1d69f, "v"),
+    (0x1d6a0, "w"), (0x1d6a1, "x"), (0x1d6a2, "y"), (0x1d6a3, "z"), (0x1d6a4, "\u{131}"),
+    (0x1d6a5, "\u{237}"), (0x1d6a8, "\u{391}"), (0x1d6a9, "\u{392}"), (0x1d6aa, "\u{393}"),
+    (0x1d6ab, "\u{394}"), (0x1d6ac, "\u{395}"), (0x1d6ad, "\u{396}"), (0x1d6ae, "\u{397}"),
+    (0x1d6af, "\u{398}"), (0x1d6b0, "\u{399}"), (0x1d6b1, "\u{39a}"), (0x1d6b2, "\u{39b}"),
+    (0x1d6b3, "\u{39c}"), (0x1d6b4, "\u{39d}"), (0x1d6b5, "\u{39e}"), (0x1d6b6, "\u{39f}"),
+    (0x1d6b7, "\u{3a0}"), (0x1d6b8, "\u{3a1}"), (0x1d6b9, "\u{398}"), (0x1d6ba, "\u{3a3}"),
+    (0x1d6bb, "\u{3a4}"), (0x1d6bc, "\u{3a5}"), (0x1d6bd, "\u{3a6}"), (0x1d6be, "\u{3a7}"),
+    (0x1d6bf, "\u{3a8}"), (0x1d6c0, "\u{3a9}"), (0x1d6c1, "\u{2207}"), (0x1d6c2, "\u{3b1}"),
+    (0x1d6c3, "\u{3b2}"), (0x1d6c4, "\u{3b3}"), (0x1d6c5, "\u{3b4}"), (0x1d6c6, "\u{3b5}"),
+    (0x1d6c7, "\u{3b6}"), (0x1d6c8, "\u{3b7}"), (0x1d6c9, "\u{3b8}"), (0x1d6ca, "\u{3b9}"),
+    (0x1d6cb, "\u{3ba}"), (0x1d6cc, "\u{3bb}"), (0x1d6cd, "\u{3bc}"), (0x1d6ce, "\u{3bd}"),
+    (0x1d6cf, "\u{3be}"), (0x1d6d0, "\u{3bf}"), (0x1d6d1, "\u{3c0}"), (0x1d6d2, "\u{3c1}"),
+    (0x1d6d3, "\u{3c2}"), (0x1d6d4, "\u{3c3}"), (0x1d6d5, "\u{3c4}"), (0x1d6d6, "\u{3c5}"),
+    (0x1d6d7, "\u{3c6}"), (0x1d6d8, "\u{3c7}"), (0x1d6d9, "\u{3c8}"), (0x1d6da, "\u{3c9}"),
+    (0x1d6db, "\u{2202}"), (0x1d6dc, "\u{3b5}"), (0x1d6dd, "\u{3b8}"), (0x1d6de, "\u{3ba}"),
+    (0x1d6df, "\u{3c6}"), (0x1d6e0, "\u{3c1}"), (0x1d6e1, "\u{3c0}"), (0x1d6e2, "\u{391}"),
+    (0x1d6e3, "\u{392}"), (0x1d6e4, "\u{393}"), (0x1d6e5, "\u{394}"), (0x1d6e6, "\u{395}"),
+    (0x1d6e7, "\u{396}"), (0x1d6e8, "\u{397}"), (0x1d6e9, "\u{398}"), (0x1d6ea, "\u{399}"),
+    (0x1d6eb, "\u{39a}"), (0x1d6ec, "\u{39b}"), (0x1d6ed, "\u{39c}"), (0x1d6ee, "\u{39d}"),
+    (0x1d6ef, "\u{39e}"), (0x1d6f0, "\u{39f}"), (0x1d6f1, "\u{3a0}"), (0x1d6f2, "\u{3a1}"),
+    (0x1d6f3, "\u{398}"), (0x1d6f4, "\u{3a3}"), (0x1d6f5, "\u{3a4}"), (0x1d6f6, "\u{3a5}"),
+    (0x1d6f7, "\u{3a6}"), (0x1d6f8, "\u{3a7}"), (0x1d6f9, "\u{3a8}"), (0x1d6fa, "\u{3a9}"),
+    (0x1d6fb, "\u{2207}"), (0x1d6fc, "\u{3b1}"), (0x1d6fd, "\u{3b2}"), (0x1d6fe, "\u{3b3}"),
+    (0x1d6ff, "\u{3b4}"), (0x1d700, "\u{3b5}"), (0x1d701, "\u{3b6}"), (0x1d702, "\u{3b7}"),
+    (0x1d703, "\u{3b8}"), (0x1d704, "\u{3b9}"), (0x1d705, "\u{3ba}"), (0x1d706, "\u{3bb}"),
+    (0x1d707, "\u{3bc}"), (0x1d708, "\u{3bd}"), (0x1d709, "\u{3be}"), (0x1d70a, "\u{3bf}"),
+    (0x1d70b, "\u{3c0}"), (0x1d70c, "\u{3c1}"), (0x1d70d, "\u{3c2}"), (0x1d70e, "\u{3c3}"),
+    (0x1d70f, "\u{3c4}"), (0x1d710, "\u{3c5}"), (0x1d711, "\u{3c6}"), (0x1d712, "\u{3c7}"),
+    (0x1d713, "\u{3c8}"), (0x1d714, "\u{3c9}"), (0x1d715, "\u{2202}"), (0x1d716, "\u{3b5}"),
+    (0x1d717, "\u{3b8}"), (0x1d718, "\u{3ba}"), (0x1d719, "\u{3c6}"), (0x1d71a, "\u{3c1}"),
+    (0x1d71b, "\u{3c0}"), (0x1d71c, "\u{391}"), (0x1d71d, "\u{392}"), (0x1d71e, "\u{393}"),
+    (0x1d71f, "\u{394}"), (0x1d720, "\u{395}"), (0x1d721, "\u{396}"), (0x1d722, "\u{397}"),
+    (0x1d723, "\u{398}"), (0x1d724, "\u{399}"), (0x1d725, "\u{39a}"), (0x1d726, "\u{39b}"),
+    (0x1d727, "\u{39c}"), (0x1d728, "\u{39d}"), (0x1d729, "\u{39e}"), (0x1d72a, "\u{39f}"),
+    (0x1d72b, "\u{3a0}"), (0x1d72c, "\u{3a1}"), (0x1d72d, "\u{398}"), (0x1d72e, "\u{3a3}"),
+    (0x1d72f, "\u{3a4}"), (0x1d730, "\u{3a5}"), (0x1d731, "\u{3a6}"), (0x1d732, "\u{3a7}"),
+    (0x1d733, "\u{3a8}"), (0x1d734, "\u{3a9}"), (0x1d735, "\u{2207}"), (0x1d736, "\u{3b1}"),
+    (0x1d737, "\u{3b2}"), (0x1d738, "\u{3b3}"), (0x1d739, "\u{3b4}"), (0x1d73a, "\u{3b5}"),
+    (0x1d73b, "\u{3b6}"), (0x1d73c, "\u{3b7}"), (0x1d73d, "\u{3b8}"), (0x1d73e, "\u{3b9}"),
+    (0x1d73f, "\u{3ba}"), (0x1d740, "\u{3bb}"), (0x1d741, "\u{3bc}"), (0x1d742, "\u{3bd}"),
+    (0x1d743, "\u{3be}"), (0x1d744, "\u{3bf}"), (0x1d745, "\u{3c0}"), (0x1d746, "\u{3c1}"),
+    (0x1d747, "\u{3c2}"), (0x1d748, "\u{3c3}"), (0x1d749, "\u{3c4}"), (0x1d74a, "\u{3c5}"),
+    (0x1d74b, "\u{3c6}"), (0x1d74c, "\u{3c7}"), (0x1d74d, "\u{3c8}"), (0x1d74e, "\u{3c9}"),
+    (0x1d74f, "\u{2202}"), (0x1d750, "\u{3b5}"), (0x1d751, "\u{3b8}"), (0x1d752, "\u{3ba}"),
+    (0x1d753, "\u{3c6}"), (0x1d754, "\u{3c1}"), (0x1d755, "\u{3c0}"), (0x1d756, "\u{391}"),
+    (0x1d757, "\u{392}"), (0x1d758, "\u{393}"), (0x1d759, "\u{394}"), (0x1d75a, "\u{395}"),
+    (0x1d75b, "\u{396}"), (0x1d75c, "\u{397}"), (0x1d75d, "\u{398}"), (0x1d75e, "\u{399}"),
+    (0x1d75f, "\u{39a}"), (0x1d760, "\u{39b}"), (0x1d761, "\u{39c}"), (0x1d762, "\u{39d}"),
+    (0x1d763, "\u{39e}"), (0x1d764, "\u{39f}"), (0x1d765, "\u{3a0}"), (0x1d766, "\u{3a1}"),
+    (0x1d767, "\u{398}"), (0x1d768, "\u{3a3}"), (0x1d769, "\u{3a4}"), (0x1d76a, "\u{3a5}"),
+    (0x1d76b, "\u{3a6}"), (0x1d76c, "\u{3a7}"), (0x1d76d, "\u{3a8}"), (0x1d76e, "\u{3a9}"),
+    (0x1d76f, "\u{2207}"), (0x1d770, "\u{3b1}"), (0x1d771, "\u{3b2}"), (0x1d772, "\u{3b3}"),
+    (0x1d773, "\u{3b4}"), (0x1d774, "\u{3b5}"), (0x1d775, "\u{3b6}"), (0x1d776, "\u{3b7}"),
+    (0x1d777, "\u{3b8}"), (0x1d778, "\u{3b9}"), (0x1d779, "\u{3ba}"), (0x1d77a, "\u{3bb}"),
+    (0x1d77b, "\u{3bc}"), (0x1d77c, "\u{3bd}"), (0x1d77d, "\u{3be}"), (0x1d77e, "\u{3bf}"),
+    (0x1d77f, "\u{3c0}"), (0x1d780, "\u{3c1}"), (0x1d781, "\u{3c2}"), (0x1d782, "\u{3c3}"),
+    (0x1d783, "\u{3c4}"), (0x1d784, "\u{3c5}"), (0x1d785, "\u{3c6}"), (0x1d786, "\u{3c7}"),
+    (0x1d787, "\u{3c8}"), (0x1d788, "\u{3c9}"), (0x1d789, "\u{2202}"), (0x1d78a, "\u{3b5}"),
+    (0x1d78b, "\u{3b8}"), (0x1d78c, "\u{3ba}"), (0x1d78d, "\u{3c6}"), (0x1d78e, "\u{3c1}"),
+    (0x1d78f, "\u{3c0}"), (0x1d790, "\u{391}"), (0x1d791, "\u{392}"), (0x1d792, "\u{393}"),
+    (0x1d793, "\u{394}"), (0x1d794, "\u{395}"), (0x1d795, "\u{396}"), (0x1d796, "\u{397}"),
+    (0x1d797, "\u{398}"), (0x1d798, "\u{399}"), (0x1d799, "\u{39a}"), (0x1d79a, "\u{39b}"),
+    (0x1d79b, "\u{39c}"), (0x1d79c, "\u{39d}"), (0x1d79d, "\u{39e}"), (0x1d79e, "\u{39f}"),
+    (0x1d79f, "\u{3a0}"), (0x1d7a0, "\u{3a1}"), (0x1d7a1, "\u{398}"), (0x1d7a2, "\u{3a3}"),
+    (0x1d7a3, "\u{3a4}"), (0x1d7a4, "\u{3a5}"), (0x1d7a5, "\u{3a6}"), (0x1d7a6, "\u{3a7}"),
+    (0x1d7a7, "\u{3a8}"), (0x1d7a8, "\u{3a9}"), (0x1d7a9, "\u{2207}"), (0x1d7aa, "\u{3b1}"),
+    (0x1d7ab, "\u{3b2}"), (0x1d7ac, "\u{3b3}"), (0x1d7ad, "\u{3b4}"), (0x1d7ae, "\u{3b5}"),
+    (0x1d7af, "\u{3b6}"), (0x1d7b0, "\u{3b7}"), (0x1d7b1, "\u{3b8}"), (0x1d7b2, "\u{3b9}"),
+    (0x1d7b3, "\u{3ba}"), (0x1d7b4, "\u{3bb}"), (0x1d7b5, "\u{3bc}"), (0x1d7b6, "\u{3bd}"),
+    (0x1d7b7, "\u{3be}"), (0x1d7b8, "\u{3bf}"), (0x1d7b9, "\u{3c0}"), (0x1d7ba, "\u{3c1}"),
+    (0x1d7bb, "\u{3c2}"), (0x1d7bc, "\u{3c3}"), (0x1d7bd, "\u{3c4}"), (0x1d7be, "\u{3c5}"),
+    (0x1d7bf, "\u{3c6}"), (0x1d7c0, "\u{3c7}"), (0x1d7c1, "\u{3c8}"), (0x1d7c2, "\u{3c9}"),
+    (0x1d7c3, "\u{2202}"), (0x1d7c4, "\u{3b5}"), (0x1d7c5, "\u{3b8}"), (0x1d7c6, "\u{3ba}"),
+    (0x1d7c7, "\u{3c6}"), (0x1d7c8, "\u{3c1}"), (0x1d7c9, "\u{3c0}"), (0x1d7ca, "\u{3dc}"),
+    (0x1d7cb, "\u{3dd}"), (0x1d7ce, "0"), (0x1d7cf, "1"), (0x1d7d0, "2"), (0x1d7d1, "3"),
+    (0x1d7d2, "4"), (0x1d7d3, "5"), (0x1d7d4, "6"), (0x1d7d5, "7"), (0x1d7d6, "8"), (0x1d7d7, "9"),
+    (0x1d7d8, "0"), (0x1d7d9, "1"), (0x1d7da, "2"), (0x1d7db, "3"), (0x1d7dc, "4"), (0x1d7dd, "5"),
+    (0x1d7de, "6"), (0x1d7df, "7"), (0x1d7e0, "8"), (0x1d7e1, "9"), (0x1d7e2, "0"), (0x1d7e3, "1"),
+    (0x1d7e4, "2"), (0x1d7e5, "3"), (0x1d7e6, "4"), (0x1d7e7, "5"), (0x1d7e8, "6"), (0x1d7e9, "7"),
+    (0x1d7ea, "8"), (0x1d7eb, "9"), (0x1d7ec, "0"), (0x1d7ed, "1"), (0x1d7ee, "2"), (0x1d7ef, "3"),
+    (0x1d7f0, "4"), (0x1d7f1, "5"), (0x1d7f2, "6"), (0x1d7f3, "7"), (0x1d7f4, "8"), (0x1d7f5, "9"),
+    (0x1d7f6, "0"), (0x1d7f7, "1"), (0x1d7f8, "2"), (0x1d7f9, "3"), (0x1d7fa, "4"), (0x1d7fb, "5"),
+    (0x1d7fc, "6"), (0x1d7fd, "7"), (0x1d7fe, "8"), (0x1d7ff, "9"), (0x1ee00, "\u{627}"),
+    (0x1ee01, "\u{628}"), (0x1ee02, "\u{62c}"), (0x1ee03, "\u{62f}"), (0x1ee05, "\u{648}"),
+    (0x1ee06, "\u{632}"), (0x1ee07, "\u{62d}"), (0x1ee08, "\u{637}"), (0x1ee09, "\u{64a}"),
+    (0x1ee0a, "\u{643}"), (0x1ee0b, "\u{644}"), (0x1ee0c, "\u{645}"), (0x1ee0d, "\u{646}"),
+    (0x1ee0e, "\u{633}"), (0x1ee0f, "\u{639}"), (0x1ee10, "\u{641}"), (0x1ee11, "\u{635}"),
+    (0x1ee12, "\u{642}"), (0x1ee13, "\u{631}"), (0x1ee14, "\u{634}"), (0x1ee15, "\u{62a}"),
+    (0x1ee16, "\u{62b}"), (0x1ee17, "\u{62e}"), (0x1ee18, "\u{630}"), (0x1ee19, "\u{636}"),
+    (0x1ee1a, "\u{638}"), (0x1ee1b, "\u{63a}"), (0x1ee1c, "\u{66e}"), (0x1ee1d, "\u{6ba}"),
+    (0x1ee1e, "\u{6a1}"), (0x1ee1f, "\u{66f}"), (0x1ee21, "\u{628}"), (0x1ee22, "\u{62c}"),
+    (0x1ee24, "\u{647}"), (0x1ee27, "\u{62d}"), (0x1ee29, "\u{64a}"), (0x1ee2a, "\u{643}"),
+    (0x1ee2b, "\u{644}"), (0x1ee2c, "\u{645}"), (0x1ee2d, "\u{646}"), (0x1ee2e, "\u{633}"),
+    (0x1ee2f, "\u{639}"), (0x1ee30, "\u{641}"), (0x1ee31, "\u{635}"), (0x1ee32, "\u{642}"),
+    (0x1ee34, "\u{634}"), (0x1ee35, "\u{62a}"), (0x1ee36, "\u{62b}"), (0x1ee37, "\u{62e}"),
+    (0x1ee39, "\u{636}"), (0x1ee3b, "\u{63a}"), (0x1ee42, "\u{62c}"), (0x1ee47, "\u{62d}"),
+    (0x1ee49, "\u{64a}"), (0x1ee4b, "\u{644}"), (0x1ee4d, "\u{646}"), (0x1ee4e, "\u{633}"),
+    (0x1ee4f, "\u{639}"), (0x1ee51, "\u{635}"), (0x1ee52, "\u{642}"), (0x1ee54, "\u{634}"),
+    (0x1ee57, "\u{62e}"), (0x1ee59, "\u{636}"), (0x1ee5b, "\u{63a}"), (0x1ee5d, "\u{6ba}"),
+    (0x1ee5f, "\u{66f}"), (0x1ee61, "\u{628}"), (0x1ee62, "\u{62c}"), (0x1ee64, "\u{647}"),
+    (0x1ee67, "\u{62d}"), (0x1ee68, "\u{637}"), (0x1ee69, "\u{64a}"), (0x1ee6a, "\u{643}"),
+    (0x1ee6c, "\u{645}"), (0x1ee6d, "\u{646}"), (0x1ee6e, "\u{633}"), (0x1ee6f, "\u{639}"),
+    (0x1ee70, "\u{641}"), (0x1ee71, "\u{635}"), (0x1ee72, "\u{642}"), (0x1ee74, "\u{634}"),
+    (0x1ee75, "\u{62a}"), (0x1ee76, "\u{62b}"), (0x1ee77, "\u{62e}"), (0x1ee79, "\u{636}"),
+    (0x1ee7a, "\u{638}"), (0x1ee7b, "\u{63a}"), (0x1ee7c, "\u{66e}"), (0x1ee7e, "\u{6a1}"),
+    (0x1ee80, "\u{627}"), (0x1ee81, "\u{628}"), (0x1ee82, "\u{62c}"), (0x1ee83, "\u{62f}"),
+    (0x1ee84, "\u{647}"), (0x1ee85, "\u{648}"), (0x1ee86, "\u{632}"), (0x1ee87, "\u{62d}"),
+    (0x1ee88, "\u{637}"), (0x1ee89, "\u{64a}"), (0x1ee8b, "\u{644}"), (0x1ee8c, "\u{645}"),
+    (0x1ee8d, "\u{646}"), (0x1ee8e, "\u{633}"), (0x1ee8f, "\u{639}"), (0x1ee90, "\u{641}"),
+    (0x1ee91, "\u{635}"), (0x1ee92, "\u{642}"), (0x1ee93, "\u{631}"), (0x1ee94, "\u{634}"),
+    (0x1ee95, "\u{62a}"), (0x1ee96, "\u{62b}"), (0x1ee97, "\u{62e}"), (0x1ee98, "\u{630}"),
+    (0x1ee99, "\u{636}"), (0x1ee9a, "\u{638}"), (0x1ee9b, "\u{63a}"), (0x1eea1, "\u{628}"),
+    (0x1eea2, "\u{62c}"), (0x1eea3, "\u{62f}"), (0x1eea5, "\u{648}"), (0x1eea6, "\u{632}"),
+    (0x1eea7, "\u{62d}"), (0x1eea8, "\u{637}"), (0x1eea9, "\u{64a}"), (0x1eeab, "\u{644}"),
+    (0x1eeac, "\u{645}"), (0x1eead, "\u{646}"), (0x1eeae, "\u{633}"), (0x1eeaf, "\u{639}"),
+    (0x1eeb0, "\u{641}"), (0x1eeb1, "\u{635}"), (0x1eeb2, "\u{642}"), (0x1eeb3, "\u{631}"),
+    (0x1eeb4, "\u{634}"), (0x1eeb5, "\u{62a}"), (0x1eeb6, "\u{62b}"), (0x1eeb7, "\u{62e}"),
+    (0x1eeb8, "\u{630}"), (0x1eeb9, "\u{636}"), (0x1eeba, "\u{638}"), (0x1eebb, "\u{63a}"),
+    (0x1f100, "0."), (0x1f101, "0,"), (0x1f102, "1,"), (0x1f103, "2,"), (0x1f104, "3,"),
+    (0x1f105, "4,"), (0x1f106, "5,"), (0x1f107, "6,"), (0x1f108, "7,"), (0x1f109, "8,"),
+    (0x1f10a, "9,"), (0x1f110, "(A)"), (0x1f111, "(B)"), (0x1f112, "(C)"), (0x1f113, "(D)"),
+    (0x1f114, "(E)"), (0x1f115, "(F)"), (0x1f116, "(G)"), (0x1f117, "(H)"), (0x1f118, "(I)"),
+    (0x1f119, "(J)"), (0x1f11a, "(K)"), (0x1f11b, "(L)"), (0x1f11c, "(M)"), (0x1f11d, "(N)"),
+    (0x1f11e, "(O)"), (0x1f11f, "(P)"), (0x1f120, "(Q)"), (0x1f121, "(R)"), (0x1f122, "(S)"),
+    (0x1f123, "(T)"), (0x1f124, "(U)"), (0x1f125, "(V)"), (0x1f126, "(W)"), (0x1f127, "(X)"),
+    (0x1f128, "(Y)"), (0x1f129, "(Z)"), (0x1f12a, "\u{3014}S\u{3015}"), (0x1f12b, "C"),
+    (0x1f12c, "R"), (0x1f12d, "CD"), (0x1f12e, "WZ"), (0x1f130, "A"), (0x1f131, "B"),
+    (0x1f132, "C"), (0x1f133, "D"), (0x1f134, "E"), (0x1f135, "F"), (0x1f136, "G"), (0x1f137, "H"),
+    (0x1f138, "I"), (0x1f139, "J"), (0x1f13a, "K"), (0x1f13b, "L"), (0x1f13c, "M"), (0x1f13d, "N"),
+    (0x1f13e, "O"), (0x1f13f, "P"), (0x1f140, "Q"), (0x1f141, "R"), (0x1f142, "S"), (0x1f143, "T"),
+    (0x1f144, "U"), (0x1f145, "V"), (0x1f146, "W"), (0x1f147, "X"), (0x1f148, "Y"), (0x1f149, "Z"),
+    (0x1f14a, "HV"), (0x1f14b, "MV"), (0x1f14c, "SD"), (0x1f14d, "SS"), (0x1f14e, "PPV"),
+    (0x1f14f, "WC"), (0x1f16a, "MC"), (0x1f16b, "MD"), (0x1f16c, "MR"), (0x1f190, "DJ"),
+    (0x1f200, "\u{307b}\u{304b}"), (0x1f201, "\u{30b3}\u{30b3}"), (0x1f202, "\u{30b5}"),
+    (0x1f210, "\u{624b}"), (0x1f211, "\u{5b57}"), (0x1f212, "\u{53cc}"),
+    (0x1f213, "\u{30c6}\u{3099}"), (0x1f214, "\u{4e8c}"), (0x1f215, "\u{591a}"),
+    (0x1f216, "\u{89e3}"), (0x1f217, "\u{5929}"), (0x1f218, "\u{4ea4}"), (0x1f219, "\u{6620}"),
+    (0x1f21a, "\u{7121}"), (0x1f21b, "\u{6599}"), (0x1f21c, "\u{524d}"), (0x1f21d, "\u{5f8c}"),
+    (0x1f21e, "\u{518d}"), (0x1f21f, "\u{65b0}"), (0x1f220, "\u{521d}"), (0x1f221, "\u{7d42}"),
+    (0x1f222, "\u{751f}"), (0x1f223, "\u{8ca9}"), (0x1f224, "\u{58f0}"), (0x1f225, "\u{5439}"),
+    (0x1f226, "\u{6f14}"), (0x1f227, "\u{6295}"), (0x1f228, "\u{6355}"), (0x1f229, "\u{4e00}"),
+    (0x1f22a, "\u{4e09}"), (0x1f22b, "\u{904a}"), (0x1f22c, "\u{5de6}"), (0x1f22d, "\u{4e2d}"),
+    (0x1f22e, "\u{53f3}"), (0x1f22f, "\u{6307}"), (0x1f230, "\u{8d70}"), (0x1f231, "\u{6253}"),
+    (0x1f232, "\u{7981}"), (0x1f233, "\u{7a7a}"), (0x1f234, "\u{5408}"), (0x1f235, "\u{6e80}"),
+    (0x1f236, "\u{6709}"), (0x1f237, "\u{6708}"), (0x1f238, "\u{7533}"), (0x1f239, "\u{5272}"),
+    (0x1f23a, "\u{55b6}"), (0x1f23b, "\u{914d}"), (0x1f240, "\u{3014}\u{672c}\u{3015}"),
+    (0x1f241, "\u{3014}\u{4e09}\u{3015}"), (0x1f242, "\u{3014}\u{4e8c}\u{3015}"),
+    (0x1f243, "\u{3014}\u{5b89}\u{3015}"), (0x1f244, "\u{3014}\u{70b9}\u{3015}"),
+    (0x1f245, "\u{3014}\u{6253}\u{3015}"), (0x1f246, "\u{3014}\u{76d7}\u{3015}"),
+    (0x1f247, "\u{3014}\u{52dd}\u{3015}"), (0x1f248, "\u{3014}\u{6557}\u{3015}"),
+    (0x1f250, "\u{5f97}"), (0x1f251, "\u{53ef}"), (0x1fbf0, "0"), (0x1fbf1, "1"), (0x1fbf2, "2"),
+    (0x1fbf3, "3"), (0x1fbf4, "4"), (0x1fbf5, "5"), (0x1fbf6, "6"), (0x1fbf7, "7"), (0x1fbf8, "8"),
+    (0x1fbf9, "9"),
+];
+
+// Pairs that compose into a primary composite, sorted by the pair
+#[rustfmt::skip]
+const COMPOSITIONS: &[(u32, u32, u32)] = &[
+    (0x3c, 0x338, 0x226e), (0x3d, 0x338, 0x2260), (0x3e, 0x338, 0x226f), (0x41, 0x300, 0xc0),
+    (0x41, 0x301, 0xc1), (0x41, 0x302, 0xc2), (0x41, 0x303, 0xc3), (0x41, 0x304, 0x100),
+    (0x41, 0x306, 0x102), (0x41, 0x307, 0x226), (0x41, 0x308, 0xc4), (0x41, 0x309, 0x1ea2),
+    (0x41, 0x30a, 0xc5), (0x41, 0x30c, 0x1cd), (0x41, 0x30f, 0x200), (0x41, 0x311, 0x202),
+    (0x41, 0x323, 0x1ea0), (0x41, 0x325, 0x1e00), (0x41, 0x328, 0x104), (0x42, 0x307, 0x1e02),
+    (0x42, 0x323, 0x1e04), (0x42, 0x331, 0x1e06), (0x43, 0x301, 0x106), (0x43, 0x302, 0x108),
+    (0x43, 0x307, 0x10a), (0x43, 0x30c, 0x10c), (0x43, 0x327, 0xc7), (0x44, 0x307, 0x1e0a),
+    (0x44, 0x30c, 0x10e), (0x44, 0x323, 0x1e0c), (0x44, 0x327, 0x1e10), (0x44, 0x32d, 0x1e12),
+    (0x44, 0x331, 0x1e0e), (0x45, 0x300, 0xc8), (0x45, 0x301, 0xc9), (0x45, 0x302, 0xca),
+    (0x45, 0x303, 0x1ebc), (0x45, 0x304, 0x112), (0x45, 0x306, 0x114), (0x45, 0x307, 0x116),
+    (0x45, 0x308, 0xcb), (0x45, 0x309, 0x1eba), (0x45, 0x30c, 0x11a), (0x45, 0x30f, 0x204),
+    (0x45, 0x311, 0x206), (0x45, 0x323, 0x1eb8), (0x45, 0x327, 0x228), (0x45, 0x328, 0x118),
+    (0x45, 0x32d, 0x1e18), (0x45, 0x330, 0x1e1a), (0x46, 0x307, 0x1e1e), (0x47, 0x301, 0x1f4),
+    (0x47, 0x302, 0x11c), (0x47, 0x304, 0x1e20), (0x47, 0x306, 0x11e), (0x47, 0x307, 0x120),
+    (0x47, 0x30c, 0x1e6), (0x47, 0x327, 0x122), (0x48, 0x302, 0x124), (0x48, 0x307, 0x1e22),
+    (0x48, 0x308, 0x1e26), (0x48, 0x30c, 0x21e), (0x48, 0x323, 0x1e24), (0x48, 0x327, 0x1e28),
+    (0x48, 0x32e, 0x1e2a), (0x49, 0x300, 0xcc), (0x49, 0x301, 0xcd), (0x49, 0x302, 0xce),
+    (0x49, 0x303, 0x128), (0x49, 0x304, 0x12a), (0x49, 0x306, 0x12c), (0x49, 0x307, 0x130),
+    (0x49, 0x308, 0xcf), (0x49, 0x309, 0x1ec8), (0x49, 0x30c, 0x1cf), (0x49, 0x30f, 0x208),
+    (0x49, 0x311, 0x20a), (0x49, 0x323, 0x1eca), (0x49, 0x328, 0x12e), (0x49, 0x330, 0x1e2c),
+    (0x4a, 0x302, 0x134), (0x4b, 0x301, 0x1e30), (0x4b, 0x30c, 0x1e8), (0x4b, 0x323, 0x1e32),
+    (0x4b, 0x327, 0x136), (0x4b, 0x331, 0x1e34), (0x4c, 0x301, 0x139), (0x4c, 0x30c, 0x13d),
+    (0x4c, 0x323, 0x1e36), (0x4c, 0x327, 0x13b), (0x4c, 0x32d, 0x1e3c), (0x4c, 0x331, 0x1e3a),
+    (0x4d, 0x301, 0x1e3e), (0x4d, 0x307, 0x1e40), (0x4d, 0x323, 0x1e42), (0x4e, 0x300, 0x1f8),
+    (0x4e, 0x301, 0x143), (0x4e, 0x303, 0xd1), (0x4e, 0x307, 0x1e44), (0x4e, 0x30c, 0x147),
+    (0x4e, 0x323, 0x1e46), (0x4e, 0x327, 0x145), (0x4e, 0x32d, 0x1e4a), (0x4e, 0x331, 0x1e48),
+    (0x4f, 0x300, 0xd2), (0x4f, 0x301, 0xd3), (0x4f, 0x302, 0xd4), (0x4f, 0x303, 0xd5),
+    (0x4f, 0x304, 0x14c), (0x4f, 0x306, 0x14e), (0x4f, 0x307, 0x22e), (0x4f, 0x308, 0xd6),
+    (0x4f, 0x309, 0x1ece), (0x4f, 0x30b, 0x150), (0x4f, 0x30c, 0x1d1), (0x4f, 0x30f, 0x20c),
+    (0x4f, 0x311, 0x20e), (0x4f, 0x31b, 0x1a0), (0x4f, 0x323, 0x1ecc), (0x4f, 0x328, 0x1ea),
+    (0x50, 0x301, 0x1e54), (0x50, 0x307, 0x1e56), (0x52, 0x301, 0x154), (0x52, 0x307, 0x1e58),
+    (0x52, 0x30c, 0x158), (0x52, 0x30f, 0x210), (0x52, 0x311, 0x212), (0x52, 0x323, 0x1e5a),
+    (0x52, 0x327, 0x156), (0x52, 0x331, 0x1e5e), (0x53, 0x301, 0x15a), (0x53, 0x302, 0x15c),
+    (0x53, 0x307, 0x1e60), (0x53, 0x30c, 0x160), (0x53, 0x323, 0x1e62), (0x53, 0x326, 0x218),
+    (0x53, 0x327, 0x15e), (0x54, 0x307, 0x1e6a), (0x54, 0x30c, 0x164), (0x54, 0x323, 0x1e6c),
+    (0x54, 0x326, 0x21a), (0x54, 0x327, 0x162), (0x54, 0x32d, 0x1e70), (0x54, 0x331, 0x1e6e),
+    (0x55, 0x300, 0xd9), (0x55, 0x301, 0xda), (0x55, 0x302, 0xdb), (0x55, 0x303, 0x168),
+    (0x55, 0x304, 0x16a), (0x55, 0x306, 0x16c), (0x55, 0x308, 0xdc), (0x55, 0x309, 0x1ee6),
+    (0x55, 0x30a, 0x16e), (0x55, 0x30b, 0x170), (0x55, 0x30c, 0x1d3), (0x55, 0x30f, 0x214),
+    (0x55, 0x311, 0x216), (0x55, 0x31b, 0x1af), (0x55, 0x323, 0x1ee4), (0x55, 0x324, 0x1e72),
+    (0x55, 0x328, 0x172), (0x55, 0x32d, 0x1e76), (0x55, 0x330, 0x1e74), (0x56, 0x303, 0x1e7c),
+    (0x56, 0x323, 0x1e7e), (0x57, 0x300, 0x1e80), (0x57, 0x301, 0x1e82), (0x57, 0x302, 0x174),
+    (0x57, 0x307, 0x1e86), (0x57, 0x308, 0x1e84), (0x57, 0x323, 0x1e88), (0x58, 0x307, 0x1e8a),
+    (0x58, 0x308, 0x1e8c), (0x59, 0x300, 0x1ef2), (0x59, 0x301, 0xdd), (0x59, 0x302, 0x176),
+    (0x59, 0x303, 0x1ef8), (0x59, 0x304, 0x232), (0x59, 0x307, 0x1e8e), (0x59, 0x308, 0x178),
+    (0x59, 0x309, 0x1ef6), (0x59, 0x323, 0x1ef4), (0x5a, 0x301, 0x179), (0x5a, 0x302, 0x1e90),
+    (0x5a, 0x307, 0x17b), (0x5a, 0x30c, 0x17d), (0x5a, 0x323, 0x1e92), (0x5a, 0x331, 0x1e94),
+    (0x61, 0x300, 0xe0), (0x61, 0x301, 0xe1), (0x61, 0x302, 0xe2), (0x61, 0x303, 0xe3),
+    (0x61, 0x304, 0x101), (0x61, 0x306, 0x103), (0x61, 0x307, 0x227), (0x61, 0x308, 0xe4),
+    (0x61, 0x309, 0x1ea3), (0x61, 0x30a, 0xe5), (0x61, 0x30c, 0x1ce), (0x61, 0x30f, 0x201),
+    (0x61, 0x311, 0x203), (0x61, 0x323, 0x1ea1), (0x61, 0x325, 0x1e01), (0x61, 0x328, 0x105),
+    (0x62, 0x307, 0x1e03), (0x62, 0x323, 0x1e05), (0x62, 0x331, 0x1e07), (0x63, 0x301, 0x107),
+    (0x63, 0x302, 0x109), (0x63, 0x307, 0x10b), (0x63, 0x30c, 0x10d), (0x63, 0x327, 0xe7),
+    (0x64, 0x307, 0x1e0b), (0x64, 0x30c, 0x10f), (0x64, 0x323, 0x1e0d), (0x64, 0x327, 0x1e11),
+    (0x64, 0x32d, 0x1e13), (0x64, 0x331, 0x1e0f), (0x65, 0x300, 0xe8), (0x65, 0x301, 0xe9),
+    (0x65, 0x302, 0xea), (0x65, 0x303, 0x1ebd), (0x65, 0x304, 0x113), (0x65, 0x306, 0x115),
+    (0x65, 0x307, 0x117), (0x65, 0x308, 0xeb), (0x65, 0x309, 0x1ebb), (0x65, 0x30c, 0x11b),
+    (0x65, 0x30f, 0x205), (0x65, 0x311, 0x207), (0x65, 0x323, 0x1eb9), (0x65, 0x327, 0x229),
+    (0x65, 0x328, 0x119), (0x65, 0x32d, 0x1e19), (0x65, 0x330, 0x1e1b), (0x66, 0x307, 0x1e1f),
+    (0x67, 0x301, 0x1f5), (0x67, 0x302, 0x11d), (0x67, 0x304, 0x1e21), (0x67, 0x306, 0x11f),
+    (0x67, 0x307, 0x121), (0x67, 0x30c, 0x1e7), (0x67, 0x327, 0x123), (0x68, 0x302, 0x125),
+    (0x68, 0x307, 0x1e23), (0x68, 0x308, 0x1e27), (0x68, 0x30c, 0x21f), (0x68, 0x323, 0x1e25),
+    (0x68, 0x327, 0x1e29), (0x68, 0x32e, 0x1e2b), (0x68, 0x331, 0x1e96), (0x69, 0x300, 0xec),
+    (0x69, 0x301, 0xed), (0x69, 0x302, 0xee), (0x69, 0x303, 0x129), (0x69, 0x304, 0x12b),
+    (0x69, 0x306, 0x12d), (0x69, 0x308, 0xef), (0x69, 0x309, 0x1ec9), (0x69, 0x30c, 0x1d0),
+    (0x69, 0x30f, 0x209), (0x69, 0x311, 0x20b), (0x69, 0x323, 0x1ecb), (0x69, 0x328, 0x12f),
+    (0x69, 0x330, 0x1e2d), (0x6a, 0x302, 0x135), (0x6a, 0x30c, 0x1f0), (0x6b, 0x301, 0x1e31),
+    (0x6b, 0x30c, 0x1e9), (0x6b, 0x323, 0x1e33), (0x6b, 0x327, 0x137), (0x6b, 0x331, 0x1e35),
+    (0x6c, 0x301, 0x13a), (0x6c, 0x30c, 0x13e), (0x6c, 0x323, 0x1e37), (0x6c, 0x327, 0x13c),
+    (0x6c, 0x32d, 0x1e3d), (0x6c, 0x331, 0x1e3b), (0x6d, 0x301, 0x1e3f), (0x6d, 0x307, 0x1e41),
+    (0x6d, 0x323, 0x1e43), (0x6e, 0x300, 0x1f9), (0x6e, 0x301, 0x144), (0x6e, 0x303, 0xf1),
+    (0x6e, 0x307, 0x1e45), (0x6e, 0x30c, 0x148), (0x6e, 0x323, 0x1e47), (0x6e, 0x327, 0x146),
+    (0x6e, 0x32d, 0x1e4b), (0x6e, 0x331, 0x1e49), (0x6f, 0x300, 0xf2), (0x6f, 0x301, 0xf3),
+    (0x6f, 0x302, 0xf4), (0x6f, 0x303, 0xf5), (0x6f, 0x304, 0x14d), (0x6f, 0x306, 0x14f),
+    (0x6f, 0x307, 0x22f), (0x6f, 0x308, 0xf6), (0x6f, 0x309, 0x1ecf), (0x6f, 0x30b, 0x151),
+    (0x6f, 0x30c, 0x1d2), (0x6f, 0x30f, 0x20d), (0x6f, 0x311, 0x20f), (0x6f, 0x31b, 0x1a1),
+    (0x6f, 0x323, 0x1ecd), (0x6f, 0x328, 0x1eb), (0x70, 0x301, 0x1e55), (0x70, 0x307, 0x1e57),
+    (0x72, 0x301, 0x155), (0x72, 0x307, 0x1e59), (0x72, 0x30c, 0x159), (0x72, 0x30f, 0x211),
+    (0x72, 0x311, 0x213), (0x72, 0x323, 0x1e5b), (0x72, 0x327, 0x157), (0x72, 0x331, 0x1e5f),
+    (0x73, 0x301, 0x15b), (0x73, 0x302, 0x15d), (0x73, 0x307, 0x1e61), (0x73, 0x30c, 0x161),
+    (0x73, 0x323, 0x1e63), (0x73, 0x326, 0x219), (0x73, 0x327, 0x15f), (0x74, 0x307, 0x1e6b),
+    (0x74, 0x308, 0x1e97), (0x74, 0x30c, 0x165), (0x74, 0x323, 0x1e6d), (0x74, 0x326, 0x21b),
+    (0x74, 0x327, 0x163), (0x74, 0x32d, 0x1e71), (0x74, 0x331, 0x1e6f), (0x75, 0x300, 0xf9),
+    (0x75, 0x301, 0xfa), (0x75, 0x302, 0xfb), (0x75, 0x303, 0x169), (0x75, 0x304, 0x16b),
+    (0x75, 0x306, 0x16d), (0x75, 0x308, 0xfc), (0x75, 0x309, 0x1ee7), (0x75, 0x30a, 0x16f),
+    (0x75, 0x30b, 0x171), (0x75, 0x30c, 0x1d4), (0x75, 0x30f, 0x215), (0x75, 0x311, 0x217),
+    (0x75, 0x31b, 0x1b0), (0x75, 0x323, 0x1ee5), (0x75, 0x324, 0x1e73), (0x75, 0x328, 0x173),
+    (0x75, 0x32d, 0x1e77), (0x75, 0x330, 0x1e75), (0x76, 0x303, 0x1e7d), (0x76, 0x323, 0x1e7f),
+    (0x77, 0x300, 0x1e81), (0x77, 0x301, 0x1e83), (0x77, 0x302, 0x175), (0x77, 0x307, 0x1e87),
+    (0x77, 0x308, 0x1e85), (0x77, 0x30a, 0x1e98), (0x77, 0x323, 0x1e89), (0x78, 0x307, 0x1e8b),
+    (0x78, 0x308, 0x1e8d), (0x79, 0x300, 0x1ef3), (0x79, 0x301, 0xfd), (0x79, 0x302, 0x177),
+    (0x79, 0x303, 0x1ef9), (0x79, 0x304, 0x233), (0x79, 0x307, 0x1e8f), (0x79, 0x308, 0xff),
+    (0x79, 0x309, 0x1ef7), (0x79, 0x30a, 0x1e99), (0x79, 0x323, 0x1ef5), (0x7a, 0x301, 0x17a),
+    (0x7a, 0x302, 0x1e91), (0x7a, 0x307, 0x17c), (0x7a, 0x30c, 0x17e), (0x7a, 0x323, 0x1e93),
+    (0x7a, 0x331, 0x1e95), (0xa8, 0x300, 0x1fed), (0xa8, 0x301, 0x385), (0xa8, 0x342, 0x1fc1),
+    (0xc2, 0x300, 0x1ea6), (0xc2, 0x301, 0x1ea4), (0xc2, 0x303, 0x1eaa), (0xc2, 0x309, 0x1ea8),
+    (0xc4, 0x304, 0x1de), (0xc5, 0x301, 0x1fa), (0xc6, 0x301, 0x1fc), (0xc6, 0x304, 0x1e2),
+    (0xc7, 0x301, 0x1e08), (0xca, 0x300, 0x1ec0), (0xca, 0x301, 0x1ebe), (0xca, 0x303, 0x1ec4),
+    (0xca, 0x309, 0x1ec2), (0xcf, 0x301, 0x1e2e), (0xd4, 0x300, 0x1ed2), (0xd4, 0x301, 0x1ed0),
+    (0xd4, 0x303, 0x1ed6), (0xd4, 0x309, 0x1ed4), (0xd5, 0x301, 0x1e4c), (0xd5, 0x304, 0x22c),
+    (0xd5, 0x308, 0x1e4e), (0xd6, 0x304, 0x22a), (0xd8, 0x301, 0x1fe), (0xdc, 0x300, 0x1db),
+    (0xdc, 0x301, 0x1d7), (0xdc, 0x304, 0x1d5), (0xdc, 0x30c, 0x1d9), (0xe2, 0x300, 0x1ea7),
+    (0xe2, 0x301, 0x1ea5), (0xe2, 0x303, 0x1eab), (0xe2, 0x309, 0x1ea9), (0xe4, 0x304, 0x1df),
+    (0xe5, 0x301, 0x1fb), (0xe6, 0x301, 0x1fd), (0xe6, 0x304, 0x1e3), (0xe7, 0x301, 0x1e09),
+    (0xea, 0x300, 0x1ec1), (0xea, 0x301, 0x1ebf), (0xea, 0x303, 0x1ec5), (0xea, 0x309, 0x1ec3),
+    (0xef, 0x301, 0x1e2f), (0xf4, 0x300, 0x1ed3), (0xf4, 0x301, 0x1ed1), (0xf4, 0x303, 0x1ed7),
+    (0xf4, 0x309, 0x1ed5), (0xf5, 0x301, 0x1e4d), (0xf5, 0x304, 0x22d), (0xf5, 0x308, 0x1e4f),
+    (0xf6, 0x304, 0x22b), (0xf8, 0x301, 0x1ff), (0xfc, 0x300, 0x1dc), (0xfc, 0x301, 0x1d8),
+    (0xfc, 0x304, 0x1d6), (0xfc, 0x30c, 0x1da), (0x102, 0x300, 0x1eb0), (0x102, 0x301, 0x1eae),
+    (0x102, 0x303, 0x1eb4), (0x102, 0x309, 0x1eb2), (0x103, 0x300, 0x1eb1), (0x103, 0x301, 0x1eaf),
+    (0x103, 0x303, 0x1eb5), (0x103, 0x309, 0x1eb3), (0x112, 0x300, 0x1e14), (0x112, 0x301, 0x1e16),
+    (0x113, 0x300, 0x1e15), (0x113, 0x301, 0x1e17), (0x14c, 0x300, 0x1e50), (0x14c, 0x301, 0x1e52),
+    (0x14d, 0x300, 0x1e51), (0x14d, 0x301, 0x1e53), (0x15a, 0x307, 0x1e64), (0x15b, 0x307, 0x1e65),
+    (0x160, 0x307, 0x1e66), (0x161, 0x307, 0x1e67), (0x168, 0x301, 0x1e78), (0x169, 0x301, 0x1e79),
+    (0x16a, 0x308, 0x1e7a), (0x16b, 0x308, 0x1e7b), (0x17f, 0x307, 0x1e9b), (0x1a0, 0x300, 0x1edc),
+    (0x1a0, 0x301, 0x1eda), (0x1a0, 0x303, 0x1ee0), (0x1a0, 0x309, 0x1ede), (0x1a0, 0x323, 0x1ee2),
+    (0x1a1, 0x300, 0x1edd), (0x1a1, 0x301, 0x1edb), (0x1a1, 0x303, 0x1ee1), (0x1a1, 0x309, 0x1edf),
+    (0x1a1, 0x323, 0x1ee3), (0x1af, 0x300, 0x1eea), (0x1af, 0x301, 0x1ee8), (0x1af, 0x303, 0x1eee),
+    (0x1af, 0x309, 0x1eec), (0x1af, 0x323, 0x1ef0), (0x1b0, 0x300, 0x1eeb), (0x1b0, 0x301, 0x1ee9),
+    (0x1b0, 0x303, 0x1eef), (0x1b0, 0x309, 0x1eed), (0x1b0, 0x323, 0x1ef1), (0x1b7, 0x30c, 0x1ee),
+    (0x1ea, 0x304, 0x1ec), (0x1eb, 0x304, 0x1ed), (0x226, 0x304, 0x1e0), (0x227, 0x304, 0x1e1),
+    (0x228, 0x306, 0x1e1c), (0x229, 0x306, 0x1e1d), (0x22e, 0x304, 0x230), (0x22f, 0x304, 0x231),
+    (0x292, 0x30c, 0x1ef), (0x391, 0x300, 0x1fba), (0x391, 0x301, 0x386), (0x391, 0x304, 0x1fb9),
+    (0x391, 0x306, 0x1fb8), (0x391, 0x313, 0x1f08), (0x391, 0x314, 0x1f09), (0x391, 0x345, 0x1fbc),
+    (0x395, 0x300, 0x1fc8), (0x395, 0x301, 0x388), (0x395, 0x313, 0x1f18), (0x395, 0x314, 0x1f19),
+    (0x397, 0x300, 0x1fca), (0x397, 0x301, 0x389), (0x397, 0x313, 0x1f28), (0x397, 0x314, 0x1f29),
+    (0x397, 0x345, 0x1fcc), (0x399, 0x300, 0x1fda), (0x399, 0x301, 0x38a), (0x399, 0x304, 0x1fd9),
+    (0x399, 0x306, 0x1fd8), (0x399, 0x308, 0x3aa), (0x399, 0x313, 0x1f38), (0x399, 0x314, 0x1f39),
+    (0x39f, 0x300, 0x1ff8), (0x39f, 0x301, 0x38c), (0x39f, 0x313, 0x1f48), (0x39f, 0x314, 0x1f49),
+    (0x3a1, 0x314, 0x1fec), (0x3a5, 0x300, 0x1fea), (0x3a5, 0x301, 0x38e), (0x3a5, 0x304, 0x1fe9),
+    (0x3a5, 0x306, 0x1fe8), (0x3a5, 0x308, 0x3ab), (0x3a5, 0x314, 0x1f59), (0x3a9, 0x300, 0x1ffa),
+    (0x3a9, 0x301, 0x38f), (0x3a9, 0x313, 0x1f68), (0x3a9, 0x314, 0x1f69), (0x3a9, 0x345, 0x1ffc),
+    (0x3ac, 0x345, 0x1fb4), (0x3ae, 0x345, 0x1fc4), (0x3b1, 0x300, 0x1f70), (0x3b1, 0x301, 0x3ac),
+    (0x3b1, 0x304, 0x1fb1), (0x3b1, 0x306, 0x1fb0), (0x3b1, 0x313, 0x1f00), (0x3b1, 0x314, 0x1f01),
+    (0x3b1, 0x342, 0x1fb6), (0x3b1, 0x345, 0x1fb3), (0x3b5, 0x300, 0x1f72), (0x3b5, 0x301, 0x3ad),
+    (0x3b5, 0x313, 0x1f10), (0x3b5, 0x314, 0x1f11), (0x3b7, 0x300, 0x1f74), (0x3b7, 0x301, 0x3ae),
+    (0x3b7, 0x313, 0x1f20), (0x3b7, 0x314, 0x1f21), (0x3b7, 0x342, 0x1fc6), (0x3b7, 0x345, 0x1fc3),
+    (0x3b9, 0x300, 0x1f76), (0x3b9, 0x301, 0x3af), (0x3b9, 0x304, 0x1fd1), (0x3b9, 0x306, 0x1fd0),
+    (0x3b9, 0x308, 0x3ca), (0x3b9, 0x313, 0x1f30), (0x3b9, 0x314, 0x1f31), (0x3b9, 0x342, 0x1fd6),
+    (0x3bf, 0x300, 0x1f78), (0x3bf, 0x301, 0x3cc), (0x3bf, 0x313, 0x1f40), (0x3bf, 0x314, 0x1f41),
+    (0x3c1, 0x313, 0x1fe4), (0x3c1, 0x314, 0x1fe5), (0x3c5, 0x300, 0x1f7a), (0x3c5, 0x301, 0x3cd),
+    (0x3c5, 0x304, 0x1fe1), (0x3c5, 0x306, 0x1fe0), (0x3c5, 0x308, 0x3cb), (0x3c5, 0x313, 0x1f50),
+    (0x3c5, 0x314, 0x1f51), (0x3c5, 0x342, 0x1fe6), (0x3c9, 0x300, 0x1f7c), (0x3c9, 0x301, 0x3ce),
+    (0x3c9, 0x313, 0x1f60), (0x3c9, 0x314, 0x1f61), (0x3c9, 0x342, 0x1ff6), (0x3c9, 0x345, 0x1ff3),
+    (0x3ca, 0x300, 0x1fd2), (0x3ca, 0x301, 0x390), (0x3ca, 0x342, 0x1fd7), (0x3cb, 0x300, 0x1fe2),
+    (0x3cb, 0x301, 0x3b0), (0x3cb, 0x342, 0x1fe7), (0x3ce, 0x345, 0x1ff4), (0x3d2, 0x301, 0x3d3),
+    (0x3d2, 0x308, 0x3d4), (0x406, 0x308, 0x407), (0x410, 0x306, 0x4d0), (0x410, 0x308, 0x4d2),
+    (0x413, 0x301, 0x403), (0x415, 0x300, 0x400), (0x415, 0x306, 0x4d6), (0x415, 0x308, 0x401),
+    (0x416, 0x306, 0x4c1), (0x416, 0x308, 0x4dc), (0x417, 0x308, 0x4de), (0x418, 0x300, 0x40d),
+    (0x418, 0x304, 0x4e2), (0x418, 0x306, 0x419), (0x418, 0x308, 0x4e4), (0x41a, 0x301, 0x40c),
+    (0x41e, 0x308, 0x4e6), (0x423, 0x304, 0x4ee), (0x423, 0x306, 0x40e), (0x423, 0x308, 0x4f0),
+    (0x423, 0x30b, 0x4f2), (0x427, 0x308, 0x4f4), (0x42b, 0x308, 0x4f8), (0x42d, 0x308, 0x4ec),
+    (0x430, 0x306, 0x4d1), (0x430, 0x308, 0x4d3), (0x433, 0x301, 0x453), (0x435, 0x300, 0x450),
+    (0x435, 0x306, 0x4d7), (0x435, 0x308, 0x451), (0x436, 0x306, 0x4c2), (0x436, 0x308, 0x4dd),
+    (0x437, 0x308, 0x4df), (0x438, 0x300, 0x45d), (0x438, 0x304, 0x4e3), (0x438, 0x306, 0x439),
+    (0x438, 0x308, 0x4e5), (0x43a, 0x301, 0x45c), (0x43e, 0x308, 0x4e7), (0x443, 0x304, 0x4ef),
+    (0x443, 0x306, 0x45e), (0x443, 0x308, 0x4f1), (0x443, 0x30b, 0x4f3), (0x447, 0x308, 0x4f5),
+    (0x44b, 0x308, 0x4f9), (0x44d, 0x308, 0x4ed), (0x456, 0x308, 0x457), (0x474, 0x30f, 0x476),
+    (0x475, 0x30f, 0x477), (0x4d8, 0x308, 0x4da), (0x4d9, 0x308, 0x4db), (0x4e8, 0x308, 0x4ea),
+    (0x4e9, 0x308, 0x4eb), (0x627, 0x653, 0x622), (0x627, 0x654, 0x623), (0x627, 0x655, 0x625),
+    (0x648, 0x654, 0x624), (0x64a, 0x654, 0x626), (0x6c1, 0x654, 0x6c2), (0x6d2, 0x654, 0x6d3),
+    (0x6d5, 0x654, 0x6c0), (0x928, 0x93c, 0x929), (0x930, 0x93c, 0x931), (0x933, 0x93c, 0x934),
+    (0x9c7, 0x9be, 0x9cb), (0x9c7, 0x9d7, 0x9cc), (0xb47, 0xb3e, 0xb4b), (0xb47, 0xb56, 0xb48),
+    (0xb47, 0xb57, 0xb4c), (0xb92, 0xbd7, 0xb94), (0xbc6, 0xbbe, 0xbca), (0xbc6, 0xbd7, 0xbcc),
+    (0xbc7, 0xbbe, 0xbcb), (0xc46, 0xc56, 0xc48), (0xcbf, 0xcd5, 0xcc0), (0xcc6, 0xcc2, 0xcca),
+    (0xcc6, 0xcd5, 0xcc7), (0xcc6, 0xcd6, 0xcc8), (0xcca, 0xcd5, 0xccb), (0xd46, 0xd3e, 0xd4a),
+    (0xd46, 0xd57, 0xd4c), (0xd47, 0xd3e, 0xd4b), (0xdd9, 0xdca, 0xdda), (0xdd9, 0xdcf, 0xddc),
+    (0xdd9, 0xddf, 0xdde), (0xddc, 0xdca, 0xddd), (0x1025, 0x102e, 0x1026),
+    (0x1b05, 0x1b35, 0x1b06), (0x1b07, 0x1b35, 0x1b08), (0x1b09, 0x1b35, 0x1b0a),
+    (0x1b0b, 0x1b35, 0x1b0c), (0x1b0d, 0x1b35, 0x1b0e), (0x1b11, 0x1b35, 0x1b12),
+    (0x1b3a, 0x1b35, 0x1b3b), (0x1b3c, 0x1b35, 0x1b3d), (0x1b3e, 0x1b35, 0x1b40),
+    (0x1b3f, 0x1b35, 0x1b41), (0x1b42, 0x1b35, 0x1b43), (0x1e36, 0x304, 0x1e38),
+    (0x1e37, 0x304, 0x1e39), (0x1e5a, 0x304, 0x1e5c), (0x1e5b, 0x304, 0x1e5d),
+    (0x1e62, 0x307, 0x1e68), (0x1e63, 0x307, 0x1e69), (0x1ea0, 0x302, 0x1eac),
+    (0x1ea0, 0x306, 0x1eb6), (0x1ea1, 0x302, 0x1ead), (0x1ea1, 0x306, 0x1eb7),
+    (0x1eb8, 0x302, 0x1ec6), (0x1eb9, 0x302, 0x1ec7), (0x1ecc, 0x302, 0x1ed8),
+    (0x1ecd, 0x302, 0x1ed9), (0x1f00, 0x300, 0x1f02), (0x1f00, 0x301, 0x1f04),
+    (0x1f00, 0x342, 0x1f06), (0x1f00, 0x345, 0x1f80), (0x1f01, 0x300, 0x1f03),
+    (0x1f01, 0x301, 0x1f05), (0x1f01, 0x342, 0x1f07), (0x1f01, 0x345, 0x1f81),
+    (0x1f02, 0x345, 0x1f82), (0x1f03, 0x345, 0x1f83), (0x1f04, 0x345, 0x1f84),
+    (0x1f05, 0x345, 0x1f85), (0x1f06, 0x345, 0x1f86), (0x1f07, 0x345, 0x1f87),
+    (0x1f08, 0x300, 0x1f0a), (0x1f08, 0x301, 0x1f0c), (0x1f08, 0x342, 0x1f0e),
+    (0x1f08, 0x345, 0x1f88), (0x1f09, 0x300, 0x1f0b), (0x1f09, 0x301, 0x1f0d),
+    (0x1f09, 0x342, 0x1f0f), (0x1f09, 0x345, 0x1f89), (0x1f0a, 0x345, 0x1f8a),
+    (0x1f0b, 0x345, 0x1f8b), (0x1f0c, 0x345, 0x1f8c), (0x1f0d, 0x345, 0x1f8d),
+    (0x1f0e, 0x345, 0x1f8e), (0x1f0f, 0x345, 0x1f8f), (0x1f10, 0x300, 0x1f12),
+    (0x1f10, 0x301, 0x1f14), (0x1f11, 0x300, 0x1f13), (0x1f11, 0x301, 0x1f15),
+    (0x1f18, 0x300, 0x1f1a), (0x1f18, 0x301, 0x1f1c), (0x1f19, 0x300, 0x1f1b),
+    (0x1f19, 0x301, 0x1f1d), (0x1f20, 0x300, 0x1f22), (0x1f20, 0x301, 0x1f24),
+    (0x1f20, 0x342, 0x1f26), (0x1f20, 0x345, 0x1f90), (0x1f21, 0x300, 0x1f23),
+    (0x1f21, 0x301, 0x1f25), (0x1f21, 0x342, 0x1f27), (0x1f21, 0x345, 0x1f91),
+    (0x1f22, 0x345, 0x1f92), (0x1f23, 0x345, 0x1f93), (0x1f24, 0x345, 0x1f94),
+    (0x1f25, 0x345, 0x1f95), (0x1f26, 0x345, 0x1f96), (0x1f27, 0x345, 0x1f97),
+    (0x1f28, 0x300, 0x1f2a), (0x1f28, 0x301, 0x1f2c), (0x1f28, 0x342, 0x1f2e),
+    (0x1f28, 0x345, 0x1f98), (0x1f29, 0x300, 0x1f2b), (0x1f29, 0x301, 0x1f2d),
+    (0x1f29, 0x342, 0x1f2f), (0x1f29, 0x345, 0x1f99), (0x1f2a, 0x345, 0x1f9a),
+    (0x1f2b, 0x345, 0x1f9b), (0x1f2c, 0x345, 0x1f9c), (0x1f2d, 0x345, 0x1f9d),
+    (0x1f2e, 0x345, 0x1f9e), (0x1f2f, 0x345, 0x1f9f), (0x1f30, 0x300, 0x1f32),
+    (0x1f30, 0x301, 0x1f34), (0x1f30, 0x342, 0x1f36), (0x1f31, 0x300, 0x1f33),
+    (0x1f31, 0x301, 0x1f35), (0x1f31, 0x342, 0x1f37), (0x1f38, 0x300, 0x1f3a),
+    (0x1f38, 0x301, 0x1f3c), (0x1f38, 0x342, 0x1f3e), (0x1f39, 0x300, 0x1f3b),
+    (0x1f39, 0x301, 0x1f3d), (0x1f39, 0x342, 0x1f3f), (0x1f40, 0x300, 0x1f42),
+    (0x1f40, 0x301, 0x1f44), (0x1f41, 0x300, 0x1f43), (0x1f41, 0x301, 0x1f45),
+    (0x1f48, 0x300, 0x1f4a), (0x1f48, 0x301, 0x1f4c), (0x1f49, 0x300, 0x1f4b),
+    (0x1f49, 0x301, 0x1f4d), (0x1f50, 0x300, 0x1f52), (0x1f50, 0x301, 0x1f54),
+    (0x1f50, 0x342, 0x1f56), (0x1f51, 0x300, 0x1f53), (0x1f51, 0x301, 0x1f55),
+    (0x1f51, 0x342, 0x1f57), (0x1f59, 0x300, 0x1f5b), (0x1f59, 0x301, 0x1f5d),
+    (0x1f59, 0x342, 0x1f5f), (0x1f60, 0x300, 0x1f62), (0x1f60, 0x301, 0x1f64),
+    (0x1f60, 0x342, 0x1f66), (0x1f60, 0x345, 0x1fa0), (0x1f61, 0x300, 0x1f63),
+    (0x1f61, 0x301, 0x1f65), (0x1f61, 0x342, 0x1f67), (0x1f61, 0x345, 0x1fa1),
+    (0x1f62, 0x345, 0x1fa2), (0x1f63, 0x345, 0x1fa3), (0x1f64, 0x345, 0x1fa4),
+    (0x1f65, 0x345, 0x1fa5), (0x1f66, 0x345, 0x1fa6), (0x1f67, 0x345, 0x1fa7),
+    (0x1f68, 0x300, 0x1f6a), (0x1f68, 0x301, 0x1f6c), (0x1f68, 0x342, 0x1f6e),
+    (0x1f68, 0x345, 0x1fa8), (0x1f69, 0x300, 0x1f6b), (0x1f69, 0x301, 0x1f6d),
+    (0x1f69, 0x342, 0x1f6f), (0x1f69, 0x345, 0x1fa9), (0x1f6a, 0x345, 0x1faa),
+    (0x1f6b, 0x345, 0x1fab), (0x1f6c, 0x345, 0x1fac), (0x1f6d, 0x345, 0x1fad),
+    (0x1f6e, 0x345, 0x1fae), (0x1f6f, 0x345, 0x1faf), (0x1f70, 0x345, 0x1fb2),
+    (0x1f74, 0x345, 0x1fc2), (0x1f7c, 0x345, 0x1ff2), (0x1fb6, 0x345, 0x1fb7),
+    (0x1fbf, 0x300, 0x1fcd), (0x1fbf, 0x301, 0x1fce), (0x1fbf, 0x342, 0x1fcf),
+    (0x1fc6, 0x345, 0x1fc7), (0x1ff6, 0x345, 0x1ff7), (0x1ffe, 0x300, 0x1fdd),
+    (0x1ffe, 0x301, 0x1fde), (0x1ffe, 0x342, 0x1fdf), (0x2190, 0x338, 0x219a),
+    (0x2192, 0x338, 0x219b), (0x2194, 0x338, 0x21ae), (0x21d0, 0x338, 0x21cd),
+    (0x21d2, 0x338, 0x21cf), (0x21d4, 0x338, 0x21ce), (0x2203, 0x338, 0x2204),
+    (0x2208, 0x338, 0x2209), (0x220b, 0x338, 0x220c), (0x2223, 0x338, 0x2224),
+    (0x2225, 0x338, 0x2226), (0x223c, 0x338, 0x2241), (0x2243, 0x338, 0x2244),
+    (0x2245, 0x338, 0x2247), (0x2248, 0x338, 0x2249), (0x224d, 0x338, 0x226d),
+    (0x2261, 0x338, 0x2262), (0x2264, 0x338, 0x2270), (0x2265, 0x338, 0x2271),
+    (0x2272, 0x338, 0x2274), (0x2273, 0x338, 0x2275), (0x2276, 0x338, 0x2278),
+    (0x2277, 0x338, 0x2279), (0x227a, 0x338, 0x2280), (0x227b, 0x338, 0x2281),
+    (0x227c, 0x338, 0x22e0), (0x227d, 0x338, 0x22e1), (0x2282, 0x338, 0x2284),
+    (0x2283, 0x338, 0x2285), (0x2286, 0x338, 0x2288), (0x2287, 0x338, 0x2289),
+    (0x2291, 0x338, 0x22e2), (0x2292, 0x338, 0x22e3), (0x22a2, 0x338, 0x22ac),
+    (0x22a8, 0x338, 0x22ad), (0x22a9, 0x338, 0x22ae), (0x22ab, 0x338, 0x22af),
+    (0x22b2, 0x338, 0x22ea), (0x22b3, 0x338, 0x22eb), (0x22b4, 0x338, 0x22ec),
+    (0x22b5, 0x338, 0x22ed), (0x3046, 0x3099, 0x3094), (0x304b, 0x3099, 0x304c),
+    (0x304d, 0x3099, 0x304e), (0x304f, 0x3099, 0x3050), (0x3051, 0x3099, 0x3052),
+    (0x3053, 0x3099, 0x3054), (0x3055, 0x3099, 0x3056), (0x3057, 0x3099, 0x3058),
+    (0x3059, 0x3099, 0x305a), (0x305b, 0x3099, 0x305c), (0x305d, 0x3099, 0x305e),
+    (0x305f, 0x3099, 0x3060), (0x3061, 0x3099, 0x3062), (0x3064, 0x3099, 0x3065),
+    (0x3066, 0x3099, 0x3067), (0x3068, 0x3099, 0x3069), (0x306f, 0x3099, 0x3070),
+    (0x306f, 0x309a, 0x3071), (0x3072, 0x3099, 0x3073), (0x3072, 0x309a, 0x3074),
+    (0x3075, 0x3099, 0x3076), (0x3075, 0x309a, 0x3077), (0x3078, 0x3099, 0x3079),
+    (0x3078, 0x309a, 0x307a), (0x307b, 0x3099, 0x307c), (0x307b, 0x309a, 0x307d),
+    (0x309d, 0x3099, 0x309e), (0x30a6, 0x3099, 0x30f4), (0x30ab, 0x3099, 0x30ac),
+    (0x30ad, 0x3099, 0x30ae), (0x30af, 0x3099, 0x30b0), (0x30b1, 0x3099, 0x30b2),
+    (0x30b3, 0x3099, 0x30b4), (0x30b5, 0x3099, 0x30b6), (0x30b7, 0x3099, 0x30b8),
+    (0x30b9, 0x3099, 0x30ba), (0x30bb, 0x3099, 0x30bc), (0x30bd, 0x3099, 0x30be),
+    (0x30bf, 0x3099, 0x30c0), (0x30c1, 0x3099, 0x30c2), (0x30c4, 0x3099, 0x30c5),
+    (0x30c6, 0x3099, 0x30c7), (0x30c8, 0x3099, 0x30c9), (0x30cf, 0x3099, 0x30d0),
+    (0x30cf, 0x309a, 0x30d1), (0x30d2, 0x3099, 0x30d3), (0x30d2, 0x309a, 0x30d4),
+    (0x30d5, 0x3099, 0x30d6), (0x30d5, 0x309a, 0x30d7), (0x30d8, 0x3099, 0x30d9),
+    (0x30d8, 0x309a, 0x30da), (0x30db, 0x3099, 0x30dc), (0x30db, 0x309a, 0x30dd),
+    (0x30ef, 0x3099, 0x30f7), (0x30f0, 0x3099, 0x30f8), (0x30f1, 0x3099, 0x30f9),
+    (0x30f2, 0x3099, 0x30fa), (0x30fd, 0x3099, 0x30fe), (0x11099, 0x110ba, 0x1109a),
+    (0x1109b, 0x110ba, 0x1109c), (0x110a5, 0x110ba, 0x110ab), (0x11131, 0x11127, 0x1112e),
+    (0x11132, 0x11127, 0x1112f), (0x11347, 0x1133e, 0x1134b), (0x11347, 0x11357, 0x1134c),
+    (0x114b9, 0x114b0, 0x114bc), (0x114b9, 0x114ba, 0x114bb), (0x114b9, 0x114bd, 0x114be),
+    (0x115b8, 0x115af, 0x115ba), (0x115b9, 0x115af, 0x115bb), (0x11935, 0x11930, 0x11938),
+];
+
+// Ranges of chars sharing a non-zero canonical combining class
+#[rustfmt::skip]
+const COMBINING_CLASSES: &[(u32, u32, u8)] = &[
+    (0x300, 0x314, 230), (0x315, 0x315, 232), (0x316, 0x319, 220), (0x31a, 0x31a, 232),
+    (0x31b, 0x31b, 216), (0x31c, 0x320, 220), (0x321, 0x322, 202), (0x323, 0x326, 220),
+    (0x327, 0x328, 202), (0x329, 0x333, 220), (0x334, 0x338, 1), (0x339, 0x33c, 220),
+    (0x33d, 0x344, 230), (0x345, 0x345, 240), (0x346, 0x346, 230), (0x347, 0x349, 220),
+    (0x34a, 0x34c, 230), (0x34d, 0x34e, 220), (0x350, 0x352, 230), (0x353, 0x356, 220),
+    (0x357, 0x357, 230), (0x358, 0x358, 232), (0x359, 0x35a, 220), (0x35b, 0x35b, 230),
+    (0x35c, 0x35c, 233), (0x35d, 0x35e, 234), (0x35f, 0x35f, 233), (0x360, 0x361, 234),
+    (0x362, 0x362, 233), (0x363, 0x36f, 230), (0x483, 0x487, 230), (0x591, 0x591, 220),
+    (0x592, 0x595, 230), (0x596, 0x596, 220), (0x597, 0x599, 230), (0x59a, 0x59a, 222),
+    (0x59b, 0x59b, 220), (0x59c, 0x5a1, 230), (0x5a2, 0x5a7, 220), (0x5a8, 0x5a9, 230),
+    (0x5aa, 0x5aa, 220), (0x5ab, 0x5ac, 230), (0x5ad, 0x5ad, 222), (0x5ae, 0x5ae, 228),
+    (0x5af, 0x5af, 230), (0x5b0, 0x5b0, 10), (0x5b1, 0x5b1, 11), (0x5b2, 0x5b2, 12),
+    (0x5b3, 0x5b3, 13), (0x5b4, 0x5b4, 14), (0x5b5, 0x5b5, 15), (0x5b6, 0x5b6, 16),
+    (0x5b7, 0x5b7, 17), (0x5b8, 0x5b8, 18), (0x5b9, 0x5ba, 19), (0x5bb, 0x5bb, 20),
+    (0x5bc, 0x5bc, 21), (0x5bd, 0x5bd, 22), (0x5bf, 0x5bf, 23), (0x5c1, 0x5c1, 24),
+    (0x5c2, 0x5c2, 25), (0x5c4, 0x5c4, 230), (0x5c5, 0x5c5, 220), (0x5c7, 0x5c7, 18),
+    (0x610, 0x617, 230), (0x618, 0x618, 30), (0x619, 0x619, 31), (0x61a, 0x61a, 32),
+    (0x64b, 0x64b, 27), (0x64c, 0x64c, 28), (0x64d, 0x64d, 29), (0x64e, 0x64e, 30),
+    (0x64f, 0x64f, 31), (0x650, 0x650, 32), (0x651, 0x651, 33), (0x652, 0x652, 34),
+    (0x653, 0x654, 230), (0x655, 0x656, 220), (0x657, 0x65b, 230), (0x65c, 0x65c, 220),
+    (0x65d, 0x65e, 230), (0x65f, 0x65f, 220), (0x670, 0x670, 35), (0x6d6, 0x6dc, 230),
+    (0x6df, 0x6e2, 230), (0x6e3, 0x6e3, 220), (0x6e4, 0x6e4, 230), (0x6e7, 0x6e8, 230),
+    (0x6ea, 0x6ea, 220), (0x6eb, 0x6ec, 230), (0x6ed, 0x6ed, 220), (0x711, 0x711, 36),
+    (0x730, 0x730, 230), (0x731, 0x731, 220), (0x732, 0x733, 230), (0x734, 0x734, 220),
+    (0x735, 0x736, 230), (0x737, 0x739, 220), (0x73a, 0x73a, 230), (0x73b, 0x73c, 220),
+    (0x73d, 0x73d, 230), (0x73e, 0x73e, 220), (0x73f, 0x741, 230), (0x742, 0x742, 220),
+    (0x743, 0x743, 230), (0x744, 0x744, 220), (0x745, 0x745, 230), (0x746, 0x746, 220),
+    (0x747, 0x747, 230), (0x748, 0x748, 220), (0x749, 0x74a, 230), (0x7eb, 0x7f1, 230),
+    (0x7f2, 0x7f2, 220), (0x7f3, 0x7f3, 230), (0x7fd, 0x7fd, 220), (0x816, 0x819, 230),
+    (0x81b, 0x823, 230), (0x825, 0x827, 230), (0x829, 0x82d, 230), (0x859, 0x85b, 220),
+    (0x898, 0x898, 230), (0x899, 0x89b, 220), (0x89c, 0x89f, 230), (0x8ca, 0x8ce, 230),
+    (0x8cf, 0x8d3, 220), (0x8d4, 0x8e1, 230), (0x8e3, 0x8e3, 220), (0x8e4, 0x8e5, 230),
+    (0x8e6, 0x8e6, 220), (0x8e7, 0x8e8, 230), (0x8e9, 0x8e9, 220), (0x8ea, 0x8ec, 230),
+    (0x8ed, 0x8ef, 220), (0x8f0, 0x8f0, 27), (0x8f1, 0x8f1, 28), (0x8f2, 0x8f2, 29),
+    (0x8f3, 0x8f5, 230), (0x8f6, 0x8f6, 220), (0x8f7, 0x8f8, 230), (0x8f9, 0x8fa, 220),
+    (0x8fb, 0x8ff, 230), (0x93c, 0x93c, 7), (0x94d, 0x94d, 9), (0x951, 0x951, 230),
+    (0x952, 0x952, 220), (0x953, 0x954, 230), (0x9bc, 0x9bc, 7), (0x9cd, 0x9cd, 9),
+    (0x9fe, 0x9fe, 230), (0xa3c, 0xa3c, 7), (0xa4d, 0xa4d, 9), (0xabc, 0xabc, 7),
+    (0xacd, 0xacd, 9), (0xb3c, 0xb3c, 7), (0xb4d, 0xb4d, 9), (0xbcd, 0xbcd, 9), (0xc3c, 0xc3c, 7),
+    (0xc4d, 0xc4d, 9), (0xc55, 0xc55, 84), (0xc56, 0xc56, 91), (0xcbc, 0xcbc, 7),
+    (0xccd, 0xccd, 9), (0xd3b, 0xd3c, 9), (0xd4d, 0xd4d, 9), (0xdca, 0xdca, 9),
+    (0xe38, 0xe39, 103), (0xe3a, 0xe3a, 9), (0xe48, 0xe4b, 107), (0xeb8, 0xeb9, 118),
+    (0xeba, 0xeba, 9), (0xec8, 0xecb, 122), (0xf18, 0xf19, 220), (0xf35, 0xf35, 220),
+    (0xf37, 0xf37, 220), (0xf39, 0xf39, 216), (0xf71, 0xf71, 129), (0xf72, 0xf72, 130),
+    (0xf74, 0xf74, 132), (0xf7a, 0xf7d, 130), (0xf80, 0xf80, 130), (0xf82, 0xf83, 230),
+    (0xf84, 0xf84, 9), (0xf86, 0xf87, 230), (0xfc6, 0xfc6, 220), (0x1037, 0x1037, 7),
+    (0x1039, 0x103a, 9), (0x108d, 0x108d, 220), (0x135d, 0x135f, 230), (0x1714, 0x1715, 9),
+    (0x1734, 0x1734, 9), (0x17d2, 0x17d2, 9), (0x17dd, 0x17dd, 230), (0x18a9, 0x18a9, 228),
+    (0x1939, 0x1939, 222), (0x193a, 0x193a, 230), (0x193b, 0x193b, 220), (0x1a17, 0x1a17, 230),
+    (0x1a18, 0x1a18, 220), (0x1a60, 0x1a60, 9), (0x1a75, 0x1a7c, 230), (0x1a7f, 0x1a7f, 220),
+    (0x1ab0, 0x1ab4, 230), (0x1ab5, 0x1aba, 220), (0x1abb, 0x1abc, 230), (0x1abd, 0x1abd, 220),
+    (0x1abf, 0x1ac0, 220), (0x1ac1, 0x1ac2, 230), (0x1ac3, 0x1ac4, 220), (0x1ac5, 0x1ac9, 230),
+    (0x1aca, 0x1aca, 220), (0x1acb, 0x1ace, 230), (0x1b34, 0x1b34, 7), (0x1b44, 0x1b44, 9),
+    (0x1b6b, 0x1b6b, 230), (0x1b6c, 0x1b6c, 220), (0x1b6d, 0x1b73, 230), (0x1baa, 0x1bab, 9),
+    (0x1be6, 0x1be6, 7), (0x1bf2, 0x1bf3, 9), (0x1c37, 0x1c37, 7), (0x1cd0, 0x1cd2, 230),
+    (0x1cd4, 0x1cd4, 1), (0x1cd5, 0x1cd9, 220), (0x1cda, 0x1cdb, 230), (0x1cdc, 0x1cdf, 220),
+    (0x1ce0, 0x1ce0, 230), (0x1ce2, 0x1ce8, 1), (0x1ced, 0x1ced, 220), (0x1cf4, 0x1cf4, 230),
+    (0x1cf8, 0x1cf9, 230), (0x1dc0, 0x1dc1, 230), (0x1dc2, 0x1dc2, 220), (0x1dc3, 0x1dc9, 230),
+    (0x1dca, 0x1dca, 220), (0x1dcb, 0x1dcc, 230), (0x1dcd, 0x1dcd, 234), (0x1dce, 0x1dce, 214),
+    (0x1dcf, 0x1dcf, 220), (0x1dd0, 0x1dd0, 202), (0x1dd1, 0x1df5, 230), (0x1df6, 0x1df6, 232),
+    (0x1df7, 0x1df8, 228), (0x1df9, 0x1df9, 220), (0x1dfa, 0x1dfa, 218), (0x1dfb, 0x1dfb, 230),
+    (0x1dfc, 0x1dfc, 233), (0x1dfd, 0x1dfd, 220), (0x1dfe, 0x1dfe, 230), (0x1dff, 0x1dff, 220),
+    (0x20d0, 0x20d1, 230), (0x20d2, 0x20d3, 1), (0x20d4, 0x20d7, 230), (0x20d8, 0x20da, 1),
+    (0x20db, 0x20dc, 230), (0x20e1, 0x20e1, 230), (0x20e5, 0x20e6, 1), (0x20e7, 0x20e7, 230),
+    (0x20e8, 0x20e8, 220), (0x20e9, 0x20e9, 230), (0x20ea, 0x20eb, 1), (0x20ec, 0x20ef, 220),
+    (0x20f0, 0x20f0, 230), (0x2cef, 0x2cf1, 230), (0x2d7f, 0x2d7f, 9), (0x2de0, 0x2dff, 230),
+    (0x302a, 0x302a, 218), (0x302b, 0x302b, 228), (0x302c, 0x302c, 232), (0x302d, 0x302d, 222),
+    (0x302e, 0x302f, 224), (0x3099, 0x309a, 8), (0xa66f, 0xa66f, 230), (0xa674, 0xa67d, 230),
+    (0xa69e, 0xa69f, 230), (0xa6f0, 0xa6f1, 230), (0xa806, 0xa806, 9), (0xa82c, 0xa82c, 9),
+    (0xa8c4, 0xa8c4, 9), (0xa8e0, 0xa8f1, 230), (0xa92b, 0xa92d, 220), (0xa953, 0xa953, 9),
+    (0xa9b3, 0xa9b3, 7), (0xa9c0, 0xa9c0, 9), (0xaab0, 0xaab0, 230), (0xaab2, 0xaab3, 230),
+    (0xaab4, 0xaab4, 220), (0xaab7, 0xaab8, 230), (0xaabe, 0xaabf, 230), (0xaac1, 0xaac1, 230),
+    (0xaaf6, 0xaaf6, 9), (0xabed, 0xabed, 9), (0xfb1e, 0xfb1e, 26), (0xfe20, 0xfe26, 230),
+    (0xfe27, 0xfe2d, 220), (0xfe2e, 0xfe2f, 230), (0x101fd, 0x101fd, 220), (0x102e0, 0x102e0, 220),
+    (0x10376, 0x1037a, 230), (0x10a0d, 0x10a0d, 220), (0x10a0f, 0x10a0f, 230),
+    (0x10a38, 0x10a38, 230), (0x10a39, 0x10a39, 1), (0x10a3a, 0x10a3a, 220), (0x10a3f, 0x10a3f, 9),
+    (0x10ae5, 0x10ae5, 230), (0x10ae6, 0x10ae6, 220), (0x10d24, 0x10d27, 230),
+    (0x10eab, 0x10eac, 230), (0x10f46, 0x10f47, 220), (0x10f48, 0x10f4a, 230),
+    (0x10f4b, 0x10f4b, 220), (0x10f4c, 0x10f4c, 230), (0x10f4d, 0x10f50, 220),
+    (0x10f82, 0x10f82, 230), (0x10f83, 0x10f83, 220), (0x10f84, 0x10f84, 230),
+    (0x10f85, 0x10f85, 220), (0x11046, 0x11046, 9), (0x11070, 0x11070, 9), (0x1107f, 0x1107f, 9),
+    (0x110b9, 0x110b9, 9), (0x110ba, 0x110ba, 7), (0x11100, 0x11102, 230), (0x11133, 0x11134, 9),
+    (0x11173, 0x11173, 7), (0x111c0, 0x111c0, 9), (0x111ca, 0x111ca, 7), (0x11235, 0x11235, 9),
+    (0x11236, 0x11236, 7), (0x112e9, 0x112e9, 7), (0x112ea, 0x112ea, 9), (0x1133b, 0x1133c, 7),
+    (0x1134d, 0x1134d, 9), (0x11366, 0x1136c, 230), (0x11370, 0x11374, 230), (0x11442, 0x11442, 9),
+    (0x11446, 0x11446, 7), (0x1145e, 0x1145e, 230), (0x114c2, 0x114c2, 9), (0x114c3, 0x114c3, 7),
+    (0x115bf, 0x115bf, 9), (0x115c0, 0x115c0, 7), (0x1163f, 0x1163f, 9), (0x116b6, 0x116b6, 9),
+    (0x116b7, 0x116b7, 7), (0x1172b, 0x1172b, 9), (0x11839, 0x11839, 9), (0x1183a, 0x1183a, 7),
+    (0x1193d, 0x1193e, 9), (0x11943, 0x11943, 7), (0x119e0, 0x119e0, 9), (0x11a34, 0x11a34, 9),
+    (0x11a47, 0x11a47, 9), (0x11a99, 0x11a99, 9), (0x11c3f, 0x11c3f, 9), (0x11d42, 0x11d42, 7),
+    (0x11d44, 0x11d45, 9), (0x11d97, 0x11d97, 9), (0x16af0, 0x16af4, 1), (0x16b30, 0x16b36, 230),
+    (0x16ff0, 0x16ff1, 6), (0x1bc9e, 0x1bc9e, 1), (0x1d165, 0x1d166, 216), (0x1d167, 0x1d169, 1),
+    (0x1d16d, 0x1d16d, 226), (0x1d16e, 0x1d172, 216), (0x1d17b, 0x1d182, 220),
+    (0x1d185, 0x1d189, 230), (0x1d18a, 0x1d18b, 220), (0x1d1aa, 0x1d1ad, 230),
+    (0x1d242, 0x1d244, 230), (0x1e000, 0x1e006, 230), (0x1e008, 0x1e018, 230),
+    (0x1e01b, 0x1e021, 230), (0x1e023, 0x1e024, 230), (0x1e026, 0x1e02a, 230),
+    (0x1e130, 0x1e136, 230), (0x1e2ae, 0x1e2ae, 230), (0x1e2ec, 0x1e2ef, 230),
+    (0x1e8d0, 0x1e8d6, 220), (0x1e944, 0x1e949, 230), (0x1e94a, 0x1e94a, 7),
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Expected results are from Python's unicodedata, at the same version
+    #[test]
+    fn each_form_matches_the_reference() {
+        let cases = [
+            (
+                "\u{1e9b}\u{323}",
+                [
+                    "\u{1e9b}\u{323}",
+                    "\u{17f}\u{323}\u{307}",
+                    "\u{1e69}",
+                    "s\u{323}\u{307}",
+                ],
+            ),
+            (
+                "\u{fb01} \u{2460}",
+                ["\u{fb01} \u{2460}", "\u{fb01} \u{2460}", "fi 1", "fi 1"],
+            ),
+            (
+                "\u{ac01}",
+                [
+                    "\u{ac01}",
+                    "\u{1100}\u{1161}\u{11a8}",
+                    "\u{ac01}",
+                    "\u{1100}\u{1161}\u{11a8}",
+                ],
+            ),
+            (
+                "a\u{301}\u{323}",
+                [
+                    "\u{1ea1}\u{301}",
+                    "a\u{323}\u{301}",
+                    "\u{1ea1}\u{301}",
+                    "a\u{323}\u{301}",
+                ],
+            ),
+            ("\u{212b}", ["\u{c5}", "A\u{30a}", "\u{c5}", "A\u{30a}"]),
+        ];
+        for (input, expected) in cases {
+            for (form, expected) in [Form::Nfc, Form::Nfd, Form::Nfkc, Form::Nfkd]
+                .into_iter()
+                .zip(expected)
+            {
+                assert_eq!(normalize(input, form), expected, "{input:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn only_the_four_form_names_are_known() {
+        assert!(Form::from_name("NFKD") == Some(Form::Nfkd));
+        assert!(Form::from_name("nfc").is_none());
+    }
+}
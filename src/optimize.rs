@@ -0,0 +1,234 @@
+use crate::{
+    interpreter::{Checked64, IntArithmetic},
+    lexer::Token,
+    parser::{Expr, Operator},
+};
+
+// Folds at the default integer width, the one Context starts with
+pub fn fold_constants(exprs: Vec<Expr>) -> Vec<Expr> {
+    fold_constants_with(exprs, &Checked64)
+}
+
+// Integer arithmetic goes through the width the program will run at, so
+// anything that would overflow there is left for the interpreter to report
+pub fn fold_constants_with(exprs: Vec<Expr>, integers: &dyn IntArithmetic) -> Vec<Expr> {
+    exprs
+        .into_iter()
+        .map(|expr| fold_expr(expr, integers))
+        .collect()
+}
+
+fn fold_expr(expr: Expr, integers: &dyn IntArithmetic) -> Expr {
+    match expr {
+        Expr::BinaryExpr { op, lhs, rhs } => {
+            let lhs = fold_expr(*lhs, integers);
+            let rhs = fold_expr(*rhs, integers);
+            if let (Expr::Token(l), Expr::Token(r)) = (&lhs, &rhs) {
+                if let Some(token) = fold_binary(&op, l, r, integers) {
+                    return Expr::Token(token);
+                }
+            }
+            Expr::BinaryExpr {
+                op,
+                lhs: Box::new(lhs),
+                rhs: Box::new(rhs),
+            }
+        }
+        Expr::UnaryExpr { op, operand } => match (op, fold_expr(*operand, integers)) {
+            (Operator::Sub, Expr::Token(Token::Int(i))) => match integers.sub(0, i) {
+                Some(negated) => Expr::Token(Token::Int(negated)),
+                None => Expr::UnaryExpr {
+                    op: Operator::Sub,
+                    operand: Box::new(Expr::Token(Token::Int(i))),
+                },
+            },
+            (Operator::Sub, Expr::Token(Token::Float(f))) => Expr::Token(Token::Float(-f)),
+            (Operator::Not, Expr::Token(Token::Bool(b))) => Expr::Token(Token::Bool(!b)),
+            (op, operand) => Expr::UnaryExpr {
+                op,
+                operand: Box::new(operand),
+            },
+        },
+        Expr::FnCall { callee, args } => Expr::FnCall {
+            callee: Box::new(fold_expr(*callee, integers)),
+            args: fold_constants_with(args, integers),
+        },
+        Expr::FnDef {
+            name,
+            args,
+            body,
+            return_type,
+        } => Expr::FnDef {
+            name,
+            args,
+            body: fold_constants_with(body, integers),
+            return_type,
+        },
+        Expr::Lambda { args, body } => Expr::Lambda {
+            args,
+            body: fold_constants_with(body, integers),
+        },
+        Expr::If {
+            cond,
+            body,
+            else_body,
+        } => Expr::If {
+            cond: Box::new(fold_expr(*cond, integers)),
+            body: fold_constants_with(body, integers),
+            else_body: else_body.map(|body| fold_constants_with(body, integers)),
+        },
+        Expr::MatchType { subject, arms } => Expr::MatchType {
+            subject: Box::new(fold_expr(*subject, integers)),
+            arms: arms
+                .into_iter()
+                .map(|(pattern, body)| (pattern, fold_constants_with(body, integers)))
+                .collect(),
+        },
+        Expr::While {
+            cond,
+            body,
+            else_body,
+        } => Expr::While {
+            cond: Box::new(fold_expr(*cond, integers)),
+            body: fold_constants_with(body, integers),
+            else_body: else_body.map(|body| fold_constants_with(body, integers)),
+        },
+        Expr::DoWhile { body, cond } => Expr::DoWhile {
+            body: fold_constants_with(body, integers),
+            cond: Box::new(fold_expr(*cond, integers)),
+        },
+        Expr::For {
+            var,
+            iterable,
+            body,
+        } => Expr::For {
+            var,
+            iterable: Box::new(fold_expr(*iterable, integers)),
+            body: fold_constants_with(body, integers),
+        },
+        Expr::Try {
+            body,
+            error_name,
+            catch_body,
+        } => Expr::Try {
+            body: fold_constants_with(body, integers),
+            error_name,
+            catch_body: fold_constants_with(catch_body, integers),
+        },
+        Expr::Return { inner } => Expr::Return {
+            inner: Box::new(fold_expr(*inner, integers)),
+        },
+        Expr::Array { items } => Expr::Array {
+            items: fold_constants_with(items, integers),
+        },
+        Expr::Tuple { items } => Expr::Tuple {
+            items: fold_constants_with(items, integers),
+        },
+        Expr::Map { entries } => Expr::Map {
+            entries: entries
+                .into_iter()
+                .map(|(key, value)| (fold_expr(key, integers), fold_expr(value, integers)))
+                .collect(),
+        },
+        Expr::Index { target, index } => Expr::Index {
+            target: Box::new(fold_expr(*target, integers)),
+            index: Box::new(fold_expr(*index, integers)),
+        },
+        Expr::Token(_) | Expr::Break | Expr::Continue => expr,
+    }
+}
+
+// Only folds operations that can't fail, anything that would overflow or
+// divide by zero is left for the interpreter to report at runtime. Zero
+// divisors are caught here since not every width checks them itself
+fn fold_binary(
+    op: &Operator,
+    lhs: &Token,
+    rhs: &Token,
+    integers: &dyn IntArithmetic,
+) -> Option<Token> {
+    match (lhs, rhs) {
+        (Token::Int(_), Token::Int(0)) if matches!(op, Operator::Div | Operator::Percent) => None,
+        (Token::Int(l), Token::Int(r)) => match op {
+            Operator::Add => integers.add(*l, *r).map(Token::Int),
+            Operator::Sub => integers.sub(*l, *r).map(Token::Int),
+            Operator::Mul => integers.mul(*l, *r).map(Token::Int),
+            Operator::Div => integers.div(*l, *r).map(Token::Int),
+            Operator::Percent => integers.rem(*l, *r).map(Token::Int),
+            Operator::Eq => Some(Token::Bool(l == r)),
+            Operator::Neq => Some(Token::Bool(l != r)),
+            Operator::Lt => Some(Token::Bool(l < r)),
+            Operator::Gt => Some(Token::Bool(l > r)),
+            Operator::Le => Some(Token::Bool(l <= r)),
+            Operator::Ge => Some(Token::Bool(l >= r)),
+            _ => None,
+        },
+        (Token::Float(l), Token::Float(r)) => match op {
+            Operator::Add => Some(Token::Float(l + r)),
+            Operator::Sub => Some(Token::Float(l - r)),
+            Operator::Mul => Some(Token::Float(l * r)),
+            Operator::Div => Some(Token::Float(l / r)),
+            Operator::Percent => Some(Token::Float(l % r)),
+            Operator::Eq => Some(Token::Bool(l == r)),
+            Operator::Neq => Some(Token::Bool(l != r)),
+            Operator::Lt => Some(Token::Bool(l < r)),
+            Operator::Gt => Some(Token::Bool(l > r)),
+            Operator::Le => Some(Token::Bool(l <= r)),
+            Operator::Ge => Some(Token::Bool(l >= r)),
+            _ => None,
+        },
+        (Token::Bool(l), Token::Bool(r)) => match op {
+            Operator::And => Some(Token::Bool(*l && *r)),
+            Operator::Or => Some(Token::Bool(*l || *r)),
+            Operator::Eq => Some(Token::Bool(l == r)),
+            Operator::Neq => Some(Token::Bool(l != r)),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        crate::{interpreter::Bignum, testing::parse},
+    };
+
+    #[test]
+    fn pure_arithmetic_folds_to_one_token() {
+        assert_eq!(
+            fold_constants(parse("2 + 3 * 4; -5;")),
+            [Expr::Token(Token::Int(14)), Expr::Token(Token::Int(-5))]
+        );
+    }
+
+    #[test]
+    fn overflow_at_the_width_is_left_for_the_interpreter() {
+        let exprs = parse("3037000500 * 3037000500;");
+        assert_eq!(fold_constants(exprs.clone()), exprs);
+        assert_eq!(
+            fold_constants_with(exprs, &Bignum),
+            [Expr::Token(Token::Int(9_223_372_037_000_250_000))]
+        );
+        assert_eq!(
+            fold_constants(parse("2 < 3; 1.5 * 2.0;")),
+            [
+                Expr::Token(Token::Bool(true)),
+                Expr::Token(Token::Float(3.0))
+            ]
+        );
+    }
+
+    #[test]
+    fn expressions_with_identifiers_are_left_alone() {
+        let exprs = parse("x + 1; f(2) * 3;");
+        assert_eq!(fold_constants(exprs.clone()), exprs);
+    }
+
+    #[test]
+    fn division_by_zero_is_left_for_runtime() {
+        let exprs = parse("1 / 0; 1 % 0;");
+        assert_eq!(fold_constants(exprs.clone()), exprs);
+    }
+}
@@ -1,7 +1,6 @@
-
 use {
-    crate::lexer::Token,
-    logos::{Span, Lexer},
+    crate::{lexer::Token, symbol::Symbol},
+    logos::{Lexer, Span},
     std::{collections::HashMap, fmt::Display, iter::Peekable},
 };
 
@@ -16,18 +15,37 @@ pub struct ParseError {
     pub spans: Vec<Span>,
 }
 
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{}", self.message)?;
+        if !self.spans.is_empty() {
+            write!(f, " at {:?}", self.spans)?;
+        }
+        Ok(())
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum Operator {
     Add,
     Sub,
     Mul,
     Div,
+    Percent,
     Eq,
     Neq,
     Lt,
     Gt,
     Le,
     Ge,
+    In,
+    And,
+    Or,
+    Pipe,
+    Coalesce,
+    Not,
+    Increment,
+    Decrement,
     SetVal(Option<String>),
 }
 
@@ -38,15 +56,34 @@ impl Operator {
             "-" => Self::Sub,
             "*" => Self::Mul,
             "/" => Self::Div,
+            "%" => Self::Percent,
             "==" => Self::Eq,
             "!=" => Self::Neq,
             "<" => Self::Lt,
             ">" => Self::Gt,
             "<=" => Self::Le,
             ">=" => Self::Ge,
+            "in" => Self::In,
+            "&&" => Self::And,
+            "||" => Self::Or,
+            "|>" => Self::Pipe,
+            "??" => Self::Coalesce,
             _ => panic!("Unknown operator"),
         }
     }
+
+    fn precedence(&self) -> u8 {
+        match self {
+            Self::Pipe => 1,
+            Self::Coalesce => 2,
+            Self::Or => 3,
+            Self::And => 4,
+            Self::Eq | Self::Neq | Self::Lt | Self::Gt | Self::Le | Self::Ge | Self::In => 5,
+            Self::Add | Self::Sub => 6,
+            Self::Mul | Self::Div | Self::Percent => 7,
+            Self::Not | Self::Increment | Self::Decrement | Self::SetVal(_) => 0,
+        }
+    }
 }
 
 impl Display for Operator {
@@ -56,17 +93,27 @@ impl Display for Operator {
             Operator::Sub => write!(f, "-"),
             Operator::Mul => write!(f, "*"),
             Operator::Div => write!(f, "/"),
+            Operator::Percent => write!(f, "%"),
             Operator::Eq => write!(f, "=="),
             Operator::Neq => write!(f, "!="),
             Operator::Lt => write!(f, "<"),
             Operator::Gt => write!(f, ">"),
             Operator::Le => write!(f, "<="),
             Operator::Ge => write!(f, ">="),
+            Operator::In => write!(f, "in"),
+            Operator::And => write!(f, "&&"),
+            Operator::Or => write!(f, "||"),
+            Operator::Pipe => write!(f, "|>"),
+            Operator::Coalesce => write!(f, "??"),
+            Operator::Not => write!(f, "!"),
+            Operator::Increment => write!(f, "++"),
+            Operator::Decrement => write!(f, "--"),
             Operator::SetVal(_) => write!(f, ":="),
         }
     }
 }
 
+#[allow(clippy::enum_variant_names)]
 #[derive(Debug, Clone, PartialEq)]
 pub enum Expr {
     Token(Token),
@@ -75,16 +122,24 @@ pub enum Expr {
         lhs: Box<Self>,
         rhs: Box<Self>,
     },
+    UnaryExpr {
+        op: Operator,
+        operand: Box<Self>,
+    },
     FnCall {
-        name: String,
+        callee: Box<Self>,
         args: Vec<Self>,
     },
     FnDef {
-        name: String,
-        args: HashMap<(usize, String), Self>,
+        name: Symbol,
+        args: HashMap<(usize, Symbol), Self>,
         body: Vec<Self>,
         return_type: String,
     },
+    Lambda {
+        args: HashMap<(usize, Symbol), Self>,
+        body: Vec<Self>,
+    },
     If {
         cond: Box<Self>,
         body: Vec<Self>,
@@ -93,6 +148,45 @@ pub enum Expr {
     Return {
         inner: Box<Self>,
     },
+    Array {
+        items: Vec<Self>,
+    },
+    Map {
+        entries: Vec<(Self, Self)>,
+    },
+    Index {
+        target: Box<Self>,
+        index: Box<Self>,
+    },
+    Tuple {
+        items: Vec<Self>,
+    },
+    MatchType {
+        subject: Box<Self>,
+        arms: Vec<(String, Vec<Self>)>,
+    },
+    // The else body runs when the loop ends without a break
+    While {
+        cond: Box<Self>,
+        body: Vec<Self>,
+        else_body: Option<Vec<Self>>,
+    },
+    DoWhile {
+        body: Vec<Self>,
+        cond: Box<Self>,
+    },
+    For {
+        var: Symbol,
+        iterable: Box<Self>,
+        body: Vec<Self>,
+    },
+    Try {
+        body: Vec<Self>,
+        error_name: Symbol,
+        catch_body: Vec<Self>,
+    },
+    Break,
+    Continue,
 }
 
 impl Display for Expr {
@@ -100,7 +194,12 @@ impl Display for Expr {
         match self {
             Expr::Token(t) => write!(f, "{t}"),
             Expr::BinaryExpr { op, lhs, rhs } => write!(f, "{lhs} {op} {rhs}"),
-            Expr::FnCall { name, args } => write!(f, "{name}({args:?})"),
+            Expr::UnaryExpr {
+                op: op @ (Operator::Increment | Operator::Decrement),
+                operand,
+            } => write!(f, "{operand}{op}"),
+            Expr::UnaryExpr { op, operand } => write!(f, "{op}{operand}"),
+            Expr::FnCall { callee, args } => write!(f, "{callee}({args:?})"),
             Expr::FnDef {
                 name,
                 args,
@@ -109,12 +208,41 @@ impl Display for Expr {
             } => {
                 write!(f, "func {name}({args:?}) {{{body:?}}} -> {return_type}")
             }
+            Expr::Lambda { args, body } => write!(f, "fn({args:?}) {{{body:?}}}"),
             Expr::If {
                 cond,
                 body,
                 else_body,
             } => write!(f, "if {cond} {{{body:?}}} else {{{else_body:?}}}"),
             Expr::Return { inner } => write!(f, "return {inner}"),
+            Expr::Array { items } => write!(f, "[{items:?}]"),
+            Expr::Map { entries } => write!(f, "{{{entries:?}}}"),
+            Expr::Index { target, index } => write!(f, "{target}[{index}]"),
+            Expr::Tuple { items } => write!(f, "({items:?})"),
+            Expr::MatchType { subject, arms } => write!(f, "match_type {subject} {{{arms:?}}}"),
+            Expr::While {
+                cond,
+                body,
+                else_body: None,
+            } => write!(f, "while {cond} {{{body:?}}}"),
+            Expr::While {
+                cond,
+                body,
+                else_body: Some(else_body),
+            } => write!(f, "while {cond} {{{body:?}}} else {{{else_body:?}}}"),
+            Expr::DoWhile { body, cond } => write!(f, "do {{{body:?}}} while {cond}"),
+            Expr::For {
+                var,
+                iterable,
+                body,
+            } => write!(f, "for {var} in {iterable} {{{body:?}}}"),
+            Expr::Try {
+                body,
+                error_name,
+                catch_body,
+            } => write!(f, "try {{{body:?}}} catch {error_name} {{{catch_body:?}}}"),
+            Expr::Break => write!(f, "break"),
+            Expr::Continue => write!(f, "continue"),
         }
     }
 }
@@ -126,9 +254,10 @@ impl<'b> Parser<'b> {
 
     pub fn parse(&'b mut self) -> Result<Vec<Expr>, ParseError> {
         let mut exprs = Vec::new();
-        while self.tokens.peek().is_some() {
-            let (expr, tokens_new) = Self::parse_expr(&mut self.tokens, true)?;
-            self.tokens = tokens_new;
+        let mut tokens = &mut *self.tokens;
+        while tokens.peek().is_some() {
+            let (expr, tokens_new) = Self::parse_expr(tokens, true)?;
+            tokens = tokens_new;
             exprs.push(expr);
         }
         Ok(exprs)
@@ -148,83 +277,18 @@ impl<'b> Parser<'b> {
                     tokens_new,
                 )
             }
-            Some(Token::Identifier(ident)) => match tokens.peek() {
-                Some(Token::SetVal) => {
-                    tokens.next();
-                    let (expr, tokens_new) = Self::parse_expr(tokens, false)?;
-                    (
-                        Expr::BinaryExpr {
-                            op: Operator::SetVal(None),
-                            lhs: Box::new(Expr::Token(Token::Identifier(ident.into()))),
-                            rhs: Box::new(expr),
-                        },
-                        tokens_new,
-                    )
-                }
-                Some(Token::LParen) => {
-                    tokens.next();
-                    Self::parse_fn_call(ident.to_string(), tokens)?
-                }
-                Some(Token::Operator(op)) => {
-                    tokens.next();
-                    let (expr, tokens_new) = Self::parse_expr(tokens, false)?;
-                    (
-                        Expr::BinaryExpr {
-                            op: Operator::from_str(op),
-                            lhs: Box::new(Expr::Token(Token::Identifier(ident.into()))),
-                            rhs: Box::new(expr),
-                        },
-                        tokens_new,
-                    )
-                }
-                _ => (Expr::Token(Token::Identifier(ident.into())), tokens),
-            },
-            Some(Token::Int(i)) => match tokens.peek() {
-                Some(Token::Operator(op)) => {
-                    tokens.next();
-                    let (expr, tokens_new) = Self::parse_expr(tokens, false)?;
-                    (
-                        Expr::BinaryExpr {
-                            op: Operator::from_str(op),
-                            lhs: Box::new(Expr::Token(Token::Int(i))),
-                            rhs: Box::new(expr),
-                        },
-                        tokens_new,
-                    )
-                }
-                _ => (Expr::Token(Token::Int(i)), tokens),
-            },
-            Some(Token::Float(f)) => match tokens.peek() {
-                Some(Token::Operator(op)) => {
-                    tokens.next();
-                    let (expr, tokens_new) = Self::parse_expr(tokens, false)?;
-                    (
-                        Expr::BinaryExpr {
-                            op: Operator::from_str(op),
-                            lhs: Box::new(Expr::Token(Token::Float(f))),
-                            rhs: Box::new(expr),
-                        },
-                        tokens_new,
-                    )
-                }
-                _ => (Expr::Token(Token::Float(f)), tokens),
-            },
-            Some(Token::Bool(bool)) => (Expr::Token(Token::Bool(bool)), tokens),
-            Some(Token::String(string)) => match tokens.peek() {
-                Some(Token::Operator(op)) => {
-                    tokens.next();
-                    let (expr, tokens_new) = Self::parse_expr(tokens, false)?;
-                    (
-                        Expr::BinaryExpr {
-                            op: Operator::from_str(op),
-                            lhs: Box::new(Expr::Token(Token::String(string.into()))),
-                            rhs: Box::new(expr),
-                        },
-                        tokens_new,
-                    )
-                }
-                _ => (Expr::Token(Token::String(string.into())), tokens),
-            },
+            Some(Token::Identifier(ident)) if tokens.peek() == Some(&Token::SetVal) => {
+                tokens.next();
+                let (expr, tokens_new) = Self::parse_expr(tokens, false)?;
+                (
+                    Expr::BinaryExpr {
+                        op: Operator::SetVal(None),
+                        lhs: Box::new(Expr::Token(Token::Identifier(ident))),
+                        rhs: Box::new(expr),
+                    },
+                    tokens_new,
+                )
+            }
             Some(Token::Func) => {
                 sc_check = false;
                 Self::parse_fn_def(tokens)?
@@ -233,14 +297,117 @@ impl<'b> Parser<'b> {
                 sc_check = false;
                 Self::parse_if(tokens)?
             }
-            Some(Token::Type(t)) => match tokens.next() {
+            Some(Token::MatchType) => {
+                sc_check = false;
+                Self::parse_match_type(tokens)?
+            }
+            Some(Token::While) => {
+                sc_check = false;
+                let (cond, tokens_new) = Self::parse_expr(tokens, false)?;
+                let (body, tokens_new) = Self::handle_block(tokens_new)?;
+                let (else_body, tokens_new) = if tokens_new.peek() == Some(&Token::Else) {
+                    tokens_new.next();
+                    let (else_body, tokens_new) = Self::handle_block(tokens_new)?;
+                    (Some(else_body), tokens_new)
+                } else {
+                    (None, tokens_new)
+                };
+                (
+                    Expr::While {
+                        cond: Box::new(cond),
+                        body,
+                        else_body,
+                    },
+                    tokens_new,
+                )
+            }
+            Some(Token::Do) => {
+                sc_check = false;
+                let (body, tokens_new) = Self::handle_block(tokens)?;
+                if tokens_new.next() != Some(Token::While) {
+                    return Err(ParseError {
+                        message: "Expected 'while' after do block".to_string(),
+                        spans: vec![],
+                    });
+                }
+                let (cond, tokens_new) = Self::parse_expr(tokens_new, false)?;
+                if tokens_new.peek() == Some(&Token::Semicolon) {
+                    tokens_new.next();
+                }
+                (
+                    Expr::DoWhile {
+                        body,
+                        cond: Box::new(cond),
+                    },
+                    tokens_new,
+                )
+            }
+            Some(Token::For) => {
+                sc_check = false;
+                let var = match tokens.next() {
+                    Some(Token::Identifier(name)) => name,
+                    _ => {
+                        return Err(ParseError {
+                            message: "Expected identifier after 'for'".to_string(),
+                            spans: vec![],
+                        })
+                    }
+                };
+                if tokens.next() != Some(Token::Operator("in".to_string())) {
+                    return Err(ParseError {
+                        message: "Expected 'in' after loop variable".to_string(),
+                        spans: vec![],
+                    });
+                }
+                let (iterable, tokens_new) = Self::parse_expr(tokens, false)?;
+                let (body, tokens_new) = Self::handle_block(tokens_new)?;
+                (
+                    Expr::For {
+                        var,
+                        iterable: Box::new(iterable),
+                        body,
+                    },
+                    tokens_new,
+                )
+            }
+            Some(Token::Try) => {
+                sc_check = false;
+                let (body, tokens_new) = Self::handle_block(tokens)?;
+                if tokens_new.next() != Some(Token::Catch) {
+                    return Err(ParseError {
+                        message: "Expected 'catch' after try block".to_string(),
+                        spans: vec![],
+                    });
+                }
+                let error_name = match tokens_new.next() {
+                    Some(Token::Identifier(name)) => name,
+                    _ => {
+                        return Err(ParseError {
+                            message: "Expected identifier after 'catch'".to_string(),
+                            spans: vec![],
+                        })
+                    }
+                };
+                let (catch_body, tokens_new) = Self::handle_block(tokens_new)?;
+                (
+                    Expr::Try {
+                        body,
+                        error_name,
+                        catch_body,
+                    },
+                    tokens_new,
+                )
+            }
+            Some(Token::Break) => (Expr::Break, tokens),
+            Some(Token::Continue) => (Expr::Continue, tokens),
+            Some(Token::Type(t)) if tokens.peek() != Some(&Token::LParen) => match tokens.next() {
                 Some(Token::Identifier(i)) => match tokens.next() {
                     Some(Token::SetVal) => {
                         let (expr, tokens_new) = Self::parse_expr(tokens, false)?;
                         (
                             Expr::BinaryExpr {
                                 op: Operator::SetVal(Some(t.clone())),
-                                lhs: Box::new(Expr::Token(Token::Identifier(i.into()))),
+                                lhs: Box::new(Expr::Token(Token::Identifier(i))),
                                 rhs: Box::new(expr),
                             },
                             tokens_new,
@@ -260,10 +427,37 @@ impl<'b> Parser<'b> {
                     })
                 }
             },
-            _ => (Expr::Token(Token::Error), tokens),
+            token => {
+                let (lhs, tokens_new) = Self::parse_unary(token, tokens)?;
+                let (expr, tokens_new) = Self::parse_binary(lhs, 0, tokens_new)?;
+                match expr {
+                    Expr::Tuple { items } if tokens_new.peek() == Some(&Token::SetVal) => {
+                        tokens_new.next();
+                        if !items
+                            .iter()
+                            .all(|item| matches!(item, Expr::Token(Token::Identifier(_))))
+                        {
+                            return Err(ParseError {
+                                message: "Expected identifiers to destructure into".to_string(),
+                                spans: vec![],
+                            });
+                        }
+                        let (rhs, tokens_new) = Self::parse_expr(tokens_new, false)?;
+                        (
+                            Expr::BinaryExpr {
+                                op: Operator::SetVal(None),
+                                lhs: Box::new(Expr::Tuple { items }),
+                                rhs: Box::new(rhs),
+                            },
+                            tokens_new,
+                        )
+                    }
+                    expr => (expr, tokens_new),
+                }
+            }
         };
         if sc_check {
-            if tokens_new.peek() == Some(&&Token::Semicolon) {
+            if tokens_new.peek() == Some(&Token::Semicolon) {
                 tokens_new.next();
                 Ok((expr, tokens_new))
             } else {
@@ -277,6 +471,157 @@ impl<'b> Parser<'b> {
         }
     }
 
+    fn parse_binary<'a>(
+        mut lhs: Expr,
+        min_precedence: u8,
+        mut tokens: &'a mut Peekable<Lexer<'a, Token>>,
+    ) -> Result<(Expr, &'a mut Peekable<Lexer<'a, Token>>), ParseError> {
+        while let Some(Token::Operator(op)) = tokens.peek().cloned() {
+            let op = Operator::from_str(&op);
+            if op.precedence() < min_precedence {
+                break;
+            }
+            tokens.next();
+            let token = tokens.next();
+            let (mut rhs, mut tokens_new) = Self::parse_unary(token, tokens)?;
+            while let Some(Token::Operator(next_op)) = tokens_new.peek().cloned() {
+                if Operator::from_str(&next_op).precedence() <= op.precedence() {
+                    break;
+                }
+                (rhs, tokens_new) = Self::parse_binary(rhs, op.precedence() + 1, tokens_new)?;
+            }
+            lhs = Expr::BinaryExpr {
+                op,
+                lhs: Box::new(lhs),
+                rhs: Box::new(rhs),
+            };
+            tokens = tokens_new;
+        }
+        Ok((lhs, tokens))
+    }
+
+    fn parse_unary<'a>(
+        token: Option<Token>,
+        tokens: &'a mut Peekable<Lexer<'a, Token>>,
+    ) -> Result<(Expr, &'a mut Peekable<Lexer<'a, Token>>), ParseError> {
+        match token {
+            Some(Token::Not) => {
+                let token = tokens.next();
+                let (expr, tokens_new) = Self::parse_unary(token, tokens)?;
+                Ok((
+                    Expr::UnaryExpr {
+                        op: Operator::Not,
+                        operand: Box::new(expr),
+                    },
+                    tokens_new,
+                ))
+            }
+            Some(Token::Operator(op)) if op == "-" => {
+                let token = tokens.next();
+                let (expr, tokens_new) = Self::parse_unary(token, tokens)?;
+                Ok((
+                    Expr::UnaryExpr {
+                        op: Operator::Sub,
+                        operand: Box::new(expr),
+                    },
+                    tokens_new,
+                ))
+            }
+            token => {
+                let (expr, tokens_new) = Self::parse_primary(token, tokens)?;
+                Self::parse_postfix(expr, tokens_new)
+            }
+        }
+    }
+
+    fn parse_postfix<'a>(
+        expr: Expr,
+        tokens: &'a mut Peekable<Lexer<'a, Token>>,
+    ) -> Result<(Expr, &'a mut Peekable<Lexer<'a, Token>>), ParseError> {
+        match tokens.peek() {
+            Some(Token::LBracket) => {
+                tokens.next();
+                let (index, tokens_new) = Self::parse_expr(tokens, false)?;
+                if tokens_new.next() != Some(Token::RBracket) {
+                    return Err(ParseError {
+                        message: "Expected ']'".to_string(),
+                        spans: vec![],
+                    });
+                }
+                let expr = Expr::Index {
+                    target: Box::new(expr),
+                    index: Box::new(index),
+                };
+                Self::parse_postfix(expr, tokens_new)
+            }
+            Some(Token::LParen) => {
+                tokens.next();
+                let (expr, tokens_new) = Self::parse_fn_call(expr, tokens)?;
+                Self::parse_postfix(expr, tokens_new)
+            }
+            Some(token @ (Token::Increment | Token::Decrement)) => {
+                let op = if *token == Token::Increment {
+                    Operator::Increment
+                } else {
+                    Operator::Decrement
+                };
+                tokens.next();
+                let expr = Expr::UnaryExpr {
+                    op,
+                    operand: Box::new(expr),
+                };
+                Self::parse_postfix(expr, tokens)
+            }
+            _ => Ok((expr, tokens)),
+        }
+    }
+
+    fn parse_primary<'a>(
+        token: Option<Token>,
+        tokens: &'a mut Peekable<Lexer<'a, Token>>,
+    ) -> Result<(Expr, &'a mut Peekable<Lexer<'a, Token>>), ParseError> {
+        match token {
+            Some(Token::Identifier(ident)) => Ok((Expr::Token(Token::Identifier(ident)), tokens)),
+            // Type names double as conversion functions, e.g. bool(x)
+            Some(Token::Type(t)) if tokens.peek() == Some(&Token::LParen) => {
+                Ok((Expr::Token(Token::Identifier(Symbol::intern(&t))), tokens))
+            }
+            Some(Token::LParen) => {
+                let (expr, mut tokens) = Self::parse_expr(tokens, false)?;
+                // A comma after the first expression makes this a tuple rather
+                // than a parenthesised expression
+                let mut items = vec![expr];
+                loop {
+                    match tokens.next() {
+                        Some(Token::Comma) => {
+                            let (item, tokens_new) = Self::parse_expr(tokens, false)?;
+                            items.push(item);
+                            tokens = tokens_new;
+                        }
+                        Some(Token::RParen) if items.len() == 1 => {
+                            return Ok((items.remove(0), tokens))
+                        }
+                        Some(Token::RParen) => return Ok((Expr::Tuple { items }, tokens)),
+                        _ => {
+                            return Err(ParseError {
+                                message: "Expected ',' or ')'".to_string(),
+                                spans: vec![],
+                            })
+                        }
+                    }
+                }
+            }
+            Some(Token::LBracket) => Self::parse_array(tokens),
+            Some(Token::LBrace) => Self::parse_map(tokens),
+            Some(Token::MatchType) => Self::parse_match_type(tokens),
+            Some(Token::Fn) => Self::parse_lambda(tokens),
+            Some(token @ (Token::Int(_) | Token::Float(_) | Token::Bool(_) | Token::String(_))) => {
+                Ok((Expr::Token(token), tokens))
+            }
+            _ => Ok((Expr::Token(Token::Error), tokens)),
+        }
+    }
+
     fn parse_fn_def<'a>(
         tokens: &'a mut Peekable<Lexer<'a, Token>>,
     ) -> Result<(Expr, &'a mut Peekable<Lexer<'a, Token>>), ParseError> {
@@ -285,19 +630,19 @@ impl<'b> Parser<'b> {
                 Some(Token::LParen) => {
                     let mut vals = HashMap::new();
                     let mut return_type: String = String::from("_none");
-                    if tokens.peek() == Some(&&Token::RParen) {
+                    if tokens.peek() == Some(&Token::RParen) {
                         tokens.next();
                     } else {
                         let mut idx = 0;
                         loop {
-                            match tokens.peek() {
+                            match tokens.peek().cloned() {
                                 Some(Token::Type(t)) => {
                                     tokens.next();
-                                    match tokens.peek() {
+                                    match tokens.peek().cloned() {
                                         Some(Token::Identifier(ident)) => {
                                             tokens.next();
                                             vals.insert(
-                                                (idx, ident.to_string()),
+                                                (idx, ident),
                                                 Expr::Token(Token::Type(t.clone())),
                                             );
                                         }
@@ -358,7 +703,7 @@ impl<'b> Parser<'b> {
                     }?;
                     Ok((
                         Expr::FnDef {
-                            name: ident.into(),
+                            name: ident,
                             args: vals,
                             body,
                             return_type,
@@ -378,11 +723,113 @@ impl<'b> Parser<'b> {
         }
     }
 
+    // Parameters may leave off their type, which accepts anything
+    fn parse_lambda<'a>(
+        tokens: &'a mut Peekable<Lexer<'a, Token>>,
+    ) -> Result<(Expr, &'a mut Peekable<Lexer<'a, Token>>), ParseError> {
+        if tokens.next() != Some(Token::LParen) {
+            return Err(ParseError {
+                message: "Expected '(' after fn".to_string(),
+                spans: vec![],
+            });
+        }
+        let mut args = HashMap::new();
+        if tokens.peek() == Some(&Token::RParen) {
+            tokens.next();
+        } else {
+            let mut idx = 0;
+            loop {
+                let (arg_type, arg_name) = match (tokens.next(), tokens.peek().cloned()) {
+                    (Some(Token::Type(t)), Some(Token::Identifier(ident))) => {
+                        tokens.next();
+                        (t, ident)
+                    }
+                    (Some(Token::Identifier(ident)), _) => ("any".to_string(), ident),
+                    _ => {
+                        return Err(ParseError {
+                            message: "Expected parameter name".to_string(),
+                            spans: vec![],
+                        })
+                    }
+                };
+                args.insert((idx, arg_name), Expr::Token(Token::Type(arg_type)));
+                match tokens.next() {
+                    Some(Token::Comma) => (),
+                    Some(Token::RParen) => break,
+                    _ => {
+                        return Err(ParseError {
+                            message: "Expected ',' or ')'".to_string(),
+                            spans: vec![],
+                        })
+                    }
+                }
+                idx += 1;
+            }
+        }
+        let (body, tokens_new) = Self::handle_lambda_body(tokens)?;
+        Ok((Expr::Lambda { args, body }, tokens_new))
+    }
+
+    // Like handle_block, except the last expression doesn't need a semicolon
+    // and is returned as the lambda's value
+    fn handle_lambda_body<'a>(
+        mut tokens: &'a mut Peekable<Lexer<'a, Token>>,
+    ) -> Result<(Vec<Expr>, &'a mut Peekable<Lexer<'a, Token>>), ParseError> {
+        if tokens.next() != Some(Token::LBrace) {
+            return Err(ParseError {
+                message: "Expected '{'".to_string(),
+                spans: vec![],
+            });
+        }
+        let mut exprs = Vec::new();
+        if tokens.peek() == Some(&Token::RBrace) {
+            tokens.next();
+            return Ok((exprs, tokens));
+        }
+        loop {
+            let (expr, tokens_new) = Self::parse_expr(tokens, false)?;
+            let is_statement = matches!(
+                expr,
+                Expr::FnDef { .. }
+                    | Expr::If { .. }
+                    | Expr::MatchType { .. }
+                    | Expr::While { .. }
+                    | Expr::DoWhile { .. }
+                    | Expr::For { .. }
+                    | Expr::Try { .. }
+                    | Expr::Return { .. }
+                    | Expr::Break
+                    | Expr::Continue
+            );
+            if tokens_new.peek() == Some(&Token::Semicolon) {
+                tokens_new.next();
+            } else if !is_statement && tokens_new.peek() != Some(&Token::RBrace) {
+                return Err(ParseError {
+                    message: "Expected semicolon".to_string(),
+                    spans: vec![],
+                });
+            }
+            if tokens_new.peek() == Some(&Token::RBrace) {
+                tokens_new.next();
+                exprs.push(if is_statement {
+                    expr
+                } else {
+                    Expr::Return {
+                        inner: Box::new(expr),
+                    }
+                });
+                return Ok((exprs, tokens_new));
+            }
+            exprs.push(expr);
+            tokens = tokens_new;
+        }
+    }
+
     fn handle_block<'a>(
         mut tokens: &'a mut Peekable<Lexer<'a, Token>>,
     ) -> Result<(Vec<Expr>, &'a mut Peekable<Lexer<'a, Token>>), ParseError> {
         let mut exprs = Vec::new();
-        if tokens.peek() == Some(&&Token::LBrace) {
+        if tokens.peek() == Some(&Token::LBrace) {
             tokens.next();
             loop {
                 let (expr, tokens_new) = Self::parse_expr(tokens, true)?;
@@ -402,13 +849,19 @@ impl<'b> Parser<'b> {
     }
 
     fn parse_fn_call<'a>(
-        ident: String,
+        callee: Expr,
         mut tokens: &'a mut Peekable<Lexer<'a, Token>>,
     ) -> Result<(Expr, &'a mut Peekable<Lexer<'a, Token>>), ParseError> {
         let mut args = Vec::new();
-        if tokens.peek() == Some(&&Token::RParen) {
+        if tokens.peek() == Some(&Token::RParen) {
             tokens.next();
-            Ok((Expr::FnCall { name: ident, args }, tokens))
+            Ok((
+                Expr::FnCall {
+                    callee: Box::new(callee),
+                    args,
+                },
+                tokens,
+            ))
         } else {
             loop {
                 let (arg, tokens_new) = Self::parse_expr(tokens, false)?;
@@ -416,7 +869,13 @@ impl<'b> Parser<'b> {
                 match tokens_new.next() {
                     Some(Token::Comma) => (),
                     Some(Token::RParen) => {
-                        return Ok((Expr::FnCall { name: ident, args }, tokens_new))
+                        return Ok((
+                            Expr::FnCall {
+                                callee: Box::new(callee),
+                                args,
+                            },
+                            tokens_new,
+                        ))
                     }
                     _ => {
                         return Err(ParseError {
@@ -430,13 +889,133 @@ impl<'b> Parser<'b> {
         }
     }
 
+    fn parse_array<'a>(
+        mut tokens: &'a mut Peekable<Lexer<'a, Token>>,
+    ) -> Result<(Expr, &'a mut Peekable<Lexer<'a, Token>>), ParseError> {
+        let mut items = Vec::new();
+        if tokens.peek() == Some(&Token::RBracket) {
+            tokens.next();
+            Ok((Expr::Array { items }, tokens))
+        } else {
+            loop {
+                let (item, tokens_new) = Self::parse_expr(tokens, false)?;
+                items.push(item);
+                match tokens_new.next() {
+                    Some(Token::Comma) => (),
+                    Some(Token::RBracket) => return Ok((Expr::Array { items }, tokens_new)),
+                    _ => {
+                        return Err(ParseError {
+                            message: "Expected ',' or ']'".to_string(),
+                            spans: vec![],
+                        })
+                    }
+                };
+                tokens = tokens_new;
+            }
+        }
+    }
+
+    fn parse_map<'a>(
+        mut tokens: &'a mut Peekable<Lexer<'a, Token>>,
+    ) -> Result<(Expr, &'a mut Peekable<Lexer<'a, Token>>), ParseError> {
+        let mut entries = Vec::new();
+        if tokens.peek() == Some(&Token::RBrace) {
+            tokens.next();
+            Ok((Expr::Map { entries }, tokens))
+        } else {
+            loop {
+                let (key, tokens_new) = Self::parse_expr(tokens, false)?;
+                if tokens_new.next() != Some(Token::Colon) {
+                    return Err(ParseError {
+                        message: "Expected ':'".to_string(),
+                        spans: vec![],
+                    });
+                }
+                let (value, tokens_new) = Self::parse_expr(tokens_new, false)?;
+                entries.push((key, value));
+                match tokens_new.next() {
+                    Some(Token::Comma) => (),
+                    Some(Token::RBrace) => return Ok((Expr::Map { entries }, tokens_new)),
+                    _ => {
+                        return Err(ParseError {
+                            message: "Expected ',' or '}'".to_string(),
+                            spans: vec![],
+                        })
+                    }
+                };
+                tokens = tokens_new;
+            }
+        }
+    }
+
+    fn parse_match_type<'a>(
+        tokens: &'a mut Peekable<Lexer<'a, Token>>,
+    ) -> Result<(Expr, &'a mut Peekable<Lexer<'a, Token>>), ParseError> {
+        let (subject, tokens) = Self::parse_expr(tokens, false)?;
+        if tokens.next() != Some(Token::LBrace) {
+            return Err(ParseError {
+                message: "Expected '{'".to_string(),
+                spans: vec![],
+            });
+        }
+        let mut arms = Vec::new();
+        let mut tokens = tokens;
+        loop {
+            let pattern = match tokens.next() {
+                Some(Token::RBrace) => break,
+                Some(Token::Type(name)) => name,
+                Some(Token::Identifier(name)) => name.to_string(),
+                _ => {
+                    return Err(ParseError {
+                        message: "Expected type name or '_'".to_string(),
+                        spans: vec![],
+                    })
+                }
+            };
+            if tokens.next() != Some(Token::FatArrow) {
+                return Err(ParseError {
+                    message: "Expected '=>'".to_string(),
+                    spans: vec![],
+                });
+            }
+            let (body, tokens_new) = if tokens.peek() == Some(&Token::LBrace) {
+                Self::handle_block(tokens)?
+            } else {
+                let (expr, tokens_new) = Self::parse_expr(tokens, false)?;
+                (vec![expr], tokens_new)
+            };
+            arms.push((pattern, body));
+            match tokens_new.next() {
+                Some(Token::Comma) => (),
+                Some(Token::RBrace) => {
+                    tokens = tokens_new;
+                    break;
+                }
+                _ => {
+                    return Err(ParseError {
+                        message: "Expected ',' or '}'".to_string(),
+                        spans: vec![],
+                    })
+                }
+            }
+            tokens = tokens_new;
+        }
+        Ok((
+            Expr::MatchType {
+                subject: Box::new(subject),
+                arms,
+            },
+            tokens,
+        ))
+    }
+
     fn parse_if<'a>(
         tokens: &'a mut Peekable<Lexer<'a, Token>>,
     ) -> Result<(Expr, &'a mut Peekable<Lexer<'a, Token>>), ParseError> {
         let (cond, tokens_after_cond) = Self::parse_expr(tokens, false)?;
         let mut else_body: Option<Vec<Expr>> = None;
         let (body, mut tokens) = Self::handle_block(tokens_after_cond)?;
-        if tokens.peek() == Some(&&Token::Else) {
+        if tokens.peek() == Some(&Token::Else) {
             tokens.next();
             match tokens.peek() {
                 Some(Token::LBrace) => {
@@ -468,3 +1047,61 @@ impl<'b> Parser<'b> {
         ))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use {super::*, crate::testing::parse, logos::Logos};
+
+    #[test]
+    fn match_type_arms_accept_type_keywords() {
+        // int and string lex as Token::Type rather than identifiers
+        match &parse(r#"match_type x { int => 1, string => 2, _ => 3 }"#)[0] {
+            Expr::MatchType { arms, .. } => assert_eq!(
+                arms.iter()
+                    .map(|(name, _)| name.as_str())
+                    .collect::<Vec<_>>(),
+                ["int", "string", "_"]
+            ),
+            other => panic!("Expected match_type, got {other}"),
+        }
+    }
+
+    #[test]
+    fn lambda_parameters_default_to_any() {
+        match &parse("fn(int x, y) { x + y };")[0] {
+            Expr::Lambda { args, body } => {
+                assert_eq!(
+                    args[&(0, Symbol::intern("x"))],
+                    Expr::Token(Token::Type("int".to_string()))
+                );
+                assert_eq!(
+                    args[&(1, Symbol::intern("y"))],
+                    Expr::Token(Token::Type("any".to_string()))
+                );
+                assert_eq!(body.len(), 1);
+            }
+            other => panic!("Expected a lambda, got {other}"),
+        }
+    }
+
+    #[test]
+    fn lambdas_need_a_parameter_list() {
+        let mut tokens = Token::lexer("fn x { x };").peekable();
+        assert_eq!(
+            Parser::new(&mut tokens).parse().unwrap_err().message,
+            "Expected '(' after fn"
+        );
+    }
+
+    #[test]
+    fn while_takes_an_optional_else_body() {
+        match &parse("while x { 1; } else { 2; 3; }")[0] {
+            Expr::While { else_body, .. } => assert_eq!(else_body.as_ref().map(Vec::len), Some(2)),
+            other => panic!("Expected a while loop, got {other}"),
+        }
+        match &parse("while x { 1; }")[0] {
+            Expr::While { else_body, .. } => assert_eq!(*else_body, None),
+            other => panic!("Expected a while loop, got {other}"),
+        }
+    }
+}
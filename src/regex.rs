@@ -0,0 +1,481 @@
+use regex_syntax::hir::{
+    Anchor, Class, GroupKind, Hir, HirKind, Literal, RepetitionKind, RepetitionRange, WordBoundary,
+};
+
+// Counted repetitions are compiled by copying their body, so a pattern like
+// (a{1000}){1000} is refused instead of building a program of a million steps
+const MAX_PROGRAM_LEN: usize = 100_000;
+
+// regex_syntax handles the parsing and reports the compile errors. The Hir is
+// compiled to a small program that runs as a Pike VM: every possible match is
+// followed at once, one char at a time, so matching takes time linear in the
+// input no matter how the pattern is written
+pub struct Regex {
+    program: Vec<Inst>,
+    slots: usize,
+}
+
+// Offsets are in bytes so they can be used to slice the searched string
+pub type Captures = Vec<Option<(usize, usize)>>;
+
+enum Inst {
+    Char(char),
+    Ranges(Vec<(u32, u32)>),
+    Look(Look),
+    // Both branches are followed, the first one is preferred
+    Split(usize, usize),
+    Jump(usize),
+    Save(usize),
+    Match,
+}
+
+enum Look {
+    StartText,
+    EndText,
+    StartLine,
+    EndLine,
+    WordBoundary { ascii: bool, negated: bool },
+}
+
+impl Regex {
+    pub fn new(pattern: &str) -> Result<Self, String> {
+        let hir = regex_syntax::Parser::new()
+            .parse(pattern)
+            .map_err(|err| err.to_string())?;
+        let mut compiler = Compiler::default();
+        compiler.push(Inst::Save(0))?;
+        compiler.compile(&hir)?;
+        compiler.push(Inst::Save(1))?;
+        compiler.push(Inst::Match)?;
+        Ok(Self {
+            program: compiler.program,
+            slots: (count_groups(&hir) + 1) * 2,
+        })
+    }
+
+    pub fn is_match(&self, s: &str) -> bool {
+        self.captures_at(s, 0).is_some()
+    }
+
+    pub fn captures(&self, s: &str) -> Option<Captures> {
+        self.captures_at(s, 0)
+    }
+
+    pub fn find_all(&self, s: &str) -> Vec<(usize, usize)> {
+        let mut found = Vec::new();
+        let mut start = 0;
+        while start <= s.len() {
+            let (match_start, match_end) = match self.captures_at(s, start) {
+                Some(captures) => captures[0].unwrap(),
+                None => break,
+            };
+            found.push((match_start, match_end));
+            // An empty match would be found again at the same place, so step
+            // over the next char instead
+            start = if match_end > match_start {
+                match_end
+            } else {
+                match s[match_end..].chars().next() {
+                    Some(c) => match_end + c.len_utf8(),
+                    None => break,
+                }
+            };
+        }
+        found
+    }
+
+    // Leftmost match that starts at or after the byte offset start. Threads
+    // are kept in priority order, so the first one to reach Match is the one
+    // a backtracking search would have found
+    fn captures_at(&self, s: &str, start: usize) -> Option<Captures> {
+        let mut current = Threads::new(self.program.len());
+        let mut next = Threads::new(self.program.len());
+        let mut matched = None;
+        let mut prev = s[..start].chars().next_back();
+        let mut chars = s[start..].char_indices().map(|(i, c)| (start + i, c));
+        let mut at = Some((start, s[start..].chars().next()));
+        while let Some((offset, c)) = at {
+            if matched.is_none() {
+                let slots = vec![None; self.slots];
+                self.add_thread(&mut current, 0, slots, offset, prev, c);
+            }
+            if current.list.is_empty() && matched.is_some() {
+                break;
+            }
+            chars.next();
+            let next_at = chars
+                .clone()
+                .next()
+                .map(|(offset, c)| (offset, Some(c)))
+                .unwrap_or((s.len(), None));
+            for (pc, slots) in std::mem::take(&mut current.list) {
+                let step = match (&self.program[pc], c) {
+                    (Inst::Char(expected), Some(c)) => *expected == c,
+                    (Inst::Ranges(ranges), Some(c)) => ranges
+                        .iter()
+                        .any(|(start, end)| (*start..=*end).contains(&(c as u32))),
+                    (Inst::Match, _) => {
+                        // Everything after this thread is lower priority
+                        matched = Some(slots);
+                        break;
+                    }
+                    _ => false,
+                };
+                if step {
+                    self.add_thread(&mut next, pc + 1, slots, next_at.0, c, next_at.1);
+                }
+            }
+            std::mem::swap(&mut current, &mut next);
+            next.clear();
+            prev = c;
+            at = c.map(|_| next_at);
+        }
+        matched.map(|slots| {
+            slots
+                .chunks(2)
+                .map(|slot| match slot {
+                    [Some(start), Some(end)] => Some((*start, *end)),
+                    _ => None,
+                })
+                .collect()
+        })
+    }
+
+    // Follows jumps, splits, saves and assertions from pc until every path
+    // rests on an instruction that needs a char, or on Match. Uses its own
+    // stack so long chains of optional items can't overflow the real one
+    fn add_thread(
+        &self,
+        threads: &mut Threads,
+        pc: usize,
+        mut slots: Vec<Option<usize>>,
+        offset: usize,
+        prev: Option<char>,
+        next: Option<char>,
+    ) {
+        enum Frame {
+            Visit(usize),
+            Restore(usize, Option<usize>),
+        }
+        let mut stack = vec![Frame::Visit(pc)];
+        while let Some(frame) = stack.pop() {
+            let pc = match frame {
+                Frame::Visit(pc) => pc,
+                Frame::Restore(slot, value) => {
+                    slots[slot] = value;
+                    continue;
+                }
+            };
+            if !threads.visit(pc) {
+                continue;
+            }
+            match &self.program[pc] {
+                Inst::Jump(to) => stack.push(Frame::Visit(*to)),
+                Inst::Split(first, second) => {
+                    stack.push(Frame::Visit(*second));
+                    stack.push(Frame::Visit(*first));
+                }
+                Inst::Save(slot) => {
+                    if *slot < slots.len() {
+                        stack.push(Frame::Restore(*slot, slots[*slot]));
+                        slots[*slot] = Some(offset);
+                    }
+                    stack.push(Frame::Visit(pc + 1));
+                }
+                Inst::Look(look) => {
+                    if look.holds(prev, next) {
+                        stack.push(Frame::Visit(pc + 1));
+                    }
+                }
+                Inst::Char(_) | Inst::Ranges(_) | Inst::Match => {
+                    threads.list.push((pc, slots.clone()))
+                }
+            }
+        }
+    }
+}
+
+impl Look {
+    fn holds(&self, prev: Option<char>, next: Option<char>) -> bool {
+        match self {
+            Look::StartText => prev.is_none(),
+            Look::EndText => next.is_none(),
+            Look::StartLine => matches!(prev, None | Some('\n')),
+            Look::EndLine => matches!(next, None | Some('\n')),
+            Look::WordBoundary { ascii, negated } => {
+                let is_word = |c: Option<char>| match c {
+                    Some(c) if *ascii => c.is_ascii_alphanumeric() || c == '_',
+                    Some(c) => c.is_alphanumeric() || c == '_',
+                    None => false,
+                };
+                (is_word(prev) != is_word(next)) != *negated
+            }
+        }
+    }
+}
+
+// The threads waiting at one position, with each pc at most once
+struct Threads {
+    list: Vec<(usize, Vec<Option<usize>>)>,
+    seen: Vec<bool>,
+}
+
+impl Threads {
+    fn new(len: usize) -> Self {
+        Self {
+            list: Vec::new(),
+            seen: vec![false; len],
+        }
+    }
+
+    fn visit(&mut self, pc: usize) -> bool {
+        !std::mem::replace(&mut self.seen[pc], true)
+    }
+
+    fn clear(&mut self) {
+        self.list.clear();
+        self.seen.iter_mut().for_each(|seen| *seen = false);
+    }
+}
+
+#[derive(Default)]
+struct Compiler {
+    program: Vec<Inst>,
+}
+
+impl Compiler {
+    fn push(&mut self, inst: Inst) -> Result<usize, String> {
+        if self.program.len() >= MAX_PROGRAM_LEN {
+            return Err(format!(
+                "pattern is too large, it needs more than {MAX_PROGRAM_LEN} steps"
+            ));
+        }
+        self.program.push(inst);
+        Ok(self.program.len() - 1)
+    }
+
+    // Split and Jump targets are filled in once the code they skip exists
+    fn patch(&mut self, at: usize, inst: Inst) {
+        self.program[at] = inst;
+    }
+
+    fn compile(&mut self, hir: &Hir) -> Result<(), String> {
+        match hir.kind() {
+            HirKind::Empty => {}
+            HirKind::Literal(Literal::Unicode(c)) => {
+                self.push(Inst::Char(*c))?;
+            }
+            HirKind::Literal(Literal::Byte(b)) => {
+                self.push(Inst::Char(char::from(*b)))?;
+            }
+            HirKind::Class(Class::Unicode(class)) => {
+                let ranges = class
+                    .ranges()
+                    .iter()
+                    .map(|range| (range.start() as u32, range.end() as u32))
+                    .collect();
+                self.push(Inst::Ranges(ranges))?;
+            }
+            HirKind::Class(Class::Bytes(class)) => {
+                let ranges = class
+                    .ranges()
+                    .iter()
+                    .map(|range| (u32::from(range.start()), u32::from(range.end())))
+                    .collect();
+                self.push(Inst::Ranges(ranges))?;
+            }
+            HirKind::Anchor(anchor) => {
+                self.push(Inst::Look(match anchor {
+                    Anchor::StartText => Look::StartText,
+                    Anchor::EndText => Look::EndText,
+                    Anchor::StartLine => Look::StartLine,
+                    Anchor::EndLine => Look::EndLine,
+                }))?;
+            }
+            HirKind::WordBoundary(boundary) => {
+                self.push(Inst::Look(Look::WordBoundary {
+                    ascii: matches!(boundary, WordBoundary::Ascii | WordBoundary::AsciiNegate),
+                    negated: matches!(
+                        boundary,
+                        WordBoundary::UnicodeNegate | WordBoundary::AsciiNegate
+                    ),
+                }))?;
+            }
+            HirKind::Repetition(repetition) => {
+                let (min, max) = match &repetition.kind {
+                    RepetitionKind::ZeroOrOne => (0, Some(1)),
+                    RepetitionKind::ZeroOrMore => (0, None),
+                    RepetitionKind::OneOrMore => (1, None),
+                    RepetitionKind::Range(RepetitionRange::Exactly(n)) => (*n, Some(*n)),
+                    RepetitionKind::Range(RepetitionRange::AtLeast(n)) => (*n, None),
+                    RepetitionKind::Range(RepetitionRange::Bounded(m, n)) => (*m, Some(*n)),
+                };
+                self.repeat(&repetition.hir, min, max, repetition.greedy)?;
+            }
+            HirKind::Group(group) => match group.kind {
+                GroupKind::CaptureIndex(index) | GroupKind::CaptureName { index, .. } => {
+                    self.push(Inst::Save(index as usize * 2))?;
+                    self.compile(&group.hir)?;
+                    self.push(Inst::Save(index as usize * 2 + 1))?;
+                }
+                GroupKind::NonCapturing => self.compile(&group.hir)?,
+            },
+            HirKind::Concat(items) => {
+                for item in items {
+                    self.compile(item)?;
+                }
+            }
+            HirKind::Alternation(items) => {
+                let mut jumps = Vec::new();
+                for (i, item) in items.iter().enumerate() {
+                    if i == items.len() - 1 {
+                        self.compile(item)?;
+                        break;
+                    }
+                    let split = self.push(Inst::Split(0, 0))?;
+                    self.compile(item)?;
+                    jumps.push(self.push(Inst::Jump(0))?);
+                    let next = self.program.len();
+                    self.patch(split, Inst::Split(split + 1, next));
+                }
+                let end = self.program.len();
+                for jump in jumps {
+                    self.patch(jump, Inst::Jump(end));
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn repeat(
+        &mut self,
+        hir: &Hir,
+        min: u32,
+        max: Option<u32>,
+        greedy: bool,
+    ) -> Result<(), String> {
+        let split = |first, second| {
+            if greedy {
+                Inst::Split(first, second)
+            } else {
+                Inst::Split(second, first)
+            }
+        };
+        for _ in 0..min {
+            self.compile(hir)?;
+        }
+        match max {
+            // The loop goes back to a split before the body, so an iteration
+            // that matches nothing finds that split already visited and stops
+            None => {
+                let start = self.push(Inst::Split(0, 0))?;
+                self.compile(hir)?;
+                self.push(Inst::Jump(start))?;
+                let end = self.program.len();
+                self.patch(start, split(start + 1, end));
+            }
+            Some(max) => {
+                let mut splits = Vec::new();
+                for _ in min..max {
+                    splits.push(self.push(Inst::Split(0, 0))?);
+                    self.compile(hir)?;
+                }
+                let end = self.program.len();
+                for at in splits {
+                    self.patch(at, split(at + 1, end));
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+fn count_groups(hir: &Hir) -> usize {
+    match hir.kind() {
+        HirKind::Group(group) => {
+            let index = match group.kind {
+                GroupKind::CaptureIndex(index) | GroupKind::CaptureName { index, .. } => {
+                    index as usize
+                }
+                GroupKind::NonCapturing => 0,
+            };
+            index.max(count_groups(&group.hir))
+        }
+        HirKind::Repetition(repetition) => count_groups(&repetition.hir),
+        HirKind::Concat(items) | HirKind::Alternation(items) => {
+            items.iter().map(count_groups).max().unwrap_or(0)
+        }
+        _ => 0,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn find_all<'a>(pattern: &str, s: &'a str) -> Vec<&'a str> {
+        Regex::new(pattern)
+            .unwrap()
+            .find_all(s)
+            .into_iter()
+            .map(|(start, end)| &s[start..end])
+            .collect()
+    }
+
+    #[test]
+    fn matches_are_leftmost_first() {
+        assert_eq!(find_all(r"\d+", "a1 22 333"), ["1", "22", "333"]);
+        assert_eq!(find_all("a|ab", "ab"), ["a"]);
+        assert_eq!(find_all("ab|a", "ab"), ["ab"]);
+        assert_eq!(find_all("a+?", "aaa"), ["a", "a", "a"]);
+        assert_eq!(find_all("x*", "ab"), ["", "", ""]);
+        assert_eq!(find_all(r"\bcat\b", "cat concat cat"), ["cat", "cat"]);
+        assert_eq!(find_all("(?m)^a", "a\nab\nb"), ["a", "a"]);
+        assert_eq!(find_all("é.", "aébéc"), ["éb", "éc"]);
+    }
+
+    #[test]
+    fn captures_follow_the_preferred_path() {
+        let regex = Regex::new(r"(a*)(b)?(\w+)").unwrap();
+        assert_eq!(
+            regex.captures("xaab"),
+            Some(vec![Some((0, 4)), Some((0, 0)), None, Some((0, 4))])
+        );
+        let regex = Regex::new("(a|ab)(c|bcd)").unwrap();
+        assert_eq!(
+            regex.captures("abcd"),
+            Some(vec![Some((0, 4)), Some((0, 1)), Some((1, 4))])
+        );
+        assert_eq!(
+            Regex::new("(a)|b").unwrap().captures("b"),
+            Some(vec![Some((0, 1)), None])
+        );
+    }
+
+    #[test]
+    fn counted_repetitions_obey_their_bounds() {
+        assert_eq!(find_all("a{2}", "aaaaa"), ["aa", "aa"]);
+        assert_eq!(find_all("a{2,3}", "aaaaaaa"), ["aaa", "aaa"]);
+        assert_eq!(find_all("a{2,}", "a aa aaaa"), ["aa", "aaaa"]);
+        assert_eq!(find_all("(?:ab){0,2}c", "ababababc"), ["ababc"]);
+    }
+
+    // Each of these takes exponential time for a backtracking matcher
+    #[test]
+    fn nested_repetition_stays_linear() {
+        let input = "a".repeat(5000);
+        for pattern in ["(a|aa)*c", "(a*)*c", "(a+)+c", "(?:a?){40}a{40}c"] {
+            assert!(!Regex::new(pattern).unwrap().is_match(&input), "{pattern}");
+        }
+        let input = format!("{input}c");
+        assert!(Regex::new("(a|aa)*c").unwrap().is_match(&input));
+    }
+
+    #[test]
+    fn oversized_programs_are_refused() {
+        assert_eq!(
+            Regex::new("(a{1000}){1000}").err().unwrap(),
+            "pattern is too large, it needs more than 100000 steps"
+        );
+    }
+}
@@ -0,0 +1,94 @@
+use std::{
+    cell::RefCell,
+    collections::HashMap,
+    fmt::{Debug, Display, Formatter},
+};
+
+// An interned identifier. Scopes are keyed on these, so a variable lookup
+// hashes and compares a u32 instead of a whole string
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Symbol(u32);
+
+#[derive(Default)]
+struct Interner {
+    ids: HashMap<&'static str, Symbol>,
+    names: Vec<&'static str>,
+}
+
+thread_local! {
+    static INTERNER: RefCell<Interner> = RefCell::new(Interner::default());
+}
+
+impl Symbol {
+    // Names are leaked so as_str can hand them out freely, there is only
+    // ever one copy of each distinct identifier
+    pub fn intern(name: &str) -> Self {
+        INTERNER.with(|interner| {
+            let mut interner = interner.borrow_mut();
+            if let Some(symbol) = interner.ids.get(name) {
+                return *symbol;
+            }
+            let name: &'static str = Box::leak(name.to_string().into_boxed_str());
+            let symbol = Symbol(interner.names.len() as u32);
+            interner.names.push(name);
+            interner.ids.insert(name, symbol);
+            symbol
+        })
+    }
+
+    // Only finds names that were already interned, for names built at
+    // runtime that shouldn't be leaked just to be looked up
+    pub fn get(name: &str) -> Option<Self> {
+        INTERNER.with(|interner| interner.borrow().ids.get(name).copied())
+    }
+
+    pub fn as_str(self) -> &'static str {
+        INTERNER.with(|interner| interner.borrow().names[self.0 as usize])
+    }
+}
+
+impl Display for Symbol {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl Debug for Symbol {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "{:?}", self.as_str())
+    }
+}
+
+impl PartialEq<str> for Symbol {
+    fn eq(&self, other: &str) -> bool {
+        self.as_str() == other
+    }
+}
+
+impl PartialEq<&str> for Symbol {
+    fn eq(&self, other: &&str) -> bool {
+        self.as_str() == *other
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn interning_gives_one_symbol_per_name() {
+        let a = Symbol::intern("symbol_test_a");
+        assert_eq!(Symbol::intern("symbol_test_a"), a);
+        assert_ne!(Symbol::intern("symbol_test_b"), a);
+        assert_eq!(a.as_str(), "symbol_test_a");
+        assert_eq!(a.to_string(), "symbol_test_a");
+    }
+
+    #[test]
+    fn get_does_not_intern() {
+        assert_eq!(Symbol::get("symbol_test_never_interned"), None);
+        assert_eq!(Symbol::get("symbol_test_never_interned"), None);
+        let c = Symbol::intern("symbol_test_c");
+        assert_eq!(Symbol::get("symbol_test_c"), Some(c));
+    }
+}
@@ -0,0 +1,128 @@
+// Helpers for the unit tests: running a snippet on both engines and reading
+// back what it printed or the variables it left behind
+use {
+    crate::{
+        interpreter::{IntArithmetic, Interpreter, RuntimeError, Value},
+        lexer::Token,
+        parser::{Expr, Parser},
+        symbol::Symbol,
+    },
+    logos::Logos,
+    std::{
+        cell::RefCell,
+        io::{self, Write},
+        rc::Rc,
+    },
+};
+
+// An output sink that can still be read after the interpreter owns it
+#[derive(Clone, Default)]
+pub struct Output(Rc<RefCell<Vec<u8>>>);
+
+impl Output {
+    pub fn text(&self) -> String {
+        String::from_utf8(self.0.borrow().clone()).unwrap()
+    }
+}
+
+impl Write for Output {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.0.borrow_mut().extend_from_slice(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+pub fn parse(source: &str) -> Vec<Expr> {
+    let mut tokens = Token::lexer(source).peekable();
+    Parser::new(&mut tokens).parse().unwrap()
+}
+
+pub fn interpreter(source: &str) -> (Interpreter, Output) {
+    let mut interpreter = Interpreter::new(parse(source));
+    let output = Output::default();
+    interpreter.context.output = Box::new(output.clone());
+    (interpreter, output)
+}
+
+pub fn run(source: &str) -> Result<String, RuntimeError> {
+    let (mut interpreter, output) = interpreter(source);
+    interpreter.run()?;
+    Ok(output.text())
+}
+
+pub fn run_bytecode(source: &str) -> Result<String, RuntimeError> {
+    let (mut interpreter, output) = interpreter(source);
+    interpreter.run_bytecode()?;
+    Ok(output.text())
+}
+
+// What the program printed, after checking both engines agree on it
+pub fn output(source: &str) -> String {
+    let printed = run(source);
+    assert_eq!(
+        printed,
+        run_bytecode(source),
+        "engines disagree on {source:?}"
+    );
+    printed.unwrap()
+}
+
+// The value the program left in the given top-level variable
+pub fn global(source: &str, name: &str) -> Value {
+    let (mut interpreter, _) = interpreter(source);
+    interpreter.run().unwrap();
+    *interpreter.state.toplevel_scope[&Symbol::intern(name)].clone()
+}
+
+// The error the program stops with, on both engines
+pub fn error(source: &str) -> RuntimeError {
+    let result = run(source);
+    assert_eq!(
+        result,
+        run_bytecode(source),
+        "engines disagree on {source:?}"
+    );
+    result.unwrap_err()
+}
+
+// Runs on both engines with the integer width swapped out first
+fn run_at_width<I: IntArithmetic + Default + 'static>(
+    source: &str,
+) -> Result<String, RuntimeError> {
+    let run = |bytecode| {
+        let (mut interpreter, output) = interpreter(source);
+        interpreter.context.integers = Box::new(I::default());
+        if bytecode {
+            interpreter.run_bytecode()
+        } else {
+            interpreter.run()
+        }
+        .map(|()| output.text())
+    };
+    let result = run(false);
+    assert_eq!(result, run(true), "engines disagree on {source:?}");
+    result
+}
+
+// Like output, at the given integer width
+pub fn output_at_width<I: IntArithmetic + Default + 'static>(source: &str) -> String {
+    run_at_width::<I>(source).unwrap()
+}
+
+// Like error, at the given integer width
+pub fn error_at_width<I: IntArithmetic + Default + 'static>(source: &str) -> RuntimeError {
+    run_at_width::<I>(source).unwrap_err()
+}
+
+// The builtin an error blames, so a test can check that without spelling
+// out the whole panic message
+pub fn panicked_builtin(err: RuntimeError) -> String {
+    match err {
+        RuntimeError::BuiltinPanicked { name, .. } => name,
+        other => panic!("Expected a builtin panic, got {other:?}"),
+    }
+}
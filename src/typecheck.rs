@@ -0,0 +1,347 @@
+use {
+    crate::{
+        interpreter::{get_valuetype_from, ValueType},
+        lexer::Token,
+        parser::{Expr, Operator},
+        visitor::{walk_expr, walk_exprs, ExprVisitor},
+    },
+    std::{collections::HashMap, fmt::Display},
+};
+
+#[derive(Debug)]
+pub struct TypeError {
+    pub message: String,
+}
+
+impl Display for TypeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
+
+// Variables whose type can't be known before running are left out, so only
+// values that are definitely wrong get reported
+type Env = HashMap<String, ValueType>;
+
+#[derive(Default)]
+struct AssignedNames(Vec<String>);
+
+impl ExprVisitor for AssignedNames {
+    fn visit_expr(&mut self, expr: &Expr) {
+        if let Expr::BinaryExpr {
+            op: Operator::SetVal(_),
+            lhs,
+            ..
+        } = expr
+        {
+            match lhs.as_ref() {
+                Expr::Tuple { items } => self.0.extend(items.iter().map(Expr::to_string)),
+                lhs => self.0.push(lhs.to_string()),
+            }
+        }
+        walk_expr(self, expr);
+    }
+}
+
+// Conditions are only required to be bools in strict mode. Otherwise if and
+// while treat anything other than true as false, like the interpreter does
+#[derive(Clone, Copy, Default)]
+pub struct Options {
+    pub strict: bool,
+}
+
+pub fn check(exprs: &[Expr], options: Options) -> Result<(), TypeError> {
+    check_block(exprs, &mut Env::new(), options)
+}
+
+fn check_block(exprs: &[Expr], env: &mut Env, options: Options) -> Result<(), TypeError> {
+    for expr in exprs {
+        infer(expr, env, options)?;
+    }
+    Ok(())
+}
+
+fn expect_bool(value_type: Option<ValueType>, context: &str) -> Result<(), TypeError> {
+    match value_type {
+        Some(ValueType::Bool) | None => Ok(()),
+        Some(value_type) => Err(TypeError {
+            message: format!("Expected bool for {context}, got {value_type}"),
+        }),
+    }
+}
+
+fn infer(expr: &Expr, env: &mut Env, options: Options) -> Result<Option<ValueType>, TypeError> {
+    match expr {
+        Expr::Token(token) => Ok(match token {
+            Token::Int(_) => Some(ValueType::Int),
+            Token::Float(_) => Some(ValueType::Float),
+            Token::String(_) => Some(ValueType::String),
+            Token::Bool(_) => Some(ValueType::Bool),
+            Token::Identifier(name) => env.get(name.as_str()).cloned(),
+            _ => None,
+        }),
+        Expr::BinaryExpr {
+            op: Operator::SetVal(expected_type),
+            lhs,
+            rhs,
+        } => {
+            let rhs_type = infer(rhs, env, options)?;
+            if let Expr::Tuple { items: names } = lhs.as_ref() {
+                for name in names {
+                    env.remove(&name.to_string());
+                }
+                return Ok(rhs_type);
+            }
+            let value_type = match expected_type {
+                Some(expected_type) => Some(get_valuetype_from(expected_type)),
+                None => rhs_type,
+            };
+            match &value_type {
+                Some(value_type) => env.insert(lhs.to_string(), value_type.clone()),
+                None => env.remove(&lhs.to_string()),
+            };
+            Ok(value_type)
+        }
+        Expr::BinaryExpr {
+            op: op @ (Operator::And | Operator::Or),
+            lhs,
+            rhs,
+        } => {
+            expect_bool(infer(lhs, env, options)?, &format!("operand of {op}"))?;
+            expect_bool(infer(rhs, env, options)?, &format!("operand of {op}"))?;
+            Ok(Some(ValueType::Bool))
+        }
+        Expr::BinaryExpr {
+            op:
+                Operator::Eq
+                | Operator::Neq
+                | Operator::Lt
+                | Operator::Gt
+                | Operator::Le
+                | Operator::Ge
+                | Operator::In,
+            lhs,
+            rhs,
+        } => {
+            infer(lhs, env, options)?;
+            infer(rhs, env, options)?;
+            Ok(Some(ValueType::Bool))
+        }
+        Expr::BinaryExpr { lhs, rhs, .. } => {
+            infer(lhs, env, options)?;
+            infer(rhs, env, options)?;
+            Ok(None)
+        }
+        Expr::UnaryExpr {
+            op: op @ Operator::Not,
+            operand,
+        } => {
+            expect_bool(infer(operand, env, options)?, &format!("operand of {op}"))?;
+            Ok(Some(ValueType::Bool))
+        }
+        Expr::UnaryExpr { operand, .. } => infer(operand, env, options),
+        Expr::FnCall { callee, args } => {
+            if let (Expr::Token(Token::Identifier(name)), [a, b]) =
+                (callee.as_ref(), args.as_slice())
+            {
+                if name == "swap" {
+                    let (a, b) = (a.to_string(), b.to_string());
+                    let (a_type, b_type) = (env.remove(&a), env.remove(&b));
+                    if let Some(b_type) = b_type {
+                        env.insert(a, b_type);
+                    }
+                    if let Some(a_type) = a_type {
+                        env.insert(b, a_type);
+                    }
+                    return Ok(Some(ValueType::Nothing));
+                }
+            }
+            if let (Expr::Token(Token::Identifier(name)), [target]) =
+                (callee.as_ref(), args.as_slice())
+            {
+                if name == "delete" {
+                    env.remove(&target.to_string());
+                    return Ok(Some(ValueType::Nothing));
+                }
+            }
+            infer(callee, env, options)?;
+            for arg in args {
+                infer(arg, env, options)?;
+            }
+            Ok(None)
+        }
+        Expr::FnDef {
+            name, args, body, ..
+        } => {
+            env.insert(name.to_string(), ValueType::Fn);
+            // Globals may be reassigned before the function is called, so only
+            // the declared argument types are known inside the body
+            let mut fn_env = Env::new();
+            for ((_, arg_name), arg_type) in args {
+                match arg_type {
+                    Expr::Token(Token::Type(t)) => {
+                        fn_env.insert(arg_name.to_string(), get_valuetype_from(t));
+                    }
+                    _ => unreachable!("This should always a be a type token"),
+                }
+            }
+            check_block(body, &mut fn_env, options)?;
+            Ok(Some(ValueType::Nothing))
+        }
+        Expr::Lambda { args, body } => {
+            let mut fn_env = Env::new();
+            for ((_, arg_name), arg_type) in args {
+                match arg_type {
+                    Expr::Token(Token::Type(t)) if t != "any" => {
+                        fn_env.insert(arg_name.to_string(), get_valuetype_from(t));
+                    }
+                    Expr::Token(Token::Type(_)) => (),
+                    _ => unreachable!("This should always be a type token"),
+                }
+            }
+            check_block(body, &mut fn_env, options)?;
+            Ok(Some(ValueType::Fn))
+        }
+        Expr::If {
+            cond,
+            body,
+            else_body,
+        } => {
+            let cond_type = infer(cond, env, options)?;
+            if options.strict {
+                expect_bool(cond_type, "if condition")?;
+            }
+            check_block(body, &mut env.clone(), options)?;
+            if let Some(else_body) = else_body {
+                check_block(else_body, &mut env.clone(), options)?;
+            }
+            Ok(Some(ValueType::Nothing))
+        }
+        Expr::MatchType { subject, arms } => {
+            infer(subject, env, options)?;
+            for (_, body) in arms {
+                check_block(body, &mut env.clone(), options)?;
+            }
+            Ok(None)
+        }
+        Expr::While { cond, body, .. } | Expr::DoWhile { body, cond } => {
+            // Anything assigned in the body may hold a different type on the
+            // next iteration, so those variables are treated as unknown. The
+            // same goes for an else body, which might not run at all
+            let else_body = match expr {
+                Expr::While { else_body, .. } => else_body.as_deref(),
+                _ => None,
+            };
+            let mut assigned = AssignedNames::default();
+            walk_exprs(&mut assigned, body);
+            walk_exprs(&mut assigned, else_body.unwrap_or_default());
+            for name in assigned.0 {
+                env.remove(&name);
+            }
+            let cond_type = infer(cond, env, options)?;
+            if options.strict {
+                expect_bool(cond_type, "loop condition")?;
+            }
+            check_block(body, env, options)?;
+            if let Some(else_body) = else_body {
+                check_block(else_body, &mut env.clone(), options)?;
+            }
+            Ok(Some(ValueType::Nothing))
+        }
+        Expr::For {
+            var,
+            iterable,
+            body,
+        } => {
+            infer(iterable, env, options)?;
+            let mut assigned = AssignedNames::default();
+            walk_exprs(&mut assigned, body);
+            for name in assigned.0 {
+                env.remove(&name);
+            }
+            // The element type isn't tracked, so the loop variable is unknown
+            env.remove(var.as_str());
+            check_block(body, env, options)?;
+            Ok(Some(ValueType::Nothing))
+        }
+        Expr::Try {
+            body,
+            error_name,
+            catch_body,
+        } => {
+            check_block(body, &mut env.clone(), options)?;
+            let mut catch_env = env.clone();
+            catch_env.insert(error_name.to_string(), ValueType::String);
+            check_block(catch_body, &mut catch_env, options)?;
+            Ok(None)
+        }
+        Expr::Break | Expr::Continue => Ok(None),
+        Expr::Return { inner } => {
+            infer(inner, env, options)?;
+            Ok(None)
+        }
+        Expr::Array { items } => {
+            for item in items {
+                infer(item, env, options)?;
+            }
+            Ok(Some(ValueType::Array))
+        }
+        Expr::Map { entries } => {
+            for (key, value) in entries {
+                infer(key, env, options)?;
+                infer(value, env, options)?;
+            }
+            Ok(Some(ValueType::Map))
+        }
+        Expr::Tuple { items } => {
+            let mut types = Vec::new();
+            for item in items {
+                types.push(infer(item, env, options)?);
+            }
+            Ok(types
+                .into_iter()
+                .collect::<Option<_>>()
+                .map(ValueType::Tuple))
+        }
+        Expr::Index { target, index } => {
+            infer(target, env, options)?;
+            infer(index, env, options)?;
+            Ok(None)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::*, crate::testing::parse};
+
+    fn check_source(source: &str, strict: bool) -> Result<(), TypeError> {
+        check(&parse(source), Options { strict })
+    }
+
+    #[test]
+    fn non_bool_operands_of_logical_operators_are_rejected() {
+        let err = check_source("x := 1 && true;", false).unwrap_err();
+        assert_eq!(err.message, "Expected bool for operand of &&, got int");
+        assert!(check_source("x := !\"s\";", false).is_err());
+        assert!(check_source("x := true || 1 < 2;", false).is_ok());
+    }
+
+    #[test]
+    fn non_bool_conditions_are_only_rejected_when_strict() {
+        let source = "if 1 { println(1); }";
+        assert!(check_source(source, false).is_ok());
+        let err = check_source(source, true).unwrap_err();
+        assert_eq!(err.message, "Expected bool for if condition, got int");
+        assert!(check_source("while \"s\" { println(1); }", true).is_err());
+        assert!(check_source("if 1 < 2 { println(1); }", true).is_ok());
+    }
+
+    #[test]
+    fn swap_exchanges_the_known_types() {
+        let before = "x := 1; y := true;";
+        assert!(check_source(&format!("{before} z := x && true;"), false).is_err());
+        assert!(check_source(&format!("{before} swap(x, y); z := x && true;"), false).is_ok());
+        assert!(check_source(&format!("{before} swap(x, y); z := y && true;"), false).is_err());
+    }
+}
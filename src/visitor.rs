@@ -0,0 +1,125 @@
+use crate::{lexer::Token, parser::Expr};
+
+pub trait ExprVisitor {
+    fn visit_expr(&mut self, expr: &Expr) {
+        walk_expr(self, expr);
+    }
+
+    fn visit_token(&mut self, _token: &Token) {}
+}
+
+pub fn walk_exprs<V: ExprVisitor + ?Sized>(visitor: &mut V, exprs: &[Expr]) {
+    for expr in exprs {
+        visitor.visit_expr(expr);
+    }
+}
+
+pub fn walk_expr<V: ExprVisitor + ?Sized>(visitor: &mut V, expr: &Expr) {
+    match expr {
+        Expr::Token(token) => visitor.visit_token(token),
+        Expr::BinaryExpr { lhs, rhs, .. } => {
+            visitor.visit_expr(lhs);
+            visitor.visit_expr(rhs);
+        }
+        Expr::UnaryExpr { operand, .. } => visitor.visit_expr(operand),
+        Expr::FnCall { callee, args } => {
+            visitor.visit_expr(callee);
+            walk_exprs(visitor, args);
+        }
+        // The argument map only holds type tokens, so just the body is walked
+        Expr::FnDef { body, .. } | Expr::Lambda { body, .. } => walk_exprs(visitor, body),
+        Expr::If {
+            cond,
+            body,
+            else_body,
+        } => {
+            visitor.visit_expr(cond);
+            walk_exprs(visitor, body);
+            if let Some(else_body) = else_body {
+                walk_exprs(visitor, else_body);
+            }
+        }
+        Expr::MatchType { subject, arms } => {
+            visitor.visit_expr(subject);
+            for (_, body) in arms {
+                walk_exprs(visitor, body);
+            }
+        }
+        Expr::While {
+            cond,
+            body,
+            else_body,
+        } => {
+            visitor.visit_expr(cond);
+            walk_exprs(visitor, body);
+            if let Some(else_body) = else_body {
+                walk_exprs(visitor, else_body);
+            }
+        }
+        // The body runs before the condition is first checked
+        Expr::DoWhile { body, cond } => {
+            walk_exprs(visitor, body);
+            visitor.visit_expr(cond);
+        }
+        Expr::For { iterable, body, .. } => {
+            visitor.visit_expr(iterable);
+            walk_exprs(visitor, body);
+        }
+        Expr::Try {
+            body, catch_body, ..
+        } => {
+            walk_exprs(visitor, body);
+            walk_exprs(visitor, catch_body);
+        }
+        Expr::Break | Expr::Continue => (),
+        Expr::Return { inner } => visitor.visit_expr(inner),
+        Expr::Array { items } | Expr::Tuple { items } => walk_exprs(visitor, items),
+        Expr::Map { entries } => {
+            for (key, value) in entries {
+                visitor.visit_expr(key);
+                visitor.visit_expr(value);
+            }
+        }
+        Expr::Index { target, index } => {
+            visitor.visit_expr(target);
+            visitor.visit_expr(index);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::*, crate::testing::parse};
+
+    // Collects every identifier a program mentions, in order
+    #[derive(Default)]
+    struct Identifiers(Vec<String>);
+
+    impl ExprVisitor for Identifiers {
+        fn visit_token(&mut self, token: &Token) {
+            if let Token::Identifier(name) = token {
+                self.0.push(name.to_string());
+            }
+        }
+    }
+
+    #[test]
+    fn walk_visits_every_identifier() {
+        let mut identifiers = Identifiers::default();
+        walk_exprs(
+            &mut identifiers,
+            &parse("x := 1; if x > limit { println(x + y); } while done { tick(); }"),
+        );
+        assert_eq!(
+            identifiers.0,
+            ["x", "x", "limit", "println", "x", "y", "done", "tick"]
+        );
+    }
+
+    #[test]
+    fn do_while_is_walked_in_the_order_it_runs() {
+        let mut identifiers = Identifiers::default();
+        walk_exprs(&mut identifiers, &parse("do { step(); } while more;"));
+        assert_eq!(identifiers.0, ["step", "more"]);
+    }
+}